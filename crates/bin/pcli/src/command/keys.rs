@@ -1,11 +1,19 @@
 use std::io::Read;
 use std::str::FromStr;
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
+use camino::Utf8PathBuf;
+use dialoguer::Confirm;
 use directories::ProjectDirs;
+use penumbra_asset::asset;
 use penumbra_crypto::keys::SeedPhrase;
+use penumbra_proto::client::v1alpha1::{
+    tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
+};
+use penumbra_transaction::plan::TransactionPlan;
 use rand_core::OsRng;
 use sha2::{Digest, Sha256};
+use url::Url;
 
 use crate::KeyStore;
 
@@ -21,13 +29,30 @@ pub enum KeysCmd {
     Generate,
     /// Delete the entire wallet permanently.
     Delete,
+    /// Sign a transaction plan written by `--plan-only`, for use on an offline machine.
+    ///
+    /// The resulting authorization data can be passed to `pcli tx finalize` along with the plan.
+    SignPlan {
+        /// The file containing the transaction plan to sign.
+        plan: Utf8PathBuf,
+        /// The file to write the authorization data to.
+        #[clap(long, short)]
+        output: Utf8PathBuf,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ImportCmd {
     /// Import wallet from an existing 24-word seed phrase. Will prompt for input interactively.
     /// Also accepts input from stdin, for use with pipes.
-    Phrase,
+    Phrase {
+        /// The block height at which the wallet was created, if known.
+        ///
+        /// Syncing will start from shortly before this height rather than from genesis, so any
+        /// notes received by the wallet before it will not be found.
+        #[clap(long)]
+        birthday: Option<u64>,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -64,7 +89,24 @@ impl KeysCmd {
         Ok(())
     }
 
-    pub fn exec(&self, data_dir: impl AsRef<camino::Utf8Path>) -> Result<()> {
+    /// Fetch the current height of the chain, to use as the birthday of a new wallet.
+    async fn current_height(node: &Url) -> Result<u64> {
+        let mut client = TendermintProxyServiceClient::new(crate::network::pd_channel(node).await?);
+        let sync_info = client
+            .get_status(GetStatusRequest {})
+            .await?
+            .into_inner()
+            .sync_info
+            .ok_or_else(|| anyhow!("could not parse sync_info in gRPC response"))?;
+        Ok(sync_info.latest_block_height)
+    }
+
+    pub async fn exec(
+        &self,
+        data_dir: impl AsRef<camino::Utf8Path>,
+        node: &Url,
+        yes: bool,
+    ) -> Result<()> {
         let data_dir = data_dir.as_ref();
         match self {
             KeysCmd::Generate => {
@@ -74,11 +116,20 @@ impl KeysCmd {
                 // shared by users accidentally in log output.
                 println!("YOUR PRIVATE SEED PHRASE: {seed_phrase}\nDO NOT SHARE WITH ANYONE!");
 
-                let wallet = KeyStore::from_seed_phrase(seed_phrase);
+                let mut wallet = KeyStore::from_seed_phrase(seed_phrase);
+                // Nothing can have been sent to a freshly generated key, so there's no need to
+                // sync any earlier blocks.
+                match Self::current_height(node).await {
+                    Ok(height) => wallet.birthday = Some(height),
+                    Err(e) => tracing::warn!(
+                        ?e,
+                        "could not fetch current height from {node}, wallet will sync from genesis"
+                    ),
+                }
                 wallet.save(data_dir.join(crate::CUSTODY_FILE_NAME))?;
                 self.archive_wallet(&wallet)?;
             }
-            KeysCmd::Import(ImportCmd::Phrase) => {
+            KeysCmd::Import(ImportCmd::Phrase { birthday }) => {
                 let mut seed_phrase = String::new();
                 // The `rpassword` crate doesn't support reading from stdin, so we check
                 // for an interactive session. We must support non-interactive use cases,
@@ -94,7 +145,8 @@ impl KeysCmd {
                         seed_phrase = seed_phrase.trim().to_string();
                     }
                 }
-                let wallet = KeyStore::from_seed_phrase(SeedPhrase::from_str(&seed_phrase)?);
+                let mut wallet = KeyStore::from_seed_phrase(SeedPhrase::from_str(&seed_phrase)?);
+                wallet.birthday = *birthday;
                 wallet.save(data_dir.join(crate::CUSTODY_FILE_NAME))?;
                 self.archive_wallet(&wallet)?;
             }
@@ -107,6 +159,45 @@ impl KeysCmd {
                 let account_group_id = wallet.spend_key.full_viewing_key().account_group_id();
                 println!("{}", serde_json::to_string_pretty(&account_group_id)?);
             }
+            KeysCmd::SignPlan { plan, output } => {
+                let wallet = KeyStore::load(data_dir.join(crate::CUSTODY_FILE_NAME))?;
+                let plan: TransactionPlan = serde_json::from_slice(
+                    &std::fs::read(plan).with_context(|| format!("can't read plan file {plan}"))?,
+                )
+                .context("can't parse plan file")?;
+                if output.exists() {
+                    return Err(anyhow!("refusing to overwrite existing file {output}"));
+                }
+
+                print!(
+                    "{}",
+                    plan.review(
+                        wallet.spend_key.full_viewing_key(),
+                        asset::Cache::with_known_assets()
+                    )
+                );
+
+                if !yes {
+                    // Confirmation needs an interactive session; non-interactive callers must
+                    // opt in explicitly.
+                    if !atty::is(atty::Stream::Stdin) {
+                        return Err(anyhow!(
+                            "confirmation requires an interactive session; pass --yes to skip it"
+                        ));
+                    }
+                    if !Confirm::new()
+                        .with_prompt("Do you want to sign this transaction?")
+                        .interact()?
+                    {
+                        return Ok(());
+                    }
+                }
+
+                let auth_data = plan.authorize(OsRng, &wallet.spend_key);
+                std::fs::write(output, serde_json::to_string_pretty(&auth_data)?)
+                    .with_context(|| format!("could not write authorization data to {output}"))?;
+                println!("wrote authorization data to {output}");
+            }
             KeysCmd::Delete => {
                 let wallet_path = data_dir.join(crate::CUSTODY_FILE_NAME);
                 if wallet_path.is_file() {
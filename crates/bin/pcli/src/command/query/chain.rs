@@ -83,6 +83,14 @@ impl ChainCmd {
                 "Missed Blocks Max",
                 &format!("{}", params.missed_blocks_maximum),
             ])
+            .add_row(vec![
+                "Max Commission Change (bps/epoch)",
+                &format!("{}", params.max_commission_change_bps),
+            ])
+            .add_row(vec![
+                "Commission Increase Delay (epochs)",
+                &format!("{}", params.commission_increase_delay_epochs),
+            ])
             .add_row(vec![
                 "Proposal Deposit Amount (upenumbra)",
                 &format!("{}", params.proposal_deposit_amount),
@@ -95,7 +103,26 @@ impl ChainCmd {
             .add_row(vec![
                 "Outbound ICS-20 Enabled",
                 &format!("{}", params.outbound_ics20_transfers_enabled),
+            ])
+            .add_row(vec![
+                "ICS-20 Rate Limit Window (blocks)",
+                &format!("{}", params.ics20_rate_limit_window_blocks),
+            ]);
+
+        for limit in &params.ics20_rate_limits {
+            let display = |limit: Option<_>| match limit {
+                Some(limit) => format!("{limit}"),
+                None => "unlimited".to_string(),
+            };
+            table.add_row(vec![
+                format!("ICS-20 Rate Limit ({}, {})", limit.channel_id, limit.denom),
+                format!(
+                    "in: {}, out: {}",
+                    display(limit.inbound_limit),
+                    display(limit.outbound_limit)
+                ),
             ]);
+        }
 
         println!("{table}");
 
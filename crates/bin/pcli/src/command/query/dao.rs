@@ -1,10 +1,11 @@
 use anyhow::{Context, Result};
 use futures::TryStreamExt;
 //use penumbra_app::dao;
-use penumbra_dao::component::state_key;
+use penumbra_dao::{component::state_key, DaoGrant};
 
 use penumbra_asset::{asset, Value};
 use penumbra_num::Amount;
+use penumbra_proto::client::v1alpha1::{PrefixValueRequest, PrefixValueResponse};
 use penumbra_view::ViewClient;
 
 use crate::{command::query::dao, App};
@@ -16,12 +17,15 @@ pub enum DaoCmd {
         /// Get only the balance of the specified asset.
         asset: Option<String>,
     },
+    /// List the DAO grants which are still being paid.
+    Grants,
 }
 
 impl DaoCmd {
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         match self {
             DaoCmd::Balance { asset } => self.print_balance(app, asset).await,
+            DaoCmd::Grants => self.print_grants(app).await,
         }
     }
 
@@ -70,4 +74,52 @@ impl DaoCmd {
 
         Ok(())
     }
+
+    pub async fn print_grants(&self, app: &mut App) -> Result<()> {
+        let mut client = app.specific_client().await?;
+        let asset_cache = app.view().assets().await?;
+
+        let results: Vec<PrefixValueResponse> = client
+            .prefix_value(PrefixValueRequest {
+                prefix: state_key::all_active_grants().into(),
+                ..Default::default()
+            })
+            .await?
+            .into_inner()
+            .try_collect()
+            .await?;
+
+        println!("Active DAO grants ({}):", results.len());
+
+        for PrefixValueResponse { key, .. } in results {
+            let grant_id: u64 = key
+                .rsplit('/')
+                .next()
+                .expect("valid key")
+                .parse()
+                .expect("valid grant ID");
+            let grant: DaoGrant = client
+                .key_domain(state_key::grant(grant_id))
+                .await?
+                .context(format!("No definition found for grant {grant_id}"))?;
+            let remaining: u64 = client
+                .key_proto(state_key::grant_remaining(grant_id))
+                .await?
+                .context(format!("No remaining count found for grant {grant_id}"))?;
+            let next_payment_epoch: u64 = client
+                .key_proto(state_key::grant_next_payment_epoch(grant_id))
+                .await?
+                .context(format!("No next payment found for grant {grant_id}"))?;
+
+            println!(
+                "#{grant_id}: {} to {} every {} epoch(s), {remaining} of {} remaining, next at end of epoch {next_payment_epoch}",
+                grant.value.format(&asset_cache),
+                grant.address,
+                grant.period_epochs,
+                grant.count,
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -7,12 +7,18 @@ use std::{
 use anyhow::{Context, Result};
 use futures::{StreamExt, TryStreamExt};
 use penumbra_app::governance::{self, state_key::*};
-use penumbra_proto::client::v1alpha1::{PrefixValueRequest, PrefixValueResponse};
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
+use penumbra_proto::client::v1alpha1::{
+    PrefixValueRequest, PrefixValueResponse, ProposalEventsRequest, ProposalEventsResponse,
+    UpgradePlanRequest, UpgradePlanResponse,
+};
 use penumbra_stake::IdentityKey;
 use penumbra_transaction::{
-    proposal::{self, Proposal},
+    proposal::{self, Proposal, UpgradePlan},
     vote::Vote,
 };
+use penumbra_view::ViewClient;
 use serde::Serialize;
 use serde_json::json;
 
@@ -34,6 +40,23 @@ pub enum GovernanceCmd {
         #[clap(subcommand)]
         query: PerProposalCmd,
     },
+    /// Display the software upgrade scheduled by the most recently passed upgrade plan proposal.
+    UpgradePlan,
+    /// Display the total amount of proposal deposits which have been slashed and sent to the DAO.
+    SlashedDeposits,
+    /// Stream proposal lifecycle events (submissions, votes, tallies, and outcomes), one JSON
+    /// object per line.
+    Events {
+        /// The block height from which to replay past events.
+        #[clap(long, default_value = "0")]
+        from_height: u64,
+        /// Only show events for this proposal.
+        #[clap(long)]
+        proposal: Option<u64>,
+        /// Keep following new events as blocks are committed, after replaying past events.
+        #[clap(short, long)]
+        follow: bool,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -232,6 +255,74 @@ impl GovernanceCmd {
                     }))?;
                 }
             },
+            GovernanceCmd::UpgradePlan => {
+                let UpgradePlanResponse {
+                    upgrade_plan,
+                    current_height,
+                } = client
+                    .upgrade_plan(UpgradePlanRequest::default())
+                    .await?
+                    .into_inner();
+
+                match upgrade_plan.map(UpgradePlan::from) {
+                    Some(UpgradePlan { height, version }) => {
+                        json(&json!({
+                            "height": height,
+                            "version": version,
+                            "current_height": current_height,
+                            "pending": height > current_height,
+                        }))?;
+                    }
+                    None => {
+                        println!("No upgrade has been scheduled.");
+                    }
+                }
+            }
+            GovernanceCmd::SlashedDeposits => {
+                let total: Amount = client
+                    .key_domain(slashed_deposits_total())
+                    .await?
+                    .unwrap_or_default();
+                let asset_cache = app.view().assets().await?;
+                println!(
+                    "{}",
+                    Value {
+                        amount: total,
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    }
+                    .format(&asset_cache)
+                );
+            }
+            GovernanceCmd::Events {
+                from_height,
+                proposal,
+                follow,
+            } => {
+                let mut events = client
+                    .proposal_events(ProposalEventsRequest {
+                        start_height: *from_height,
+                        proposal_id: *proposal,
+                        keep_alive: *follow,
+                        ..Default::default()
+                    })
+                    .await?
+                    .into_inner();
+
+                let mut writer = stdout();
+                while let Some(ProposalEventsResponse { height, event }) =
+                    events.next().await.transpose()?
+                {
+                    serde_json::to_writer(
+                        &mut writer,
+                        &json!({
+                            "height": height,
+                            "event": event.context("missing proposal event")?,
+                        }),
+                    )?;
+                    writer.write_all(b"\n")?;
+                    writer.flush()?;
+                }
+            }
         }
 
         Ok(())
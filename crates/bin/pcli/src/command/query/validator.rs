@@ -102,18 +102,22 @@ impl ValidatorCmd {
                         0.0
                     };
                     let power_percent = 100.0 * active_voting_power / total_voting_power;
-                    let commission_bps = v
-                        .validator
-                        .funding_streams
-                        .as_ref()
-                        .iter()
-                        .map(|fs| fs.rate_bps())
-                        .sum::<u16>();
+                    let commission_bps = v.validator.funding_streams.total_rate_bps();
+                    // Show announced commission increases alongside the current commission, so
+                    // delegators can see them before they take effect.
+                    let commission = match &v.pending_commission {
+                        Some(pending) => format!(
+                            "{commission_bps}bps (-> {}bps in epoch {})",
+                            pending.funding_streams.total_rate_bps(),
+                            pending.effective_epoch_index,
+                        ),
+                        None => format!("{commission_bps}bps"),
+                    };
 
                     table.add_row(vec![
                         format!("{voting_power:.3}"),
                         format!("{power_percent:.2}%"),
-                        format!("{commission_bps}bps"),
+                        commission,
                         v.status.state.to_string(),
                         v.status.bonding_state.to_string(),
                         // TODO: consider rewriting this with term colors
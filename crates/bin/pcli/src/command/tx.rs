@@ -8,10 +8,11 @@ use std::{
 
 use anyhow::{anyhow, Context, Result};
 use ark_ff::UniformRand;
+use camino::Utf8PathBuf;
 use decaf377::{Fq, Fr};
 use ibc_types2::core::channel::{ChannelId, PortId};
 use penumbra_asset::{asset, asset::DenomMetadata, Value, STAKING_TOKEN_ASSET_ID};
-use penumbra_crypto::keys::AddressIndex;
+use penumbra_crypto::{keys::AddressIndex, FullViewingKey};
 use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
 use penumbra_fee::Fee;
 use penumbra_ibc::Ics20Withdrawal;
@@ -25,8 +26,14 @@ use penumbra_proto::{
 };
 use penumbra_stake::rate::RateData;
 use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
-use penumbra_transaction::{memo::MemoPlaintext, proposal::ProposalToml, vote::Vote};
-use penumbra_view::ViewClient;
+use penumbra_transaction::{
+    memo::MemoPlaintext,
+    plan::TransactionPlan,
+    proposal::ProposalToml,
+    vote::{SplitVote, Vote},
+    AuthorizationData,
+};
+use penumbra_view::{PaymentRequest, ViewClient};
 use penumbra_wallet::plan::{self, Planner};
 use rand_core::OsRng;
 
@@ -60,6 +67,21 @@ pub enum TxCmd {
         #[clap(long)]
         memo: Option<String>,
     },
+    /// Pay a payment request, given as a `penumbra:` URI.
+    ///
+    /// The requested value is sent to the requested address, with the request's reference, if
+    /// any, as the memo.
+    #[clap(display_order = 150)]
+    Pay {
+        /// The payment request to pay.
+        uri: String,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0", display_order = 200)]
+        fee: u64,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+    },
     /// Deposit stake into a validator's delegation pool.
     #[clap(display_order = 200)]
     Delegate {
@@ -75,6 +97,30 @@ pub enum TxCmd {
         #[clap(long, default_value = "0", display_order = 300)]
         source: u32,
     },
+    /// Periodically delegate an account's liquid staking tokens to a validator.
+    ///
+    /// This command runs until interrupted: every `interval` seconds, all of the
+    /// staking tokens held by the `source` account, except for the `reserve`
+    /// amount, are delegated to the validator. This can be used to compound
+    /// funding stream rewards paid out in staking tokens.
+    #[clap(display_order = 200)]
+    AutoDelegate {
+        /// The identity key of the validator to delegate to.
+        #[clap(long, display_order = 100)]
+        to: String,
+        /// How often to delegate the account's staking tokens, in seconds.
+        #[clap(long, default_value = "3600", display_order = 150)]
+        interval: u64,
+        /// The amount of staking tokens to leave undelegated, e.g. to pay future fees.
+        #[clap(long, default_value = "0penumbra", display_order = 160)]
+        reserve: String,
+        /// The transaction fee (paid in upenumbra).
+        #[clap(long, default_value = "0", display_order = 200)]
+        fee: u64,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+    },
     /// Withdraw stake from a validator's delegation pool.
     #[clap(display_order = 200)]
     Undelegate {
@@ -176,6 +222,20 @@ pub enum TxCmd {
 
         #[clap(long, default_value = "0", display_order = 200)]
         source: u32,
+
+        /// Optional. A memo to include in the transfer, for the destination chain to interpret:
+        /// for instance, instructions to forward the transfer on to another chain.
+        #[clap(long, display_order = 300)]
+        memo: Option<String>,
+    },
+    /// Build and broadcast a transaction from a plan written with `--plan-only` and the
+    /// authorization data produced for it by `pcli keys sign-plan`.
+    #[clap(display_order = 995)]
+    Finalize {
+        /// The file containing the transaction plan.
+        plan: Utf8PathBuf,
+        /// The file containing the authorization data for the plan.
+        authorization: Utf8PathBuf,
     },
 }
 
@@ -203,26 +263,52 @@ pub enum VoteCmd {
         #[clap(long = "on")]
         proposal_id: u64,
     },
+    /// Split your voting power between several options on a proposal (delegators only).
+    #[clap(display_order = 400)]
+    Split {
+        /// The proposal ID to vote on.
+        #[clap(long = "on")]
+        proposal_id: u64,
+        /// The percentage of voting power to vote for each option, e.g. "60% yes, 40% no".
+        ///
+        /// The percentages must sum to 100; any option not mentioned receives no voting power.
+        split: SplitVote,
+    },
 }
 
-impl From<VoteCmd> for (u64, Vote) {
-    fn from(cmd: VoteCmd) -> (u64, Vote) {
+impl From<VoteCmd> for (u64, SplitVote) {
+    fn from(cmd: VoteCmd) -> (u64, SplitVote) {
         match cmd {
-            VoteCmd::Yes { proposal_id } => (proposal_id, Vote::Yes),
-            VoteCmd::No { proposal_id } => (proposal_id, Vote::No),
-            VoteCmd::Abstain { proposal_id } => (proposal_id, Vote::Abstain),
+            VoteCmd::Yes { proposal_id } => (proposal_id, Vote::Yes.into()),
+            VoteCmd::No { proposal_id } => (proposal_id, Vote::No.into()),
+            VoteCmd::Abstain { proposal_id } => (proposal_id, Vote::Abstain.into()),
+            VoteCmd::Split { proposal_id, split } => (proposal_id, split),
         }
     }
 }
 
+impl TryFrom<VoteCmd> for (u64, Vote) {
+    type Error = anyhow::Error;
+
+    fn try_from(cmd: VoteCmd) -> Result<(u64, Vote)> {
+        let (proposal_id, split): (u64, SplitVote) = cmd.into();
+        let vote = split
+            .single()
+            .ok_or_else(|| anyhow!("split votes can only be cast by delegators"))?;
+        Ok((proposal_id, vote))
+    }
+}
+
 impl TxCmd {
     /// Determine if this command requires a network sync before it executes.
     pub fn offline(&self) -> bool {
         match self {
             TxCmd::Send { .. } => false,
+            TxCmd::Pay { .. } => false,
             TxCmd::Sweep { .. } => false,
             TxCmd::Swap { .. } => false,
             TxCmd::Delegate { .. } => false,
+            TxCmd::AutoDelegate { .. } => false,
             TxCmd::Undelegate { .. } => false,
             TxCmd::UndelegateClaim { .. } => false,
             TxCmd::Vote { .. } => false,
@@ -230,10 +316,27 @@ impl TxCmd {
             TxCmd::DaoDeposit { .. } => false,
             TxCmd::Position(lp_cmd) => lp_cmd.offline(),
             TxCmd::Withdraw { .. } => false,
+            TxCmd::Finalize { .. } => false,
         }
     }
 
     pub async fn exec(&self, app: &mut App) -> Result<()> {
+        // These commands submit several transactions, each depending on the last, so they can't
+        // be planned up front.
+        if app.plan_only.is_some()
+            && matches!(
+                self,
+                TxCmd::Sweep
+                    | TxCmd::Swap { .. }
+                    | TxCmd::AutoDelegate { .. }
+                    | TxCmd::UndelegateClaim { .. }
+            )
+        {
+            anyhow::bail!(
+                "--plan-only cannot be used with commands that submit several transactions"
+            );
+        }
+
         match self {
             TxCmd::Send {
                 values,
@@ -272,6 +375,47 @@ impl TxCmd {
                 .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Pay { uri, fee, source } => {
+                let request = uri.parse::<PaymentRequest>()?;
+                let current_height = app
+                    .view()
+                    .status(app.fvk.account_group_id())
+                    .await?
+                    .sync_height;
+                if request.is_expired(current_height) {
+                    anyhow::bail!(
+                        "payment request expired at height {}",
+                        request.expiry_height.unwrap_or_default()
+                    );
+                }
+
+                let fee = Fee::from_staking_token_amount((*fee).into());
+                let memo_plaintext = MemoPlaintext {
+                    sender: app
+                        .fvk
+                        .ephemeral_address(OsRng, AddressIndex::new(*source))
+                        .0,
+                    text: request.memo.clone().unwrap_or_default(),
+                };
+
+                let mut plan = plan::send(
+                    app.fvk.account_group_id(),
+                    app.view.as_mut().unwrap(),
+                    OsRng,
+                    &[request.value],
+                    fee,
+                    request.address,
+                    AddressIndex::new(*source),
+                    Some(memo_plaintext),
+                )
+                .await?;
+                // Payments made after the request expires don't count towards it, so the
+                // transaction shouldn't be included in a block past the expiry height.
+                if let Some(expiry_height) = request.expiry_height {
+                    plan.expiry_height = expiry_height;
+                }
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::DaoDeposit {
                 fee,
                 values,
@@ -310,7 +454,7 @@ impl TxCmd {
 
                 for (i, plan) in plans.into_iter().enumerate() {
                     println!("building sweep {i} of {num_plans}");
-                    let tx = app.build_transaction(plan).await?;
+                    let tx = app.review_and_build_transaction(plan).await?;
                     app.submit_transaction_unconfirmed(tx).await?;
                 }
                 if num_plans == 0 {
@@ -452,6 +596,86 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::AutoDelegate {
+                to,
+                interval,
+                reserve,
+                fee,
+                source,
+            } => {
+                let reserve = {
+                    let Value { amount, asset_id } = reserve.parse::<Value>()?;
+                    if asset_id != *STAKING_TOKEN_ASSET_ID {
+                        return Err(anyhow!("the reserve must be in the staking token"));
+                    }
+                    amount
+                };
+                let to = to.parse::<IdentityKey>()?;
+                let fee = Fee::from_staking_token_amount((*fee).into());
+                let account_group_id = app.fvk.account_group_id();
+
+                loop {
+                    // This runs unattended, so don't let a transient error (e.g., the node being
+                    // unreachable) stop it: log it and try again after the interval.
+                    let result = async {
+                        let liquid_amount = app
+                            .view()
+                            .unspent_notes_by_address_and_asset(account_group_id)
+                            .await?
+                            .into_iter()
+                            .filter(|(index, _)| index.account == *source)
+                            .filter_map(|(_, mut notes_by_asset)| {
+                                notes_by_asset.remove(&*STAKING_TOKEN_ASSET_ID)
+                            })
+                            .flatten()
+                            .map(|record| record.note.amount())
+                            .sum::<Amount>();
+
+                        // Only delegate if there's something left over after the reserve and
+                        // the fee.
+                        match liquid_amount
+                            .checked_sub(&reserve)
+                            .and_then(|amount| amount.checked_sub(&fee.amount()))
+                            .filter(|amount| *amount > Amount::zero())
+                        {
+                            Some(unbonded_amount) => {
+                                println!("delegating {unbonded_amount}upenumbra to {to}");
+
+                                let mut client = app.specific_client().await?;
+                                let rate_data: RateData = client
+                                    .current_validator_rate(tonic::Request::new(to.into()))
+                                    .await?
+                                    .into_inner()
+                                    .try_into()?;
+
+                                let plan = plan::delegate(
+                                    account_group_id,
+                                    app.view.as_mut().unwrap(),
+                                    OsRng,
+                                    rate_data,
+                                    unbonded_amount.value(),
+                                    fee.clone(),
+                                    AddressIndex::new(*source),
+                                )
+                                .await?;
+
+                                app.build_and_submit_transaction(plan).await?;
+                            }
+                            None => {
+                                println!("no staking tokens to delegate in account {source}");
+                            }
+                        }
+
+                        Ok::<_, anyhow::Error>(())
+                    }
+                    .await;
+                    if let Err(e) = result {
+                        tracing::warn!(?e, "failed to auto-delegate, retrying after interval");
+                    }
+
+                    tokio::time::sleep(std::time::Duration::from_secs(*interval)).await;
+                }
+            }
             TxCmd::Undelegate {
                 amount,
                 fee,
@@ -655,6 +879,7 @@ impl TxCmd {
             TxCmd::Proposal(ProposalCmd::DepositClaim {
                 fee,
                 proposal_id,
+                all,
                 source,
             }) => {
                 use penumbra_app::governance::state_key;
@@ -662,39 +887,54 @@ impl TxCmd {
 
                 let fee = Fee::from_staking_token_amount((*fee).into());
 
-                let mut client = app.specific_client().await?;
-                let state: proposal::State = client
-                    .key_domain(state_key::proposal_state(*proposal_id))
-                    .await?
-                    .context(format!(
-                        "proposal state for proposal {} was not found",
-                        proposal_id
-                    ))?;
-
-                let outcome = match state {
-                    proposal::State::Voting => anyhow::bail!(
-                        "proposal {} is still voting, so the deposit cannot yet be claimed",
-                        proposal_id
-                    ),
-                    proposal::State::Withdrawn { reason: _ } => {
-                        anyhow::bail!("proposal {} has been withdrawn but voting has not yet concluded, so the deposit cannot yet be claimed", proposal_id);
-                    }
-                    proposal::State::Finished { outcome } => outcome.map(|_| ()),
-                    proposal::State::Claimed { outcome: _ } => {
-                        anyhow::bail!("proposal {} has already been claimed", proposal_id)
+                let claims = if *all {
+                    let claims = crate::command::view::proposals::claimable_deposits(app).await?;
+                    if claims.is_empty() {
+                        anyhow::bail!("no proposal deposits are ready to be claimed");
                     }
-                };
+                    claims
+                } else {
+                    let proposal_id = proposal_id.expect("proposal id is required without --all");
 
-                let deposit_amount: Amount = client
-                    .key_domain(state_key::proposal_deposit_amount(*proposal_id))
-                    .await?
-                    .context(format!(
-                        "deposit amount for proposal {} was not found",
-                        proposal_id
-                    ))?;
+                    let mut client = app.specific_client().await?;
+                    let state: proposal::State = client
+                        .key_domain(state_key::proposal_state(proposal_id))
+                        .await?
+                        .context(format!(
+                            "proposal state for proposal {} was not found",
+                            proposal_id
+                        ))?;
+
+                    let outcome = match state {
+                        proposal::State::Voting => anyhow::bail!(
+                            "proposal {} is still voting, so the deposit cannot yet be claimed",
+                            proposal_id
+                        ),
+                        proposal::State::Withdrawn { reason: _ } => {
+                            anyhow::bail!("proposal {} has been withdrawn but voting has not yet concluded, so the deposit cannot yet be claimed", proposal_id);
+                        }
+                        proposal::State::Finished { outcome } => outcome.map(|_| ()),
+                        proposal::State::Claimed { outcome: _ } => {
+                            anyhow::bail!("proposal {} has already been claimed", proposal_id)
+                        }
+                    };
 
-                let plan = Planner::new(OsRng)
-                    .proposal_deposit_claim(*proposal_id, deposit_amount, outcome)
+                    let deposit_amount: Amount = client
+                        .key_domain(state_key::proposal_deposit_amount(proposal_id))
+                        .await?
+                        .context(format!(
+                            "deposit amount for proposal {} was not found",
+                            proposal_id
+                        ))?;
+
+                    vec![(proposal_id, deposit_amount, outcome)]
+                };
+
+                let mut planner = Planner::new(OsRng);
+                for (proposal_id, deposit_amount, outcome) in claims {
+                    planner.proposal_deposit_claim(proposal_id, deposit_amount, outcome);
+                }
+                let plan = planner
                     .fee(fee)
                     .plan(
                         app.view.as_mut().unwrap(),
@@ -706,7 +946,7 @@ impl TxCmd {
                 app.build_and_submit_transaction(plan).await?;
             }
             TxCmd::Vote { vote, fee, source } => {
-                let (proposal_id, vote): (u64, Vote) = (*vote).into();
+                let (proposal_id, vote): (u64, SplitVote) = (*vote).into();
 
                 // Before we vote on the proposal, we have to gather some information about it so
                 // that we can prepare our vote:
@@ -798,6 +1038,7 @@ impl TxCmd {
                 timeout_timestamp,
                 source_channel,
                 source,
+                memo,
             } => {
                 // TODO: should we be using a standard address parser here?
                 let to_components = to.split('@').collect::<Vec<_>>();
@@ -841,6 +1082,7 @@ impl TxCmd {
                     return_address: ephemeral_return_address,
                     source_channel: ChannelId::from_str(source_channel)?,
                     source_port: PortId::from_str("transfer")?,
+                    memo: memo.clone().unwrap_or_default(),
                 };
 
                 let plan = Planner::new(OsRng)
@@ -1085,7 +1327,108 @@ impl TxCmd {
             TxCmd::Position(PositionCmd::Replicate(replicate_cmd)) => {
                 replicate_cmd.exec(app).await?;
             }
+            TxCmd::Finalize {
+                plan,
+                authorization,
+            } => {
+                let plan: TransactionPlan = serde_json::from_slice(
+                    &std::fs::read(plan).with_context(|| format!("can't read plan file {plan}"))?,
+                )
+                .context("can't parse plan file")?;
+                let auth_data: AuthorizationData =
+                    serde_json::from_slice(&std::fs::read(authorization).with_context(|| {
+                        format!("can't read authorization file {authorization}")
+                    })?)
+                    .context("can't parse authorization file")?;
+
+                check_authorization(&plan, &auth_data, &app.fvk)?;
+
+                println!("building transaction...");
+                let tx = app.view().witness_and_build(plan, auth_data).await?;
+                app.submit_transaction(tx).await?;
+            }
         }
         Ok(())
     }
 }
+
+/// Checks that the authorization data was made for this plan, rather than letting the transaction
+/// fail to verify once it's been built.
+fn check_authorization(
+    plan: &TransactionPlan,
+    auth_data: &AuthorizationData,
+    fvk: &FullViewingKey,
+) -> Result<()> {
+    if auth_data.effect_hash != plan.effect_hash(fvk) {
+        anyhow::bail!("the authorization data was not produced for this plan");
+    }
+    if auth_data.spend_auths.len() != plan.spend_plans().count()
+        || auth_data.delegator_vote_auths.len() != plan.delegator_vote_plans().count()
+    {
+        anyhow::bail!("the authorization data does not have a signature for every action");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_chain::test_keys;
+    use penumbra_crypto::Note;
+    use penumbra_shielded_pool::SpendPlan;
+
+    use super::*;
+
+    fn plan() -> TransactionPlan {
+        let note = Note::generate(
+            &mut OsRng,
+            &test_keys::ADDRESS_0,
+            Value {
+                amount: 1000u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        );
+        TransactionPlan {
+            actions: vec![SpendPlan::new(&mut OsRng, note, 0u64.into()).into()],
+            chain_id: "penumbra-test".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn authorization_data_roundtrips_through_json() {
+        let auth_data = plan().authorize(OsRng, &test_keys::SPEND_KEY);
+
+        let json = serde_json::to_vec(&auth_data).unwrap();
+        let parsed: AuthorizationData = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(parsed.effect_hash, auth_data.effect_hash);
+        assert_eq!(parsed.spend_auths.len(), 1);
+        assert!(parsed.delegator_vote_auths.is_empty());
+        assert_eq!(serde_json::to_vec(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn finalize_accepts_authorization_for_the_plan() {
+        let plan = plan();
+        let auth_data = plan.authorize(OsRng, &test_keys::SPEND_KEY);
+
+        check_authorization(&plan, &auth_data, &test_keys::FULL_VIEWING_KEY).unwrap();
+    }
+
+    #[test]
+    fn finalize_rejects_authorization_for_another_plan() {
+        let other_plan = plan();
+        let auth_data = other_plan.authorize(OsRng, &test_keys::SPEND_KEY);
+
+        assert!(check_authorization(&plan(), &auth_data, &test_keys::FULL_VIEWING_KEY).is_err());
+    }
+
+    #[test]
+    fn finalize_rejects_authorization_missing_signatures() {
+        let plan = plan();
+        let mut auth_data = plan.authorize(OsRng, &test_keys::SPEND_KEY);
+        auth_data.spend_auths.clear();
+
+        assert!(check_authorization(&plan, &auth_data, &test_keys::FULL_VIEWING_KEY).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 use anyhow::{Context, Result};
 
-use penumbra_chain::params::ChainParameters;
+use penumbra_chain::params::{change::ParameterChange, ChainParameters};
+use penumbra_crypto::Address;
+use penumbra_dao::DaoGrant;
 use penumbra_transaction::{
     plan::TransactionPlan,
     proposal::{Proposal, ProposalPayload},
@@ -13,7 +15,7 @@ pub enum ProposalCmd {
         /// The file to output the template to.
         #[clap(long, global = true)]
         file: Option<camino::Utf8PathBuf>,
-        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, or dao-spend].
+        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, dao-spend, upgrade-plan, partial-parameter-change, dao-grant, or dao-grant-cancel].
         #[clap(subcommand)]
         kind: ProposalKindCmd,
     },
@@ -48,13 +50,17 @@ pub enum ProposalCmd {
     ///
     /// This consumes the voting or withdrawn proposal NFT and mints an NFT representing whether the
     /// proposal passed, failed, or was slashed. In the case of a slash, the deposit is not returned
-    /// by this action; in other cases, it is returned to you.
+    /// by this action (it was sent to the DAO); in other cases, it is returned to you.
     DepositClaim {
         /// The transaction fee (paid in upenumbra).
         #[clap(long, default_value = "0")]
         fee: u64,
         /// The proposal id to claim the deposit for.
-        proposal_id: u64,
+        #[clap(required_unless_present = "all")]
+        proposal_id: Option<u64>,
+        /// Claim the deposits of every finished proposal held by the wallet, in one transaction.
+        #[clap(long, conflicts_with = "proposal_id")]
+        all: bool,
         /// Only spend funds originally received by the given account.
         #[clap(long, default_value = "0")]
         source: u32,
@@ -78,6 +84,55 @@ pub enum ProposalKindCmd {
         #[clap(long)]
         transaction_plan: Option<camino::Utf8PathBuf>,
     },
+    /// Generate a template for an upgrade plan proposal.
+    UpgradePlan {
+        /// The height of the last block to be processed before the upgrade.
+        ///
+        /// If not specified, this is left as 0, to be replaced in the template before submission.
+        #[clap(long, default_value = "0")]
+        height: u64,
+        /// The software version which will process blocks after the upgrade.
+        ///
+        /// If not specified, this is left empty, to be filled in the template before submission.
+        #[clap(long, default_value = "")]
+        version: String,
+    },
+    /// Generate a template for a partial parameter change proposal.
+    PartialParameterChange {
+        /// The names of the chain parameters to change.
+        ///
+        /// The template will list each parameter with its current value as both the old and new
+        /// value, to be replaced in the template before submission.
+        #[clap(long = "parameter", default_value = "proposal_voting_blocks")]
+        parameters: Vec<String>,
+    },
+    /// Generate a template for a DAO grant proposal.
+    DaoGrant {
+        /// The recipient of the grant.
+        #[clap(long)]
+        recipient: Address,
+        /// The value paid in each tranche of the grant.
+        #[clap(long, default_value = "0penumbra")]
+        value: String,
+        /// The number of epochs between tranches.
+        #[clap(long, default_value = "1")]
+        period_epochs: u64,
+        /// The number of tranches to pay.
+        #[clap(long, default_value = "1")]
+        count: u64,
+    },
+    /// Generate a template for a DAO grant cancellation proposal.
+    DaoGrantCancel {
+        /// The ID of the grant to cancel, which is the ID of the proposal that created it.
+        grant_id: u64,
+    },
+    /// Generate a template for an IBC client substitution proposal.
+    IbcClientSubstitution {
+        /// The ID of the frozen or expired client to recover.
+        subject_client_id: String,
+        /// The ID of the active client whose state will replace that of the subject client.
+        substitute_client_id: String,
+    },
 }
 
 impl ProposalKindCmd {
@@ -110,6 +165,48 @@ impl ProposalKindCmd {
                     }
                 }
             }
+            ProposalKindCmd::UpgradePlan { height, version } => ProposalPayload::UpgradePlan {
+                height: *height,
+                version: version.clone(),
+            },
+            ProposalKindCmd::PartialParameterChange { parameters } => {
+                ProposalPayload::PartialParameterChange {
+                    changes: parameters
+                        .iter()
+                        .map(|parameter| {
+                            let value = chain_params.get_by_name(parameter)?;
+                            Ok(ParameterChange {
+                                parameter: parameter.clone(),
+                                old_value: value.clone(),
+                                new_value: value,
+                            })
+                        })
+                        .collect::<Result<_>>()?,
+                }
+            }
+            ProposalKindCmd::DaoGrant {
+                recipient,
+                value,
+                period_epochs,
+                count,
+            } => ProposalPayload::DaoGrant {
+                grant: DaoGrant {
+                    value: value.parse()?,
+                    address: *recipient,
+                    period_epochs: *period_epochs,
+                    count: *count,
+                },
+            },
+            ProposalKindCmd::DaoGrantCancel { grant_id } => ProposalPayload::DaoGrantCancel {
+                grant_id: *grant_id,
+            },
+            ProposalKindCmd::IbcClientSubstitution {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayload::IbcClientSubstitution {
+                subject_client_id: subject_client_id.clone(),
+                substitute_client_id: substitute_client_id.clone(),
+            },
         };
 
         Ok(Proposal {
@@ -154,8 +154,9 @@ impl ConstantProduct {
                 AddressIndex::new(self.source),
             )
             .await?;
-        let tx_id = app.build_and_submit_transaction(plan).await?;
-        println!("posted with transaction id: {tx_id}");
+        if let Some(tx_id) = app.build_and_submit_transaction(plan).await? {
+            println!("posted with transaction id: {tx_id}");
+        }
 
         Ok(())
     }
@@ -96,8 +96,7 @@ impl ValidatorCmd {
 
     // TODO: move use of sk into custody service
     pub async fn exec(&self, app: &mut App) -> Result<()> {
-        let sk = app.wallet.spend_key.clone();
-        let fvk = sk.full_viewing_key().clone();
+        let fvk = app.fvk.clone();
         match self {
             ValidatorCmd::Identity { base64 } => {
                 let ik = IdentityKey(fvk.spend_verification_key().clone());
@@ -135,7 +134,7 @@ impl ValidatorCmd {
                 // Sign the validator definition with the wallet's spend key.
                 let protobuf_serialized: ProtoValidator = new_validator.clone().into();
                 let v_bytes = protobuf_serialized.encode_to_vec();
-                let auth_sig = sk.spend_auth_key().sign(OsRng, &v_bytes);
+                let auth_sig = app.spend_key()?.spend_auth_key().sign(OsRng, &v_bytes);
                 let vd = validator::Definition {
                     validator: new_validator,
                     auth_sig,
@@ -160,11 +159,11 @@ impl ValidatorCmd {
             }
             ValidatorCmd::Vote { fee, source, vote } => {
                 // TODO: support submitting a separate governance key.
-                let identity_key = IdentityKey(*sk.full_viewing_key().spend_verification_key());
+                let identity_key = IdentityKey(*fvk.spend_verification_key());
                 // Currently this is always just copied from the identity key
                 let governance_key = GovernanceKey(identity_key.0);
 
-                let (proposal, vote): (u64, Vote) = (*vote).into();
+                let (proposal, vote): (u64, Vote) = (*vote).try_into()?;
 
                 // Construct the vote body
                 let body = ValidatorVoteBody {
@@ -175,7 +174,7 @@ impl ValidatorCmd {
                 };
 
                 // TODO: support signing with a separate governance key
-                let governance_auth_key = sk.spend_auth_key();
+                let governance_auth_key = app.spend_key()?.spend_auth_key();
 
                 // Generate an authorizing signature with the governance key for the vote body
                 let body_bytes = body.encode_to_vec();
@@ -277,6 +276,10 @@ impl ValidatorCmd {
 #
 # Every time you upload a new validator config, you'll need to increment the
 # `sequence_number`.
+#
+# To have a funding stream's rewards automatically delegated back to this
+# validator, set `restake = true` on it; the recipient will then receive
+# delegation tokens instead of staking tokens.
 
 {}
 ",
@@ -4,7 +4,13 @@ mod balance;
 use balance::BalanceCmd;
 mod address;
 use address::AddressCmd;
+mod history;
+use history::HistoryCmd;
+mod payment_request;
+use payment_request::PaymentRequestCmd;
+pub mod proposals;
 mod staked;
+use proposals::ProposalsCmd;
 
 use staked::StakedCmd;
 pub mod transaction_hashes;
@@ -22,6 +28,8 @@ pub enum ViewCmd {
     Balance(BalanceCmd),
     /// View your staked delegation tokens.
     Staked(StakedCmd),
+    /// View the proposals you submitted, their deposits, and whether they can be claimed.
+    Proposals(ProposalsCmd),
     /// Deletes all scanned data and local state, while leaving keys untouched.
     Reset(Reset),
     /// Synchronizes the client, privately scanning the chain state.
@@ -34,6 +42,11 @@ pub enum ViewCmd {
     ListTransactionHashes(TransactionHashesCmd),
     /// Displays a transaction's details by hash.
     Tx(TxCmd),
+    /// Exports the changes to an account's balances made by each transaction, for accounting.
+    History(HistoryCmd),
+    /// Requests payments into your wallet, and tracks whether they've been paid.
+    #[clap(subcommand)]
+    PaymentRequest(PaymentRequestCmd),
 }
 
 impl ViewCmd {
@@ -42,10 +55,13 @@ impl ViewCmd {
             ViewCmd::Address(address_cmd) => address_cmd.offline(),
             ViewCmd::Balance(balance_cmd) => balance_cmd.offline(),
             ViewCmd::Staked(staked_cmd) => staked_cmd.offline(),
+            ViewCmd::Proposals(proposals_cmd) => proposals_cmd.offline(),
             ViewCmd::Reset(_) => true,
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
+            ViewCmd::History(history_cmd) => history_cmd.offline(),
+            ViewCmd::PaymentRequest(payment_request_cmd) => payment_request_cmd.offline(),
         }
     }
 
@@ -57,12 +73,22 @@ impl ViewCmd {
             ViewCmd::Tx(tx_cmd) => {
                 tx_cmd.exec(app).await?;
             }
+            ViewCmd::Proposals(proposals_cmd) => {
+                proposals_cmd.exec(app).await?;
+            }
+            ViewCmd::PaymentRequest(payment_request_cmd) => {
+                payment_request_cmd.exec(app).await?;
+            }
             ViewCmd::ListTransactionHashes(transactions_cmd) => {
                 let view_client = app.view();
                 transactions_cmd
                     .exec(&full_viewing_key, view_client)
                     .await?;
             }
+            ViewCmd::History(history_cmd) => {
+                let view_client = app.view();
+                history_cmd.exec(&full_viewing_key, view_client).await?;
+            }
             ViewCmd::Sync => {
                 // We set needs_sync() -> true, so by this point, we have
                 // already synchronized the wallet above, so we can just return.
@@ -0,0 +1,585 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use penumbra_asset::{
+    asset::{self, Cache},
+    STAKING_TOKEN_ASSET_ID,
+};
+use penumbra_crypto::{AddressView, FullViewingKey, NoteView};
+use penumbra_dex::{
+    swap::{SwapPlaintext, SwapView},
+    swap_claim::SwapClaimView,
+    BatchSwapOutputData,
+};
+use penumbra_num::Amount;
+use penumbra_stake::DelegationToken;
+use penumbra_tct as tct;
+use penumbra_transaction::{
+    view::action_view::{ActionView, OutputView, SpendView},
+    Id as TransactionId, TransactionView,
+};
+use penumbra_view::ViewClient;
+use serde::Serialize;
+
+#[derive(Clone, Copy, clap::ValueEnum, Debug)]
+pub enum HistoryFormat {
+    Csv,
+    Json,
+}
+
+/// Exports the history of an account's balances, one row per asset per transaction.
+#[derive(Debug, clap::Args)]
+pub struct HistoryCmd {
+    /// The account to export the history of.
+    #[clap(long, default_value = "0")]
+    account: u32,
+    /// The height to start the history at.
+    #[clap(short, long)]
+    start_height: Option<u64>,
+    /// The height to end the history at.
+    #[clap(short, long)]
+    end_height: Option<u64>,
+    /// The format to write the history in.
+    #[clap(long, value_enum, default_value = "csv")]
+    format: HistoryFormat,
+}
+
+/// The change in the account's balance of one asset caused by one transaction.
+///
+/// All amounts are in base units, so that they can be summed without loss of precision. The
+/// columns describing the transaction as a whole are repeated for each asset it changed.
+#[derive(Debug, Serialize)]
+struct HistoryEntry {
+    height: u64,
+    transaction_id: String,
+    asset_id: String,
+    denom: String,
+    /// The net change in the balance of the asset, which is negative if more was spent than
+    /// received.
+    delta: String,
+    /// The fee paid by the transaction, if it was paid by this account.
+    fee: Option<String>,
+    /// The inputs of any swaps made by the account in the transaction, and the outputs they were
+    /// filled with in their batch.
+    swaps: Option<String>,
+    /// The exchange rates of any delegations or undelegations in the transaction.
+    delegations: Option<String>,
+}
+
+/// Format an amount of an asset in base units, so that it can be read back exactly.
+fn format_base_units(cache: &Cache, amount: Amount, asset_id: asset::Id) -> String {
+    match cache.get(&asset_id) {
+        Some(denom) => format!("{}{}", amount, denom.base_denom()),
+        None => format!("{}{}", amount, asset_id),
+    }
+}
+
+impl HistoryCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec<V: ViewClient>(&self, fvk: &FullViewingKey, view: &mut V) -> Result<()> {
+        let cache = view.assets().await?;
+        let txs = view
+            .transaction_info(self.start_height, self.end_height)
+            .await?;
+
+        let mut entries = Vec::new();
+        for tx_info in txs {
+            let swap_outputs = self.swap_outputs(fvk, view, &tx_info.view).await;
+            entries.extend(self.entries(
+                fvk,
+                &cache,
+                tx_info.height,
+                &tx_info.id,
+                &tx_info.view,
+                &swap_outputs,
+            ));
+        }
+
+        match self.format {
+            HistoryFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(std::io::stdout());
+                for entry in entries {
+                    writer.serialize(entry)?;
+                }
+                writer.flush()?;
+            }
+            HistoryFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&entries)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Look up the output data of the batches the account's swaps in the transaction were
+    /// executed in, which the view service records alongside each swap.
+    async fn swap_outputs<V: ViewClient>(
+        &self,
+        fvk: &FullViewingKey,
+        view: &mut V,
+        tx_view: &TransactionView,
+    ) -> BTreeMap<tct::StateCommitment, BatchSwapOutputData> {
+        let mut swap_outputs = BTreeMap::new();
+        for action_view in &tx_view.body_view.action_views {
+            let ActionView::Swap(SwapView::Visible {
+                swap,
+                swap_plaintext,
+            }) = action_view
+            else {
+                continue;
+            };
+            if !self.claims(fvk, swap_plaintext) {
+                continue;
+            }
+
+            let commitment = swap.body.payload.commitment;
+            match view
+                .swap_by_commitment(fvk.account_group_id(), commitment)
+                .await
+            {
+                Ok(record) => {
+                    swap_outputs.insert(commitment, record.output_data);
+                }
+                Err(e) => {
+                    tracing::warn!(?e, "could not find swap record for swap outputs");
+                }
+            }
+        }
+        swap_outputs
+    }
+
+    // whether the outputs of the swap are claimed by the account.
+    fn claims(&self, fvk: &FullViewingKey, swap_plaintext: &SwapPlaintext) -> bool {
+        matches!(
+            fvk.address_index(&swap_plaintext.claim_address),
+            Some(index) if index.account == self.account
+        )
+    }
+
+    /// Compute the entries for a single transaction, which may be none if the transaction didn't
+    /// change the account's balances.
+    ///
+    /// The outputs of the account's swaps are taken from `swap_outputs`, keyed by swap commitment.
+    fn entries(
+        &self,
+        fvk: &FullViewingKey,
+        cache: &Cache,
+        height: u64,
+        id: &TransactionId,
+        tx_view: &TransactionView,
+        swap_outputs: &BTreeMap<tct::StateCommitment, BatchSwapOutputData>,
+    ) -> Vec<HistoryEntry> {
+        // Whether a note belongs to the account we're exporting.
+        let ours = |note: &NoteView| match note.address {
+            AddressView::Visible { index, .. } => index.account == self.account,
+            AddressView::Opaque { .. } => false,
+        };
+
+        let mut received = BTreeMap::<asset::Id, Amount>::new();
+        let mut spent = BTreeMap::<asset::Id, Amount>::new();
+        let mut swaps = Vec::new();
+        // Delegations and undelegations, along with the delegation token the account must have
+        // received or spent for them to be its own.
+        let mut delegations = Vec::new();
+
+        for action_view in &tx_view.body_view.action_views {
+            match action_view {
+                ActionView::Spend(SpendView::Visible { note, .. }) if ours(note) => {
+                    *spent.entry(note.asset_id()).or_default() += note.value.value().amount;
+                }
+                ActionView::Output(OutputView::Visible { note, .. }) if ours(note) => {
+                    *received.entry(note.asset_id()).or_default() += note.value.value().amount;
+                }
+                ActionView::SwapClaim(SwapClaimView::Visible {
+                    output_1, output_2, ..
+                }) => {
+                    for note in [output_1, output_2] {
+                        if ours(note) {
+                            *received.entry(note.asset_id()).or_default() +=
+                                note.value.value().amount;
+                        }
+                    }
+                }
+                ActionView::Swap(SwapView::Visible {
+                    swap,
+                    swap_plaintext,
+                }) => {
+                    if !self.claims(fvk, swap_plaintext) {
+                        continue;
+                    }
+
+                    let pair = swap_plaintext.trading_pair;
+                    let inputs = (swap_plaintext.delta_1_i, swap_plaintext.delta_2_i);
+                    let mut description = format!(
+                        "{} and {}",
+                        format_base_units(cache, inputs.0, pair.asset_1()),
+                        format_base_units(cache, inputs.1, pair.asset_2()),
+                    );
+                    if let Some(output_data) = swap_outputs.get(&swap.body.payload.commitment) {
+                        let outputs = output_data.pro_rata_outputs(inputs);
+                        description.push_str(&format!(
+                            " for {} and {}",
+                            format_base_units(cache, outputs.0, pair.asset_1()),
+                            format_base_units(cache, outputs.1, pair.asset_2()),
+                        ));
+                    }
+
+                    swaps.push(description);
+                }
+                ActionView::Delegate(delegate) => {
+                    let delegation_token =
+                        DelegationToken::new(delegate.validator_identity.clone()).id();
+                    delegations.push((
+                        delegation_token,
+                        format!(
+                            "delegated {} for {}",
+                            format_base_units(
+                                cache,
+                                delegate.unbonded_amount,
+                                *STAKING_TOKEN_ASSET_ID
+                            ),
+                            format_base_units(cache, delegate.delegation_amount, delegation_token),
+                        ),
+                    ));
+                }
+                ActionView::Undelegate(undelegate) => {
+                    let delegation_token =
+                        DelegationToken::new(undelegate.validator_identity.clone()).id();
+                    delegations.push((
+                        delegation_token,
+                        format!(
+                            "undelegated {} for {}",
+                            format_base_units(
+                                cache,
+                                undelegate.delegation_amount,
+                                delegation_token
+                            ),
+                            format_base_units(
+                                cache,
+                                undelegate.unbonded_amount,
+                                undelegate.unbonding_token().id()
+                            ),
+                        ),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        // The account only paid the fee if it spent something in the transaction.
+        let fee = tx_view.body_view.fee.0;
+        let fee = (!spent.is_empty()).then(|| format_base_units(cache, fee.amount, fee.asset_id));
+        let swaps = (!swaps.is_empty()).then(|| swaps.join("; "));
+        // Leave out the delegations of other accounts in the same transaction.
+        let delegations = delegations
+            .into_iter()
+            .filter(|(token, _)| received.contains_key(token) || spent.contains_key(token))
+            .map(|(_, description)| description)
+            .collect::<Vec<_>>();
+        let delegations = (!delegations.is_empty()).then(|| delegations.join("; "));
+
+        let asset_ids = received.keys().chain(spent.keys()).collect::<BTreeSet<_>>();
+
+        asset_ids
+            .into_iter()
+            .map(|asset_id| {
+                let received = received.get(asset_id).copied().unwrap_or_default();
+                let spent = spent.get(asset_id).copied().unwrap_or_default();
+                let delta = if received >= spent {
+                    (received - spent).to_string()
+                } else {
+                    format!("-{}", spent - received)
+                };
+
+                HistoryEntry {
+                    height,
+                    transaction_id: id.to_string(),
+                    asset_id: asset_id.to_string(),
+                    denom: cache.get(asset_id).map_or_else(
+                        || asset_id.to_string(),
+                        |denom| denom.base_denom().to_string(),
+                    ),
+                    delta,
+                    fee: fee.clone(),
+                    swaps: swaps.clone(),
+                    delegations: delegations.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_chain::test_keys;
+    use penumbra_crypto::{
+        keys::AddressIndex,
+        proofs::groth16::{OutputProof, SpendProof},
+        rdsa::{Signature, SigningKey, SpendAuth},
+        Fq, Note, Nullifier, PayloadKey,
+    };
+    use penumbra_dex::{
+        swap_claim::{self, SwapClaim, SwapClaimProof},
+        TradingPair,
+    };
+    use penumbra_fee::Fee;
+    use penumbra_proto::core::crypto::v1alpha1 as pb_crypto;
+    use penumbra_shielded_pool::{Output, OutputPlan, Spend, SpendPlan};
+    use penumbra_stake::{Delegate, IdentityKey, Undelegate};
+    use penumbra_transaction::view::TransactionBodyView;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn fvk() -> &'static FullViewingKey {
+        &test_keys::FULL_VIEWING_KEY
+    }
+
+    fn cache() -> Cache {
+        Cache::with_known_assets()
+    }
+
+    fn note(account: u32, amount: u64, asset_id: asset::Id) -> Note {
+        let (address, _) = fvk().payment_address(AddressIndex::new(account));
+        Note::generate(
+            &mut OsRng,
+            &address,
+            penumbra_asset::Value {
+                amount: amount.into(),
+                asset_id,
+            },
+        )
+    }
+
+    fn note_view(note: &Note) -> NoteView {
+        NoteView {
+            value: note.value().view_with_cache(&cache()),
+            rseed: note.rseed(),
+            address: fvk().view_address(note.address()),
+        }
+    }
+
+    fn spend(note: Note) -> ActionView {
+        let plan = SpendPlan::new(&mut OsRng, note.clone(), 0u64.into());
+        ActionView::Spend(SpendView::Visible {
+            spend: Spend {
+                body: plan.spend_body(fvk()),
+                auth_sig: Signature::from([0; 64]),
+                proof: SpendProof::try_from(pb_crypto::ZkSpendProof {
+                    inner: vec![0; 192],
+                })
+                .unwrap(),
+            },
+            note: note_view(&note),
+        })
+    }
+
+    fn output(note: Note) -> ActionView {
+        let plan = OutputPlan::new(&mut OsRng, note.value(), note.address());
+        let payload_key = PayloadKey::random_key(&mut OsRng);
+        ActionView::Output(OutputView::Visible {
+            output: Output {
+                body: plan.output_body(fvk().outgoing(), &payload_key),
+                proof: OutputProof::try_from(pb_crypto::ZkOutputProof {
+                    inner: vec![0; 192],
+                })
+                .unwrap(),
+            },
+            note: note_view(&plan.output_note()),
+            payload_key,
+        })
+    }
+
+    fn swap_claim(output_1: Note, output_2: Note) -> ActionView {
+        let trading_pair = TradingPair::new(output_1.asset_id(), output_2.asset_id());
+        ActionView::SwapClaim(SwapClaimView::Visible {
+            swap_claim: SwapClaim {
+                proof: SwapClaimProof([0; 192]),
+                body: swap_claim::Body {
+                    nullifier: Nullifier(Fq::from(0u64)),
+                    fee: Fee::default(),
+                    output_1_commitment: output_1.commit(),
+                    output_2_commitment: output_2.commit(),
+                    output_data: BatchSwapOutputData {
+                        delta_1: 0u64.into(),
+                        delta_2: 0u64.into(),
+                        lambda_1: 0u64.into(),
+                        lambda_2: 0u64.into(),
+                        unfilled_1: 0u64.into(),
+                        unfilled_2: 0u64.into(),
+                        height: 1,
+                        trading_pair,
+                        epoch_starting_height: 0,
+                    },
+                },
+                epoch_duration: 10,
+            },
+            output_1: note_view(&output_1),
+            output_2: note_view(&output_2),
+        })
+    }
+
+    fn validator() -> IdentityKey {
+        IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into())
+    }
+
+    fn delegate(validator: &IdentityKey, amount: u64) -> ActionView {
+        ActionView::Delegate(Delegate {
+            validator_identity: validator.clone(),
+            epoch_index: 0,
+            unbonded_amount: amount.into(),
+            delegation_amount: amount.into(),
+        })
+    }
+
+    fn undelegate(validator: &IdentityKey, amount: u64) -> ActionView {
+        ActionView::Undelegate(Undelegate {
+            validator_identity: validator.clone(),
+            start_epoch_index: 0,
+            unbonded_amount: amount.into(),
+            delegation_amount: amount.into(),
+        })
+    }
+
+    fn delegation_token(validator: &IdentityKey) -> asset::Id {
+        DelegationToken::new(validator.clone()).id()
+    }
+
+    // the entries of a transaction with the given actions and fee, from the perspective of the
+    // given account.
+    fn entries(account: u32, action_views: Vec<ActionView>, fee: u64) -> Vec<HistoryEntry> {
+        let tx_view = TransactionView {
+            body_view: TransactionBodyView {
+                action_views,
+                expiry_height: 0,
+                chain_id: "penumbra-test".to_string(),
+                fee: Fee::from_staking_token_amount(fee.into()),
+                fmd_clues: Vec::new(),
+                memo_view: None,
+            },
+            binding_sig: Signature::from([0; 64]),
+            anchor: tct::Tree::new().root(),
+        };
+        let cmd = HistoryCmd {
+            account,
+            start_height: None,
+            end_height: None,
+            format: HistoryFormat::Csv,
+        };
+        cmd.entries(
+            fvk(),
+            &cache(),
+            1,
+            &TransactionId([0; 32]),
+            &tx_view,
+            &BTreeMap::new(),
+        )
+    }
+
+    #[test]
+    fn spends_and_change_are_netted_and_pay_the_fee() {
+        let staking = *STAKING_TOKEN_ASSET_ID;
+        let entries = entries(
+            0,
+            vec![
+                spend(note(0, 100, staking)),
+                output(note(1, 60, staking)),
+                output(note(0, 35, staking)),
+            ],
+            5,
+        );
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].asset_id, staking.to_string());
+        assert_eq!(entries[0].delta, "-65");
+        assert_eq!(entries[0].fee.as_deref(), Some("5upenumbra"));
+    }
+
+    #[test]
+    fn outputs_to_the_account_are_received_without_a_fee() {
+        let staking = *STAKING_TOKEN_ASSET_ID;
+        let action_views = vec![spend(note(1, 100, staking)), output(note(0, 40, staking))];
+
+        let entries_0 = entries(0, action_views.clone(), 5);
+        assert_eq!(entries_0.len(), 1);
+        assert_eq!(entries_0[0].delta, "40");
+        assert_eq!(entries_0[0].fee, None);
+
+        // Another account's output isn't counted.
+        assert!(entries(2, action_views, 5).is_empty());
+    }
+
+    #[test]
+    fn swap_claim_outputs_are_received() {
+        let staking = *STAKING_TOKEN_ASSET_ID;
+        let gm = asset::REGISTRY.parse_denom("ugm").unwrap().id();
+        let entries = entries(
+            0,
+            vec![swap_claim(note(0, 10, staking), note(0, 20, gm))],
+            0,
+        );
+
+        assert_eq!(entries.len(), 2);
+        for entry in entries {
+            let expected = if entry.asset_id == staking.to_string() {
+                "10"
+            } else {
+                assert_eq!(entry.asset_id, gm.to_string());
+                "20"
+            };
+            assert_eq!(entry.delta, expected);
+        }
+    }
+
+    #[test]
+    fn delegations_are_only_described_for_the_account_making_them() {
+        let staking = *STAKING_TOKEN_ASSET_ID;
+        let validator = validator();
+        let token = delegation_token(&validator);
+        let action_views = vec![
+            spend(note(0, 100, staking)),
+            delegate(&validator, 100),
+            output(note(0, 100, token)),
+        ];
+
+        let entries_0 = entries(0, action_views.clone(), 0);
+        assert_eq!(entries_0.len(), 2);
+        for entry in &entries_0 {
+            assert!(entry
+                .delegations
+                .as_deref()
+                .unwrap()
+                .starts_with("delegated 100upenumbra"));
+        }
+
+        // An account sharing the transaction doesn't see the delegation as its own.
+        let mut shared = action_views;
+        shared.push(spend(note(1, 7, staking)));
+        shared.push(output(note(1, 2, staking)));
+        let entries_1 = entries(1, shared, 0);
+        assert_eq!(entries_1.len(), 1);
+        assert_eq!(entries_1[0].delta, "-5");
+        assert_eq!(entries_1[0].delegations, None);
+    }
+
+    #[test]
+    fn undelegations_are_described_for_the_account_spending_the_delegation_tokens() {
+        let validator = validator();
+        let token = delegation_token(&validator);
+        let action_views = vec![spend(note(0, 50, token)), undelegate(&validator, 50)];
+
+        let entries_0 = entries(0, action_views.clone(), 0);
+        assert_eq!(entries_0.len(), 1);
+        assert_eq!(entries_0[0].delta, "-50");
+        assert!(entries_0[0]
+            .delegations
+            .as_deref()
+            .unwrap()
+            .starts_with("undelegated 50"));
+
+        assert!(entries(1, action_views, 0).is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use penumbra_asset::Value;
+use penumbra_num::Amount;
+use penumbra_view::ViewClient;
+
+use crate::App;
+
+/// Request payments into your wallet, and check whether they've been paid.
+#[derive(Debug, clap::Subcommand)]
+pub enum PaymentRequestCmd {
+    /// Create a payment request, printing it as a URI to give to the payer.
+    ///
+    /// Each request asks to be paid at a fresh address, so that payments towards it can be told
+    /// apart from anything else received by the wallet.
+    New {
+        /// The value to request, written as a typed value, e.g. 1.87penumbra.
+        value: String,
+        /// The account to be paid into.
+        #[clap(long, default_value = "0")]
+        account: u32,
+        /// A reference for the payment, which the payer will include in their memo.
+        #[clap(long)]
+        memo: Option<String>,
+        /// The height after which the request should no longer be paid.
+        #[clap(long)]
+        expiry_height: Option<u64>,
+    },
+    /// List the payment requests you've made, and whether they've been paid.
+    List,
+}
+
+impl PaymentRequestCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            PaymentRequestCmd::New {
+                value,
+                account,
+                memo,
+                expiry_height,
+            } => {
+                let value = value.parse::<Value>()?;
+                let request = app
+                    .view()
+                    .payment_request(*account, value, memo.clone(), *expiry_height)
+                    .await?;
+
+                println!("{request}");
+            }
+            PaymentRequestCmd::List => {
+                let account_group_id = app.fvk.account_group_id();
+                let current_height = app.view().status(account_group_id).await?.sync_height;
+                let asset_cache = app.view().assets().await?;
+                let records = app.view().payment_requests().await?;
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Account", "Requested", "Received", "Memo", "Status"]);
+
+                for record in records {
+                    let request = &record.request;
+                    let received = Value {
+                        amount: record.amount_received,
+                        asset_id: request.value.asset_id,
+                    };
+
+                    let status = match record.height_paid {
+                        Some(height) => format!("paid at height {height}"),
+                        None if request.is_expired(current_height) => "expired".to_string(),
+                        None if record.amount_received > Amount::zero() => {
+                            "partially paid".to_string()
+                        }
+                        None => "unpaid".to_string(),
+                    };
+
+                    table.add_row(vec![
+                        record.address_index.account.to_string(),
+                        request.value.format(&asset_cache),
+                        received.format(&asset_cache),
+                        request.memo.clone().unwrap_or_default(),
+                        status,
+                    ]);
+                }
+
+                println!("{table}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
+use penumbra_app::governance::{state_key, DepositOutcome, Disposition};
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_governance::{proposal_nft::Kind, ProposalNft};
+use penumbra_num::Amount;
+use penumbra_transaction::proposal;
+use penumbra_view::ViewClient;
+
+use crate::App;
+
+#[derive(Debug, clap::Parser)]
+pub struct ProposalsCmd {}
+
+impl ProposalsCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let asset_cache = app.view().assets().await?;
+        let nfts = held_proposal_nfts(app).await?;
+        let mut client = app.specific_client().await?;
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "Proposal",
+            "Token",
+            "State",
+            "Deposit",
+            "Claim Status",
+        ]);
+
+        for (proposal_id, kind) in nfts {
+            let state: Option<proposal::State> = client
+                .key_domain(state_key::proposal_state(proposal_id))
+                .await?;
+            let deposit_amount: Option<Amount> = client
+                .key_domain(state_key::proposal_deposit_amount(proposal_id))
+                .await?;
+            let deposit_outcome: Option<DepositOutcome> = client
+                .key_domain(state_key::proposal_deposit_outcome(proposal_id))
+                .await?;
+
+            let deposit = match (deposit_amount, deposit_outcome) {
+                (_, Some(outcome)) => {
+                    let value = Value {
+                        amount: outcome.amount,
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    }
+                    .format(&asset_cache);
+                    match outcome.disposition {
+                        Disposition::Refundable => value,
+                        Disposition::SlashedToDao => format!("{value} (slashed to DAO)"),
+                    }
+                }
+                (Some(amount), None) => Value {
+                    amount,
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                }
+                .format(&asset_cache),
+                (None, None) => "unknown".to_string(),
+            };
+
+            let state_description = match &state {
+                Some(proposal::State::Voting) => "voting".to_string(),
+                Some(proposal::State::Withdrawn { .. }) => "withdrawn".to_string(),
+                Some(proposal::State::Finished { outcome }) => {
+                    format!("finished ({})", outcome_description(outcome))
+                }
+                Some(proposal::State::Claimed { outcome }) => {
+                    format!("claimed ({})", outcome_description(outcome))
+                }
+                None => "unknown".to_string(),
+            };
+
+            table.add_row(vec![
+                proposal_id.to_string(),
+                kind.display_static().to_string(),
+                state_description,
+                deposit,
+                claim_status(kind, state.as_ref()).to_string(),
+            ]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
+
+/// Get the proposal NFTs held by the wallet, by proposal ID.
+///
+/// A wallet only ever holds one proposal NFT for each proposal it submitted, since each state
+/// transition of the proposal burns the previous NFT and mints the next one.
+async fn held_proposal_nfts(app: &mut App) -> Result<BTreeMap<u64, Kind>> {
+    let account_group_id = app.fvk.account_group_id();
+    let view = app.view();
+    let asset_cache = view.assets().await?;
+    let notes = view
+        .unspent_notes_by_asset_and_address(account_group_id)
+        .await?;
+
+    let mut nfts = BTreeMap::new();
+    for (asset_id, notes_by_address) in notes.iter() {
+        let nft = if let Some(Ok(nft)) = asset_cache
+            .get(asset_id)
+            .map(|denom| ProposalNft::try_from(denom.clone()))
+        {
+            nft
+        } else {
+            continue;
+        };
+
+        let held = notes_by_address
+            .values()
+            .flat_map(|notes| notes.iter())
+            .any(|note| note.note.amount() > Amount::zero());
+        if held {
+            nfts.insert(nft.proposal_id(), nft.proposal_state());
+        }
+    }
+
+    Ok(nfts)
+}
+
+/// Get every proposal whose deposit the wallet can claim right now, along with the deposit amount
+/// and outcome needed to plan the claim.
+pub async fn claimable_deposits(
+    app: &mut App,
+) -> Result<Vec<(u64, Amount, proposal::Outcome<()>)>> {
+    let nfts = held_proposal_nfts(app).await?;
+    let mut client = app.specific_client().await?;
+
+    let mut claimable = Vec::new();
+    for (proposal_id, kind) in nfts {
+        if !matches!(kind, Kind::Deposit | Kind::UnbondingDeposit) {
+            continue;
+        }
+
+        let state: Option<proposal::State> = client
+            .key_domain(state_key::proposal_state(proposal_id))
+            .await?;
+        let outcome = if let Some(proposal::State::Finished { outcome }) = state {
+            outcome.map(|_| ())
+        } else {
+            continue;
+        };
+
+        let deposit_amount: Amount = client
+            .key_domain(state_key::proposal_deposit_amount(proposal_id))
+            .await?
+            .context(format!(
+                "deposit amount for proposal {} was not found",
+                proposal_id
+            ))?;
+
+        claimable.push((proposal_id, deposit_amount, outcome));
+    }
+
+    Ok(claimable)
+}
+
+fn outcome_description<W>(outcome: &proposal::Outcome<W>) -> &'static str {
+    match outcome {
+        proposal::Outcome::Passed => "passed",
+        proposal::Outcome::Failed { .. } => "failed",
+        proposal::Outcome::Slashed { .. } => "slashed",
+    }
+}
+
+fn claim_status(kind: Kind, state: Option<&proposal::State>) -> &'static str {
+    match kind {
+        Kind::Passed | Kind::Failed | Kind::Slashed => "claimed",
+        Kind::Deposit | Kind::UnbondingDeposit => match state {
+            Some(proposal::State::Voting) | Some(proposal::State::Withdrawn { .. }) => {
+                "claimable after voting ends"
+            }
+            Some(proposal::State::Finished { outcome }) if outcome.should_be_refunded() => {
+                "claimable (deposit refunded)"
+            }
+            Some(proposal::State::Finished { .. }) => "claimable (no refund)",
+            Some(proposal::State::Claimed { .. }) => "claimed",
+            None => "unknown",
+        },
+    }
+}
@@ -9,12 +9,13 @@ use penumbra_dex::{
     swap_claim::{SwapClaim, SwapClaimView},
     DirectedUnitPair,
 };
+use penumbra_ibc::Ics20Withdrawal;
 use penumbra_proto::{client::v1alpha1::GetTxRequest, DomainType};
 use penumbra_transaction::{
     view::action_view::{OutputView, SpendView},
     Transaction,
 };
-use penumbra_view::{TransactionInfo, ViewClient};
+use penumbra_view::{Ics20WithdrawalRecord, TransactionInfo, ViewClient};
 
 use crate::App;
 
@@ -180,6 +181,25 @@ fn format_address(ivk: &IncomingViewingKey, address: &Address) -> String {
     }
 }
 
+fn format_ics20_withdrawal_row(
+    asset_cache: &Cache,
+    withdrawal: &Ics20Withdrawal,
+    record: Option<&Ics20WithdrawalRecord>,
+) -> String {
+    let status = record.map_or_else(
+        || "status unknown".to_string(),
+        |record| format!("packet {}, {}", record.sequence, record.status),
+    );
+
+    format!(
+        "{} to {} via {} ({})",
+        withdrawal.value().format(asset_cache),
+        withdrawal.destination_chain_address,
+        withdrawal.source_channel,
+        status,
+    )
+}
+
 fn format_position_row(asset_cache: &Cache, position: Position) -> String {
     let trading_pair = position.phi.pair;
     let denom_1 = asset_cache
@@ -256,6 +276,7 @@ impl TxCmd {
                 transaction: tx,
                 perspective: txp,
                 view: txv,
+                ics20_withdrawals: Vec::new(),
             }
         };
 
@@ -278,7 +299,7 @@ impl TxCmd {
             let asset_cache = app.view().assets().await?;
             // Iterate over the ActionViews in the TxV & display as appropriate
 
-            for av in tx_info.view.body_view.action_views {
+            for (index, av) in tx_info.view.body_view.action_views.into_iter().enumerate() {
                 actions_table.add_row(match av {
                     penumbra_transaction::ActionView::Swap(SwapView::Visible {
                         swap: _,
@@ -381,9 +402,17 @@ impl TxCmd {
                         "Claim Liquidity Position Reward".to_string(),
                         "".to_string(),
                     ],
-                    penumbra_transaction::ActionView::Ics20Withdrawal(_) => {
-                        ["Ics20 Withdrawal".to_string(), "".to_string()]
-                    }
+                    penumbra_transaction::ActionView::Ics20Withdrawal(withdrawal) => [
+                        "Ics20 Withdrawal".to_string(),
+                        format_ics20_withdrawal_row(
+                            &asset_cache,
+                            &withdrawal,
+                            tx_info
+                                .ics20_withdrawals
+                                .iter()
+                                .find(|record| record.action_index == index as u64),
+                        ),
+                    ],
                     penumbra_transaction::ActionView::DaoDeposit(_) => {
                         ["Dao Deposit".to_string(), "".to_string()]
                     }
@@ -20,6 +20,7 @@ pub fn migrate(
 
     let new_wallet = crate::KeyStore {
         spend_key: legacy_wallet.wallet.spend_key,
+        birthday: None,
     };
     new_wallet.save(custody_path)?;
 
@@ -2,9 +2,10 @@
 use std::fs;
 
 use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
 use clap::Parser;
 use futures::StreamExt;
-use penumbra_crypto::FullViewingKey;
+use penumbra_crypto::{keys::SpendKey, FullViewingKey};
 use penumbra_proto::{
     custody::v1alpha1::custody_protocol_service_client::CustodyProtocolServiceClient,
     view::v1alpha1::view_protocol_service_client::ViewProtocolServiceClient,
@@ -35,10 +36,17 @@ pub struct App {
     /// `.offline()` and Some(_) otherwise. Assuming `.offline()` has been implemenented
     /// correctly, this can be unwrapped safely.
     pub view: Option<ViewProtocolServiceClient<BoxGrpcService>>,
-    pub custody: CustodyProtocolServiceClient<BoxGrpcService>,
+    /// custody and wallet will be `None` when running in view-only mode with just a full
+    /// viewing key.
+    pub custody: Option<CustodyProtocolServiceClient<BoxGrpcService>>,
     pub fvk: FullViewingKey,
-    pub wallet: KeyStore,
+    pub wallet: Option<KeyStore>,
     pub pd_url: Url,
+    /// If set, transaction plans are written to this path rather than being signed and
+    /// broadcast.
+    pub plan_only: Option<Utf8PathBuf>,
+    /// If set, transactions are signed without asking for confirmation.
+    pub yes: bool,
 }
 
 impl App {
@@ -46,6 +54,14 @@ impl App {
         self.view.as_mut().unwrap()
     }
 
+    /// The wallet's spend key, which is only available outside of view-only mode.
+    pub fn spend_key(&self) -> Result<&SpendKey> {
+        self.wallet
+            .as_ref()
+            .map(|wallet| &wallet.spend_key)
+            .ok_or_else(|| anyhow::anyhow!("no spend key is available in view-only mode"))
+    }
+
     async fn sync(&mut self) -> Result<()> {
         let mut status_stream =
             ViewClient::status_stream(self.view.as_mut().unwrap(), self.fvk.account_group_id())
@@ -105,7 +121,9 @@ async fn main() -> Result<()> {
     // create the client state, so handle it specially here so that we can have
     // common code for the other subcommands.
     if let Command::Keys(keys_cmd) = &opt.cmd {
-        keys_cmd.exec(opt.data_path.as_path())?;
+        keys_cmd
+            .exec(opt.data_path.as_path(), &opt.node, opt.yes)
+            .await?;
         return Ok(());
     }
 
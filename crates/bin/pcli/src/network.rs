@@ -1,4 +1,6 @@
 use anyhow::Context;
+use camino::Utf8Path;
+use dialoguer::Confirm;
 use penumbra_proto::{
     client::v1alpha1::{
         oblivious_query_service_client::ObliviousQueryServiceClient,
@@ -13,16 +15,62 @@ use rand_core::OsRng;
 use std::future::Future;
 use tonic::transport::{Channel, ClientTlsConfig};
 use tracing::instrument;
+use url::Url;
 
 use crate::App;
 
 impl App {
+    /// Builds and submits a transaction, returning its ID.
+    ///
+    /// If `--plan-only` was given, the plan is written out instead, and `None` is returned.
     pub async fn build_and_submit_transaction(
         &mut self,
         plan: TransactionPlan,
-    ) -> anyhow::Result<TransactionId> {
-        let transaction = self.build_transaction(plan).await?;
-        self.submit_transaction(transaction).await
+    ) -> anyhow::Result<Option<TransactionId>> {
+        if let Some(path) = self.plan_only.clone() {
+            Self::write_plan(&path, &plan)?;
+            return Ok(None);
+        }
+
+        let transaction = self.review_and_build_transaction(plan).await?;
+        self.submit_transaction(transaction).await.map(Some)
+    }
+
+    /// Reviews a transaction plan, then signs it and builds the transaction.
+    pub async fn review_and_build_transaction(
+        &mut self,
+        plan: TransactionPlan,
+    ) -> anyhow::Result<Transaction> {
+        self.review_plan(&plan).await?;
+        self.build_transaction(plan).await
+    }
+
+    /// Shows a review of a transaction plan and, when running interactively without `--yes`,
+    /// asks for confirmation before it is signed.
+    async fn review_plan(&mut self, plan: &TransactionPlan) -> anyhow::Result<()> {
+        let assets = self.view().assets().await?;
+        print!("{}", plan.review(&self.fvk, assets));
+
+        if !self.yes
+            && atty::is(atty::Stream::Stdin)
+            && !Confirm::new()
+                .with_prompt("Do you want to sign and broadcast this transaction?")
+                .interact()?
+        {
+            anyhow::bail!("transaction cancelled");
+        }
+        Ok(())
+    }
+
+    /// Writes a transaction plan to a file, to be signed with `pcli keys sign-plan`.
+    fn write_plan(path: &Utf8Path, plan: &TransactionPlan) -> anyhow::Result<()> {
+        if path.exists() {
+            anyhow::bail!("refusing to overwrite existing file {path}");
+        }
+        std::fs::write(path, serde_json::to_string_pretty(plan)?)
+            .with_context(|| format!("could not write transaction plan to {path}"))?;
+        println!("wrote transaction plan to {path}");
+        Ok(())
     }
 
     pub fn build_transaction(
@@ -31,15 +79,23 @@ impl App {
     ) -> impl Future<Output = anyhow::Result<Transaction>> + '_ {
         println!("building transaction...");
         let start = std::time::Instant::now();
-        let tx = penumbra_wallet::build_transaction(
-            &self.fvk,
-            self.view.as_mut().unwrap(),
-            &mut self.custody,
-            OsRng,
-            plan,
-        );
+        let tx = self.custody.as_mut().map(|custody| {
+            penumbra_wallet::build_transaction(
+                &self.fvk,
+                self.view.as_mut().unwrap(),
+                custody,
+                OsRng,
+                plan,
+            )
+        });
         async move {
-            let tx = tx.await?;
+            let tx = tx
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "cannot sign transactions in view-only mode; use --plan-only instead"
+                    )
+                })?
+                .await?;
             let elapsed = start.elapsed();
             println!(
                 "finished proving in {}.{:03} seconds [{} actions, {} proofs, {} bytes]",
@@ -87,17 +143,7 @@ impl App {
     }
 
     async fn pd_channel(&self) -> anyhow::Result<Channel> {
-        match self.pd_url.scheme() {
-            "http" => Ok(Channel::from_shared(self.pd_url.to_string())?
-                .connect()
-                .await?),
-            "https" => Ok(Channel::from_shared(self.pd_url.to_string())?
-                .tls_config(ClientTlsConfig::new())?
-                .connect()
-                .await?),
-            other => Err(anyhow::anyhow!("unknown url scheme {other}"))
-                .with_context(|| format!("could not connect to {}", self.pd_url)),
-        }
+        pd_channel(&self.pd_url).await
     }
 
     pub async fn specific_client(
@@ -121,3 +167,16 @@ impl App {
         Ok(TendermintProxyServiceClient::new(channel))
     }
 }
+
+/// Connect to the pd gRPC endpoint at `pd_url`, using TLS if the URL asks for it.
+pub async fn pd_channel(pd_url: &Url) -> anyhow::Result<Channel> {
+    match pd_url.scheme() {
+        "http" => Ok(Channel::from_shared(pd_url.to_string())?.connect().await?),
+        "https" => Ok(Channel::from_shared(pd_url.to_string())?
+            .tls_config(ClientTlsConfig::new())?
+            .connect()
+            .await?),
+        other => Err(anyhow::anyhow!("unknown url scheme {other}"))
+            .with_context(|| format!("could not connect to {pd_url}")),
+    }
+}
@@ -20,6 +20,7 @@ use penumbra_proto::{
 };
 use penumbra_view::ViewService;
 use penumbra_wallet::KeyStore;
+use std::str::FromStr;
 use tracing_subscriber::EnvFilter;
 use url::Url;
 
@@ -38,7 +39,7 @@ pub struct Opt {
         env = "PENUMBRA_NODE_PD_URL",
         parse(try_from_str = Url::parse),
     )]
-    node: Url,
+    pub node: Url,
     #[clap(subcommand)]
     pub cmd: Command,
     /// The directory to store the wallet and view data in.
@@ -47,6 +48,23 @@ pub struct Opt {
     /// The path to the wallet file itself (overrides `--data-path` if it is set).
     #[clap(long, env = "PENUMBRA_CUSTODY_PATH")]
     pub custody_path: Option<Utf8PathBuf>,
+    /// If set, run in view-only mode with the given full viewing key, without loading a wallet.
+    ///
+    /// Transactions can't be signed in this mode, so they must be planned with `--plan-only` and
+    /// signed elsewhere with `pcli keys sign-plan`.
+    #[clap(
+        long,
+        env = "PENUMBRA_FULL_VIEWING_KEY",
+        parse(try_from_str = FullViewingKey::from_str)
+    )]
+    full_viewing_key: Option<FullViewingKey>,
+    /// If set, write the plans of any transactions to this file instead of signing and
+    /// broadcasting them.
+    #[clap(long, global = true)]
+    plan_only: Option<Utf8PathBuf>,
+    /// Sign transactions without asking for confirmation.
+    #[clap(long, global = true)]
+    pub yes: bool,
     /// If set, use a remote view service instead of local synchronization.
     /// Should be specified as a URL, e.g. http://127.0.0.1:8081.
     #[clap(short, long, env = "PENUMBRA_VIEW_ADDRESS")]
@@ -84,17 +102,26 @@ impl Opt {
             legacy::migrate(&legacy_wallet_path, custody_path.as_path())?;
         }
 
-        // Build the custody service...
-        let wallet = KeyStore::load(custody_path)?;
-        let soft_kms = SoftKms::new(wallet.spend_key.clone().into());
-        let custody_svc = CustodyProtocolServiceServer::new(soft_kms);
-        let custody = CustodyProtocolServiceClient::new(box_grpc_svc::local(custody_svc));
+        // Build the custody service, unless we're running in view-only mode...
+        let (wallet, custody, fvk) = if let Some(fvk) = self.full_viewing_key.clone() {
+            tracing::info!("running in view-only mode");
+            (None, None, fvk)
+        } else {
+            let wallet = KeyStore::load(custody_path)?;
+            let soft_kms = SoftKms::new(wallet.spend_key.clone().into());
+            let custody_svc = CustodyProtocolServiceServer::new(soft_kms);
+            let custody = CustodyProtocolServiceClient::new(box_grpc_svc::local(custody_svc));
 
-        let fvk = wallet.spend_key.full_viewing_key().clone();
+            let fvk = wallet.spend_key.full_viewing_key().clone();
+            (Some(wallet), Some(custody), fvk)
+        };
 
         // ...and the view service...
         let view = if !self.cmd.offline() {
-            Some(self.view_client(&fvk).await?)
+            Some(
+                self.view_client(&fvk, wallet.as_ref().and_then(|w| w.birthday))
+                    .await?,
+            )
         } else {
             None
         };
@@ -107,14 +134,20 @@ impl Opt {
             fvk,
             wallet,
             pd_url,
+            plan_only: self.plan_only,
+            yes: self.yes,
         };
         Ok((app, self.cmd))
     }
 
     /// Constructs a [`ViewProtocolServiceClient`] based on the command-line options.
+    ///
+    /// If a local view database has to be created, it starts syncing from the wallet's `birthday`
+    /// height, if one is known.
     async fn view_client(
         &self,
         fvk: &FullViewingKey,
+        birthday: Option<u64>,
     ) -> Result<ViewProtocolServiceClient<BoxGrpcService>> {
         let svc = if let Some(address) = self.view_address.clone() {
             // Use a remote view service.
@@ -127,7 +160,13 @@ impl Opt {
             let path = self.data_path.join(crate::VIEW_FILE_NAME);
             tracing::info!(%path, "using local view service");
 
-            let svc = ViewService::load_or_initialize(Some(path), fvk, self.node.clone()).await?;
+            let svc = ViewService::load_or_initialize_with_birthday(
+                Some(path),
+                fvk,
+                self.node.clone(),
+                birthday,
+            )
+            .await?;
 
             // Now build the view and custody clients, doing gRPC with ourselves
             let svc = ViewProtocolServiceServer::new(svc);
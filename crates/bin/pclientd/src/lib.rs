@@ -13,9 +13,12 @@ use penumbra_proto::{
     client::v1alpha1::oblivious_query_service_client::ObliviousQueryServiceClient,
     client::v1alpha1::ChainParametersRequest,
     custody::v1alpha1::custody_protocol_service_server::CustodyProtocolServiceServer,
-    view::v1alpha1::view_protocol_service_server::ViewProtocolServiceServer,
+    view::v1alpha1::{
+        view_protocol_service_client::ViewProtocolServiceClient,
+        view_protocol_service_server::ViewProtocolServiceServer,
+    },
 };
-use penumbra_view::{Storage, ViewService};
+use penumbra_view::{DetectionConfig, Storage, ViewService};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -29,6 +32,9 @@ use url::Url;
 mod proxy;
 pub use proxy::{ObliviousQueryProxy, SpecificQueryProxy, TendermintProxyProxy};
 
+mod relayer;
+pub use relayer::{RelayedChannel, Relayer, RelayerConfig};
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PclientdConfig {
@@ -37,6 +43,12 @@ pub struct PclientdConfig {
     pub fvk: FullViewingKey,
     /// Optional KMS config for custody mode
     pub kms_config: Option<soft_kms::Config>,
+    /// Optional config for the built-in IBC relayer, which requires custody mode
+    #[serde(default)]
+    pub relayer: Option<RelayerConfig>,
+    /// Optional config for syncing from a detection server, rather than scanning every block
+    #[serde(default)]
+    pub detection: Option<DetectionConfig>,
 }
 
 impl PclientdConfig {
@@ -106,6 +118,12 @@ impl Opt {
         path
     }
 
+    fn relayer_state_path(&self) -> Utf8PathBuf {
+        let mut path = self.home.clone();
+        path.push("relayer-state.json");
+        path
+    }
+
     async fn init_sqlite(&self, fvk: &FullViewingKey) -> Result<Storage> {
         // Initialize client and storage
         let mut client = ObliviousQueryServiceClient::connect(self.node.to_string()).await?;
@@ -203,6 +221,8 @@ impl Opt {
                 let client_config = PclientdConfig {
                     kms_config,
                     fvk: FullViewingKey::from_str(full_viewing_key.as_ref())?,
+                    relayer: None,
+                    detection: None,
                 };
 
                 let encoded = toml::to_string_pretty(&client_config).unwrap();
@@ -223,6 +243,7 @@ impl Opt {
                     "Failed to load pclientd config file. Have you run `pclientd init` with a FVK?",
                 )?;
                 let storage = opt.load_or_init_sqlite(&config.fvk).await?;
+                let relayer_state_path = opt.relayer_state_path();
 
                 let proxy_channel = tonic::transport::Channel::from_shared(opt.node.to_string())
                     .expect("this is a valid address")
@@ -233,8 +254,33 @@ impl Opt {
                 let specific_query_proxy = SpecificQueryProxy(proxy_channel.clone());
                 let tendermint_proxy_proxy = TendermintProxyProxy(proxy_channel.clone());
 
-                let view_service =
-                    ViewProtocolServiceServer::new(ViewService::new(storage, opt.node).await?);
+                let view_service = ViewProtocolServiceServer::new(
+                    ViewService::new_with_detection(storage, opt.node, config.detection.clone())
+                        .await?,
+                );
+                let relayer = match (&config.relayer, &config.kms_config) {
+                    (Some(relayer_config), Some(kms_config)) => {
+                        // The relayer plans and builds its transactions through our own view
+                        // service, which will be listening by the time it first needs it.
+                        let view = ViewProtocolServiceClient::new(
+                            tonic::transport::Endpoint::from_shared(format!("http://{bind_addr}"))?
+                                .connect_lazy(),
+                        );
+                        Some(Relayer::new(
+                            relayer_config.clone(),
+                            config.fvk.clone(),
+                            kms_config.clone(),
+                            view,
+                            proxy_channel.clone(),
+                            relayer_state_path,
+                        )?)
+                    }
+                    (Some(_), None) => {
+                        anyhow::bail!("the IBC relayer requires pclientd to run in custody mode")
+                    }
+                    (None, _) => None,
+                };
+
                 let custody_service = config.kms_config.as_ref().map(|kms_config| {
                     CustodyProtocolServiceServer::new(SoftKms::new(
                         kms_config.spend_key.clone().into(),
@@ -258,6 +304,11 @@ impl Opt {
                     ))
                     .serve(bind_addr.clone());
 
+                if let Some(relayer) = relayer {
+                    tracing::info!("starting IBC relayer");
+                    tokio::spawn(relayer.run());
+                }
+
                 tokio::spawn(server).await??;
 
                 Ok(())
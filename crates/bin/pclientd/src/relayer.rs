@@ -0,0 +1,946 @@
+//! A minimal IBC relayer, which relays packets from a counterparty chain to Penumbra.
+//!
+//! The relayer only submits transactions to Penumbra, so it relays the packets that Penumbra
+//! needs to hear about: packets sent to Penumbra by the counterparty, and acknowledgements and
+//! timeouts of packets sent from Penumbra to the counterparty. Relaying in the other direction is
+//! left to the counterparty's relayers.
+
+use std::{collections::HashMap, fs, time::Duration};
+
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use ibc_proto::ibc::core::{channel::v1::Packet as RawPacket, client::v1::Height as RawHeight};
+use ibc_types2::{
+    core::{
+        channel::{
+            msgs::{MsgAcknowledgement, MsgRecvPacket, MsgTimeout},
+            ChannelId, Packet,
+        },
+        client::{msgs::MsgUpdateClient, ClientId, Height},
+        commitment::MerkleProof,
+    },
+    lightclients::tendermint::{
+        client_state::ClientState as TendermintClientState, header::Header as TendermintHeader,
+    },
+    path::{AckPath, CommitmentPath, ReceiptPath},
+    timestamp::Timestamp,
+};
+use penumbra_crypto::{keys::AddressIndex, FullViewingKey};
+use penumbra_custody::{policy::AuthPolicy, soft_kms, soft_kms::SoftKms, AuthorizeRequest};
+use penumbra_ibc::IbcAction;
+use penumbra_proto::{
+    client::v1alpha1::{
+        specific_query_service_client::SpecificQueryServiceClient, KeyValueRequest,
+    },
+    view::v1alpha1::view_protocol_service_client::ViewProtocolServiceClient,
+    DomainType,
+};
+use penumbra_view::{Planner, ViewClient};
+use prost::Message;
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tendermint::{abci, block::signed_header::SignedHeader, validator};
+use tendermint_rpc::{endpoint::tx_search, query::Query, Client, HttpClient, Order, Paging};
+use tonic::transport::Channel;
+use url::Url;
+
+/// Configuration for the built-in relayer.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RelayerConfig {
+    /// The CometBFT RPC endpoint of the Penumbra node, used to find packets sent from Penumbra.
+    pub penumbra_rpc: Url,
+    /// The CometBFT RPC endpoint of the counterparty chain.
+    pub counterparty_rpc: Url,
+    /// The ABCI query path on which the counterparty serves proofs of its IBC state.
+    ///
+    /// This is `store/ibc/key` for Cosmos SDK chains, and `state/key` for Penumbra.
+    #[serde(default = "default_counterparty_proof_path")]
+    pub counterparty_proof_path: String,
+    /// The client on Penumbra which tracks the counterparty chain.
+    #[serde_as(as = "DisplayFromStr")]
+    pub client_id: ClientId,
+    /// The channels to relay packets over.
+    pub channels: Vec<RelayedChannel>,
+    /// How long to wait between looking for packets to relay, in seconds.
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+}
+
+/// A transfer channel between Penumbra and the counterparty chain.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RelayedChannel {
+    /// The ID of the channel on Penumbra.
+    #[serde_as(as = "DisplayFromStr")]
+    pub channel_id: ChannelId,
+    /// The ID of the channel on the counterparty chain.
+    #[serde_as(as = "DisplayFromStr")]
+    pub counterparty_channel_id: ChannelId,
+}
+
+fn default_counterparty_proof_path() -> String {
+    "store/ibc/key".to_string()
+}
+
+fn default_poll_interval_secs() -> u64 {
+    10
+}
+
+/// How many times relaying a packet may fail before the relayer stops trying to relay it, until
+/// it's restarted.
+const MAX_RELAY_ATTEMPTS: u32 = 3;
+
+/// The kind of packet event, Penumbra channel, and direction of the packets an [`EventScan`]
+/// finds the events of.
+type ScanKey = (&'static str, ChannelId, bool);
+
+/// Relays packets over the configured channels, paying for and signing the relay transactions
+/// with pclientd's own keys.
+pub struct Relayer {
+    config: RelayerConfig,
+    fvk: FullViewingKey,
+    kms: SoftKms,
+    view: ViewProtocolServiceClient<Channel>,
+    penumbra_query: SpecificQueryServiceClient<Channel>,
+    penumbra_rpc: HttpClient,
+    counterparty_rpc: HttpClient,
+    /// The packet events found so far, by event kind, Penumbra channel, and whether the packets
+    /// were sent from Penumbra.
+    scans: HashMap<ScanKey, EventScan>,
+    /// Where the heights the scans have reached are saved between runs.
+    state_path: Utf8PathBuf,
+    /// How many times relaying each packet has failed, by the scan which found it and its
+    /// sequence number.
+    failures: HashMap<(ScanKey, u64), u32>,
+}
+
+/// One of the chains the relayer relays between.
+#[derive(Clone, Copy, Debug)]
+enum Chain {
+    Penumbra,
+    Counterparty,
+}
+
+/// The packet events of one kind, for one channel and direction, found so far on a chain.
+///
+/// Each poll only searches the blocks since the last one. Only the height the scan has reached is
+/// saved between runs, so a restarted relayer searches the chain again from just before the
+/// earliest event which was still waiting to be relayed.
+#[derive(Default)]
+struct EventScan {
+    /// The height up to which the chain has been searched.
+    scanned_height: u64,
+    /// The events found which are still waiting to be relayed, along with the heights of the
+    /// blocks they were emitted in.
+    events: Vec<(u64, abci::Event)>,
+}
+
+impl EventScan {
+    /// The height up to which every event found has been relayed, from which to resume the scan.
+    fn resume_height(&self) -> u64 {
+        self.events
+            .iter()
+            .map(|(height, _)| height.saturating_sub(1))
+            .min()
+            .unwrap_or(self.scanned_height)
+    }
+}
+
+/// The heights the relayer's scans have reached, as saved between runs.
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+struct RelayerState {
+    scans: Vec<SavedScan>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct SavedScan {
+    kind: String,
+    channel_id: String,
+    from_penumbra: bool,
+    /// The height up to which every event the scan found has been relayed.
+    height: u64,
+}
+
+impl RelayerState {
+    fn load(path: &Utf8PathBuf) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        serde_json::from_str(&fs::read_to_string(path)?)
+            .with_context(|| format!("invalid relayer state in {path}"))
+    }
+
+    fn save(&self, path: &Utf8PathBuf) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn from_scans(scans: &HashMap<ScanKey, EventScan>) -> Self {
+        let mut scans = scans
+            .iter()
+            .map(|((kind, channel_id, from_penumbra), scan)| SavedScan {
+                kind: kind.to_string(),
+                channel_id: channel_id.to_string(),
+                from_penumbra: *from_penumbra,
+                height: scan.resume_height(),
+            })
+            .collect::<Vec<_>>();
+        scans.sort();
+
+        Self { scans }
+    }
+
+    fn into_scans(self) -> Result<HashMap<ScanKey, EventScan>> {
+        self.scans
+            .into_iter()
+            .map(|scan| {
+                let kind = match scan.kind.as_str() {
+                    "send_packet" => "send_packet",
+                    "write_acknowledgement" => "write_acknowledgement",
+                    other => anyhow::bail!("unknown packet event kind {other}"),
+                };
+                Ok((
+                    (kind, scan.channel_id.parse()?, scan.from_penumbra),
+                    EventScan {
+                        scanned_height: scan.height,
+                        events: Vec::new(),
+                    },
+                ))
+            })
+            .collect()
+    }
+}
+
+impl Relayer {
+    /// Creates a relayer, which saves how far it has searched each chain at `state_path`, and
+    /// resumes from there if it was saved by an earlier run.
+    pub fn new(
+        config: RelayerConfig,
+        fvk: FullViewingKey,
+        kms_config: soft_kms::Config,
+        view: ViewProtocolServiceClient<Channel>,
+        node: Channel,
+        state_path: Utf8PathBuf,
+    ) -> Result<Self> {
+        // The relayer signs the transactions it plans itself, so it can't provide the
+        // pre-authorizations such a policy requires.
+        if kms_config
+            .auth_policy
+            .iter()
+            .any(|policy| matches!(policy, AuthPolicy::PreAuthorization(_)))
+        {
+            anyhow::bail!("the relayer can't be used with a pre-authorization policy");
+        }
+        let kms = SoftKms::new(kms_config);
+
+        Ok(Self {
+            penumbra_rpc: HttpClient::new(config.penumbra_rpc.as_str())?,
+            counterparty_rpc: HttpClient::new(config.counterparty_rpc.as_str())?,
+            penumbra_query: SpecificQueryServiceClient::new(node),
+            config,
+            fvk,
+            kms,
+            view,
+            scans: RelayerState::load(&state_path)?.into_scans()?,
+            state_path,
+            failures: HashMap::new(),
+        })
+    }
+
+    /// Relay packets until pclientd stops, logging (rather than stopping on) failures.
+    pub async fn run(mut self) -> Result<()> {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.poll_interval_secs));
+
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.relay().await {
+                tracing::warn!(?e, "failed to relay packets");
+            }
+            if let Err(e) = RelayerState::from_scans(&self.scans).save(&self.state_path) {
+                tracing::warn!(?e, "failed to save relayer state");
+            }
+        }
+    }
+
+    /// Relay every packet that is currently waiting to be relayed, each in its own transaction,
+    /// so that a packet which can't be relayed doesn't hold up the others.
+    async fn relay(&mut self) -> Result<()> {
+        let client_state = self.client_state().await?;
+
+        // The counterparty's state at some height is committed to by the app hash in the header
+        // of the next block, so query its state at the height before its latest block, and prove
+        // it at the latest block.
+        let latest_height = self
+            .counterparty_rpc
+            .status()
+            .await?
+            .sync_info
+            .latest_block_height;
+        let query_height = tendermint::block::Height::try_from(latest_height.value() - 1)?;
+        let signed_header = self
+            .counterparty_rpc
+            .commit(latest_height)
+            .await?
+            .signed_header;
+        let proof_height = Height::new(
+            client_state.latest_height().revision_number(),
+            latest_height.value(),
+        )?;
+
+        let penumbra_status = self.penumbra_rpc.status().await?.sync_info;
+        let penumbra_height = Height::new(0, penumbra_status.latest_block_height.value())?;
+        let penumbra_time: Timestamp = penumbra_status.latest_block_time.into();
+        let counterparty_time: Timestamp = signed_header.header.time.into();
+
+        let mut actions = Vec::new();
+        for channel in self.config.channels.clone() {
+            // Packets sent to Penumbra, which haven't been received yet or timed out.
+            let events = self
+                .pending_events(
+                    Chain::Counterparty,
+                    "send_packet",
+                    &channel,
+                    false,
+                    latest_height.value(),
+                )
+                .await?;
+            let scan = ("send_packet", channel.channel_id.clone(), false);
+            let mut waiting = Vec::new();
+            for (height, event) in events {
+                let packet = packet_from_event(&event)?;
+                if packet.timed_out(&penumbra_time, penumbra_height)
+                    || self.penumbra_has(&receipt_key(&packet)).await?
+                {
+                    continue;
+                }
+                waiting.push((height, event));
+                if self.is_parked(&scan, &packet) {
+                    continue;
+                }
+
+                let path = CommitmentPath {
+                    port_id: packet.port_on_a.clone(),
+                    channel_id: packet.chan_on_a.clone(),
+                    sequence: packet.sequence,
+                };
+                let Some(proof) = self
+                    .prove_membership(path.to_string(), query_height)
+                    .await?
+                else {
+                    continue;
+                };
+
+                let sequence = packet.sequence.into();
+                actions.push((
+                    (scan.clone(), sequence),
+                    IbcAction::RecvPacket(MsgRecvPacket {
+                        packet,
+                        proof_commitment_on_a: proof,
+                        proof_height_on_a: proof_height,
+                        signer: String::new(),
+                    }),
+                ));
+            }
+            self.keep_events(&scan, waiting);
+
+            // Packets sent from Penumbra, which the counterparty has acknowledged.
+            let events = self
+                .pending_events(
+                    Chain::Counterparty,
+                    "write_acknowledgement",
+                    &channel,
+                    true,
+                    latest_height.value(),
+                )
+                .await?;
+            let scan = ("write_acknowledgement", channel.channel_id.clone(), true);
+            let mut waiting = Vec::new();
+            for (height, event) in events {
+                let packet = packet_from_event(&event)?;
+                if !self.penumbra_has(&commitment_key(&packet)).await? {
+                    continue;
+                }
+                let acknowledgement = hex::decode(attribute(&event, "packet_ack_hex")?)?;
+                waiting.push((height, event));
+                if self.is_parked(&scan, &packet) {
+                    continue;
+                }
+
+                let path = AckPath {
+                    port_id: packet.port_on_b.clone(),
+                    channel_id: packet.chan_on_b.clone(),
+                    sequence: packet.sequence,
+                };
+                let Some(proof) = self
+                    .prove_membership(path.to_string(), query_height)
+                    .await?
+                else {
+                    continue;
+                };
+
+                let sequence = packet.sequence.into();
+                actions.push((
+                    (scan.clone(), sequence),
+                    IbcAction::Acknowledgement(MsgAcknowledgement {
+                        acknowledgement: acknowledgement.try_into()?,
+                        packet,
+                        proof_acked_on_b: proof,
+                        proof_height_on_b: proof_height,
+                        signer: String::new(),
+                    }),
+                ));
+            }
+            self.keep_events(&scan, waiting);
+
+            // Packets sent from Penumbra, which timed out before the counterparty received them.
+            let events = self
+                .pending_events(
+                    Chain::Penumbra,
+                    "send_packet",
+                    &channel,
+                    true,
+                    penumbra_status.latest_block_height.value(),
+                )
+                .await?;
+            let scan = ("send_packet", channel.channel_id.clone(), true);
+            let mut waiting = Vec::new();
+            for (height, event) in events {
+                let packet = packet_from_event(&event)?;
+                if !self.penumbra_has(&commitment_key(&packet)).await? {
+                    continue;
+                }
+                waiting.push((height, event));
+
+                if !packet.timed_out(&counterparty_time, proof_height)
+                    || self.is_parked(&scan, &packet)
+                {
+                    continue;
+                }
+                let path = ReceiptPath {
+                    port_id: packet.port_on_b.clone(),
+                    channel_id: packet.chan_on_b.clone(),
+                    sequence: packet.sequence,
+                };
+                let Some(proof) = self.prove_absence(path.to_string(), query_height).await? else {
+                    continue;
+                };
+
+                let sequence = packet.sequence.into();
+                actions.push((
+                    (scan.clone(), sequence),
+                    IbcAction::Timeout(MsgTimeout {
+                        // Transfer channels are unordered, so the counterparty's next receive
+                        // sequence is irrelevant.
+                        next_seq_recv_on_b: packet.sequence,
+                        packet,
+                        proof_unreceived_on_b: proof,
+                        proof_height_on_b: proof_height,
+                        signer: String::new(),
+                    }),
+                ));
+            }
+            self.keep_events(&scan, waiting);
+        }
+
+        if actions.is_empty() {
+            return Ok(());
+        }
+
+        // The proofs are checked against the client's consensus state at the proof height, so
+        // bring the client up to that height first. None of the packets can be relayed without
+        // it, so give up on this poll if it fails.
+        if client_state.latest_height() < proof_height {
+            let update = self.update_client(&client_state, signed_header).await?;
+            self.submit(vec![update])
+                .await
+                .context("can't update client")?;
+        }
+
+        for (packet, action) in actions {
+            match self.submit(vec![action]).await {
+                Ok(()) => {
+                    self.failures.remove(&packet);
+                }
+                Err(e) => {
+                    let ((kind, channel_id, _), sequence) = &packet;
+                    let failures = self.failures.entry(packet.clone()).or_default();
+                    *failures += 1;
+                    tracing::warn!(
+                        ?e,
+                        kind,
+                        %channel_id,
+                        sequence,
+                        failures = *failures,
+                        "failed to relay packet"
+                    );
+                    if *failures >= MAX_RELAY_ATTEMPTS {
+                        tracing::warn!(
+                            kind,
+                            %channel_id,
+                            sequence,
+                            "giving up on relaying packet until the relayer restarts"
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether relaying the packet, found by the given scan, has failed too many times to try
+    /// again.
+    fn is_parked(&self, scan: &ScanKey, packet: &Packet) -> bool {
+        self.failures
+            .get(&(scan.clone(), packet.sequence.into()))
+            .map_or(false, |failures| *failures >= MAX_RELAY_ATTEMPTS)
+    }
+
+    /// The events of the given kind on a chain, for packets sent over the channel in the given
+    /// direction, which may still need relaying: those kept from earlier polls, and those emitted
+    /// since, up to the given height of the chain.
+    async fn pending_events(
+        &mut self,
+        chain: Chain,
+        kind: &'static str,
+        channel: &RelayedChannel,
+        from_penumbra: bool,
+        latest_height: u64,
+    ) -> Result<Vec<(u64, abci::Event)>> {
+        let rpc = match chain {
+            Chain::Penumbra => &self.penumbra_rpc,
+            Chain::Counterparty => &self.counterparty_rpc,
+        };
+        let scan = self
+            .scans
+            .entry((kind, channel.channel_id.clone(), from_penumbra))
+            .or_default();
+
+        if scan.scanned_height < latest_height {
+            let new_events = events(
+                rpc,
+                kind,
+                channel,
+                from_penumbra,
+                scan.scanned_height,
+                latest_height,
+            )
+            .await?;
+            scan.events.extend(new_events);
+            scan.scanned_height = latest_height;
+        }
+
+        Ok(scan.events.clone())
+    }
+
+    /// Replace the events kept for the next poll with the given ones, which still need relaying.
+    fn keep_events(&mut self, scan: &ScanKey, events: Vec<(u64, abci::Event)>) {
+        if let Some(scan) = self.scans.get_mut(scan) {
+            scan.events = events;
+        }
+    }
+
+    /// Submit a transaction containing the given IBC actions to Penumbra.
+    async fn submit(&mut self, actions: Vec<IbcAction>) -> Result<()> {
+        let mut planner = Planner::new(OsRng);
+        for action in actions {
+            planner.ibc_action(action);
+        }
+        let plan = planner
+            .plan(
+                &mut self.view,
+                self.fvk.account_group_id(),
+                AddressIndex::new(0),
+            )
+            .await
+            .context("can't plan relay transaction")?;
+
+        let auth_data = self.kms.sign(&AuthorizeRequest {
+            plan: plan.clone(),
+            account_group_id: Some(self.fvk.account_group_id()),
+            pre_authorizations: Vec::new(),
+        })?;
+        let tx = self.view.witness_and_build(plan, auth_data).await?;
+        let (id, height) = self.view.broadcast_transaction(tx, true).await?;
+        tracing::info!(?id, height, "relayed IBC packets to Penumbra");
+
+        Ok(())
+    }
+
+    /// Build an update of the client on Penumbra to the given header of the counterparty.
+    async fn update_client(
+        &self,
+        client_state: &TendermintClientState,
+        signed_header: SignedHeader,
+    ) -> Result<IbcAction> {
+        let trusted_height = client_state.latest_height();
+        let validator_set = self.validator_set(signed_header.header.height).await?;
+        let trusted_next_validator_set = self
+            .validator_set(tendermint::block::Height::try_from(
+                trusted_height.revision_height() + 1,
+            )?)
+            .await?;
+
+        let header = TendermintHeader {
+            signed_header,
+            validator_set,
+            trusted_height,
+            trusted_validator_set: trusted_next_validator_set,
+        };
+
+        Ok(IbcAction::UpdateClient(MsgUpdateClient {
+            client_id: self.config.client_id.clone(),
+            header: header.into(),
+            signer: String::new(),
+        }))
+    }
+
+    async fn validator_set(&self, height: tendermint::block::Height) -> Result<validator::Set> {
+        let validators = self
+            .counterparty_rpc
+            .validators(height, Paging::All)
+            .await?
+            .validators;
+
+        Ok(validator::Set::without_proposer(validators))
+    }
+
+    /// The state of the client on Penumbra which tracks the counterparty.
+    async fn client_state(&mut self) -> Result<TendermintClientState> {
+        let key = format!("clients/{}/clientState", self.config.client_id);
+        let value = self
+            .penumbra_value(&key)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("client {} not found", self.config.client_id))?;
+
+        TendermintClientState::decode(value.as_slice())
+    }
+
+    async fn penumbra_has(&mut self, key: &str) -> Result<bool> {
+        Ok(self.penumbra_value(key).await?.is_some())
+    }
+
+    async fn penumbra_value(&mut self, key: &str) -> Result<Option<Vec<u8>>> {
+        let response = self
+            .penumbra_query
+            .key_value(KeyValueRequest {
+                key: key.to_string(),
+                ..Default::default()
+            })
+            .await?
+            .into_inner();
+
+        Ok(response.value.map(|value| value.value))
+    }
+
+    /// A proof that the counterparty's state contains the given path, if it does.
+    async fn prove_membership(
+        &self,
+        path: String,
+        height: tendermint::block::Height,
+    ) -> Result<Option<MerkleProof>> {
+        Ok(self
+            .counterparty_query(path, height)
+            .await?
+            .and_then(|(value, proof)| (!value.is_empty()).then_some(proof)))
+    }
+
+    /// A proof that the counterparty's state doesn't contain the given path, if it doesn't.
+    async fn prove_absence(
+        &self,
+        path: String,
+        height: tendermint::block::Height,
+    ) -> Result<Option<MerkleProof>> {
+        Ok(self
+            .counterparty_query(path, height)
+            .await?
+            .and_then(|(value, proof)| value.is_empty().then_some(proof)))
+    }
+
+    // returns the value of the path in the counterparty's state, along with a proof of it, or
+    // `None` if the counterparty couldn't prove it.
+    async fn counterparty_query(
+        &self,
+        path: String,
+        height: tendermint::block::Height,
+    ) -> Result<Option<(Vec<u8>, MerkleProof)>> {
+        let response = self
+            .counterparty_rpc
+            .abci_query(
+                Some(self.config.counterparty_proof_path.clone()),
+                path.clone().into_bytes(),
+                Some(height),
+                true,
+            )
+            .await?;
+
+        let Some(proof_ops) = response.proof.filter(|_| response.code.is_ok()) else {
+            tracing::debug!(%path, log = %response.log, "counterparty couldn't prove path");
+            return Ok(None);
+        };
+
+        let proofs = proof_ops
+            .ops
+            .iter()
+            .map(|op| ics23::CommitmentProof::decode(op.data.as_slice()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Some((response.value, MerkleProof { proofs })))
+    }
+}
+
+/// The events of the given kind on a chain, in the blocks after `after_height` up to and including
+/// `up_to_height`, for packets which were sent over the channel in the given direction, along with
+/// the heights of the blocks they were emitted in.
+async fn events(
+    rpc: &HttpClient,
+    kind: &str,
+    channel: &RelayedChannel,
+    from_penumbra: bool,
+    after_height: u64,
+    up_to_height: u64,
+) -> Result<Vec<(u64, abci::Event)>> {
+    let (src_channel, dst_channel) = if from_penumbra {
+        (&channel.channel_id, &channel.counterparty_channel_id)
+    } else {
+        (&channel.counterparty_channel_id, &channel.channel_id)
+    };
+    let (src_channel, dst_channel) = (src_channel.to_string(), dst_channel.to_string());
+    let query = Query::eq(format!("{kind}.packet_src_channel"), src_channel.clone())
+        .and_eq(format!("{kind}.packet_dst_channel"), dst_channel.clone())
+        .and_gt("tx.height", after_height)
+        .and_lte("tx.height", up_to_height);
+
+    const PER_PAGE: u8 = 100;
+    let mut events = Vec::new();
+    let mut page = 1;
+    loop {
+        let tx_search::Response { txs, total_count } = rpc
+            .tx_search(query.clone(), false, page, PER_PAGE, Order::Ascending)
+            .await?;
+
+        // A transaction may contain other packets as well as the ones we searched for.
+        events.extend(
+            txs.into_iter()
+                .flat_map(|tx| {
+                    let height = tx.height.value();
+                    tx.tx_result
+                        .events
+                        .into_iter()
+                        .map(move |event| (height, event))
+                })
+                .filter(|(_, event)| {
+                    event.kind == kind
+                        && attribute(event, "packet_src_channel").ok() == Some(&src_channel)
+                        && attribute(event, "packet_dst_channel").ok() == Some(&dst_channel)
+                }),
+        );
+
+        if page * u32::from(PER_PAGE) >= total_count {
+            break;
+        }
+        page += 1;
+    }
+
+    Ok(events)
+}
+
+fn attribute<'a>(event: &'a abci::Event, key: &str) -> Result<&'a String> {
+    event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| &attribute.value)
+        .ok_or_else(|| anyhow::anyhow!("{} event missing {key}", event.kind))
+}
+
+// reconstructs a packet from the attributes of a packet event, in the format ibc-go emits them.
+fn packet_from_event(event: &abci::Event) -> Result<Packet> {
+    let (revision_number, revision_height) = attribute(event, "packet_timeout_height")?
+        .split_once('-')
+        .context("invalid packet timeout height")?;
+
+    let raw = RawPacket {
+        sequence: attribute(event, "packet_sequence")?.parse()?,
+        source_port: attribute(event, "packet_src_port")?.clone(),
+        source_channel: attribute(event, "packet_src_channel")?.clone(),
+        destination_port: attribute(event, "packet_dst_port")?.clone(),
+        destination_channel: attribute(event, "packet_dst_channel")?.clone(),
+        data: hex::decode(attribute(event, "packet_data_hex")?)?,
+        timeout_height: Some(RawHeight {
+            revision_number: revision_number.parse()?,
+            revision_height: revision_height.parse()?,
+        }),
+        timeout_timestamp: attribute(event, "packet_timeout_timestamp")?.parse()?,
+    };
+
+    Ok(Packet::try_from(raw)?)
+}
+
+// the key of Penumbra's receipt for a packet sent to it.
+fn receipt_key(packet: &Packet) -> String {
+    format!(
+        "receipts/ports/{}/channels/{}/receipts/{}",
+        packet.port_on_b, packet.chan_on_b, packet.sequence
+    )
+}
+
+// the key of Penumbra's commitment to a packet sent from it, which is removed once the packet is
+// acknowledged or times out.
+fn commitment_key(packet: &Packet) -> String {
+    format!(
+        "commitments/ports/{}/channels/{}/packets/{}",
+        packet.port_on_a, packet.chan_on_a, packet.sequence
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_types2::core::channel::PortId;
+    use penumbra_custody::policy::PreAuthorizationPolicy;
+    use tendermint::abci::EventAttributeIndexExt;
+
+    use super::*;
+
+    #[test]
+    fn packets_are_parsed_from_penumbra_events() -> Result<()> {
+        let packet = Packet::try_from(RawPacket {
+            sequence: 7,
+            source_port: PortId::transfer().to_string(),
+            source_channel: "channel-0".to_string(),
+            destination_port: PortId::transfer().to_string(),
+            destination_channel: "channel-3".to_string(),
+            data: b"{}".to_vec(),
+            timeout_height: Some(RawHeight {
+                revision_number: 1,
+                revision_height: 1_000,
+            }),
+            timeout_timestamp: 1_690_000_000_000_000_000,
+        })?;
+
+        let event = penumbra_ibc::event::write_acknowledgement(&packet, b"ack");
+        assert_eq!(packet_from_event(&event)?, packet);
+        assert_eq!(attribute(&event, "packet_ack_hex")?, &hex::encode(b"ack"));
+        // the attributes the event had before it followed ibc-go are still emitted.
+        assert_eq!(attribute(&event, "acknowledgement")?, &hex::encode(b"ack"));
+        assert_eq!(attribute(&event, "packet_data")?, &hex::encode(b"{}"));
+        assert_eq!(
+            attribute(&event, "timeout_timestamp")?,
+            &packet.timeout_timestamp_on_b.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pre_authorization_policies_are_rejected() -> Result<()> {
+        let config = RelayerConfig {
+            penumbra_rpc: "http://127.0.0.1:26657".parse()?,
+            counterparty_rpc: "http://127.0.0.1:36657".parse()?,
+            counterparty_proof_path: default_counterparty_proof_path(),
+            client_id: "07-tendermint-0".parse()?,
+            channels: Vec::new(),
+            poll_interval_secs: default_poll_interval_secs(),
+        };
+        let spend_key = penumbra_chain::test_keys::SPEND_KEY.clone();
+        let fvk = spend_key.full_viewing_key().clone();
+        let node = Channel::from_static("http://127.0.0.1:8080").connect_lazy();
+        let home = tempfile::tempdir()?;
+        let state_path = Utf8PathBuf::from_path_buf(home.path().join("relayer-state.json"))
+            .expect("temp dir is utf-8");
+        let relayer = |kms_config| {
+            Relayer::new(
+                config.clone(),
+                fvk.clone(),
+                kms_config,
+                ViewProtocolServiceClient::new(node.clone()),
+                node.clone(),
+                state_path.clone(),
+            )
+        };
+
+        let pre_authorization = AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Ed25519 {
+            required_signatures: 1,
+            allowed_signers: vec![ed25519_consensus::SigningKey::new(OsRng).verification_key()],
+        });
+        assert!(relayer(soft_kms::Config {
+            spend_key: spend_key.clone(),
+            auth_policy: vec![AuthPolicy::OnlyIbcRelay, pre_authorization],
+        })
+        .is_err());
+        assert!(relayer(soft_kms::Config {
+            spend_key,
+            auth_policy: vec![AuthPolicy::OnlyIbcRelay],
+        })
+        .is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn scans_resume_before_the_first_event_waiting_to_be_relayed() -> Result<()> {
+        let event = |sequence: u64| {
+            abci::Event::new(
+                "send_packet",
+                vec![("packet_sequence", sequence.to_string()).index()],
+            )
+        };
+
+        let mut scans = HashMap::new();
+        scans.insert(
+            ("send_packet", ChannelId::new(0), false),
+            EventScan {
+                scanned_height: 100,
+                events: vec![(42, event(1)), (37, event(2)), (90, event(3))],
+            },
+        );
+        scans.insert(
+            ("write_acknowledgement", ChannelId::new(0), true),
+            EventScan {
+                scanned_height: 100,
+                events: Vec::new(),
+            },
+        );
+
+        let home = tempfile::tempdir()?;
+        let path = Utf8PathBuf::from_path_buf(home.path().join("relayer-state.json"))
+            .expect("temp dir is utf-8");
+        // a relayer which has never run starts from the beginning of each chain.
+        assert_eq!(RelayerState::load(&path)?, RelayerState::default());
+
+        RelayerState::from_scans(&scans).save(&path)?;
+        let state = RelayerState::load(&path)?;
+        assert_eq!(
+            state,
+            RelayerState {
+                scans: vec![
+                    SavedScan {
+                        kind: "send_packet".to_string(),
+                        channel_id: "channel-0".to_string(),
+                        from_penumbra: false,
+                        height: 36,
+                    },
+                    SavedScan {
+                        kind: "write_acknowledgement".to_string(),
+                        channel_id: "channel-0".to_string(),
+                        from_penumbra: true,
+                        height: 100,
+                    },
+                ]
+            }
+        );
+
+        let scans = state.into_scans()?;
+        assert_eq!(
+            scans[&("send_packet", ChannelId::new(0), false)].scanned_height,
+            36
+        );
+        assert!(scans.values().all(|scan| scan.events.is_empty()));
+
+        Ok(())
+    }
+}
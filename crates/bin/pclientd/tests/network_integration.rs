@@ -41,6 +41,8 @@ async fn transaction_send_flow() -> anyhow::Result<()> {
             spend_key: test_keys::SPEND_KEY.clone(),
             auth_policy: Vec::new(),
         }),
+        relayer: None,
+        detection: None,
     };
 
     let mut config_file_path = data_dir.path().to_owned();
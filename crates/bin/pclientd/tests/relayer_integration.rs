@@ -0,0 +1,201 @@
+//! Integration testing of `pclientd`'s built-in relayer, between two local `pd` instances.
+//!
+//! Relays to the Penumbra chain in the `PENUMBRA_NODE_PD_URL` environment variable, whose
+//! CometBFT RPC endpoint is in `PENUMBRA_RELAYER_PENUMBRA_RPC`, from the counterparty chain whose
+//! CometBFT RPC endpoint is in `PENUMBRA_RELAYER_COUNTERPARTY_RPC`.
+//!
+//! Tests assume that the two chains are connected by a transfer channel `channel-0` on both
+//! sides, with the client `07-tendermint-0` on Penumbra tracking the counterparty, and that none
+//! of the following have been relayed to Penumbra yet:
+//!
+//! - the first packet sent over the channel from the counterparty;
+//! - the acknowledgement of the first packet sent over the channel from Penumbra, which the
+//!   counterparty has received;
+//! - the timeout of the second packet sent over the channel from Penumbra, which timed out before
+//!   the counterparty received it.
+//!
+//! Each test runs its own relayer, which relays all of them, so the tests should be run one at a
+//! time, with `--test-threads 1`.
+
+use assert_cmd::cargo::CommandCargoExt;
+use ibc_types2::lightclients::tendermint::client_state::ClientState as TendermintClientState;
+use pclientd::{PclientdConfig, RelayedChannel, RelayerConfig};
+use penumbra_chain::test_keys;
+use penumbra_custody::soft_kms;
+use penumbra_proto::{
+    client::v1alpha1::{
+        specific_query_service_client::SpecificQueryServiceClient, KeyValueRequest,
+    },
+    DomainType,
+};
+use std::process::Command as StdCommand;
+use tempfile::{tempdir, TempDir};
+use tokio::process::{Child, Command as TokioCommand};
+use tonic::transport::Channel;
+
+// runs a `pclientd` instance in the background, which relays over the channel.
+fn start_relayer() -> anyhow::Result<(TempDir, Child)> {
+    // Create a tempdir for the pclientd instance to run in.
+    let data_dir = tempdir().unwrap();
+
+    let config = PclientdConfig {
+        fvk: test_keys::FULL_VIEWING_KEY.clone(),
+        kms_config: Some(soft_kms::Config {
+            spend_key: test_keys::SPEND_KEY.clone(),
+            auth_policy: Vec::new(),
+        }),
+        relayer: Some(RelayerConfig {
+            penumbra_rpc: std::env::var("PENUMBRA_RELAYER_PENUMBRA_RPC")?.parse()?,
+            counterparty_rpc: std::env::var("PENUMBRA_RELAYER_COUNTERPARTY_RPC")?.parse()?,
+            counterparty_proof_path: "state/key".to_string(),
+            client_id: "07-tendermint-0".parse()?,
+            channels: vec![RelayedChannel {
+                channel_id: "channel-0".parse()?,
+                counterparty_channel_id: "channel-0".parse()?,
+            }],
+            poll_interval_secs: 1,
+        }),
+        detection: None,
+    };
+
+    let mut config_file_path = data_dir.path().to_owned();
+    config_file_path.push("config.toml");
+    config.save(&config_file_path)?;
+
+    let home_dir = data_dir.path().to_owned();
+    let mut pclientd_cmd = StdCommand::cargo_bin("pclientd")?;
+    pclientd_cmd.args(["--home", home_dir.as_path().to_str().unwrap(), "start"]);
+    let mut pclientd_cmd = TokioCommand::from(pclientd_cmd);
+    pclientd_cmd.kill_on_drop(true);
+
+    let pclientd = pclientd_cmd.spawn()?;
+
+    Ok((data_dir, pclientd))
+}
+
+async fn query_client() -> anyhow::Result<SpecificQueryServiceClient<Channel>> {
+    Ok(SpecificQueryServiceClient::connect(std::env::var("PENUMBRA_NODE_PD_URL")?).await?)
+}
+
+async fn penumbra_value(
+    query_client: &mut SpecificQueryServiceClient<Channel>,
+    key: &str,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    let response = query_client
+        .key_value(KeyValueRequest {
+            key: key.to_string(),
+            ..Default::default()
+        })
+        .await?
+        .into_inner();
+
+    Ok(response.value.map(|value| value.value))
+}
+
+// waits for the key to be present in Penumbra's state, or absent, as the relayer relays packets,
+// returning whether it was.
+async fn wait_for_key(
+    pclientd: &mut Child,
+    query_client: &mut SpecificQueryServiceClient<Channel>,
+    key: &str,
+    present: bool,
+) -> anyhow::Result<bool> {
+    for _ in 0..60 {
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        if let Some(status) = pclientd.try_wait()? {
+            return Err(anyhow::anyhow!("pclientd exited early: {status:?}"));
+        }
+
+        if penumbra_value(query_client, key).await?.is_some() == present {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+#[ignore]
+#[tokio::test]
+async fn relay_packet_to_penumbra() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+    let mut query_client = query_client().await?;
+    let client_state_key = "clients/07-tendermint-0/clientState";
+    let client_state = |value: Option<Vec<u8>>| {
+        TendermintClientState::decode(value.expect("client exists").as_slice())
+    };
+    let height_before =
+        client_state(penumbra_value(&mut query_client, client_state_key).await?)?.latest_height();
+
+    let (_data_dir, mut pclientd) = start_relayer()?;
+
+    // Penumbra only records its receipt of the packet once the relayer has relayed it.
+    assert!(
+        wait_for_key(
+            &mut pclientd,
+            &mut query_client,
+            "receipts/ports/transfer/channels/channel-0/receipts/1",
+            true,
+        )
+        .await?,
+        "packet was not relayed to Penumbra"
+    );
+
+    // The packet was proven against a later state of the counterparty than the client had, so
+    // the relayer updated the client first.
+    let height_after =
+        client_state(penumbra_value(&mut query_client, client_state_key).await?)?.latest_height();
+    assert!(
+        height_after > height_before,
+        "client was not updated before relaying"
+    );
+
+    pclientd.kill().await?;
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn relay_acknowledgement_to_penumbra() -> anyhow::Result<()> {
+    let mut query_client = query_client().await?;
+    let (_data_dir, mut pclientd) = start_relayer()?;
+
+    // Penumbra removes its commitment to the packet once it processes the acknowledgement.
+    assert!(
+        wait_for_key(
+            &mut pclientd,
+            &mut query_client,
+            "commitments/ports/transfer/channels/channel-0/packets/1",
+            false,
+        )
+        .await?,
+        "acknowledgement was not relayed to Penumbra"
+    );
+
+    pclientd.kill().await?;
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn relay_timeout_to_penumbra() -> anyhow::Result<()> {
+    let mut query_client = query_client().await?;
+    let (_data_dir, mut pclientd) = start_relayer()?;
+
+    // Penumbra removes its commitment to the packet once it processes the timeout.
+    assert!(
+        wait_for_key(
+            &mut pclientd,
+            &mut query_client,
+            "commitments/ports/transfer/channels/channel-0/packets/2",
+            false,
+        )
+        .await?,
+        "timeout was not relayed to Penumbra"
+    );
+
+    pclientd.kill().await?;
+
+    Ok(())
+}
@@ -0,0 +1,304 @@
+use std::{collections::BTreeSet, pin::Pin, sync::Arc};
+
+use futures::{StreamExt, TryStreamExt};
+use penumbra_chain::NoteSource;
+use penumbra_compact_block::{CompactBlock, StatePayload};
+use penumbra_crypto::fmd;
+use penumbra_proto::{
+    client::v1alpha1::{
+        detection_service_server::DetectionService,
+        oblivious_query_service_server::ObliviousQueryService,
+        tendermint_proxy_service_server::TendermintProxyService, CompactBlockRangeRequest,
+        CompactBlockRangeResponse, DetectedCompactBlockRangeRequest, GetBlockByHeightRequest,
+    },
+    DomainType,
+};
+use penumbra_tendermint_proxy::TendermintProxy;
+use penumbra_transaction::Transaction;
+use sha2::Digest;
+use tokio::sync::Semaphore;
+use tonic::Status;
+use tracing::instrument;
+
+use crate::Info;
+
+/// The most detection keys a client can give a detection server, which examines every clue on
+/// the chain with each of them.
+pub const MAX_DETECTION_KEYS: usize = 1024;
+
+/// Serves compact blocks filtered by fuzzy message detection, so that clients can delegate the
+/// bulk of their scanning to this node.
+///
+/// The clues that FMD examines are only found in full transactions, which are stored by
+/// Tendermint rather than pd, so they're fetched through the Tendermint proxy.
+///
+/// Since every stream examines every clue with each of its client's keys, only `max_streams`
+/// streams are served at once, and further requests are turned away until one of them ends.
+#[derive(Clone)]
+pub struct Detection {
+    info: Info,
+    tm_proxy: TendermintProxy,
+    streams: Arc<Semaphore>,
+}
+
+impl Detection {
+    pub fn new(info: Info, tm_proxy: TendermintProxy, max_streams: usize) -> Self {
+        Self {
+            info,
+            tm_proxy,
+            streams: Arc::new(Semaphore::new(max_streams)),
+        }
+    }
+
+    /// Roll up the note payloads of the transactions in the compact block that weren't flagged
+    /// by any of the detection keys.
+    async fn detect(
+        &self,
+        detection_keys: &[fmd::DetectionKey],
+        mut block: CompactBlock,
+    ) -> anyhow::Result<CompactBlock> {
+        // Only blocks with notes from transactions have anything to withhold, so there's no need
+        // to fetch the others.
+        if !has_transaction_notes(&block) {
+            return Ok(block);
+        }
+
+        let tm_block = self
+            .tm_proxy
+            .get_block_by_height(tonic::Request::new(GetBlockByHeightRequest {
+                height: block.height.try_into()?,
+            }))
+            .await?
+            .into_inner()
+            .block
+            .ok_or_else(|| anyhow::anyhow!("missing block at height {}", block.height))?;
+        let txs = tm_block.data.map(|data| data.txs).unwrap_or_default();
+
+        let undetected = undetected_transactions(detection_keys, &block, &txs)?;
+        block.roll_up_notes_from(&undetected);
+
+        Ok(block)
+    }
+}
+
+// returns whether the compact block has any notes created by transactions.
+fn has_transaction_notes(block: &CompactBlock) -> bool {
+    block.state_payloads.iter().any(|payload| {
+        matches!(
+            payload,
+            StatePayload::Note {
+                source: NoteSource::Transaction { .. },
+                ..
+            }
+        )
+    })
+}
+
+// returns the IDs of the transactions in the block whose notes can be withheld, because none of
+// their clues were flagged by any of the detection keys.
+fn undetected_transactions(
+    detection_keys: &[fmd::DetectionKey],
+    block: &CompactBlock,
+    txs: &[Vec<u8>],
+) -> anyhow::Result<BTreeSet<[u8; 32]>> {
+    let mut undetected = BTreeSet::new();
+    for tx_bytes in txs {
+        let id: [u8; 32] = sha2::Sha256::digest(tx_bytes.as_slice())
+            .as_slice()
+            .try_into()
+            .expect("sha256 hashes are 32 bytes");
+        let clues = Transaction::decode(tx_bytes.as_slice())?
+            .transaction_body()
+            .fmd_clues;
+
+        let flagged = clues
+            .iter()
+            .any(|clue| detection_keys.iter().any(|dtk| dtk.examine(clue)));
+
+        // Transactions only carry one clue per output, but some of their other actions, like
+        // swap claims or inbound ICS-20 transfers, can also create notes.  Since those notes
+        // aren't covered by a clue, they can't be withheld.
+        let notes = block
+            .state_payloads
+            .iter()
+            .filter(|payload| {
+                matches!(
+                    payload,
+                    StatePayload::Note {
+                        source: NoteSource::Transaction { id: source },
+                        ..
+                    } if source == &id
+                )
+            })
+            .count();
+
+        if !flagged && notes <= clues.len() {
+            undetected.insert(id);
+        }
+    }
+
+    Ok(undetected)
+}
+
+#[tonic::async_trait]
+impl DetectionService for Detection {
+    type DetectedCompactBlockRangeStream = Pin<
+        Box<dyn futures::Stream<Item = Result<CompactBlockRangeResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn detected_compact_block_range(
+        &self,
+        request: tonic::Request<DetectedCompactBlockRangeRequest>,
+    ) -> Result<tonic::Response<Self::DetectedCompactBlockRangeStream>, Status> {
+        let DetectedCompactBlockRangeRequest {
+            chain_id,
+            start_height,
+            end_height,
+            keep_alive,
+            detection_keys,
+        } = request.into_inner();
+
+        if detection_keys.is_empty() {
+            return Err(Status::invalid_argument("no detection keys provided"));
+        }
+        if detection_keys.len() > MAX_DETECTION_KEYS {
+            return Err(Status::invalid_argument(format!(
+                "too many detection keys provided: at most {MAX_DETECTION_KEYS} are allowed"
+            )));
+        }
+        let detection_keys = detection_keys
+            .into_iter()
+            .map(|bytes| {
+                bytes
+                    .as_slice()
+                    .try_into()
+                    .ok()
+                    .and_then(|bytes| fmd::DetectionKey::from_bytes(bytes).ok())
+                    .ok_or_else(|| Status::invalid_argument("invalid detection key"))
+            })
+            .collect::<Result<Vec<_>, Status>>()?;
+        let detection_keys = Arc::new(detection_keys);
+
+        // The permit is held by the stream, and released when the client drops it.
+        let permit = Arc::new(self.streams.clone().try_acquire_owned().map_err(|_| {
+            Status::resource_exhausted("too many detection streams are being served")
+        })?);
+
+        let blocks = self
+            .info
+            .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
+                chain_id,
+                start_height,
+                end_height,
+                keep_alive,
+            }))
+            .await?
+            .into_inner();
+
+        let detection = self.clone();
+        let blocks = blocks.and_then(move |response| {
+            let detection = detection.clone();
+            let detection_keys = detection_keys.clone();
+            let permit = permit.clone();
+            async move {
+                let _permit = permit;
+                let block = CompactBlock::try_from(response)
+                    .map_err(|e| Status::internal(e.to_string()))?;
+                let block = detection
+                    .detect(&detection_keys, block)
+                    .await
+                    .map_err(|e| Status::unavailable(format!("error detecting block: {e}")))?;
+
+                Ok(CompactBlockRangeResponse::from(block))
+            }
+        });
+
+        Ok(tonic::Response::new(blocks.boxed()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_chain::test_keys;
+    use penumbra_crypto::{
+        keys::{SeedPhrase, SpendKey},
+        Address, Note,
+    };
+    use rand_core::OsRng;
+
+    use super::*;
+
+    // a transaction with a clue for each of the given addresses, and the ID it is known by.
+    fn transaction(clued: &[Address]) -> (Vec<u8>, [u8; 32]) {
+        let mut transaction = Transaction::default();
+        transaction.transaction_body.fmd_clues = clued
+            .iter()
+            .map(|address| {
+                address
+                    .clue_key()
+                    .create_clue(fmd::MAX_PRECISION, OsRng)
+                    .unwrap()
+            })
+            .collect();
+
+        let bytes = transaction.encode_to_vec();
+        let id = sha2::Sha256::digest(&bytes)
+            .as_slice()
+            .try_into()
+            .expect("sha256 hashes are 32 bytes");
+
+        (bytes, id)
+    }
+
+    fn note(address: &Address, id: [u8; 32]) -> StatePayload {
+        let value = Value {
+            amount: 1u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+
+        StatePayload::Note {
+            source: NoteSource::Transaction { id },
+            note: Box::new(Note::generate(&mut OsRng, address, value).payload()),
+        }
+    }
+
+    #[test]
+    fn only_flagged_transactions_are_detected() -> anyhow::Result<()> {
+        let (ours, dtk) = test_keys::FULL_VIEWING_KEY.payment_address(0u32.into());
+        let (theirs, _) = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0)
+            .full_viewing_key()
+            .payment_address(0u32.into());
+
+        // a transaction sending a note to us, one sending a note to someone else, and one which
+        // created a note without a clue, like a swap claim.
+        let (to_us, to_us_id) = transaction(&[ours]);
+        let (to_them, to_them_id) = transaction(&[theirs]);
+        let (unclued, unclued_id) = transaction(&[]);
+
+        let mut block = CompactBlock {
+            state_payloads: vec![
+                note(&ours, to_us_id),
+                note(&theirs, to_them_id),
+                note(&ours, unclued_id),
+            ],
+            ..Default::default()
+        };
+        assert!(has_transaction_notes(&block));
+
+        let undetected = undetected_transactions(&[dtk], &block, &[to_us, to_them, unclued])?;
+        assert_eq!(undetected, BTreeSet::from([to_them_id]));
+
+        block.roll_up_notes_from(&undetected);
+        assert!(matches!(block.state_payloads[0], StatePayload::Note { .. }));
+        assert!(matches!(block.state_payloads[1], StatePayload::RolledUp(_)));
+        assert!(matches!(block.state_payloads[2], StatePayload::Note { .. }));
+
+        // once every note has been rolled up, there's nothing left to detect.
+        block.roll_up_notes_from(&BTreeSet::from([to_us_id, unclued_id]));
+        assert!(!has_transaction_notes(&block));
+
+        Ok(())
+    }
+}
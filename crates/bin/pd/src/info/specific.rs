@@ -19,8 +19,10 @@ use penumbra_proto::{
     self as proto,
     client::v1alpha1::{
         specific_query_service_server::SpecificQueryService, BatchSwapOutputDataRequest,
-        DenomMetadataByIdRequest, KeyValueRequest, KeyValueResponse, ProposalInfoRequest,
-        ProposalInfoResponse, ProposalRateDataRequest, ProposalRateDataResponse,
+        DenomMetadataByIdRequest, KeyValueRequest, KeyValueResponse, ProposalEventsRequest,
+        ProposalEventsResponse, ProposalInfoRequest, ProposalInfoResponse, ProposalRateDataRequest,
+        ProposalRateDataResponse, StateCommitmentTreeFrontierRequest,
+        StateCommitmentTreeFrontierResponse, UpgradePlanRequest, UpgradePlanResponse,
         ValidatorStatusRequest,
     },
     StateReadProto as _,
@@ -766,6 +768,157 @@ impl SpecificQueryService for Info {
         ))
     }
 
+    #[instrument(skip(self, request))]
+    async fn upgrade_plan(
+        &self,
+        request: tonic::Request<UpgradePlanRequest>,
+    ) -> Result<tonic::Response<UpgradePlanResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let upgrade_plan = state
+            .upgrade_plan()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        let current_height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(UpgradePlanResponse {
+            upgrade_plan: upgrade_plan.map(Into::into),
+            current_height,
+        }))
+    }
+
+    type ProposalEventsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<ProposalEventsResponse, tonic::Status>> + Send>>;
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            start_height = request.get_ref().start_height,
+            proposal_id = request.get_ref().proposal_id,
+            keep_alive = request.get_ref().keep_alive,
+        ),
+    )]
+    async fn proposal_events(
+        &self,
+        request: tonic::Request<ProposalEventsRequest>,
+    ) -> Result<tonic::Response<Self::ProposalEventsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let ProposalEventsRequest {
+            start_height,
+            proposal_id,
+            keep_alive,
+            ..
+        } = request.into_inner();
+
+        // Subscribe to new snapshots before replaying past events, so that no block can be
+        // committed in between the end of the replay and the start of following new blocks.
+        let mut state_rx = self.storage.subscribe();
+
+        let s = try_stream! {
+            let mut snapshot = state_rx.borrow_and_update().clone();
+            let mut next_height = start_height;
+            loop {
+                // Send all the events from blocks we haven't yet covered, up to the latest one
+                // (more than one block may have been committed since we were last notified).
+                let current_height = snapshot.version();
+                let events = snapshot.proposal_events(next_height..=current_height).await?;
+                for (height, event) in events {
+                    if proposal_id.map_or(true, |id| id == event.proposal_id) {
+                        yield ProposalEventsResponse {
+                            height,
+                            event: Some(event.into()),
+                        };
+                    }
+                }
+                next_height = next_height.max(current_height + 1);
+
+                // If the client didn't request a keep-alive, we're done after the replay.
+                if !keep_alive {
+                    break;
+                }
+
+                // Wait for the next block to be committed.
+                state_rx.changed().await?;
+                snapshot = state_rx.borrow().clone();
+            }
+        };
+
+        Ok(tonic::Response::new(
+            s.map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error getting proposal events: {e}"))
+            })
+            // TODO: how do we instrument a Stream
+            //.instrument(Span::current())
+            .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request), fields(height = request.get_ref().height))]
+    async fn state_commitment_tree_frontier(
+        &self,
+        request: tonic::Request<StateCommitmentTreeFrontierRequest>,
+    ) -> Result<tonic::Response<StateCommitmentTreeFrontierResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        state
+            .check_chain_id(&request.get_ref().chain_id)
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("chain_id not OK: {e}")))?;
+
+        let birthday = request.get_ref().height;
+        let current_height = state
+            .get_block_height()
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting block height: {e}")))?;
+
+        let (height, tree) = if birthday >= current_height {
+            // Nothing before the latest block can be relevant, so the current tree will do.
+            (current_height, state.state_commitment_tree().await)
+        } else {
+            // Otherwise, use the snapshot taken at the end of the epoch before the birthday.
+            let epoch = state
+                .epoch_by_height(birthday)
+                .await
+                .map_err(|e| Status::unavailable(format!("error getting epoch: {e}")))?;
+            let previous_epoch = epoch
+                .index
+                .checked_sub(1)
+                .ok_or_else(|| Status::not_found("no snapshot exists before the first epoch"))?;
+            state
+                .state_commitment_tree_frontier(previous_epoch)
+                .await
+                .map_err(|e| Status::unavailable(format!("error getting snapshot: {e}")))?
+                .ok_or_else(|| {
+                    Status::not_found(format!("no snapshot exists for epoch {previous_epoch}"))
+                })?
+        };
+
+        let fmd_parameters = state
+            .get_current_fmd_parameters()
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting FMD parameters: {e}")))?;
+
+        Ok(tonic::Response::new(StateCommitmentTreeFrontierResponse {
+            height,
+            tree: bincode::serialize(&tree)
+                .map_err(|e| Status::internal(format!("error serializing snapshot: {e}")))?,
+            anchor: Some(tree.root().into()),
+            fmd_parameters: Some(fmd_parameters.into()),
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn key_value(
         &self,
@@ -933,3 +1086,103 @@ impl SpecificQueryService for Info {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_chain::{
+        component::StateWriteExt as _,
+        params::{ChainParameters, FmdParameters},
+        Epoch,
+    };
+    use penumbra_crypto::Fq;
+    use penumbra_sct::component::SctManager as _;
+    use penumbra_storage::{Storage, TempStorage};
+    use penumbra_tct as tct;
+
+    use super::*;
+
+    const CHAIN_ID: &str = "penumbra-test";
+
+    // commits blocks 1 through 4, with two blocks in each epoch and a note in each block.
+    async fn storage() -> anyhow::Result<TempStorage> {
+        let storage = TempStorage::new().await?;
+        for height in 1..=4u64 {
+            let mut state = StateDelta::new(storage.latest_snapshot());
+            if height == 1 {
+                state.put_chain_params(ChainParameters {
+                    chain_id: CHAIN_ID.to_string(),
+                    ..Default::default()
+                });
+                state.put_current_fmd_parameters(FmdParameters::default());
+            }
+            state.put_block_height(height);
+            state.put_epoch_by_height(
+                height,
+                Epoch {
+                    index: (height - 1) / 2,
+                    start_height: height - (height - 1) % 2,
+                },
+            );
+            state
+                .add_sct_commitment(tct::StateCommitment(Fq::from(height)), None)
+                .await?;
+            state.end_sct_block(height % 2 == 0).await?;
+            storage.commit(state).await?;
+        }
+        Ok(storage)
+    }
+
+    async fn frontier(
+        storage: &TempStorage,
+        height: u64,
+    ) -> Result<StateCommitmentTreeFrontierResponse, Status> {
+        let info = Info::new(Storage::clone(storage));
+        info.state_commitment_tree_frontier(tonic::Request::new(
+            StateCommitmentTreeFrontierRequest {
+                chain_id: CHAIN_ID.to_string(),
+                height,
+            },
+        ))
+        .await
+        .map(tonic::Response::into_inner)
+    }
+
+    #[tokio::test]
+    async fn frontier_is_taken_from_the_end_of_the_previous_epoch() -> anyhow::Result<()> {
+        let storage = storage().await?;
+        let state = storage.latest_snapshot();
+
+        let response = frontier(&storage, 3).await?;
+        assert_eq!(response.height, 2);
+        let tree: tct::Tree = bincode::deserialize(&response.tree)?;
+        let anchor = state.anchor_by_height(2).await?.unwrap();
+        assert_eq!(tree.root(), anchor);
+        assert_eq!(response.anchor, Some(anchor.into()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn frontier_after_the_latest_block_is_the_current_tree() -> anyhow::Result<()> {
+        let storage = storage().await?;
+        let state = storage.latest_snapshot();
+
+        let response = frontier(&storage, 10).await?;
+        assert_eq!(response.height, 4);
+        let tree: tct::Tree = bincode::deserialize(&response.tree)?;
+        assert_eq!(tree.root(), state.state_commitment_tree().await.root());
+        assert_eq!(Some(tree.root()), state.anchor_by_height(4).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn there_is_no_frontier_before_the_first_epoch() -> anyhow::Result<()> {
+        let storage = storage().await?;
+
+        let status = frontier(&storage, 1).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        Ok(())
+    }
+}
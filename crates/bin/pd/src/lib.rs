@@ -3,6 +3,7 @@
 #![recursion_limit = "512"]
 
 mod consensus;
+mod detection;
 mod info;
 mod mempool;
 mod metrics;
@@ -13,6 +14,7 @@ pub mod testnet;
 
 pub use crate::metrics::register_metrics;
 pub use consensus::Consensus;
+pub use detection::Detection;
 pub use info::Info;
 pub use mempool::Mempool;
 pub use penumbra_app::app::App;
@@ -18,6 +18,7 @@ use pd::testnet::{
     url_has_necessary_parts,
 };
 use penumbra_proto::client::v1alpha1::{
+    detection_service_server::DetectionServiceServer,
     oblivious_query_service_server::ObliviousQueryServiceServer,
     specific_query_service_server::SpecificQueryServiceServer,
     tendermint_proxy_service_server::TendermintProxyServiceServer,
@@ -85,6 +86,16 @@ enum RootCommand {
         /// and force re-issuance, possibly hitting the rate limit.
         #[clap(long, value_name = "DOMAIN", display_order = 200)]
         grpc_auto_https: Option<String>,
+        /// Serve compact blocks filtered by fuzzy message detection on the gRPC server, for
+        /// clients that give it their detection keys.
+        ///
+        /// Each detection stream examines every clue on the chain with each of the client's keys,
+        /// so this is off by default.
+        #[clap(long, display_order = 202)]
+        enable_detection_server: bool,
+        /// The most detection streams to serve at once, if the detection server is enabled.
+        #[clap(long, default_value = "16", display_order = 203)]
+        detection_max_streams: usize,
         /// Bind the metrics endpoint to this socket.
         #[clap(
             short,
@@ -220,6 +231,8 @@ async fn main() -> anyhow::Result<()> {
             abci_bind,
             grpc_bind,
             grpc_auto_https,
+            enable_detection_server,
+            detection_max_streams,
             metrics_bind,
             tendermint_addr,
         } => {
@@ -227,6 +240,7 @@ async fn main() -> anyhow::Result<()> {
                 ?abci_bind,
                 ?grpc_bind,
                 ?grpc_auto_https,
+                ?enable_detection_server,
                 ?metrics_bind,
                 ?tendermint_addr,
                 "starting pd"
@@ -273,6 +287,8 @@ async fn main() -> anyhow::Result<()> {
                 }));
             let info = pd::Info::new(storage.clone());
             let tm_proxy = TendermintProxy::new(tendermint_addr);
+            let detection = enable_detection_server
+                .then(|| pd::Detection::new(info.clone(), tm_proxy.clone(), detection_max_streams));
             let snapshot = pd::Snapshot {};
 
             let abci_server = tokio::task::Builder::new()
@@ -308,6 +324,10 @@ async fn main() -> anyhow::Result<()> {
                 .add_service(tonic_web::enable(TendermintProxyServiceServer::new(
                     tm_proxy.clone(),
                 )))
+                .add_optional_service(
+                    detection
+                        .map(|detection| tonic_web::enable(DetectionServiceServer::new(detection))),
+                )
                 .add_service(tonic_web::enable(
                     tonic_reflection::server::Builder::configure()
                         .register_encoded_file_descriptor_set(penumbra_proto::FILE_DESCRIPTOR_SET)
@@ -1,17 +1,20 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use anyhow::{Context, Result};
 use ark_ff::PrimeField;
 use async_trait::async_trait;
 use decaf377::Fq;
+use ibc_types2::core::client::ClientId;
 use once_cell::sync::Lazy;
 use penumbra_asset::STAKING_TOKEN_DENOM;
-use penumbra_chain::component::StateReadExt as _;
+use penumbra_chain::{component::StateReadExt as _, params::ChainParameters};
 use penumbra_crypto::{
     keys::{FullViewingKey, NullifierKey},
     rdsa::{VerificationKey, VerificationKeyBytes},
 };
+use penumbra_dao::component::StateReadExt as _;
 use penumbra_governance::{ProposalNft, VotingReceiptToken};
+use penumbra_ibc::component::ClientStateReadExt as _;
 use penumbra_sct::component::StateReadExt as _;
 use penumbra_shielded_pool::component::SupplyWrite;
 use penumbra_storage::{StateDelta, StateRead, StateWrite};
@@ -22,7 +25,7 @@ use penumbra_transaction::{AuthorizationData, WitnessData};
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 
 use crate::action_handler::ActionHandler;
-use crate::governance::{StateReadExt as _, StateWriteExt as _};
+use crate::governance::{ProposalEventKind, StateReadExt as _, StateWriteExt as _};
 
 // IMPORTANT: these length limits are enforced by consensus! Changing them will change which
 // transactions are accepted by the network, and so they *cannot* be changed without a network
@@ -68,10 +71,35 @@ impl ActionHandler for ProposalSubmit {
         match payload {
             Signaling { commit: _ } => { /* all signaling proposals are valid */ }
             Emergency { halt_chain: _ } => { /* all emergency proposals are valid */ }
+            UpgradePlan { height: _, version } => {
+                if version.is_empty() {
+                    anyhow::bail!("upgrade plan proposal must specify a version");
+                }
+            }
             ParameterChange { old, new } => {
                 old.check_valid_update(new)
                     .context("invalid change to chain parameters")?;
             }
+            PartialParameterChange { changes } => {
+                ChainParameters::check_valid_changes(changes)
+                    .context("invalid partial change to chain parameters")?;
+            }
+            DaoGrant { grant } => {
+                grant.check_valid().context("invalid DAO grant")?;
+            }
+            DaoGrantCancel { grant_id: _ } => { /* all grant cancellations are well-formed */ }
+            IbcClientSubstitution {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                let subject_client_id =
+                    ClientId::from_str(subject_client_id).context("invalid subject client ID")?;
+                let substitute_client_id = ClientId::from_str(substitute_client_id)
+                    .context("invalid substitute client ID")?;
+                if subject_client_id == substitute_client_id {
+                    anyhow::bail!("subject and substitute clients must be different");
+                }
+            }
             DaoSpend { transaction_plan } => {
                 // Check to make sure that the transaction plan contains only valid actions for the
                 // DAO (none of them should require proving to build):
@@ -149,6 +177,47 @@ impl ActionHandler for ProposalSubmit {
             ProposalPayload::ParameterChange { .. } => {
                 /* no stateful checks for parameter change (checks are applied when proposal finishes) */
             }
+            ProposalPayload::PartialParameterChange { changes } => {
+                // The changes must apply to the current chain parameters (they are checked again
+                // when the proposal finishes, since the parameters may change during voting)
+                chain_parameters
+                    .apply_changes(changes)
+                    .context("partial change does not apply to current chain parameters")?;
+            }
+            ProposalPayload::DaoGrant { .. } => {
+                // DAO grants spend from the DAO, so they are subject to the same switch as DAO
+                // spend proposals
+                anyhow::ensure!(
+                    chain_parameters.dao_spend_proposals_enabled,
+                    "DAO spend proposals are not enabled",
+                );
+            }
+            ProposalPayload::DaoGrantCancel { grant_id } => {
+                // Only grants which are still being paid can be cancelled
+                anyhow::ensure!(
+                    state.dao_grant_remaining(*grant_id).await?.is_some(),
+                    "DAO grant {grant_id} is not active",
+                );
+            }
+            ProposalPayload::IbcClientSubstitution {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                // Both clients must exist (whether they are frozen, expired, or active is checked
+                // when the proposal finishes, since that may change during voting)
+                for client_id in [subject_client_id, substitute_client_id] {
+                    state
+                        .get_client_state(&ClientId::from_str(client_id)?)
+                        .await?;
+                }
+            }
+            ProposalPayload::UpgradePlan { height, .. } => {
+                // The upgrade can't be scheduled for a height before the proposal could pass
+                state
+                    .check_height_in_future_of_voting_end(*height)
+                    .await
+                    .context("upgrade height is too soon")?;
+            }
             ProposalPayload::DaoSpend { transaction_plan } => {
                 // If DAO spend proposals aren't enabled, then we can't allow them to be submitted
                 anyhow::ensure!(
@@ -248,6 +317,25 @@ impl ActionHandler for ProposalSubmit {
         // state needed to vote as delegators
         state.mark_proposal_started();
 
+        // Record the submission and the start of voting for clients following the proposal
+        state
+            .record_proposal_event(
+                proposal_id,
+                ProposalEventKind::Submitted {
+                    proposal: proposal.clone(),
+                },
+            )
+            .await?;
+        state
+            .record_proposal_event(
+                proposal_id,
+                ProposalEventKind::VotingStarted {
+                    start_height: current_block,
+                    end_height: voting_end,
+                },
+            )
+            .await?;
+
         tracing::debug!(proposal = %proposal_id, "created proposal");
 
         Ok(())
@@ -9,7 +9,7 @@ use penumbra_transaction::{action::ProposalWithdraw, proposal};
 
 use crate::{
     action_handler::ActionHandler,
-    governance::{StateReadExt, StateWriteExt},
+    governance::{ProposalEventKind, StateReadExt, StateWriteExt},
 };
 
 #[async_trait]
@@ -44,6 +44,14 @@ impl ActionHandler for ProposalWithdraw {
                 reason: reason.clone(),
             },
         );
+        state
+            .record_proposal_event(
+                *proposal,
+                ProposalEventKind::Withdrawn {
+                    reason: reason.clone(),
+                },
+            )
+            .await?;
 
         // Register the denom for the withdrawn proposal NFT
         state
@@ -12,7 +12,7 @@ use penumbra_transaction::{
 
 use crate::{
     action_handler::ActionHandler,
-    governance::{StateReadExt, StateWriteExt},
+    governance::{ProposalEventKind, StateReadExt, StateWriteExt},
 };
 
 #[async_trait]
@@ -74,6 +74,21 @@ impl ActionHandler for ValidatorVote {
 
         tracing::debug!(proposal = %proposal, "cast validator vote");
         state.cast_validator_vote(*proposal, *identity_key, *vote);
+        state
+            .record_proposal_event(
+                *proposal,
+                ProposalEventKind::ValidatorVoteCast {
+                    identity_key: *identity_key,
+                    vote: *vote,
+                },
+            )
+            .await?;
+
+        // Validator votes are counted immediately, so the tally changes with each one
+        let tally = state.current_tally(*proposal).await?;
+        state
+            .record_proposal_event(*proposal, ProposalEventKind::TallyUpdated { tally })
+            .await?;
 
         // If a proposal is an emergency proposal, every validator vote triggers a check to see if
         // we should immediately enact the proposal (if it's reached a 2/3 majority).
@@ -89,7 +104,6 @@ impl ActionHandler for ValidatorVote {
         // withdrawal should prevent any proposal, even an emergency proposal, from being enacted.
         if !proposal_state.is_withdrawn() && proposal_payload.is_emergency() {
             tracing::debug!(proposal = %proposal, "proposal is emergency, checking for emergency pass condition");
-            let tally = state.current_tally(*proposal).await?;
             let total_voting_power = state
                 .total_voting_power_at_proposal_start(*proposal)
                 .await?;
@@ -104,6 +118,10 @@ impl ActionHandler for ValidatorVote {
                         tracing::warn!(proposal = %proposal, %error, "error enacting emergency proposal")
                     }
                 }
+                // The deposit of a passed proposal is always refundable
+                state
+                    .conclude_proposal_deposit(*proposal, &proposal::Outcome::<()>::Passed)
+                    .await?;
                 // Update the proposal state to reflect the outcome (it will always be passed,
                 // because we got to this point)
                 state.put_proposal_state(
@@ -112,6 +130,14 @@ impl ActionHandler for ValidatorVote {
                         outcome: proposal::Outcome::Passed,
                     },
                 );
+                state
+                    .record_proposal_event(
+                        *proposal,
+                        ProposalEventKind::Concluded {
+                            outcome: proposal::Outcome::Passed,
+                        },
+                    )
+                    .await?;
             }
         }
 
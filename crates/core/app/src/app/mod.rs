@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use penumbra_chain::params::FmdParameters;
 use penumbra_chain::{
     component::{AppHash, StateReadExt as _, StateWriteExt as _},
@@ -9,6 +9,7 @@ use penumbra_chain::{
 use penumbra_compact_block::component::StateWriteExt as _;
 use penumbra_compact_block::CompactBlock;
 use penumbra_component::Component;
+use penumbra_dao::component::Dao;
 use penumbra_dex::component::{Dex, SwapManager};
 use penumbra_distributions::component::Distributions;
 use penumbra_ibc::component::IBCComponent;
@@ -54,6 +55,13 @@ impl App {
             anyhow::bail!("chain is halted, refusing to restart");
         }
 
+        // Similarly, if a scheduled upgrade has taken effect, we should not proceed unless this is
+        // the version of the software named by the upgrade plan.
+        state
+            .check_upgrade_plan_allows(APP_VERSION)
+            .await
+            .context("refusing to restart after a scheduled upgrade")?;
+
         Ok(Self { state })
     }
 
@@ -116,6 +124,7 @@ impl App {
         IBCComponent::init_chain(&mut state_tx, &()).await;
         Dex::init_chain(&mut state_tx, &()).await;
         Governance::init_chain(&mut state_tx, &()).await;
+        Dao::init_chain(&mut state_tx, &()).await;
         ShieldedPool::init_chain(&mut state_tx, app_state).await;
 
         // Create a synthetic height-zero block
@@ -258,6 +267,7 @@ impl App {
         IBCComponent::end_block(&mut arc_state_tx, end_block).await;
         Dex::end_block(&mut arc_state_tx, end_block).await;
         Governance::end_block(&mut arc_state_tx, end_block).await;
+        Dao::end_block(&mut arc_state_tx, end_block).await;
         ShieldedPool::end_block(&mut arc_state_tx, end_block).await;
         let mut state_tx = Arc::try_unwrap(arc_state_tx)
             .expect("components did not retain copies of shared state");
@@ -279,6 +289,7 @@ impl App {
             IBCComponent::end_epoch(&mut arc_state_tx).await.unwrap();
             Dex::end_epoch(&mut arc_state_tx).await.unwrap();
             Governance::end_epoch(&mut arc_state_tx).await.unwrap();
+            Dao::end_epoch(&mut arc_state_tx).await.unwrap();
             ShieldedPool::end_epoch(&mut arc_state_tx).await.unwrap();
 
             let mut state_tx = Arc::try_unwrap(arc_state_tx)
@@ -420,6 +431,9 @@ impl App {
             .await
             .expect("must be able to read halt flag");
 
+        // Check if a scheduled upgrade means this software should stop processing blocks.
+        let should_upgrade = state.check_upgrade_plan_allows(APP_VERSION).await;
+
         // Commit the pending writes, clearing the state.
         let jmt_root = storage
             .commit(state)
@@ -432,6 +446,12 @@ impl App {
             std::process::exit(0);
         }
 
+        // If a scheduled upgrade has taken effect, we should also end the process here.
+        if let Err(error) = should_upgrade {
+            tracing::info!(%error, "committed block at the height of a scheduled upgrade; exiting now");
+            std::process::exit(0);
+        }
+
         let app_hash: AppHash = jmt_root.into();
 
         tracing::debug!(?app_hash, "finished committing state");
@@ -452,6 +472,10 @@ impl App {
     }
 }
 
+/// The version of this software, which is compared against the version named by any scheduled
+/// upgrade plan to decide whether to proceed past the upgrade height.
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 /// The total number of times the chain has been halted.
 ///
 /// Increment this manually after fixing the root cause for a chain halt: updated nodes will then be
@@ -8,7 +8,7 @@ use penumbra_transaction::proposal;
 use tendermint::v0_34::abci;
 use tracing::instrument;
 
-use super::{tally, StateReadExt as _, StateWriteExt as _};
+use super::{tally, ProposalEventKind, StateReadExt as _, StateWriteExt as _};
 use penumbra_component::Component;
 
 pub struct Governance {}
@@ -24,11 +24,16 @@ impl Component for Governance {
         state.init_proposal_counter();
     }
 
-    #[instrument(name = "governance", skip(_state, _begin_block))]
+    #[instrument(name = "governance", skip(state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
-        _state: &mut Arc<S>,
+        state: &mut Arc<S>,
         _begin_block: &abci::request::BeginBlock,
     ) {
+        let state = Arc::get_mut(state).expect("state should be unique");
+        state
+            .clear_applied_upgrade_plan()
+            .await
+            .expect("clearing the applied upgrade plan should never fail");
     }
 
     #[instrument(name = "governance", skip(state, _end_block))]
@@ -140,8 +145,21 @@ pub async fn enact_all_passed_proposals<S: StateWrite>(mut state: S) -> Result<(
             }
         };
 
+        // Settle the deposit: refundable deposits await their claim, slashed ones go to the DAO
+        state
+            .conclude_proposal_deposit(proposal_id, &outcome)
+            .await?;
+
         // Update the proposal state to reflect the outcome
-        state.put_proposal_state(proposal_id, proposal::State::Finished { outcome });
+        state.put_proposal_state(
+            proposal_id,
+            proposal::State::Finished {
+                outcome: outcome.clone(),
+            },
+        );
+        state
+            .record_proposal_event(proposal_id, ProposalEventKind::Concluded { outcome })
+            .await?;
     }
 
     Ok(())
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use penumbra_num::Amount;
+use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType, TypeUrl};
+use penumbra_transaction::proposal::Outcome;
+
+/// What became of a proposal's deposit, recorded when voting on the proposal concluded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ProposalDepositOutcome",
+    into = "pb::ProposalDepositOutcome"
+)]
+pub struct DepositOutcome {
+    /// The amount of the deposit.
+    pub amount: Amount,
+    /// The height at which voting on the proposal concluded.
+    pub height: u64,
+    /// Where the deposit went.
+    pub disposition: Disposition,
+}
+
+/// Where a proposal's deposit went once voting concluded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disposition {
+    /// The deposit is returned to the proposer when they claim it.
+    Refundable,
+    /// The deposit was slashed, and sent to the DAO.
+    SlashedToDao,
+}
+
+impl Disposition {
+    /// The disposition of the deposit for a proposal with the given outcome.
+    pub fn for_outcome<W>(outcome: &Outcome<W>) -> Self {
+        if outcome.should_be_refunded() {
+            Disposition::Refundable
+        } else {
+            Disposition::SlashedToDao
+        }
+    }
+}
+
+impl From<DepositOutcome> for pb::ProposalDepositOutcome {
+    fn from(value: DepositOutcome) -> Self {
+        use pb::proposal_deposit_outcome::{Outcome, Refundable, SlashedToDao};
+
+        pb::ProposalDepositOutcome {
+            amount: Some(value.amount.into()),
+            height: value.height,
+            outcome: Some(match value.disposition {
+                Disposition::Refundable => Outcome::Refundable(Refundable {}),
+                Disposition::SlashedToDao => Outcome::SlashedToDao(SlashedToDao {}),
+            }),
+        }
+    }
+}
+
+impl TryFrom<pb::ProposalDepositOutcome> for DepositOutcome {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ProposalDepositOutcome) -> Result<Self, Self::Error> {
+        use pb::proposal_deposit_outcome::Outcome;
+
+        Ok(DepositOutcome {
+            amount: msg
+                .amount
+                .ok_or_else(|| anyhow::anyhow!("missing amount in `ProposalDepositOutcome`"))?
+                .try_into()?,
+            height: msg.height,
+            disposition: match msg
+                .outcome
+                .ok_or_else(|| anyhow::anyhow!("missing outcome in `ProposalDepositOutcome`"))?
+            {
+                Outcome::Refundable(_) => Disposition::Refundable,
+                Outcome::SlashedToDao(_) => Disposition::SlashedToDao,
+            },
+        })
+    }
+}
+
+impl TypeUrl for DepositOutcome {
+    const TYPE_URL: &'static str = "/penumbra.core.governance.v1alpha1.ProposalDepositOutcome";
+}
+
+impl DomainType for DepositOutcome {
+    type Proto = pb::ProposalDepositOutcome;
+}
@@ -1 +1,191 @@
+use serde::{Deserialize, Serialize};
 
+use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType, TypeUrl};
+use penumbra_stake::IdentityKey;
+use penumbra_transaction::{
+    action::Vote,
+    proposal::{self, Proposal},
+};
+
+use super::tally::Tally;
+
+/// An event in the lifecycle of a governance proposal.
+///
+/// These are recorded in the non-consensus state, indexed by the height at which they occurred, so
+/// that clients can replay the history of proposals and follow along as new events happen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::ProposalEvent", into = "pb::ProposalEvent")]
+pub struct ProposalEvent {
+    /// The proposal this event concerns.
+    pub proposal_id: u64,
+    /// What happened to the proposal.
+    pub kind: ProposalEventKind,
+}
+
+/// The kind of a [`ProposalEvent`].
+#[derive(Debug, Clone)]
+pub enum ProposalEventKind {
+    /// The proposal was submitted.
+    Submitted { proposal: Proposal },
+    /// Voting began on the proposal.
+    VotingStarted { start_height: u64, end_height: u64 },
+    /// A validator voted on the proposal.
+    ValidatorVoteCast {
+        identity_key: IdentityKey,
+        vote: Vote,
+    },
+    /// A delegator voted on the proposal, with the given voting power for each option.
+    DelegatorVoteCast {
+        identity_key: IdentityKey,
+        tally: Tally,
+    },
+    /// The running tally of the proposal changed.
+    TallyUpdated { tally: Tally },
+    /// The proposal was withdrawn by its proposer.
+    Withdrawn { reason: String },
+    /// Voting on the proposal concluded with the given outcome.
+    Concluded { outcome: proposal::Outcome<String> },
+}
+
+impl From<ProposalEvent> for pb::ProposalEvent {
+    fn from(event: ProposalEvent) -> Self {
+        use pb::proposal_event::{Event, Submitted, TallyUpdated, VoteCast, VotingStarted};
+
+        let ProposalEvent { proposal_id, kind } = event;
+        let event = match kind {
+            ProposalEventKind::Submitted { proposal } => Event::Submitted(Submitted {
+                proposal: Some(proposal.into()),
+            }),
+            ProposalEventKind::VotingStarted {
+                start_height,
+                end_height,
+            } => Event::VotingStarted(VotingStarted {
+                start_height,
+                end_height,
+            }),
+            ProposalEventKind::ValidatorVoteCast { identity_key, vote } => {
+                Event::VoteCast(VoteCast {
+                    identity_key: Some(identity_key.into()),
+                    validator_vote: Some(vote.into()),
+                    delegator_tally: None,
+                })
+            }
+            ProposalEventKind::DelegatorVoteCast {
+                identity_key,
+                tally,
+            } => Event::VoteCast(VoteCast {
+                identity_key: Some(identity_key.into()),
+                validator_vote: None,
+                delegator_tally: Some(tally.into()),
+            }),
+            ProposalEventKind::TallyUpdated { tally } => Event::TallyUpdated(TallyUpdated {
+                tally: Some(tally.into()),
+            }),
+            ProposalEventKind::Withdrawn { reason } => {
+                Event::Withdrawn(pb::proposal_state::Withdrawn { reason })
+            }
+            ProposalEventKind::Concluded { outcome } => {
+                match pb::ProposalOutcome::from(outcome)
+                    .outcome
+                    .expect("outcome is always set")
+                {
+                    pb::proposal_outcome::Outcome::Passed(passed) => Event::Passed(passed),
+                    pb::proposal_outcome::Outcome::Failed(failed) => Event::Failed(failed),
+                    pb::proposal_outcome::Outcome::Slashed(slashed) => Event::Slashed(slashed),
+                }
+            }
+        };
+
+        pb::ProposalEvent {
+            proposal_id,
+            event: Some(event),
+        }
+    }
+}
+
+impl TryFrom<pb::ProposalEvent> for ProposalEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ProposalEvent) -> Result<Self, Self::Error> {
+        use pb::proposal_event::{Event, Submitted, TallyUpdated, VoteCast, VotingStarted};
+
+        let kind = match msg
+            .event
+            .ok_or_else(|| anyhow::anyhow!("missing proposal event"))?
+        {
+            Event::Submitted(Submitted { proposal }) => ProposalEventKind::Submitted {
+                proposal: proposal
+                    .ok_or_else(|| anyhow::anyhow!("missing proposal"))?
+                    .try_into()?,
+            },
+            Event::VotingStarted(VotingStarted {
+                start_height,
+                end_height,
+            }) => ProposalEventKind::VotingStarted {
+                start_height,
+                end_height,
+            },
+            Event::VoteCast(VoteCast {
+                identity_key,
+                validator_vote,
+                delegator_tally,
+            }) => {
+                let identity_key = identity_key
+                    .ok_or_else(|| anyhow::anyhow!("missing identity key"))?
+                    .try_into()?;
+                match (validator_vote, delegator_tally) {
+                    (Some(vote), None) => ProposalEventKind::ValidatorVoteCast {
+                        identity_key,
+                        vote: vote.try_into()?,
+                    },
+                    (None, Some(tally)) => ProposalEventKind::DelegatorVoteCast {
+                        identity_key,
+                        tally: tally.into(),
+                    },
+                    _ => anyhow::bail!(
+                        "vote cast event must have exactly one of a validator vote or a delegator tally"
+                    ),
+                }
+            }
+            Event::TallyUpdated(TallyUpdated { tally }) => ProposalEventKind::TallyUpdated {
+                tally: tally
+                    .ok_or_else(|| anyhow::anyhow!("missing tally"))?
+                    .into(),
+            },
+            Event::Withdrawn(pb::proposal_state::Withdrawn { reason }) => {
+                ProposalEventKind::Withdrawn { reason }
+            }
+            Event::Passed(passed) => ProposalEventKind::Concluded {
+                outcome: pb::ProposalOutcome {
+                    outcome: Some(pb::proposal_outcome::Outcome::Passed(passed)),
+                }
+                .try_into()?,
+            },
+            Event::Failed(failed) => ProposalEventKind::Concluded {
+                outcome: pb::ProposalOutcome {
+                    outcome: Some(pb::proposal_outcome::Outcome::Failed(failed)),
+                }
+                .try_into()?,
+            },
+            Event::Slashed(slashed) => ProposalEventKind::Concluded {
+                outcome: pb::ProposalOutcome {
+                    outcome: Some(pb::proposal_outcome::Outcome::Slashed(slashed)),
+                }
+                .try_into()?,
+            },
+        };
+
+        Ok(ProposalEvent {
+            proposal_id: msg.proposal_id,
+            kind,
+        })
+    }
+}
+
+impl TypeUrl for ProposalEvent {
+    const TYPE_URL: &'static str = "/penumbra.core.governance.v1alpha1.ProposalEvent";
+}
+
+impl DomainType for ProposalEvent {
+    type Proto = pb::ProposalEvent;
+}
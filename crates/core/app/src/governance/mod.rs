@@ -1,4 +1,5 @@
-mod component;
+pub(crate) mod component;
+mod deposit;
 pub(crate) mod event;
 mod metrics;
 mod view;
@@ -8,5 +9,7 @@ pub mod tally;
 
 pub use self::metrics::register_metrics;
 pub use component::Governance;
+pub use deposit::{DepositOutcome, Disposition};
+pub use event::{ProposalEvent, ProposalEventKind};
 pub use tally::Tally;
 pub use view::{StateReadExt, StateWriteExt};
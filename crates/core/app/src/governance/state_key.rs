@@ -21,6 +21,14 @@ pub fn proposal_deposit_amount(proposal_id: u64) -> String {
     format!("governance/proposal/{proposal_id:020}/deposit_amount")
 }
 
+pub fn proposal_deposit_outcome(proposal_id: u64) -> String {
+    format!("governance/proposal/{proposal_id:020}/deposit_outcome")
+}
+
+pub fn slashed_deposits_total() -> &'static str {
+    "governance/slashed_deposits_total"
+}
+
 pub fn proposal_voting_start(proposal_id: u64) -> String {
     format!("governance/proposal/{proposal_id:020}/voting_start")
 }
@@ -113,8 +121,36 @@ pub fn change_chain_params_at_height(block_height: u64) -> String {
     format!("governance/change_chain_params/{block_height}/")
 }
 
+pub fn upgrade_plan() -> &'static str {
+    "governance/upgrade_plan"
+}
+
 // Used for object store:
 
 pub fn proposal_started() -> &'static str {
     "governance/proposal_started"
 }
+
+pub fn proposal_event(height: u64, index: u64) -> String {
+    format!("governance/events/{height:020}/{index:020}")
+}
+
+pub fn proposal_events_at_height(height: u64) -> String {
+    // Note: this has to be the prefix of the `proposal_event` function above.
+    format!("governance/events/{height:020}/")
+}
+
+pub fn proposal_event_height(key: &[u8]) -> Option<u64> {
+    // Note: this has to parse the keys written by the `proposal_event` function above.
+    std::str::from_utf8(key)
+        .ok()?
+        .strip_prefix("governance/events/")?
+        .split('/')
+        .next()?
+        .parse()
+        .ok()
+}
+
+pub fn proposal_event_count() -> &'static str {
+    "governance/proposal_event_count"
+}
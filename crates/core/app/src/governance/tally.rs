@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use penumbra_chain::params::{ChainParameters, Ratio};
 use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType, TypeUrl};
 use penumbra_transaction::{
-    action::Vote,
+    action::{SplitVote, Vote},
     proposal::{self, Withdrawn},
 };
 
@@ -81,6 +81,15 @@ impl From<(u64, Vote)> for Tally {
     }
 }
 
+impl From<(SplitVote, u64)> for Tally {
+    fn from((vote, power): (SplitVote, u64)) -> Self {
+        vote.split_power(power)
+            .into_iter()
+            .map(Tally::from)
+            .fold(Tally::default(), Add::add)
+    }
+}
+
 impl Add for Tally {
     type Output = Self;
 
@@ -1,26 +1,30 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
+    ops::RangeInclusive,
     str::FromStr,
 };
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use futures::{StreamExt, TryStreamExt};
-use penumbra_asset::{asset, Value, STAKING_TOKEN_DENOM};
+use futures::{future, StreamExt, TryStreamExt};
+use ibc_types2::core::client::ClientId;
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID, STAKING_TOKEN_DENOM};
 use penumbra_chain::{
     component::{StateReadExt as _, StateWriteExt as _},
     params::ChainParameters,
 };
 use penumbra_crypto::Nullifier;
+use penumbra_dao::component::StateWriteExt as _;
+use penumbra_ibc::component::ClientStateWriteExt as _;
 use penumbra_num::Amount;
-use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
 use penumbra_shielded_pool::component::{StateReadExt as _, SupplyRead};
 use penumbra_stake::{DelegationToken, GovernanceKey, IdentityKey};
 use penumbra_storage::{StateRead, StateWrite};
 use penumbra_tct as tct;
 use penumbra_transaction::{
-    action::Vote,
-    proposal::{self, Proposal, ProposalPayload},
+    action::{SplitVote, Vote},
+    proposal::{self, Proposal, ProposalPayload, UpgradePlan},
     Transaction,
 };
 use tokio::task::JoinSet;
@@ -28,7 +32,12 @@ use tracing::instrument;
 
 use penumbra_stake::{rate::RateData, validator, StateReadExt as _};
 
-use super::{state_key, tally::Tally};
+use super::{
+    deposit::{DepositOutcome, Disposition},
+    event::{ProposalEvent, ProposalEventKind},
+    state_key,
+    tally::Tally,
+};
 
 #[async_trait]
 pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
@@ -54,6 +63,20 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
             .await
     }
 
+    /// Get what became of a proposal's deposit, if voting on the proposal has concluded.
+    async fn proposal_deposit_outcome(&self, proposal_id: u64) -> Result<Option<DepositOutcome>> {
+        self.get(&state_key::proposal_deposit_outcome(proposal_id))
+            .await
+    }
+
+    /// Get the total amount of proposal deposits which have been slashed and sent to the DAO.
+    async fn slashed_deposits_total(&self) -> Result<Amount> {
+        Ok(self
+            .get(state_key::slashed_deposits_total())
+            .await?
+            .unwrap_or_default())
+    }
+
     /// Get the state of a proposal.
     async fn proposal_state(&self, proposal_id: u64) -> Result<Option<proposal::State>> {
         Ok(self
@@ -552,6 +575,75 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
             .await?)
     }
 
+    /// Get the upgrade plan scheduled by the most recently passed upgrade plan proposal, if any.
+    async fn upgrade_plan(&self) -> Result<Option<UpgradePlan>> {
+        self.get(state_key::upgrade_plan()).await
+    }
+
+    /// Check whether the given software version is allowed to process blocks after the current
+    /// block height.
+    ///
+    /// Returns an error if an upgrade plan has been scheduled for the current block height, and
+    /// the upgrade requires a different software version than `version`. The plan only applies at
+    /// its height: once the upgraded software processes the next block, the plan is cleared (see
+    /// [`StateWriteExt::clear_applied_upgrade_plan`]), so later releases aren't held to it.
+    async fn check_upgrade_plan_allows(&self, version: &str) -> Result<()> {
+        if let Some(UpgradePlan {
+            height: upgrade_height,
+            version: upgrade_version,
+        }) = self.upgrade_plan().await?
+        {
+            let height = self.get_block_height().await?;
+            if upgrade_height == height && upgrade_version != version {
+                anyhow::bail!(
+                    "chain was upgraded to version {} at height {}, but this is version {}",
+                    upgrade_version,
+                    upgrade_height,
+                    version
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Get all the proposal lifecycle events which occurred within a range of heights, in order,
+    /// along with the height of each.
+    ///
+    /// This is a single ordered scan over the recorded events, rather than a lookup per height,
+    /// since most blocks have no proposal events.
+    async fn proposal_events(
+        &self,
+        heights: RangeInclusive<u64>,
+    ) -> Result<Vec<(u64, ProposalEvent)>> {
+        if heights.is_empty() {
+            return Ok(Vec::new());
+        }
+        let start = state_key::proposal_events_at_height(*heights.start());
+        let end = state_key::proposal_events_at_height(*heights.end());
+
+        // Heights are zero-padded, so every event in the range has the common prefix of the keys
+        // for the first and last heights, which narrows the scan to start near the first height.
+        let common = start
+            .bytes()
+            .zip(end.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        self.nonconsensus_prefix_raw(start[..common].as_bytes())
+            .try_skip_while(|(key, _)| future::ready(Ok(key.as_slice() < start.as_bytes())))
+            .try_take_while(|(key, _)| {
+                future::ready(Ok(key.get(..end.len()) <= Some(end.as_bytes())))
+            })
+            .map(|entry| {
+                let (key, bytes) = entry?;
+                let height =
+                    state_key::proposal_event_height(&key).context("invalid proposal event key")?;
+                Ok((height, ProposalEvent::decode(bytes.as_slice())?))
+            })
+            .try_collect()
+            .await
+    }
+
     /// Check if any proposal is started in this block.
     fn proposal_started(&self) -> bool {
         self.object_get::<()>(&state_key::proposal_started())
@@ -633,6 +725,29 @@ pub trait StateWriteExt: StateWrite {
         Ok(proposal_id)
     }
 
+    /// Record an event in the lifecycle of a proposal, as having occurred in the current block.
+    async fn record_proposal_event(
+        &mut self,
+        proposal_id: u64,
+        kind: ProposalEventKind,
+    ) -> Result<()> {
+        let height = self.get_block_height().await?;
+
+        // Number the events within the block, so that they can be replayed in the order they
+        // occurred (the counter is in the object store, so it starts over with each block)
+        let index = self
+            .object_get::<u64>(state_key::proposal_event_count())
+            .unwrap_or_default();
+        self.object_put(state_key::proposal_event_count(), index + 1);
+
+        self.nonconsensus_put_raw(
+            state_key::proposal_event(height, index).into_bytes(),
+            ProposalEvent { proposal_id, kind }.encode_to_vec(),
+        );
+
+        Ok(())
+    }
+
     /// Mark a nullifier as spent for a given proposal.
     async fn mark_nullifier_voted(&mut self, proposal_id: u64, nullifier: &Nullifier) {
         self.put_proto(
@@ -653,6 +768,53 @@ pub trait StateWriteExt: StateWrite {
         self.put(state_key::proposal_deposit_amount(proposal_id), amount);
     }
 
+    /// Record what becomes of a proposal's deposit now that voting on it has concluded with the
+    /// given outcome.
+    ///
+    /// The deposit was burned when the proposal was submitted. If the proposal is refunded, the
+    /// proposer re-mints it when they claim it; if the proposal was slashed, it is deposited
+    /// into the DAO here, which is the only path by which slashed deposits leave governance.
+    async fn conclude_proposal_deposit<W: Send + Sync>(
+        &mut self,
+        proposal_id: u64,
+        outcome: &proposal::Outcome<W>,
+    ) -> Result<()> {
+        let amount = self
+            .proposal_deposit_amount(proposal_id)
+            .await?
+            .context("proposal has deposit amount")?;
+        let disposition = Disposition::for_outcome(outcome);
+
+        if disposition == Disposition::SlashedToDao {
+            self.dao_deposit(Value {
+                amount,
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            })
+            .await?;
+            let total = self.slashed_deposits_total().await?;
+            self.put(
+                state_key::slashed_deposits_total().to_owned(),
+                total + amount,
+            );
+            tracing::info!(
+                proposal = %proposal_id,
+                %amount,
+                "slashed proposal deposit sent to DAO"
+            );
+        }
+
+        self.put(
+            state_key::proposal_deposit_outcome(proposal_id),
+            DepositOutcome {
+                amount,
+                height: self.get_block_height().await?,
+                disposition,
+            },
+        );
+
+        Ok(())
+    }
+
     /// Set the state of a proposal.
     fn put_proposal_state(&mut self, proposal_id: u64, state: proposal::State) {
         // Set the state of the proposal
@@ -715,11 +877,11 @@ pub trait StateWriteExt: StateWrite {
         &mut self,
         proposal_id: u64,
         identity_key: IdentityKey,
-        vote: Vote,
+        vote: SplitVote,
         nullifier: &Nullifier,
         unbonded_amount: Amount,
     ) -> Result<()> {
-        // Convert the unbonded amount into voting power
+        // Convert the unbonded amount into voting power, divided between the options of the vote
         let power = unbonded_amount.value() as u64;
         let tally: Tally = (vote, power).into();
 
@@ -729,7 +891,14 @@ pub trait StateWriteExt: StateWrite {
             tally,
         );
 
-        Ok(())
+        self.record_proposal_event(
+            proposal_id,
+            ProposalEventKind::DelegatorVoteCast {
+                identity_key,
+                tally,
+            },
+        )
+        .await
     }
 
     /// Tally delegator votes by sweeping them into the aggregate for each validator, for each proposal.
@@ -796,6 +965,7 @@ pub trait StateWriteExt: StateWrite {
         }
 
         // Actually record the new tallies in the state
+        let updated_proposals = new_tallies.keys().copied().collect::<Vec<_>>();
         for (proposal_id, new_tallies_for_proposal) in new_tallies {
             for (identity_key, tally) in new_tallies_for_proposal {
                 tracing::debug!(
@@ -813,6 +983,13 @@ pub trait StateWriteExt: StateWrite {
             }
         }
 
+        // Let clients following the proposals know that their tallies have changed
+        for proposal_id in updated_proposals {
+            let tally = self.current_tally(proposal_id).await?;
+            self.record_proposal_event(proposal_id, ProposalEventKind::TallyUpdated { tally })
+                .await?;
+        }
+
         Ok(())
     }
 
@@ -875,6 +1052,79 @@ pub trait StateWriteExt: StateWrite {
 
                 tracing::info!("chain parameters updated successfully");
             }
+            ProposalPayload::PartialParameterChange { changes } => {
+                tracing::info!(
+                    "partial parameter change proposal passed, attempting to update chain parameters"
+                );
+
+                // As for full parameter changes, re-check the stateless verification criteria in
+                // case they changed while the proposal was pending:
+                ChainParameters::check_valid_changes(changes)
+                    .context("final check for validity of partial chain parameter update failed")?;
+
+                // Sequence the update on top of any pending parameter change, as for full
+                // parameter changes:
+                let current =
+                    if let Some(params) = self.next_block_pending_chain_parameters().await? {
+                        params
+                    } else {
+                        self.get_chain_params().await?
+                    };
+
+                // Only the named parameters have to match their old values in the proposal, so
+                // this doesn't clash with updates to other parameters.
+                let new = match current.apply_changes(changes) {
+                    Ok(new) => new,
+                    Err(e) => {
+                        return Ok(Err(e.context(
+                            "could not apply partial change to current chain parameters",
+                        )))
+                    }
+                };
+
+                // Tell the app to update the chain parameters in the next block
+                self.schedule_chain_params_change(new).await?;
+
+                tracing::info!("chain parameters updated successfully");
+            }
+            ProposalPayload::DaoGrant { grant } => {
+                tracing::info!(?grant, "DAO grant proposal passed, scheduling grant");
+
+                // The first tranche is due at the end of the current epoch
+                let epoch = self.epoch().await?;
+                self.schedule_dao_grant(proposal_id, grant.clone(), epoch.index);
+            }
+            ProposalPayload::DaoGrantCancel { grant_id } => {
+                tracing::info!(%grant_id, "DAO grant cancellation proposal passed, cancelling grant");
+
+                // The grant may have finished, or been cancelled by another proposal, while this
+                // proposal was being voted on
+                if let Err(error) = self.cancel_dao_grant(*grant_id).await {
+                    return Ok(Err(error));
+                }
+            }
+            ProposalPayload::IbcClientSubstitution {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                tracing::info!(
+                    %subject_client_id,
+                    %substitute_client_id,
+                    "IBC client substitution proposal passed, substituting client"
+                );
+
+                // The client IDs were checked to parse when the proposal was submitted, and the
+                // clients' statuses can only be checked now, since they may have changed while the
+                // proposal was being voted on
+                let subject_client_id = ClientId::from_str(subject_client_id)?;
+                let substitute_client_id = ClientId::from_str(substitute_client_id)?;
+                if let Err(error) = self
+                    .substitute_client(&subject_client_id, &substitute_client_id)
+                    .await
+                {
+                    return Ok(Err(error));
+                }
+            }
             ProposalPayload::DaoSpend {
                 transaction_plan: _,
             } => {
@@ -882,11 +1132,50 @@ pub trait StateWriteExt: StateWrite {
                 // be slotted in at the end of the block:
                 self.deliver_dao_transaction(proposal_id).await?;
             }
+            ProposalPayload::UpgradePlan { height, version } => {
+                tracing::info!(%height, %version, "upgrade plan proposal passed, scheduling upgrade");
+
+                // The upgrade height was checked to be after the end of voting when the proposal
+                // was submitted, but check it again in case the proposal concluded late:
+                let block_height = self.get_block_height().await?;
+                if *height <= block_height {
+                    return Ok(Err(anyhow::anyhow!(
+                        "upgrade height {} is not after the current block height {}",
+                        height,
+                        block_height
+                    )));
+                }
+
+                // Record the upgrade plan, replacing any previously scheduled upgrade: nodes will
+                // halt after committing the block at the upgrade height.
+                self.put_upgrade_plan(UpgradePlan {
+                    height: *height,
+                    version: version.clone(),
+                });
+            }
         }
 
         Ok(Ok(()))
     }
 
+    fn put_upgrade_plan(&mut self, plan: UpgradePlan) {
+        self.put(state_key::upgrade_plan().to_string(), plan);
+    }
+
+    /// Clear the upgrade plan if its height has passed.
+    ///
+    /// Software which processes a block after the upgrade height is necessarily the upgraded
+    /// version, so the plan has been applied and no longer constrains which version may run.
+    async fn clear_applied_upgrade_plan(&mut self) -> Result<()> {
+        if let Some(plan) = self.upgrade_plan().await? {
+            if plan.height < self.get_block_height().await? {
+                tracing::info!(height = plan.height, version = %plan.version, "upgrade plan applied");
+                self.delete(state_key::upgrade_plan().to_string());
+            }
+        }
+        Ok(())
+    }
+
     fn put_dao_transaction(&mut self, proposal: u64, transaction: Transaction) {
         self.put(state_key::dao_transaction(proposal), transaction);
     }
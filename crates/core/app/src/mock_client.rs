@@ -133,6 +133,10 @@ impl MockClient {
         self.notes.get(commitment).cloned()
     }
 
+    pub fn notes(&self) -> impl Iterator<Item = &Note> {
+        self.notes.values()
+    }
+
     pub fn swap_by_commitment(&self, commitment: &note::StateCommitment) -> Option<SwapPlaintext> {
         self.swaps.get(commitment).cloned()
     }
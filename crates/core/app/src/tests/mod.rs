@@ -1 +1,4 @@
+mod proposal_deposits;
+mod proposal_events;
 mod swap_and_swap_claim;
+mod upgrade_plan;
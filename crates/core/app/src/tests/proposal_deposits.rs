@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+use penumbra_chain::{component::StateWriteExt as _, params::ChainParameters};
+use penumbra_crypto::rdsa::{SigningKey, SpendAuth};
+use penumbra_dao::component::StateReadExt as _;
+use penumbra_num::Amount;
+use penumbra_proto::{DomainType, StateWriteProto as _};
+use penumbra_stake::{GovernanceKey, IdentityKey};
+use penumbra_storage::{ArcStateDeltaExt, StateDelta, StateWrite};
+use penumbra_transaction::{
+    action::{ValidatorVote, ValidatorVoteBody, Vote},
+    proposal::{self, Proposal, ProposalPayload},
+};
+use rand_core::OsRng;
+
+use crate::{
+    action_handler::ActionHandler,
+    governance::{
+        component::enact_all_passed_proposals, state_key, DepositOutcome, Disposition,
+        StateReadExt as _, StateWriteExt as _,
+    },
+};
+
+const VOTING_END: u64 = 10;
+
+/// Write a proposal in its voting period, with a single validator holding all the voting power.
+fn put_voting_proposal<S: StateWrite>(
+    state: &mut S,
+    proposal_id: u64,
+    deposit: u64,
+    payload: ProposalPayload,
+    identity_key: IdentityKey,
+) {
+    state.put(
+        state_key::proposal_definition(proposal_id),
+        Proposal {
+            id: proposal_id,
+            title: format!("proposal {proposal_id}"),
+            description: String::new(),
+            payload,
+        },
+    );
+    state.put_deposit_amount(proposal_id, deposit.into());
+    state.put_proposal_voting_end(proposal_id, VOTING_END);
+    state.put_proposal_state(proposal_id, proposal::State::Voting);
+    state.put_proto(
+        state_key::voting_power_at_proposal_start(proposal_id, identity_key),
+        100u64,
+    );
+}
+
+fn signaling() -> ProposalPayload {
+    ProposalPayload::Signaling { commit: None }
+}
+
+fn new_state(height: u64) -> Arc<StateDelta<()>> {
+    let mut state = Arc::new(StateDelta::new(()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_chain_params(ChainParameters::default());
+    state_tx.put_block_height(height);
+    state_tx.apply();
+    state
+}
+
+#[tokio::test]
+async fn slashed_deposits_go_to_the_dao_and_others_are_refundable() -> anyhow::Result<()> {
+    let identity_key = IdentityKey((&SigningKey::<SpendAuth>::new(OsRng)).into());
+    let mut state = new_state(VOTING_END);
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    for (proposal_id, deposit, vote) in [
+        (0, 1_000, Vote::Yes),
+        (1, 2_000, Vote::Abstain),
+        (2, 3_000, Vote::No),
+        (3, 4_000, Vote::No),
+    ] {
+        put_voting_proposal(
+            &mut state_tx,
+            proposal_id,
+            deposit,
+            signaling(),
+            identity_key,
+        );
+        state_tx.cast_validator_vote(proposal_id, identity_key, vote);
+    }
+    enact_all_passed_proposals(&mut state_tx).await?;
+    state_tx.apply();
+
+    let outcome = |proposal_id| {
+        let state = state.clone();
+        async move {
+            anyhow::Ok(
+                state
+                    .proposal_deposit_outcome(proposal_id)
+                    .await?
+                    .expect("deposit outcome is recorded when voting concludes"),
+            )
+        }
+    };
+
+    // Passed and failed proposals keep their deposit for the proposer to claim...
+    for (proposal_id, amount) in [(0, 1_000u64), (1, 2_000)] {
+        assert_eq!(
+            outcome(proposal_id).await?,
+            DepositOutcome {
+                amount: amount.into(),
+                height: VOTING_END,
+                disposition: Disposition::Refundable,
+            }
+        );
+    }
+
+    // ... while slashed proposals' deposits are sent to the DAO, and counted in the total.
+    for (proposal_id, amount) in [(2, 3_000u64), (3, 4_000)] {
+        assert_eq!(
+            outcome(proposal_id).await?,
+            DepositOutcome {
+                amount: amount.into(),
+                height: VOTING_END,
+                disposition: Disposition::SlashedToDao,
+            }
+        );
+    }
+    assert_eq!(
+        state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+        Amount::from(7_000u64)
+    );
+    assert_eq!(
+        state.slashed_deposits_total().await?,
+        Amount::from(7_000u64)
+    );
+
+    // The total carries over as later proposals are slashed.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(VOTING_END + 1);
+    put_voting_proposal(&mut state_tx, 4, 500, signaling(), identity_key);
+    state_tx.cast_validator_vote(4, identity_key, Vote::No);
+    enact_all_passed_proposals(&mut state_tx).await?;
+    state_tx.apply();
+
+    assert_eq!(
+        state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+        Amount::from(7_500u64)
+    );
+    assert_eq!(
+        state.slashed_deposits_total().await?,
+        Amount::from(7_500u64)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn emergency_passed_deposits_are_refundable() -> anyhow::Result<()> {
+    let signing_key = SigningKey::<SpendAuth>::new(OsRng);
+    let identity_key = IdentityKey((&signing_key).into());
+    // Emergency proposals pass as soon as enough validators vote for them, before voting ends.
+    let mut state = new_state(VOTING_END - 5);
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    put_voting_proposal(
+        &mut state_tx,
+        0,
+        1_000,
+        ProposalPayload::Emergency { halt_chain: false },
+        identity_key,
+    );
+    state_tx.apply();
+
+    let body = ValidatorVoteBody {
+        proposal: 0,
+        vote: Vote::Yes,
+        identity_key,
+        governance_key: GovernanceKey((&signing_key).into()),
+    };
+    let vote = ValidatorVote {
+        auth_sig: signing_key.sign(OsRng, &body.encode_to_vec()),
+        body,
+    };
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    vote.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    assert!(matches!(
+        state.proposal_state(0).await?,
+        Some(proposal::State::Finished {
+            outcome: proposal::Outcome::Passed,
+        })
+    ));
+    assert_eq!(
+        state.proposal_deposit_outcome(0).await?,
+        Some(DepositOutcome {
+            amount: 1_000u64.into(),
+            height: VOTING_END - 5,
+            disposition: Disposition::Refundable,
+        })
+    );
+    assert_eq!(
+        state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+        Amount::zero()
+    );
+    assert_eq!(state.slashed_deposits_total().await?, Amount::zero());
+
+    // The concluded proposal is no longer unfinished, so it isn't concluded again when voting
+    // would have ended.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(VOTING_END);
+    enact_all_passed_proposals(&mut state_tx).await?;
+    state_tx.apply();
+    assert_eq!(
+        state.proposal_deposit_outcome(0).await?.map(|o| o.height),
+        Some(VOTING_END - 5)
+    );
+
+    Ok(())
+}
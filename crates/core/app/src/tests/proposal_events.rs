@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use penumbra_chain::component::StateWriteExt as _;
+use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+
+use crate::governance::{ProposalEventKind, StateReadExt as _, StateWriteExt as _};
+
+#[tokio::test]
+async fn proposal_events_are_scanned_by_height_range() -> anyhow::Result<()> {
+    let mut state = Arc::new(StateDelta::new(()));
+
+    // Record two events at some heights, and none at the others.
+    let heights = [1, 2, 9, 10, 11, 100];
+    for height in heights {
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(height);
+        for proposal_id in [height, height + 1000] {
+            state_tx
+                .record_proposal_event(
+                    proposal_id,
+                    ProposalEventKind::Withdrawn {
+                        reason: String::new(),
+                    },
+                )
+                .await?;
+        }
+        state_tx.apply();
+    }
+
+    let events_in = |range| {
+        let state = state.clone();
+        async move {
+            anyhow::Ok(
+                state
+                    .proposal_events(range)
+                    .await?
+                    .into_iter()
+                    .map(|(height, event)| (height, event.proposal_id))
+                    .collect::<Vec<_>>(),
+            )
+        }
+    };
+
+    // Events are returned in order of height, then in the order they were recorded, including
+    // across heights which differ in their number of digits.
+    assert_eq!(
+        events_in(2..=10).await?,
+        vec![(2, 2), (2, 1002), (9, 9), (9, 1009), (10, 10), (10, 1010)]
+    );
+    assert_eq!(events_in(11..=11).await?, vec![(11, 11), (11, 1011)]);
+    assert_eq!(events_in(12..=99).await?, vec![]);
+    assert_eq!(
+        events_in(0..=u64::MAX)
+            .await?
+            .into_iter()
+            .map(|(height, _)| height)
+            .collect::<Vec<_>>(),
+        heights
+            .iter()
+            .flat_map(|&height| [height, height])
+            .collect::<Vec<_>>()
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = events_in(10..=2).await?;
+    assert!(empty.is_empty());
+
+    Ok(())
+}
@@ -3,10 +3,10 @@ use std::{ops::Deref, sync::Arc};
 
 use crate::{app::App, MockClient, TempStorageExt};
 use decaf377::Fq;
-use penumbra_asset::asset;
+use penumbra_asset::{asset, Value};
 use penumbra_chain::{
     component::{StateReadExt, StateWriteExt},
-    test_keys,
+    test_keys, NoteSource,
 };
 use penumbra_component::{ActionHandler, Component};
 use penumbra_crypto::Address;
@@ -19,7 +19,7 @@ use rand_core::SeedableRng;
 use tendermint::abci;
 
 use penumbra_dex::{
-    component::{Dex, StateReadExt as _},
+    component::{Dex, StateReadExt as _, StateWriteExt as _},
     swap::{SwapPlaintext, SwapPlan},
     swap_claim::SwapClaimPlan,
     TradingPair,
@@ -197,3 +197,94 @@ async fn swap_with_nonzero_fee() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn deposit_swap_is_delivered_to_claim_address() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let height = 1;
+
+    // 1. Simulate BeginBlock
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_epoch_by_height(
+        height,
+        penumbra_chain::Epoch {
+            index: 0,
+            start_height: 0,
+        },
+    );
+    state_tx.put_block_height(height);
+    state_tx.apply();
+
+    // 2. Swap a deposit of gm, as an inbound transfer would
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let deposit = gm.value(Amount::from(123_456u64));
+    let claim_address: Address = *test_keys::ADDRESS_0;
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.swap_deposit(deposit, gn.id(), claim_address, NoteSource::Ics20Transfer)?;
+    state_tx.apply();
+
+    // Swapping a deposit into the asset it is already in is an error.
+    {
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        assert!(state_tx
+            .swap_deposit(deposit, gm.id(), claim_address, NoteSource::Ics20Transfer)
+            .is_err());
+    }
+
+    // 3. Execute EndBlock, where the swap is executed and its outputs are minted
+
+    let end_block = abci::request::EndBlock {
+        height: height.try_into().unwrap(),
+    };
+    Dex::end_block(&mut state, &end_block).await;
+    ShieldedPool::end_block(&mut state, &end_block).await;
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    App::finish_block(&mut state_tx).await;
+    state_tx.apply();
+
+    // 4. The claim address should hold the outputs of the swap, without claiming it. There is no
+    // liquidity for the swap, so the output is the deposit itself, returned unfilled.
+
+    let output_data = state
+        .output_data(height, TradingPair::new(gm.id(), gn.id()))
+        .await?
+        .unwrap();
+    let trading_pair = output_data.trading_pair;
+    let (delta_1_i, delta_2_i) = if trading_pair.asset_1() == gm.id() {
+        (deposit.amount, Amount::zero())
+    } else {
+        (Amount::zero(), deposit.amount)
+    };
+    let (lambda_1_i, lambda_2_i) = output_data.pro_rata_outputs((delta_1_i, delta_2_i));
+    let expected_outputs = [
+        Value {
+            amount: lambda_1_i,
+            asset_id: trading_pair.asset_1(),
+        },
+        Value {
+            amount: lambda_2_i,
+            asset_id: trading_pair.asset_2(),
+        },
+    ];
+
+    let mut client = MockClient::new(test_keys::FULL_VIEWING_KEY.clone());
+    client.sync_to(1, state.deref()).await?;
+
+    for expected in expected_outputs {
+        if expected.amount == Amount::zero() {
+            continue;
+        }
+        assert!(client
+            .notes()
+            .any(|note| note.value() == expected && note.address() == claim_address));
+    }
+
+    Ok(())
+}
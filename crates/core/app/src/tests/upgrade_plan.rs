@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use penumbra_chain::component::StateWriteExt as _;
+use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+use penumbra_transaction::proposal::UpgradePlan;
+
+use crate::governance::{StateReadExt as _, StateWriteExt as _};
+
+fn set_height(state: &mut Arc<StateDelta<()>>, height: u64) {
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(height);
+    state_tx.apply();
+}
+
+#[tokio::test]
+async fn upgrade_plan_halts_only_at_upgrade_height() -> anyhow::Result<()> {
+    let mut state = Arc::new(StateDelta::new(()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(99);
+    state_tx.put_upgrade_plan(UpgradePlan {
+        height: 100,
+        version: "0.2.0".to_string(),
+    });
+    state_tx.apply();
+
+    // Before the upgrade height, any version may run.
+    state.check_upgrade_plan_allows("0.1.0").await?;
+    state.check_upgrade_plan_allows("0.2.0").await?;
+
+    // At the upgrade height, only the upgraded version may continue.
+    set_height(&mut state, 100);
+    assert!(state.check_upgrade_plan_allows("0.1.0").await.is_err());
+    state.check_upgrade_plan_allows("0.2.0").await?;
+
+    // Clearing the plan does nothing until the chain has moved past the upgrade height...
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.clear_applied_upgrade_plan().await?;
+    state_tx.apply();
+    assert!(state.upgrade_plan().await?.is_some());
+
+    // ... after which the plan no longer constrains later releases.
+    set_height(&mut state, 101);
+    state.check_upgrade_plan_allows("0.3.0").await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.clear_applied_upgrade_plan().await?;
+    state_tx.apply();
+    assert!(state.upgrade_plan().await?.is_none());
+
+    set_height(&mut state, 100_000);
+    state.check_upgrade_plan_allows("0.3.0").await?;
+
+    Ok(())
+}
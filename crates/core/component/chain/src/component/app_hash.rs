@@ -3,30 +3,22 @@ use async_trait::async_trait;
 use ibc_types2::core::commitment::{MerklePrefix, MerkleProof};
 use once_cell::sync::Lazy;
 use penumbra_proto::Message;
-use penumbra_storage::{RootHash, Snapshot};
-use sha2::{Digest, Sha256};
+use penumbra_storage::{proof, RootHash, Snapshot};
 use tendermint::merkle::proof::ProofOps as TendermintMerkleProof;
 
-pub static PENUMBRA_PROOF_SPECS: Lazy<Vec<ics23::ProofSpec>> =
-    Lazy::new(|| vec![penumbra_storage::ics23_spec(), apphash_spec()]);
+pub static PENUMBRA_PROOF_SPECS: Lazy<Vec<ics23::ProofSpec>> = Lazy::new(proof::proof_specs);
 
 pub static PENUMBRA_COMMITMENT_PREFIX: Lazy<MerklePrefix> = Lazy::new(|| MerklePrefix {
-    key_prefix: APPHASH_DOMSEP.as_bytes().to_vec(),
+    key_prefix: proof::APP_HASH_DOMAIN_SEPARATOR.as_bytes().to_vec(),
 });
 
-static APPHASH_DOMSEP: &str = "PenumbraAppHash";
-
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct AppHash(pub [u8; 32]);
 
 // the app hash of penumbra's state is defined as SHA256("PenumbraAppHash" || jmt.root_hash())
 impl From<RootHash> for AppHash {
     fn from(r: RootHash) -> Self {
-        let mut h = Sha256::new();
-        h.update(APPHASH_DOMSEP);
-        h.update(r.0);
-
-        AppHash(h.finalize().into())
+        AppHash(proof::app_hash(&r))
     }
 }
 
@@ -37,34 +29,6 @@ impl std::fmt::Debug for AppHash {
             .finish()
     }
 }
-/// this is a proof spec for computing Penumbra's AppHash, which is defined as
-/// SHA256("PenumbraAppHash" || jmt.root()). In ICS/IBC terms, this applies a single global prefix
-/// to Penumbra's state. Having a stable merkle prefix is currently required for our IBC
-/// counterparties to verify our proofs.
-fn apphash_spec() -> ics23::ProofSpec {
-    ics23::ProofSpec {
-        // the leaf hash is simply H(key || value)
-        leaf_spec: Some(ics23::LeafOp {
-            prefix: vec![],
-            hash: ics23::HashOp::Sha256.into(),
-            length: ics23::LengthOp::NoPrefix.into(),
-            prehash_key: ics23::HashOp::NoHash.into(),
-            prehash_value: ics23::HashOp::NoHash.into(),
-        }),
-        // NOTE: we don't actually use any InnerOps.
-        inner_spec: Some(ics23::InnerSpec {
-            hash: ics23::HashOp::Sha256.into(),
-            child_order: vec![0, 1],
-            child_size: 32,
-            empty_child: vec![],
-            min_prefix_length: 0,
-            max_prefix_length: 0,
-        }),
-        min_depth: 0,
-        max_depth: 1,
-        prehash_key_before_comparison: true,
-    }
-}
 
 #[async_trait]
 pub trait AppHashRead {
@@ -98,16 +62,7 @@ impl AppHashRead for Snapshot {
         let (some_value, membership_proof) = self.get_with_proof(key.clone()).await?;
 
         let jmt_root = self.root_hash().await?;
-        let root_proof = ics23::CommitmentProof {
-            proof: Some(ics23::commitment_proof::Proof::Exist(
-                ics23::ExistenceProof {
-                    key: APPHASH_DOMSEP.into(),
-                    value: jmt_root.0.to_vec(),
-                    path: vec![],
-                    leaf: apphash_spec().leaf_spec,
-                },
-            )),
-        };
+        let root_proof = proof::app_hash_proof(&jmt_root);
 
         Ok((
             some_value,
@@ -138,7 +93,7 @@ impl AppHashRead for Snapshot {
         };
         let root_op = tendermint::merkle::proof::ProofOp {
             field_type: "apphash".to_string(),
-            key: APPHASH_DOMSEP.into(),
+            key: proof::APP_HASH_DOMAIN_SEPARATOR.into(),
             data: ics23_proof.proofs[1].encode_to_vec(),
         };
 
@@ -38,6 +38,10 @@ pub struct ChainParameters {
     pub signed_blocks_window_len: u64,
     /// The maximum number of blocks in the window each validator can miss signing without slashing.
     pub missed_blocks_maximum: u64,
+    /// The maximum change in a validator's total commission allowed per epoch, in basis points.
+    pub max_commission_change_bps: u64,
+    /// The number of full epochs a commission increase must be announced for before it takes effect.
+    pub commission_increase_delay_epochs: u64,
 
     /// Whether IBC (forming connections, processing IBC packets) is enabled.
     pub ibc_enabled: bool,
@@ -60,6 +64,11 @@ pub struct ChainParameters {
 
     /// Whether DAO spend proposals are enabled.
     pub dao_spend_proposals_enabled: bool,
+
+    /// The length, in blocks, of the rolling window over which ICS-20 rate limits are enforced.
+    pub ics20_rate_limit_window_blocks: u64,
+    /// Limits on the value of ICS-20 transfers of particular denoms over particular channels.
+    pub ics20_rate_limits: Vec<Ics20RateLimit>,
 }
 
 impl TypeUrl for ChainParameters {
@@ -74,6 +83,7 @@ impl TryFrom<pb_chain::ChainParameters> for ChainParameters {
     type Error = anyhow::Error;
 
     fn try_from(msg: pb_chain::ChainParameters) -> anyhow::Result<Self> {
+        let defaults = ChainParameters::default();
         Ok(ChainParameters {
             chain_id: msg.chain_id,
             epoch_duration: msg.epoch_duration,
@@ -84,6 +94,14 @@ impl TryFrom<pb_chain::ChainParameters> for ChainParameters {
             base_reward_rate: msg.base_reward_rate,
             missed_blocks_maximum: msg.missed_blocks_maximum,
             signed_blocks_window_len: msg.signed_blocks_window_len,
+            // Parameters stored before these were added don't set them, so fall back to the
+            // defaults rather than reading them as zero, which would forbid any commission change.
+            max_commission_change_bps: msg
+                .max_commission_change_bps
+                .unwrap_or(defaults.max_commission_change_bps),
+            commission_increase_delay_epochs: msg
+                .commission_increase_delay_epochs
+                .unwrap_or(defaults.commission_increase_delay_epochs),
             ibc_enabled: msg.ibc_enabled,
             inbound_ics20_transfers_enabled: msg.inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled: msg.outbound_ics20_transfers_enabled,
@@ -105,6 +123,15 @@ impl TryFrom<pb_chain::ChainParameters> for ChainParameters {
                 .parse()
                 .context("couldn't parse proposal_slash_threshold")?,
             dao_spend_proposals_enabled: msg.dao_spend_proposals_enabled,
+            // Likewise, a window of zero blocks would never count any transfers against the limits.
+            ics20_rate_limit_window_blocks: msg
+                .ics20_rate_limit_window_blocks
+                .unwrap_or(defaults.ics20_rate_limit_window_blocks),
+            ics20_rate_limits: msg
+                .ics20_rate_limits
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
         })
     }
 }
@@ -143,6 +170,8 @@ impl From<ChainParameters> for pb_chain::ChainParameters {
             slashing_penalty_downtime: params.slashing_penalty_downtime,
             slashing_penalty_misbehavior: params.slashing_penalty_misbehavior,
             base_reward_rate: params.base_reward_rate,
+            max_commission_change_bps: Some(params.max_commission_change_bps),
+            commission_increase_delay_epochs: Some(params.commission_increase_delay_epochs),
             ibc_enabled: params.ibc_enabled,
             inbound_ics20_transfers_enabled: params.inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled: params.outbound_ics20_transfers_enabled,
@@ -152,6 +181,12 @@ impl From<ChainParameters> for pb_chain::ChainParameters {
             proposal_pass_threshold: params.proposal_pass_threshold.to_string(),
             proposal_slash_threshold: params.proposal_slash_threshold.to_string(),
             dao_spend_proposals_enabled: params.dao_spend_proposals_enabled,
+            ics20_rate_limit_window_blocks: Some(params.ics20_rate_limit_window_blocks),
+            ics20_rate_limits: params
+                .ics20_rate_limits
+                .into_iter()
+                .map(Into::into)
+                .collect(),
         }
     }
 }
@@ -174,6 +209,10 @@ impl Default for ChainParameters {
             slashing_penalty_downtime: 1_0000,
             // 3bps -> 11% return over 365 epochs
             base_reward_rate: 3_0000,
+            // 500 basis points = 5% commission change per epoch
+            max_commission_change_bps: 500,
+            // commission increases must be announced a full epoch in advance
+            commission_increase_delay_epochs: 1,
             ibc_enabled: true,
             inbound_ics20_transfers_enabled: true,
             outbound_ics20_transfers_enabled: true,
@@ -186,10 +225,125 @@ impl Default for ChainParameters {
             // slash threshold means if (no / no + yes + abstain) > slash_threshold, then proposal is slashed
             proposal_slash_threshold: Ratio::new(80, 100),
             dao_spend_proposals_enabled: true,
+            // ICS-20 transfers aren't rate limited unless governance adds limits
+            ics20_rate_limit_window_blocks: 17_280, // 24 hours, at a 5 second block time
+            ics20_rate_limits: Vec::new(),
+        }
+    }
+}
+
+/// A limit on the total value of ICS-20 transfers of one denom over one channel, in each
+/// direction, within the rolling window of [`ChainParameters::ics20_rate_limit_window_blocks`].
+///
+/// Rate limits are written as `channel:denom:inbound:outbound`, where each limit is an amount in
+/// base units, or `-` if transfers in that direction aren't limited; for example,
+/// `channel-0:transfer/channel-0/uatom:1000000000:-`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(
+    try_from = "pb_chain::Ics20RateLimit",
+    into = "pb_chain::Ics20RateLimit"
+)]
+pub struct Ics20RateLimit {
+    /// The channel on Penumbra's end that the limit applies to.
+    pub channel_id: String,
+    /// The denom, as it is known on Penumbra, that the limit applies to.
+    pub denom: String,
+    /// The most that may be transferred in to Penumbra within the window, if limited.
+    pub inbound_limit: Option<Amount>,
+    /// The most that may be transferred out of Penumbra within the window, if limited.
+    pub outbound_limit: Option<Amount>,
+}
+
+impl TypeUrl for Ics20RateLimit {
+    const TYPE_URL: &'static str = "/penumbra.core.chain.v1alpha1.Ics20RateLimit";
+}
+
+impl DomainType for Ics20RateLimit {
+    type Proto = pb_chain::Ics20RateLimit;
+}
+
+impl TryFrom<pb_chain::Ics20RateLimit> for Ics20RateLimit {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb_chain::Ics20RateLimit) -> anyhow::Result<Self> {
+        Ok(Ics20RateLimit {
+            channel_id: msg.channel_id,
+            denom: msg.denom,
+            inbound_limit: msg.inbound_limit.map(TryInto::try_into).transpose()?,
+            outbound_limit: msg.outbound_limit.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl From<Ics20RateLimit> for pb_chain::Ics20RateLimit {
+    fn from(limit: Ics20RateLimit) -> Self {
+        pb_chain::Ics20RateLimit {
+            channel_id: limit.channel_id,
+            denom: limit.denom,
+            inbound_limit: limit.inbound_limit.map(Into::into),
+            outbound_limit: limit.outbound_limit.map(Into::into),
         }
     }
 }
 
+impl Display for Ics20RateLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let limit = |limit: &Option<Amount>| match limit {
+            Some(limit) => limit.to_string(),
+            None => "-".to_string(),
+        };
+        write!(
+            f,
+            "{}:{}:{}:{}",
+            self.channel_id,
+            self.denom,
+            limit(&self.inbound_limit),
+            limit(&self.outbound_limit)
+        )
+    }
+}
+
+impl FromStr for Ics20RateLimit {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let limit = |limit: &str| -> anyhow::Result<Option<Amount>> {
+            match limit {
+                "-" => Ok(None),
+                limit => Ok(Some(
+                    limit
+                        .parse::<u128>()
+                        .context("couldn't parse rate limit")?
+                        .into(),
+                )),
+            }
+        };
+
+        // Denoms may contain slashes, but not colons, so split off the channel from the front and
+        // the limits from the back, and leave the rest as the denom.
+        let (channel_id, rest) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("missing channel in rate limit"))?;
+        let mut parts = rest.rsplitn(3, ':');
+        let outbound_limit = parts
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("missing outbound limit"))?;
+        let inbound_limit = parts
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("missing inbound limit"))?;
+        let denom = parts
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("missing denom in rate limit"))?;
+
+        Ok(Ics20RateLimit {
+            channel_id: channel_id.to_string(),
+            denom: denom.to_string(),
+            inbound_limit: limit(inbound_limit)?,
+            outbound_limit: limit(outbound_limit)?,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(try_from = "pb_chain::FmdParameters", into = "pb_chain::FmdParameters")]
 pub struct FmdParameters {
@@ -325,3 +479,54 @@ impl From<pb_chain::Ratio> for Ratio {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commission_limits_default_when_unset() -> anyhow::Result<()> {
+        let params = ChainParameters {
+            max_commission_change_bps: 250,
+            commission_increase_delay_epochs: 3,
+            ..Default::default()
+        };
+
+        // Parameters stored before the commission limits existed don't set them at all.
+        let mut legacy = pb_chain::ChainParameters::from(params.clone());
+        legacy.max_commission_change_bps = None;
+        legacy.commission_increase_delay_epochs = None;
+        let decoded = ChainParameters::try_from(legacy)?;
+        assert_eq!(decoded.max_commission_change_bps, 500);
+        assert_eq!(decoded.commission_increase_delay_epochs, 1);
+
+        // Explicitly set values, including zero, are kept as they are.
+        assert_eq!(
+            ChainParameters::try_from(pb_chain::ChainParameters::from(params.clone()))?,
+            params
+        );
+        let mut zero = pb_chain::ChainParameters::from(params);
+        zero.commission_increase_delay_epochs = Some(0);
+        assert_eq!(
+            ChainParameters::try_from(zero)?.commission_increase_delay_epochs,
+            0
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rate_limit_window_defaults_when_unset() -> anyhow::Result<()> {
+        let mut legacy = pb_chain::ChainParameters::from(ChainParameters {
+            ics20_rate_limit_window_blocks: 100,
+            ..Default::default()
+        });
+        legacy.ics20_rate_limit_window_blocks = None;
+        assert_eq!(
+            ChainParameters::try_from(legacy)?.ics20_rate_limit_window_blocks,
+            17_280
+        );
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 
 use super::{ChainParameters, Ratio};
 
@@ -22,6 +23,8 @@ impl ChainParameters {
             slashing_penalty_downtime: _,
             signed_blocks_window_len,
             missed_blocks_maximum: _,
+            max_commission_change_bps: _,
+            commission_increase_delay_epochs: _,
             ibc_enabled: _,
             inbound_ics20_transfers_enabled: _,
             outbound_ics20_transfers_enabled: _,
@@ -31,6 +34,8 @@ impl ChainParameters {
             proposal_pass_threshold,
             proposal_slash_threshold,
             dao_spend_proposals_enabled: _,
+            ics20_rate_limit_window_blocks: _,
+            ics20_rate_limits: _,
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
 
@@ -81,6 +86,8 @@ impl ChainParameters {
             slashing_penalty_downtime,
             signed_blocks_window_len,
             missed_blocks_maximum,
+            max_commission_change_bps,
+            commission_increase_delay_epochs: _,
             ibc_enabled,
             inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled,
@@ -90,9 +97,16 @@ impl ChainParameters {
             proposal_pass_threshold,
             proposal_slash_threshold,
             dao_spend_proposals_enabled: _,
+            ics20_rate_limit_window_blocks,
+            ics20_rate_limits,
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
 
+        let mut rate_limited = BTreeSet::new();
+        let rate_limits_are_distinct = ics20_rate_limits
+            .iter()
+            .all(|limit| rate_limited.insert((&limit.channel_id, &limit.denom)));
+
         check_all([
             (!chain_id.is_empty(), "chain ID must be a non-empty string"),
             (
@@ -135,6 +149,14 @@ impl ChainParameters {
                 *missed_blocks_maximum >= 1,
                 "missed blocks maximum must be at least 1",
             ),
+            (
+                *max_commission_change_bps >= 1,
+                "max commission change must be at least 1 basis point",
+            ),
+            (
+                *max_commission_change_bps <= 10_000,
+                "max commission change must be at most 10,000 basis points",
+            ),
             (
                 (!*inbound_ics20_transfers_enabled && !*outbound_ics20_transfers_enabled)
                     || *ibc_enabled,
@@ -160,10 +182,259 @@ impl ChainParameters {
                 *proposal_slash_threshold > Ratio::new(1, 2),
                 "proposal slash threshold must be greater than 1/2",
             ),
+            (
+                *ics20_rate_limit_window_blocks >= 1,
+                "ICS20 rate limit window must be at least 1 block",
+            ),
+            (
+                ics20_rate_limits
+                    .iter()
+                    .all(|limit| !limit.channel_id.is_empty() && !limit.denom.is_empty()),
+                "ICS20 rate limits must each name a channel and a denom",
+            ),
+            (
+                rate_limits_are_distinct,
+                "ICS20 rate limits must each be for a different channel and denom",
+            ),
         ])
     }
 }
 
+/// A change to a single named chain parameter, as part of a partial parameter change.
+///
+/// Values are written in the same format as they are displayed: integers and booleans are written
+/// plainly, amounts are written in base units, and ratios are written as `numerator/denominator`.
+/// ICS-20 rate limits are written as a comma-separated list, in the format described by
+/// [`Ics20RateLimit`](super::Ics20RateLimit).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterChange {
+    /// The name of the chain parameter to change, e.g. `proposal_voting_blocks`.
+    pub parameter: String,
+    /// The value the parameter is expected to have before the change.
+    ///
+    /// If the parameter does not have this value when the change is applied, the change is not
+    /// applied.
+    pub old_value: String,
+    /// The value to set the parameter to.
+    pub new_value: String,
+}
+
+impl ChainParameters {
+    /// Get the value of the named chain parameter, formatted as a string.
+    pub fn get_by_name(&self, name: &str) -> Result<String> {
+        let ChainParameters {
+            chain_id,
+            epoch_duration,
+            unbonding_epochs,
+            active_validator_limit,
+            base_reward_rate,
+            slashing_penalty_misbehavior,
+            slashing_penalty_downtime,
+            signed_blocks_window_len,
+            missed_blocks_maximum,
+            max_commission_change_bps,
+            commission_increase_delay_epochs,
+            ibc_enabled,
+            inbound_ics20_transfers_enabled,
+            outbound_ics20_transfers_enabled,
+            proposal_voting_blocks,
+            proposal_deposit_amount,
+            proposal_valid_quorum,
+            proposal_pass_threshold,
+            proposal_slash_threshold,
+            dao_spend_proposals_enabled,
+            ics20_rate_limit_window_blocks,
+            ics20_rate_limits,
+            // IMPORTANT: Don't use `..` here! We want to ensure every single field is nameable!
+        } = self;
+
+        Ok(match name {
+            "chain_id" => chain_id.clone(),
+            "epoch_duration" => epoch_duration.to_string(),
+            "unbonding_epochs" => unbonding_epochs.to_string(),
+            "active_validator_limit" => active_validator_limit.to_string(),
+            "base_reward_rate" => base_reward_rate.to_string(),
+            "slashing_penalty_misbehavior" => slashing_penalty_misbehavior.to_string(),
+            "slashing_penalty_downtime" => slashing_penalty_downtime.to_string(),
+            "signed_blocks_window_len" => signed_blocks_window_len.to_string(),
+            "missed_blocks_maximum" => missed_blocks_maximum.to_string(),
+            "max_commission_change_bps" => max_commission_change_bps.to_string(),
+            "commission_increase_delay_epochs" => commission_increase_delay_epochs.to_string(),
+            "ibc_enabled" => ibc_enabled.to_string(),
+            "inbound_ics20_transfers_enabled" => inbound_ics20_transfers_enabled.to_string(),
+            "outbound_ics20_transfers_enabled" => outbound_ics20_transfers_enabled.to_string(),
+            "proposal_voting_blocks" => proposal_voting_blocks.to_string(),
+            "proposal_deposit_amount" => proposal_deposit_amount.to_string(),
+            "proposal_valid_quorum" => proposal_valid_quorum.to_string(),
+            "proposal_pass_threshold" => proposal_pass_threshold.to_string(),
+            "proposal_slash_threshold" => proposal_slash_threshold.to_string(),
+            "dao_spend_proposals_enabled" => dao_spend_proposals_enabled.to_string(),
+            "ics20_rate_limit_window_blocks" => ics20_rate_limit_window_blocks.to_string(),
+            "ics20_rate_limits" => ics20_rate_limits
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+            _ => anyhow::bail!("unknown chain parameter: {name}"),
+        })
+    }
+
+    /// Set the value of the named chain parameter, parsing it from a string.
+    ///
+    /// The chain ID can't be set, since it can never be changed by governance.
+    pub fn set_by_name(&mut self, name: &str, value: &str) -> Result<()> {
+        let ChainParameters {
+            chain_id: _,
+            epoch_duration,
+            unbonding_epochs,
+            active_validator_limit,
+            base_reward_rate,
+            slashing_penalty_misbehavior,
+            slashing_penalty_downtime,
+            signed_blocks_window_len,
+            missed_blocks_maximum,
+            max_commission_change_bps,
+            commission_increase_delay_epochs,
+            ibc_enabled,
+            inbound_ics20_transfers_enabled,
+            outbound_ics20_transfers_enabled,
+            proposal_voting_blocks,
+            proposal_deposit_amount,
+            proposal_valid_quorum,
+            proposal_pass_threshold,
+            proposal_slash_threshold,
+            dao_spend_proposals_enabled,
+            ics20_rate_limit_window_blocks,
+            ics20_rate_limits,
+            // IMPORTANT: Don't use `..` here! We want to ensure every single field is nameable!
+        } = self;
+
+        let context = || format!("invalid value {value:?} for chain parameter {name}");
+        match name {
+            "chain_id" => anyhow::bail!("chain parameter chain_id can't be changed"),
+            "epoch_duration" => *epoch_duration = value.parse().with_context(context)?,
+            "unbonding_epochs" => *unbonding_epochs = value.parse().with_context(context)?,
+            "active_validator_limit" => {
+                *active_validator_limit = value.parse().with_context(context)?
+            }
+            "base_reward_rate" => *base_reward_rate = value.parse().with_context(context)?,
+            "slashing_penalty_misbehavior" => {
+                *slashing_penalty_misbehavior = value.parse().with_context(context)?
+            }
+            "slashing_penalty_downtime" => {
+                *slashing_penalty_downtime = value.parse().with_context(context)?
+            }
+            "signed_blocks_window_len" => {
+                *signed_blocks_window_len = value.parse().with_context(context)?
+            }
+            "missed_blocks_maximum" => {
+                *missed_blocks_maximum = value.parse().with_context(context)?
+            }
+            "max_commission_change_bps" => {
+                *max_commission_change_bps = value.parse().with_context(context)?
+            }
+            "commission_increase_delay_epochs" => {
+                *commission_increase_delay_epochs = value.parse().with_context(context)?
+            }
+            "ibc_enabled" => *ibc_enabled = value.parse().with_context(context)?,
+            "inbound_ics20_transfers_enabled" => {
+                *inbound_ics20_transfers_enabled = value.parse().with_context(context)?
+            }
+            "outbound_ics20_transfers_enabled" => {
+                *outbound_ics20_transfers_enabled = value.parse().with_context(context)?
+            }
+            "proposal_voting_blocks" => {
+                *proposal_voting_blocks = value.parse().with_context(context)?
+            }
+            "proposal_deposit_amount" => {
+                *proposal_deposit_amount = value.parse::<u128>().with_context(context)?.into()
+            }
+            "proposal_valid_quorum" => {
+                *proposal_valid_quorum = value.parse().with_context(context)?
+            }
+            "proposal_pass_threshold" => {
+                *proposal_pass_threshold = value.parse().with_context(context)?
+            }
+            "proposal_slash_threshold" => {
+                *proposal_slash_threshold = value.parse().with_context(context)?
+            }
+            "dao_spend_proposals_enabled" => {
+                *dao_spend_proposals_enabled = value.parse().with_context(context)?
+            }
+            "ics20_rate_limit_window_blocks" => {
+                *ics20_rate_limit_window_blocks = value.parse().with_context(context)?
+            }
+            "ics20_rate_limits" => {
+                *ics20_rate_limits = value
+                    .split(',')
+                    .filter(|limit| !limit.is_empty())
+                    .map(str::parse)
+                    .collect::<Result<_>>()
+                    .with_context(context)?
+            }
+            _ => anyhow::bail!("unknown chain parameter: {name}"),
+        }
+
+        Ok(())
+    }
+
+    /// Apply a partial parameter change to these chain parameters, returning the new parameters.
+    ///
+    /// Each changed parameter must currently have the old value given by the change: other
+    /// parameters are left untouched, so partial changes to disjoint sets of parameters don't
+    /// conflict with one another. The result is checked to be a valid update of these parameters.
+    pub fn apply_changes(&self, changes: &[ParameterChange]) -> Result<ChainParameters> {
+        let mut new = self.clone();
+        for ParameterChange {
+            parameter,
+            old_value,
+            new_value,
+        } in changes
+        {
+            // Compare parsed values rather than strings, so that e.g. equivalent ratios match:
+            let mut expected = self.clone();
+            expected.set_by_name(parameter, old_value)?;
+            if expected != *self {
+                anyhow::bail!(
+                    "chain parameter {} is {}, not the expected old value {}",
+                    parameter,
+                    self.get_by_name(parameter)?,
+                    old_value
+                );
+            }
+            new.set_by_name(parameter, new_value)?;
+        }
+
+        self.check_valid_update(&new)?;
+        Ok(new)
+    }
+
+    /// Check that a partial parameter change is well-formed, without reference to the current
+    /// chain parameters.
+    ///
+    /// This checks that at least one parameter is changed, every changed parameter exists, can be
+    /// set, and is changed at most once, and all values parse. Whether the old values match, and
+    /// whether the new values are a valid update, depends on the current chain parameters, so is
+    /// checked by [`ChainParameters::apply_changes`].
+    pub fn check_valid_changes(changes: &[ParameterChange]) -> Result<()> {
+        if changes.is_empty() {
+            anyhow::bail!("partial parameter change must change at least one parameter");
+        }
+
+        let mut seen = BTreeSet::new();
+        let mut scratch = ChainParameters::default();
+        for change in changes {
+            if !seen.insert(change.parameter.as_str()) {
+                anyhow::bail!("chain parameter {} is changed twice", change.parameter);
+            }
+            scratch.set_by_name(&change.parameter, &change.old_value)?;
+            scratch.set_by_name(&change.parameter, &change.new_value)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Ensure all of the booleans are true, and if any are false, generate an error describing which
 /// failed, based on the provided descriptions.
 fn check_all<'a>(checks: impl IntoIterator<Item = (bool, impl Display + 'a)>) -> Result<()> {
@@ -196,3 +467,103 @@ fn check_invariant<'a, T: Eq + 'a>(
             .map(|(old, new, name)| ((*old == *new), format!("{name} can't be changed"))),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(parameter: &str, old_value: &str, new_value: &str) -> ParameterChange {
+        ParameterChange {
+            parameter: parameter.to_string(),
+            old_value: old_value.to_string(),
+            new_value: new_value.to_string(),
+        }
+    }
+
+    fn testnet_params() -> ChainParameters {
+        ChainParameters {
+            chain_id: "penumbra-testnet".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn get_and_set_by_name_roundtrip() -> Result<()> {
+        let mut params = testnet_params();
+
+        for (name, value) in [
+            ("unbonding_epochs", "5"),
+            ("ibc_enabled", "false"),
+            ("proposal_deposit_amount", "20000000"),
+            ("proposal_pass_threshold", "2/3"),
+            (
+                "ics20_rate_limits",
+                "channel-0:transfer/channel-0/uatom:1000:-,channel-1:upenumbra:-:5",
+            ),
+            ("ics20_rate_limits", ""),
+        ] {
+            params.set_by_name(name, value)?;
+            assert_eq!(params.get_by_name(name)?, value);
+        }
+
+        assert_eq!(params.get_by_name("chain_id")?, "penumbra-testnet");
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_by_name_rejects_bad_values() {
+        let mut params = testnet_params();
+
+        assert!(params.set_by_name("unbonding_epochs", "-1").is_err());
+        assert!(params.set_by_name("ibc_enabled", "yes").is_err());
+        assert!(params.set_by_name("proposal_pass_threshold", "2").is_err());
+        assert!(params
+            .set_by_name("ics20_rate_limits", "channel-0")
+            .is_err());
+        assert!(params.set_by_name("no_such_parameter", "1").is_err());
+        assert!(params.get_by_name("no_such_parameter").is_err());
+
+        // The chain ID can be read, but not changed.
+        assert!(params.set_by_name("chain_id", "other-chain").is_err());
+        assert_eq!(params, testnet_params());
+    }
+
+    #[test]
+    fn changes_are_checked_against_current_parameters() -> Result<()> {
+        let mut current = testnet_params();
+        current.proposal_voting_blocks = 100;
+
+        // Old values must match the current parameters, not the defaults...
+        let changes = [change("proposal_voting_blocks", "100", "200")];
+        ChainParameters::check_valid_changes(&changes)?;
+        assert_eq!(current.apply_changes(&changes)?.proposal_voting_blocks, 200);
+        let stale = [change("proposal_voting_blocks", "17280", "200")];
+        ChainParameters::check_valid_changes(&stale)?;
+        assert!(current.apply_changes(&stale).is_err());
+
+        // ... though they are compared as values rather than strings.
+        let ratio = [change("proposal_pass_threshold", "1/2", "50/100")];
+        assert_eq!(current.apply_changes(&ratio)?, current);
+
+        // Invariant parameters still can't be changed.
+        let invariant = [change("epoch_duration", "719", "720")];
+        ChainParameters::check_valid_changes(&invariant)?;
+        assert!(current.apply_changes(&invariant).is_err());
+
+        assert!(ChainParameters::check_valid_changes(&[]).is_err());
+        assert!(ChainParameters::check_valid_changes(&[
+            change("unbonding_epochs", "2", "3"),
+            change("unbonding_epochs", "3", "4"),
+        ])
+        .is_err());
+        assert!(ChainParameters::check_valid_changes(&[change(
+            "chain_id",
+            "penumbra-testnet",
+            "other-chain"
+        )])
+        .is_err());
+
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
-use std::{collections::BTreeMap, convert::TryFrom};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+};
 
 use anyhow::Result;
-use penumbra_chain::params::{ChainParameters, FmdParameters};
+use penumbra_chain::{
+    params::{ChainParameters, FmdParameters},
+    NoteSource,
+};
 use penumbra_crypto::Nullifier;
 use penumbra_dex::{BatchSwapOutputData, TradingPair};
 use penumbra_proto::{
@@ -65,6 +71,25 @@ impl CompactBlock {
             || self.proposal_started // need to process proposal start
             || self.chain_parameters.is_some() // need to save latest chain parameters
     }
+
+    /// Roll up the note payloads created by any of the given transactions into their commitments.
+    ///
+    /// This is used by detection servers to withhold the notes of transactions which weren't
+    /// flagged for a client, so that the client doesn't have to trial-decrypt them.
+    pub fn roll_up_notes_from(&mut self, transaction_ids: &BTreeSet<[u8; 32]>) {
+        for payload in self.state_payloads.iter_mut() {
+            let rolled_up = match payload {
+                StatePayload::Note {
+                    source: NoteSource::Transaction { id },
+                    note,
+                } if transaction_ids.contains(id) => Some(note.note_commitment),
+                _ => None,
+            };
+            if let Some(commitment) = rolled_up {
+                *payload = StatePayload::RolledUp(commitment);
+            }
+        }
+    }
 }
 
 impl TypeUrl for CompactBlock {
@@ -150,3 +175,78 @@ impl TryFrom<CompactBlockRangeResponse> for CompactBlock {
             .try_into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_crypto::{
+        ka,
+        note::{StateCommitment, NOTE_CIPHERTEXT_BYTES},
+        Fq, NoteCiphertext, NotePayload,
+    };
+
+    use super::*;
+
+    fn note(commitment: u64, source: NoteSource) -> StatePayload {
+        StatePayload::Note {
+            source,
+            note: Box::new(NotePayload {
+                note_commitment: StateCommitment(Fq::from(commitment)),
+                ephemeral_key: ka::Public([0u8; 32]),
+                encrypted_note: NoteCiphertext([0u8; NOTE_CIPHERTEXT_BYTES]),
+            }),
+        }
+    }
+
+    // the commitments of the payloads which are still full notes, and of those which were rolled up.
+    fn commitments(block: &CompactBlock) -> (Vec<StateCommitment>, Vec<StateCommitment>) {
+        let mut notes = Vec::new();
+        let mut rolled_up = Vec::new();
+        for payload in &block.state_payloads {
+            match payload {
+                StatePayload::Note { note, .. } => notes.push(note.note_commitment),
+                StatePayload::RolledUp(commitment) => rolled_up.push(*commitment),
+                StatePayload::Swap { .. } => {}
+            }
+        }
+
+        (notes, rolled_up)
+    }
+
+    #[test]
+    fn notes_from_the_given_transactions_are_rolled_up() {
+        let withheld = NoteSource::Transaction { id: [1u8; 32] };
+        let kept = NoteSource::Transaction { id: [2u8; 32] };
+
+        let mut block = CompactBlock {
+            state_payloads: vec![
+                note(1, withheld),
+                note(2, kept),
+                note(3, NoteSource::Genesis),
+                note(4, withheld),
+                StatePayload::RolledUp(StateCommitment(Fq::from(5u64))),
+            ],
+            ..Default::default()
+        };
+        block.roll_up_notes_from(&BTreeSet::from([[1u8; 32], [3u8; 32]]));
+
+        // the notes keep their places in the block, so the client's tree is built in order.
+        let (notes, rolled_up) = commitments(&block);
+        assert_eq!(
+            notes,
+            vec![
+                StateCommitment(Fq::from(2u64)),
+                StateCommitment(Fq::from(3u64))
+            ]
+        );
+        assert_eq!(
+            rolled_up,
+            vec![
+                StateCommitment(Fq::from(1u64)),
+                StateCommitment(Fq::from(4u64)),
+                StateCommitment(Fq::from(5u64)),
+            ]
+        );
+        assert!(matches!(block.state_payloads[0], StatePayload::RolledUp(_)));
+        assert!(matches!(block.state_payloads[3], StatePayload::RolledUp(_)));
+    }
+}
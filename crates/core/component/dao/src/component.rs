@@ -1,8 +1,10 @@
-/// The DAO isn't really a "component" because it doesn't really execute anything by itself. It's
-/// just a collection of state that is modified by DaoSpend and DaoDeposit actions.
+/// The DAO is mostly a collection of state that is modified by DaoSpend and DaoDeposit actions, but
+/// it also pays out the recurring tranches of DAO grants at the end of each epoch.
 pub mod state_key;
 
 mod action_handler;
+mod dao;
 mod view;
 
+pub use dao::Dao;
 pub use view::{StateReadExt, StateWriteExt};
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_chain::component::StateReadExt as _;
+use penumbra_component::Component;
+use penumbra_storage::StateWrite;
+use tendermint::v0_34::abci;
+use tracing::instrument;
+
+use super::StateWriteExt as _;
+
+pub struct Dao {}
+
+#[async_trait]
+impl Component for Dao {
+    type AppState = ();
+
+    #[instrument(name = "dao", skip(_state, _app_state))]
+    async fn init_chain<S: StateWrite>(_state: S, _app_state: &()) {}
+
+    #[instrument(name = "dao", skip(_state, _begin_block))]
+    async fn begin_block<S: StateWrite + 'static>(
+        _state: &mut Arc<S>,
+        _begin_block: &abci::request::BeginBlock,
+    ) {
+    }
+
+    #[instrument(name = "dao", skip(_state, _end_block))]
+    async fn end_block<S: StateWrite + 'static>(
+        _state: &mut Arc<S>,
+        _end_block: &abci::request::EndBlock,
+    ) {
+    }
+
+    #[instrument(name = "dao", skip(state))]
+    async fn end_epoch<S: StateWrite + 'static>(state: &mut Arc<S>) -> Result<()> {
+        let state = Arc::get_mut(state).expect("state should be unique");
+        // Pay out any DAO grant tranches which fall due in the epoch that is ending
+        let epoch = state.epoch().await?;
+        state.pay_dao_grants(epoch.index).await?;
+        Ok(())
+    }
+}
@@ -8,3 +8,24 @@ pub fn all_assets_balance() -> &'static str {
     // Note: this must be the prefix of the above.
     "dao/asset/"
 }
+
+pub fn grant(grant_id: u64) -> String {
+    format!("dao/grant/{grant_id:020}/definition")
+}
+
+pub fn grant_remaining(grant_id: u64) -> String {
+    format!("dao/grant/{grant_id:020}/remaining")
+}
+
+pub fn grant_next_payment_epoch(grant_id: u64) -> String {
+    format!("dao/grant/{grant_id:020}/next_payment_epoch")
+}
+
+pub fn active_grant(grant_id: u64) -> String {
+    format!("dao/active_grants/{grant_id:020}")
+}
+
+pub fn all_active_grants() -> &'static str {
+    // Note: this must be the prefix of the above.
+    "dao/active_grants/"
+}
@@ -1,15 +1,21 @@
-use std::{collections::BTreeMap, str::FromStr};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
 
 use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::{asset, Value};
+use penumbra_chain::NoteSource;
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_shielded_pool::component::NoteManager;
 use penumbra_storage::{StateRead, StateWrite};
 
 use super::state_key;
+use crate::DaoGrant;
 
 #[async_trait]
 pub trait StateReadExt: StateRead {
@@ -32,6 +38,36 @@ pub trait StateReadExt: StateRead {
             .try_collect()
             .await
     }
+
+    /// Get the definition of a DAO grant, if it has ever been scheduled.
+    async fn dao_grant(&self, grant_id: u64) -> Result<Option<DaoGrant>> {
+        self.get(&state_key::grant(grant_id)).await
+    }
+
+    /// Get the number of tranches of a DAO grant which remain to be paid, if it is still active.
+    async fn dao_grant_remaining(&self, grant_id: u64) -> Result<Option<u64>> {
+        self.get_proto(&state_key::grant_remaining(grant_id)).await
+    }
+
+    /// Get the index of the epoch at the end of which the next tranche of a DAO grant is due, if it
+    /// is still active.
+    async fn dao_grant_next_payment_epoch(&self, grant_id: u64) -> Result<Option<u64>> {
+        self.get_proto(&state_key::grant_next_payment_epoch(grant_id))
+            .await
+    }
+
+    /// Get the IDs of all the DAO grants which are still being paid.
+    async fn active_dao_grants(&self) -> Result<BTreeSet<u64>> {
+        let prefix = state_key::all_active_grants();
+        self.prefix_proto::<()>(prefix)
+            .map(|result| {
+                let (key, ()) = result?;
+                let grant_id = key.rsplit('/').next().expect("key is well-formed");
+                Ok(u64::from_str(grant_id)?)
+            })
+            .try_collect()
+            .await
+    }
 }
 
 impl<T> StateReadExt for T where T: StateRead + ?Sized {}
@@ -63,6 +99,209 @@ pub trait StateWriteExt: StateWrite {
         }
         Ok(())
     }
+
+    /// Schedule a DAO grant, with its first tranche due at the end of the given epoch.
+    fn schedule_dao_grant(&mut self, grant_id: u64, grant: DaoGrant, first_payment_epoch: u64) {
+        self.put_proto(state_key::grant_remaining(grant_id), grant.count);
+        self.put_proto(
+            state_key::grant_next_payment_epoch(grant_id),
+            first_payment_epoch,
+        );
+        self.put(state_key::grant(grant_id), grant);
+        self.put_proto(state_key::active_grant(grant_id), ());
+    }
+
+    /// Stop paying a DAO grant, leaving its definition in place for future reference.
+    async fn cancel_dao_grant(&mut self, grant_id: u64) -> Result<()> {
+        if self.dao_grant_remaining(grant_id).await?.is_none() {
+            anyhow::bail!("DAO grant {grant_id} is not active");
+        }
+        self.finish_dao_grant(grant_id);
+        Ok(())
+    }
+
+    /// Pay the next tranche of every DAO grant which is due by the end of the given epoch.
+    ///
+    /// If the DAO can't currently afford a tranche, it is deferred to the end of the next epoch,
+    /// rather than skipped.
+    async fn pay_dao_grants(&mut self, epoch_index: u64) -> Result<()> {
+        for grant_id in self.active_dao_grants().await? {
+            let next_payment_epoch = self
+                .dao_grant_next_payment_epoch(grant_id)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("active DAO grant {grant_id} has no next payment")
+                })?;
+            if epoch_index < next_payment_epoch {
+                continue;
+            }
+
+            let grant = self
+                .dao_grant(grant_id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("active DAO grant {grant_id} has no definition"))?;
+            let remaining = self.dao_grant_remaining(grant_id).await?.ok_or_else(|| {
+                anyhow::anyhow!("active DAO grant {grant_id} has no remaining count")
+            })?;
+
+            if let Err(error) = self.dao_withdraw(grant.value).await {
+                tracing::warn!(grant_id, %error, "deferring DAO grant tranche to the next epoch");
+                continue;
+            }
+            self.mint_note(grant.value, &grant.address, NoteSource::DaoOutput)
+                .await?;
+            tracing::debug!(
+                grant_id,
+                remaining = remaining - 1,
+                "paid DAO grant tranche"
+            );
+
+            if remaining > 1 {
+                self.put_proto(state_key::grant_remaining(grant_id), remaining - 1);
+                self.put_proto(
+                    state_key::grant_next_payment_epoch(grant_id),
+                    epoch_index.saturating_add(grant.period_epochs),
+                );
+            } else {
+                self.finish_dao_grant(grant_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove a DAO grant from the set of active grants.
+    fn finish_dao_grant(&mut self, grant_id: u64) {
+        self.delete(state_key::grant_remaining(grant_id));
+        self.delete(state_key::grant_next_payment_epoch(grant_id));
+        self.delete(state_key::active_grant(grant_id));
+    }
 }
 
 impl<T> StateWriteExt for T where T: StateWrite + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_chain::test_keys;
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+
+    use super::*;
+
+    fn staking(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    fn grant(amount: u64, period_epochs: u64, count: u64) -> DaoGrant {
+        DaoGrant {
+            value: staking(amount),
+            address: *test_keys::ADDRESS_0,
+            period_epochs,
+            count,
+        }
+    }
+
+    /// Pay grants at the end of the given epoch, returning the number of notes minted.
+    async fn pay(state: &mut Arc<StateDelta<()>>, epoch_index: u64) -> Result<usize> {
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        let before = state_tx.pending_note_payloads().await.len();
+        state_tx.pay_dao_grants(epoch_index).await?;
+        let minted = state_tx.pending_note_payloads().await.len() - before;
+        state_tx.apply();
+        Ok(minted)
+    }
+
+    #[tokio::test]
+    async fn grant_tranches_are_paid_every_period() -> Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.dao_deposit(staking(1000)).await?;
+        state_tx.schedule_dao_grant(7, grant(100, 2, 3), 0);
+        state_tx.apply();
+
+        // The first tranche is paid at the end of the first epoch...
+        assert_eq!(pay(&mut state, 0).await?, 1);
+        assert_eq!(state.dao_grant_remaining(7).await?, Some(2));
+        assert_eq!(state.dao_grant_next_payment_epoch(7).await?, Some(2));
+
+        // ... the next one only once a full period has passed...
+        assert_eq!(pay(&mut state, 1).await?, 0);
+        assert_eq!(pay(&mut state, 2).await?, 1);
+        assert_eq!(state.dao_grant_next_payment_epoch(7).await?, Some(4));
+
+        // ... and after the last one, the grant is finished.
+        assert_eq!(pay(&mut state, 4).await?, 1);
+        assert!(state.active_dao_grants().await?.is_empty());
+        assert_eq!(state.dao_grant_remaining(7).await?, None);
+        assert_eq!(state.dao_grant(7).await?, Some(grant(100, 2, 3)));
+        assert_eq!(pay(&mut state, 6).await?, 0);
+
+        assert_eq!(
+            state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+            700u64.into()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unaffordable_tranches_are_deferred() -> Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.dao_deposit(staking(50)).await?;
+        state_tx.schedule_dao_grant(7, grant(100, 5, 2), 0);
+        state_tx.apply();
+
+        // The DAO can't afford the tranche, so it's retried at the end of the next epoch...
+        assert_eq!(pay(&mut state, 0).await?, 0);
+        assert_eq!(state.dao_grant_remaining(7).await?, Some(2));
+        assert_eq!(state.dao_grant_next_payment_epoch(7).await?, Some(0));
+
+        // ... and paid once the DAO has the funds, with the next tranche a period after that.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.dao_deposit(staking(50)).await?;
+        state_tx.apply();
+        assert_eq!(pay(&mut state, 1).await?, 1);
+        assert_eq!(state.dao_grant_remaining(7).await?, Some(1));
+        assert_eq!(state.dao_grant_next_payment_epoch(7).await?, Some(6));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancelled_grants_are_not_paid() -> Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.dao_deposit(staking(1000)).await?;
+        state_tx.schedule_dao_grant(7, grant(100, 1, 3), 0);
+        state_tx.cancel_dao_grant(7).await?;
+        assert!(state_tx.cancel_dao_grant(7).await.is_err());
+        state_tx.apply();
+
+        assert_eq!(pay(&mut state, 0).await?, 0);
+        assert_eq!(
+            state.dao_asset_balance(*STAKING_TOKEN_ASSET_ID).await?,
+            1000u64.into()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn grant_periods_are_bounded() {
+        assert!(grant(100, 1, 1).check_valid().is_ok());
+        assert!(grant(100, DaoGrant::MAX_PERIOD_EPOCHS, 1)
+            .check_valid()
+            .is_ok());
+        assert!(grant(100, 0, 1).check_valid().is_err());
+        assert!(grant(100, DaoGrant::MAX_PERIOD_EPOCHS + 1, 1)
+            .check_valid()
+            .is_err());
+        assert!(grant(100, u64::MAX, 1).check_valid().is_err());
+    }
+}
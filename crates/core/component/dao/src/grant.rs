@@ -0,0 +1,90 @@
+use anyhow::{Context, Error};
+use penumbra_asset::Value;
+use penumbra_crypto::Address;
+use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType, TypeUrl};
+use serde::{Deserialize, Serialize};
+
+/// A grant from the DAO, paying a fixed value to a recipient in recurring tranches.
+///
+/// Grants are created by passed DAO grant proposals, and are identified by the ID of the proposal
+/// which created them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::proposal::DaoGrant", into = "pb::proposal::DaoGrant")]
+pub struct DaoGrant {
+    /// The value paid to the recipient in each tranche.
+    pub value: Value,
+    /// The recipient of the grant.
+    pub address: Address,
+    /// The number of epochs between successive tranches.
+    pub period_epochs: u64,
+    /// The total number of tranches to pay.
+    pub count: u64,
+}
+
+impl DaoGrant {
+    /// The longest period between successive tranches of a grant.
+    pub const MAX_PERIOD_EPOCHS: u64 = 10_000;
+
+    /// Check that the grant is well-formed: it must pay a non-zero value at least once, with a
+    /// period of at least one epoch and at most [`DaoGrant::MAX_PERIOD_EPOCHS`].
+    pub fn check_valid(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.value.amount > 0u64.into(),
+            "DAO grant must pay a non-zero amount"
+        );
+        anyhow::ensure!(self.count > 0, "DAO grant must pay at least one tranche");
+        anyhow::ensure!(
+            self.period_epochs > 0,
+            "DAO grant must have a period of at least one epoch"
+        );
+        anyhow::ensure!(
+            self.period_epochs <= Self::MAX_PERIOD_EPOCHS,
+            "DAO grant must have a period of at most {} epochs",
+            Self::MAX_PERIOD_EPOCHS
+        );
+        Ok(())
+    }
+}
+
+impl TypeUrl for DaoGrant {
+    const TYPE_URL: &'static str = "/penumbra.core.governance.v1alpha1.Proposal.DaoGrant";
+}
+
+impl DomainType for DaoGrant {
+    type Proto = pb::proposal::DaoGrant;
+}
+
+impl From<DaoGrant> for pb::proposal::DaoGrant {
+    fn from(grant: DaoGrant) -> Self {
+        pb::proposal::DaoGrant {
+            value: Some(grant.value.into()),
+            address: Some(grant.address.into()),
+            period_epochs: grant.period_epochs,
+            count: grant.count,
+        }
+    }
+}
+
+impl TryFrom<pb::proposal::DaoGrant> for DaoGrant {
+    type Error = Error;
+
+    fn try_from(proto: pb::proposal::DaoGrant) -> anyhow::Result<Self, Self::Error> {
+        let value = proto
+            .value
+            .ok_or_else(|| anyhow::anyhow!("missing value"))?
+            .try_into()
+            .context("malformed value")?;
+        let address = proto
+            .address
+            .ok_or_else(|| anyhow::anyhow!("missing address"))?
+            .try_into()
+            .context("malformed address")?;
+
+        Ok(DaoGrant {
+            value,
+            address,
+            period_epochs: proto.period_epochs,
+            count: proto.count,
+        })
+    }
+}
@@ -8,3 +8,5 @@ pub mod event;
 
 mod action;
 pub use action::{DaoDeposit, DaoOutput, DaoSpend};
+mod grant;
+pub use grant::DaoGrant;
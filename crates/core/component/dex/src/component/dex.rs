@@ -2,10 +2,13 @@ use std::{collections::BTreeMap, sync::Arc};
 
 use anyhow::Result;
 use async_trait::async_trait;
-use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
-use penumbra_chain::component::StateReadExt as _;
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_chain::{component::StateReadExt as _, NoteSource};
 use penumbra_component::Component;
+use penumbra_crypto::Address;
+use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_shielded_pool::component::NoteManager;
 use penumbra_storage::{StateRead, StateWrite};
 use tendermint::v0_34::abci;
 use tracing::instrument;
@@ -64,6 +67,19 @@ impl Component for Dex {
                 .expect("handling batch swaps is infaillible");
         }
 
+        // Deliver the outputs of swaps made on behalf of deposits, now that their batches have
+        // executed.
+        Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete")
+            .deliver_deposit_swaps(
+                end_block
+                    .height
+                    .try_into()
+                    .expect("height is part of the end block data"),
+            )
+            .await
+            .expect("delivering deposit swaps is infallible");
+
         // Then, perform arbitrage:
         let arb_burn = state
             .arbitrage(
@@ -209,6 +225,106 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         swap_flows.insert(*trading_pair, swap_flow);
         self.object_put(state_key::swap_flows(), swap_flows)
     }
+
+    /// Swap value deposited into Penumbra from outside (for instance, by an inbound IBC
+    /// transfer) into the given asset, in this block's batch.
+    ///
+    /// Deposited value isn't in the shielded pool yet, so its owner can't swap it with a `Swap`
+    /// action. Instead, the chain adds it to the batch swap flow itself, and pays the outputs of
+    /// the swap directly to `claim_address` once the batch has executed. The caller is
+    /// responsible for adding the deposit to the token supply, since the outputs aren't.
+    fn swap_deposit(
+        &mut self,
+        input: Value,
+        output_asset: asset::Id,
+        claim_address: Address,
+        source: NoteSource,
+    ) -> Result<()> {
+        if input.asset_id == output_asset {
+            anyhow::bail!("cannot swap a deposit into the asset it is already in");
+        }
+
+        let trading_pair = TradingPair::new(input.asset_id, output_asset);
+        let (delta_1_i, delta_2_i) = if input.asset_id == trading_pair.asset_1() {
+            (input.amount, Amount::zero())
+        } else {
+            (Amount::zero(), input.amount)
+        };
+
+        let mut swap_flow = self.swap_flow(&trading_pair);
+        swap_flow.0 += delta_1_i;
+        swap_flow.1 += delta_2_i;
+        self.put_swap_flow(&trading_pair, swap_flow);
+
+        let mut deposit_swaps: im::Vector<DepositSwap> = self
+            .object_get(state_key::pending_deposit_swaps())
+            .unwrap_or_default();
+        deposit_swaps.push_back(DepositSwap {
+            trading_pair,
+            delta_1_i,
+            delta_2_i,
+            claim_address,
+            source,
+        });
+        self.object_put(state_key::pending_deposit_swaps(), deposit_swaps);
+
+        Ok(())
+    }
+
+    /// Pay the outputs of the deposit swaps made in this block to their claim addresses.
+    ///
+    /// This must be called after the block's batch swaps have executed.
+    async fn deliver_deposit_swaps(&mut self, height: u64) -> Result<()> {
+        let deposit_swaps: im::Vector<DepositSwap> = self
+            .object_get(state_key::pending_deposit_swaps())
+            .unwrap_or_default();
+
+        for swap in deposit_swaps {
+            let output_data = self
+                .output_data(height, swap.trading_pair)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "missing output data for deposit swap on {:?}",
+                        swap.trading_pair
+                    )
+                })?;
+
+            let (lambda_1_i, lambda_2_i) =
+                output_data.pro_rata_outputs((swap.delta_1_i, swap.delta_2_i));
+
+            for value in [
+                Value {
+                    amount: lambda_1_i,
+                    asset_id: swap.trading_pair.asset_1(),
+                },
+                Value {
+                    amount: lambda_2_i,
+                    asset_id: swap.trading_pair.asset_2(),
+                },
+            ] {
+                // The outputs are paid out of the DEX's reserves, and the deposit was added to the
+                // token supply when it was received, so this doesn't change the supply.
+                if value.amount > Amount::zero() {
+                    self.create_public_note(value, &swap.claim_address, swap.source)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A swap made on behalf of a deposit, whose outputs are paid to its claim address once its
+/// batch has executed.
+#[derive(Debug, Clone)]
+struct DepositSwap {
+    trading_pair: TradingPair,
+    delta_1_i: Amount,
+    delta_2_i: Amount,
+    claim_address: Address,
+    source: NoteSource,
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
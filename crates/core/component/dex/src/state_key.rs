@@ -55,6 +55,10 @@ pub fn pending_payloads() -> &'static str {
     "dex/pending_payloads"
 }
 
+pub fn pending_deposit_swaps() -> &'static str {
+    "dex/pending_deposit_swaps"
+}
+
 pub fn pending_outputs() -> &'static str {
     "dex/pending_outputs"
 }
@@ -10,6 +10,7 @@ mod metrics;
 mod msg_handler;
 mod packet;
 mod proof_verification;
+mod rate_limit;
 mod state_key;
 mod transfer;
 
@@ -18,5 +19,6 @@ use msg_handler::MsgHandler;
 pub use self::metrics::register_metrics;
 pub use channel::StateReadExt as ChannelStateReadExt;
 pub use client::StateReadExt as ClientStateReadExt;
+pub use client::StateWriteExt as ClientStateWriteExt;
 pub use connection::StateReadExt as ConnectionStateReadExt;
 pub use ibc_component::IBCComponent;
@@ -14,6 +14,8 @@ impl ActionHandler for IbcAction {
         match self {
             IbcAction::CreateClient(msg) => msg.check_stateless().await?,
             IbcAction::UpdateClient(msg) => msg.check_stateless().await?,
+            IbcAction::SubmitMisbehavior(msg) => msg.check_stateless().await?,
+            IbcAction::UpgradeClient(msg) => msg.check_stateless().await?,
             IbcAction::ConnectionOpenInit(msg) => msg.check_stateless().await?,
             IbcAction::ConnectionOpenTry(msg) => msg.check_stateless().await?,
             IbcAction::ConnectionOpenAck(msg) => msg.check_stateless().await?,
@@ -47,6 +49,8 @@ impl ActionHandler for IbcAction {
         match self {
             IbcAction::CreateClient(msg) => msg.try_execute(state).await?,
             IbcAction::UpdateClient(msg) => msg.try_execute(state).await?,
+            IbcAction::SubmitMisbehavior(msg) => msg.try_execute(state).await?,
+            IbcAction::UpgradeClient(msg) => msg.try_execute(state).await?,
             IbcAction::ConnectionOpenInit(msg) => msg.try_execute(state).await?,
             IbcAction::ConnectionOpenTry(msg) => msg.try_execute(state).await?,
             IbcAction::ConnectionOpenAck(msg) => msg.try_execute(state).await?,
@@ -165,6 +165,93 @@ pub trait StateWriteExt: StateWrite + StateReadExt {
         self.put(state_key::client_state(client_id), client_state);
     }
 
+    /// Freeze a client at the given height, so that it can no longer be updated or used to verify
+    /// proofs, because the counterparty chain has been shown to misbehave.
+    async fn freeze_client(&mut self, client_id: &ClientId, frozen_height: Height) -> Result<()> {
+        let client_state = self.get_client_state(client_id).await?;
+        self.put_client(client_id, client_state.with_frozen_height(frozen_height));
+        self.record(event::client_misbehaviour(client_id.clone(), frozen_height));
+        Ok(())
+    }
+
+    /// Recover a frozen or expired client by replacing its state with that of an active substitute
+    /// client for the same counterparty chain, as directed by governance.
+    ///
+    /// Connections and channels refer to clients by ID, so recovering the subject client in place
+    /// revives everything built on top of it.
+    async fn substitute_client(
+        &mut self,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+    ) -> Result<()> {
+        anyhow::ensure!(
+            subject_client_id != substitute_client_id,
+            "subject and substitute clients must be different"
+        );
+
+        let subject = self.get_client_state(subject_client_id).await?;
+        let substitute = self.get_client_state(substitute_client_id).await?;
+
+        anyhow::ensure!(
+            subject.is_frozen() || self.client_is_expired(subject_client_id, &subject).await?,
+            "subject client {subject_client_id} is active, so it does not need to be substituted"
+        );
+        anyhow::ensure!(
+            !substitute.is_frozen(),
+            "substitute client {substitute_client_id} is frozen"
+        );
+        anyhow::ensure!(
+            !self
+                .client_is_expired(substitute_client_id, &substitute)
+                .await?,
+            "substitute client {substitute_client_id} is expired"
+        );
+
+        // The substitute must be tracking the same chain in the same way: only the chain ID (which
+        // changes across counterparty upgrades), the trusting period, and the heights may differ.
+        anyhow::ensure!(
+            subject.trust_level == substitute.trust_level
+                && subject.unbonding_period == substitute.unbonding_period
+                && subject.max_clock_drift == substitute.max_clock_drift
+                && subject.proof_specs == substitute.proof_specs
+                && subject.upgrade_path == substitute.upgrade_path,
+            "substitute client {substitute_client_id} does not match subject {subject_client_id}"
+        );
+        anyhow::ensure!(
+            substitute.latest_height() > subject.latest_height(),
+            "substitute client height {} is not greater than subject client height {}",
+            substitute.latest_height(),
+            subject.latest_height()
+        );
+
+        let substitute_consensus_state = self
+            .get_verified_consensus_state(substitute.latest_height(), substitute_client_id.clone())
+            .await?;
+
+        let recovered = TendermintClientState {
+            chain_id: substitute.chain_id.clone(),
+            trusting_period: substitute.trusting_period,
+            latest_height: substitute.latest_height(),
+            frozen_height: None,
+            ..subject
+        };
+
+        self.put_client(subject_client_id, recovered);
+        self.put_verified_consensus_state(
+            substitute.latest_height(),
+            subject_client_id.clone(),
+            substitute_consensus_state,
+        )
+        .await?;
+        self.record(event::update_client_proposal(
+            subject_client_id.clone(),
+            substitute_client_id.clone(),
+            substitute.latest_height(),
+        ));
+
+        Ok(())
+    }
+
     fn put_verified_heights(&mut self, client_id: &ClientId, verified_heights: VerifiedHeights) {
         self.put(
             format!(
@@ -254,6 +341,23 @@ pub trait StateReadExt: StateRead {
         client_state.context(format!("could not find client state for {client_id}"))
     }
 
+    /// Check whether a client has expired, meaning that its latest consensus state is older than
+    /// its trusting period allows.
+    async fn client_is_expired(
+        &self,
+        client_id: &ClientId,
+        client_state: &TendermintClientState,
+    ) -> Result<bool> {
+        let latest_consensus_state = self
+            .get_verified_consensus_state(client_state.latest_height(), client_id.clone())
+            .await?;
+
+        let now = self.get_block_timestamp().await?;
+        let time_elapsed = now.duration_since(latest_consensus_state.timestamp)?;
+
+        Ok(client_state.expired(time_elapsed))
+    }
+
     async fn get_verified_heights(&self, client_id: &ClientId) -> Result<Option<VerifiedHeights>> {
         self.get(&format!(
             // NOTE: this is an implementation detail of the Penumbra ICS2 implementation, so
@@ -394,12 +498,24 @@ impl<T: StateRead + ?Sized> StateReadExt for T {}
 mod tests {
     use std::sync::Arc;
 
+    use super::StateWriteExt as _;
     use super::*;
-    use ibc_types2::{core::client::msgs::MsgCreateClient, DomainType};
-    use penumbra_chain::component::StateWriteExt;
+    use ibc_proto::google::protobuf::Any;
+    use ibc_types2::{
+        core::{
+            client::msgs::{MsgCreateClient, MsgSubmitMisbehaviour, MsgUpgradeClient},
+            commitment::{MerkleProof, MerkleRoot},
+        },
+        lightclients::tendermint::{
+            misbehaviour::Misbehaviour as TendermintMisbehaviour, TrustThreshold,
+        },
+        DomainType,
+    };
+    use penumbra_chain::component::{AppHashRead as _, StateWriteExt, PENUMBRA_PROOF_SPECS};
     use penumbra_component::ActionHandler;
-    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
-    use std::str::FromStr;
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta, TempStorage};
+    use prost::Message as _;
+    use std::{str::FromStr, time::Duration};
     use tendermint::Time;
 
     use crate::IbcAction;
@@ -510,4 +626,613 @@ mod tests {
 
         Ok(())
     }
+
+    /// Set up a state with the stargaze client created from the fixtures, returning the state and
+    /// the first update to the client (which has not been applied).
+    async fn stargaze_client() -> anyhow::Result<(Arc<StateDelta<()>>, MsgUpdateClient)> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_chain_params(Default::default());
+        state_tx.put_block_timestamp(Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?);
+        state_tx.put_block_height(1);
+        state_tx.put_epoch_by_height(
+            1,
+            penumbra_chain::Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+        state_tx.apply();
+
+        let msg_create_client_stargaze_raw =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let msg_create_stargaze_client =
+            MsgCreateClient::decode(msg_create_client_stargaze_raw.as_slice()).unwrap();
+        let create_client_action = IbcAction::CreateClient(msg_create_stargaze_client);
+        create_client_action.check_stateless(()).await?;
+        create_client_action.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        create_client_action.execute(&mut state_tx).await?;
+        state_tx.apply();
+
+        let msg_update_client_stargaze_raw =
+            base64::decode(include_str!("./test/update_client_1.msg").replace('\n', "")).unwrap();
+        let mut msg_update_stargaze_client =
+            MsgUpdateClient::decode(msg_update_client_stargaze_raw.as_slice()).unwrap();
+        msg_update_stargaze_client.client_id = ClientId::from_str("07-tendermint-0").unwrap();
+
+        Ok((state, msg_update_stargaze_client))
+    }
+
+    /// Make a header which conflicts with the given one: it is at the same height, but for a
+    /// different block, which its commit does not actually sign.
+    fn conflicting_header(header: &TendermintHeader) -> anyhow::Result<TendermintHeader> {
+        let mut block_header = header.signed_header.header().clone();
+        block_header.app_hash = tendermint::AppHash::try_from(vec![0u8; 32])?;
+
+        let mut conflicting = header.clone();
+        conflicting.signed_header = tendermint::block::signed_header::SignedHeader::new(
+            block_header,
+            header.signed_header.commit().clone(),
+        )?;
+        Ok(conflicting)
+    }
+
+    fn submit_misbehaviour(header1: TendermintHeader, header2: TendermintHeader) -> IbcAction {
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        IbcAction::SubmitMisbehavior(MsgSubmitMisbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: TendermintMisbehaviour {
+                client_id,
+                header1,
+                header2,
+            }
+            .into(),
+            signer: String::new(),
+        })
+    }
+
+    // test that misbehaviour is only accepted if its headers actually conflict.
+    #[tokio::test]
+    async fn test_misbehaviour_requires_conflicting_headers() -> anyhow::Result<()> {
+        let (_state, msg_update) = stargaze_client().await?;
+        let header = ics02_validation::get_tendermint_header(msg_update.header.clone())?;
+
+        // The same header twice is not evidence of anything.
+        let same_block = submit_misbehaviour(header.clone(), header.clone());
+        assert!(same_block.check_stateless(()).await.is_err());
+
+        // Two different blocks at the same height is an equivocation.
+        let equivocation = submit_misbehaviour(header.clone(), conflicting_header(&header)?);
+        equivocation.check_stateless(()).await?;
+
+        Ok(())
+    }
+
+    // test that misbehaviour whose headers do not verify against the stored consensus state is
+    // rejected, and does not freeze the client.
+    #[tokio::test]
+    async fn test_unverifiable_misbehaviour_does_not_freeze_client() -> anyhow::Result<()> {
+        let (mut state, msg_update) = stargaze_client().await?;
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let header = ics02_validation::get_tendermint_header(msg_update.header.clone())?;
+
+        // The conflicting header's commit signs the original block, not the conflicting one, so
+        // it can't be verified and the misbehaviour must be rejected.
+        let misbehaviour = submit_misbehaviour(header.clone(), conflicting_header(&header)?);
+        misbehaviour.check_stateless(()).await?;
+        misbehaviour.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        assert!(misbehaviour.execute(&mut state_tx).await.is_err());
+
+        // Neither the failed execution itself, nor the state after it is applied, has the client
+        // frozen.
+        assert!(!state_tx.get_client_state(&client_id).await?.is_frozen());
+        state_tx.apply();
+        assert!(!state.get_client_state(&client_id).await?.is_frozen());
+
+        Ok(())
+    }
+
+    /// Sign a block at `height`, extending the client's latest trusted consensus state, with a
+    /// validator set consisting only of `signing_key`. The block is otherwise a copy of `template`,
+    /// except for its app hash, so that different app hashes give conflicting blocks.
+    fn sign_header(
+        signing_key: &ed25519_consensus::SigningKey,
+        template: &TendermintHeader,
+        trusted_height: Height,
+        time: Time,
+        app_hash: u8,
+    ) -> anyhow::Result<TendermintHeader> {
+        let public_key =
+            tendermint::PublicKey::from_raw_ed25519(signing_key.verification_key().as_bytes())
+                .context("valid ed25519 key")?;
+        let validator = tendermint::validator::Info::new(public_key, 10u32.into());
+        let validator_set =
+            tendermint::validator::Set::new(vec![validator.clone()], Some(validator.clone()));
+
+        let mut block_header = template.signed_header.header().clone();
+        block_header.height = (trusted_height.revision_height() + 1).try_into()?;
+        block_header.time = time;
+        block_header.validators_hash = validator_set.hash();
+        block_header.next_validators_hash = validator_set.hash();
+        block_header.proposer_address = validator.address;
+        block_header.app_hash = tendermint::AppHash::try_from(vec![app_hash; 32])?;
+
+        let mut commit = template.signed_header.commit().clone();
+        commit.height = block_header.height;
+        commit.block_id.hash = block_header.hash();
+        let vote = tendermint::vote::Vote {
+            vote_type: tendermint::vote::Type::Precommit,
+            height: commit.height,
+            round: commit.round,
+            block_id: Some(commit.block_id),
+            timestamp: Some(time),
+            validator_address: validator.address,
+            validator_index: 0u32.try_into()?,
+            signature: None,
+        };
+        let sign_bytes = vote.to_signable_vec(block_header.chain_id.clone())?;
+        commit.signatures = vec![tendermint::block::CommitSig::BlockIdFlagCommit {
+            validator_address: validator.address,
+            timestamp: time,
+            signature: tendermint::Signature::new(signing_key.sign(&sign_bytes).to_bytes())?,
+        }];
+
+        Ok(TendermintHeader {
+            signed_header: tendermint::block::signed_header::SignedHeader::new(
+                block_header,
+                commit,
+            )?,
+            validator_set: validator_set.clone(),
+            trusted_height,
+            trusted_validator_set: validator_set,
+        })
+    }
+
+    // test that misbehaviour consisting of two conflicting headers, both signed by the validators
+    // the client trusts, freezes the client.
+    #[tokio::test]
+    async fn test_verified_misbehaviour_freezes_client() -> anyhow::Result<()> {
+        let (mut state, msg_update) = stargaze_client().await?;
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let template = ics02_validation::get_tendermint_header(msg_update.header.clone())?;
+        let signing_key = ed25519_consensus::SigningKey::from([7u8; 32]);
+
+        // Have the client trust a validator set whose key we hold, so that we can sign headers
+        // which verify against it.
+        let client_state = state.get_client_state(&client_id).await?;
+        let trusted_height = client_state.latest_height();
+        let trusted = state
+            .get_verified_consensus_state(trusted_height, client_id.clone())
+            .await?;
+        let header_time = (trusted.timestamp + Duration::from_secs(5))?;
+        let header1 = sign_header(&signing_key, &template, trusted_height, header_time, 1)?;
+        let header2 = sign_header(&signing_key, &template, trusted_height, header_time, 2)?;
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx
+            .put_verified_consensus_state(
+                trusted_height,
+                client_id.clone(),
+                TendermintConsensusState::new(
+                    trusted.root.clone(),
+                    trusted.timestamp,
+                    header1.trusted_validator_set.hash(),
+                ),
+            )
+            .await?;
+        state_tx.put_block_timestamp((header_time + Duration::from_secs(5))?);
+        state_tx.apply();
+
+        // Both headers verify against the trusted validators, and together they show that the
+        // counterparty signed two different blocks at the same height.
+        let misbehaviour = submit_misbehaviour(header1.clone(), header2);
+        misbehaviour.check_stateless(()).await?;
+        misbehaviour.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        misbehaviour.execute(&mut state_tx).await?;
+        state_tx.apply();
+
+        let client_state = state.get_client_state(&client_id).await?;
+        assert!(client_state.is_frozen());
+        assert_eq!(client_state.frozen_height, Some(header1.height()));
+
+        // Once frozen, the client can't be updated, even by a header that verified before.
+        let update = IbcAction::UpdateClient(MsgUpdateClient {
+            client_id,
+            header: header1.into(),
+            signer: String::new(),
+        });
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        assert!(update.execute(&mut state_tx).await.is_err());
+
+        Ok(())
+    }
+
+    // test that once a client is frozen, it can't be updated any further.
+    #[tokio::test]
+    async fn test_frozen_client_rejects_updates() -> anyhow::Result<()> {
+        let (mut state, msg_update) = stargaze_client().await?;
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let header = ics02_validation::get_tendermint_header(msg_update.header.clone())?;
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.freeze_client(&client_id, header.height()).await?;
+        state_tx.apply();
+        assert!(state.get_client_state(&client_id).await?.is_frozen());
+
+        let update_client_action = IbcAction::UpdateClient(msg_update);
+        update_client_action.check_stateless(()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        let result = update_client_action.execute(&mut state_tx).await;
+        assert!(result.unwrap_err().to_string().contains("frozen"));
+
+        Ok(())
+    }
+
+    /// Commit an upgraded client and consensus state to the state of a counterparty Penumbra
+    /// chain, under the upgrade path `["PenumbraAppHash", "upgrade"]` for a client whose latest
+    /// height is `last_height`, returning the counterparty's app hash and proofs of both states.
+    async fn commit_upgrade(
+        last_height: u64,
+        client_state: &Any,
+        consensus_state: &Any,
+    ) -> anyhow::Result<(MerkleRoot, MerkleProof, MerkleProof)> {
+        let storage = TempStorage::new().await?;
+        let client_key = format!("upgrade/{last_height}/upgradedClient");
+        let consensus_key = format!("upgrade/{last_height}/upgradedConsState");
+        let mut delta = StateDelta::new(storage.latest_snapshot());
+        delta.put_raw(client_key.clone(), client_state.encode_to_vec());
+        delta.put_raw(consensus_key.clone(), consensus_state.encode_to_vec());
+        storage.commit(delta).await?;
+
+        let snapshot = storage.latest_snapshot();
+        let (_, client_proof) = snapshot
+            .get_with_proof_to_apphash(client_key.into_bytes())
+            .await?;
+        let (_, consensus_proof) = snapshot
+            .get_with_proof_to_apphash(consensus_key.into_bytes())
+            .await?;
+        let root = MerkleRoot {
+            hash: snapshot.app_hash().await?.0.to_vec(),
+        };
+
+        Ok((root, client_proof, consensus_proof))
+    }
+
+    /// Set up a state with the stargaze client, tracking a chain which is about to upgrade: the
+    /// client has an upgrade path, and the trusted consensus state commits to the upgraded client
+    /// and consensus states, which are returned along with proofs of them.
+    async fn upgrading_client(
+    ) -> anyhow::Result<(Arc<StateDelta<()>>, TendermintClientState, MsgUpgradeClient)> {
+        let (mut state, _) = stargaze_client().await?;
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let client_state = state.get_client_state(&client_id).await?;
+        let last_height = client_state.latest_height();
+        let consensus_state = state
+            .get_verified_consensus_state(last_height, client_id.clone())
+            .await?;
+
+        let upgraded_client_state = TendermintClientState {
+            latest_height: last_height.increment(),
+            ..client_state.clone()
+        };
+        let upgraded_client = Any::from(upgraded_client_state.clone());
+        let upgraded_consensus = Any::from(consensus_state.clone());
+        let (root, proof_upgrade_client, proof_upgrade_consensus_state) = commit_upgrade(
+            last_height.revision_height(),
+            &upgraded_client,
+            &upgraded_consensus,
+        )
+        .await?;
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_client(
+            &client_id,
+            TendermintClientState {
+                proof_specs: PENUMBRA_PROOF_SPECS.clone(),
+                upgrade_path: vec!["PenumbraAppHash".to_string(), "upgrade".to_string()],
+                ..client_state
+            },
+        );
+        state_tx
+            .put_verified_consensus_state(
+                last_height,
+                client_id.clone(),
+                TendermintConsensusState::new(
+                    root,
+                    consensus_state.timestamp,
+                    consensus_state.next_validators_hash,
+                ),
+            )
+            .await?;
+        state_tx.apply();
+
+        let msg = MsgUpgradeClient {
+            client_id,
+            client_state: upgraded_client,
+            consensus_state: upgraded_consensus,
+            proof_upgrade_client: proof_upgrade_client.into(),
+            proof_upgrade_consensus_state: proof_upgrade_consensus_state.into(),
+            signer: String::new(),
+        };
+
+        Ok((state, upgraded_client_state, msg))
+    }
+
+    // test that a client can be upgraded to the upgraded client state committed by the
+    // counterparty.
+    #[tokio::test]
+    async fn test_client_upgrade() -> anyhow::Result<()> {
+        let (mut state, upgraded_client_state, msg) = upgrading_client().await?;
+        let client_id = msg.client_id.clone();
+
+        let upgrade = IbcAction::UpgradeClient(msg);
+        upgrade.check_stateless(()).await?;
+        upgrade.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        upgrade.execute(&mut state_tx).await?;
+        state_tx.apply();
+
+        // The client follows the upgraded chain, with the counterparty's choice of proof specs
+        // and upgrade path.
+        let client_state = state.get_client_state(&client_id).await?;
+        assert_eq!(
+            client_state.latest_height(),
+            upgraded_client_state.latest_height()
+        );
+        assert_eq!(client_state.proof_specs, upgraded_client_state.proof_specs);
+        assert_eq!(
+            client_state.upgrade_path,
+            upgraded_client_state.upgrade_path
+        );
+        state
+            .get_verified_consensus_state(client_state.latest_height(), client_id)
+            .await?;
+
+        Ok(())
+    }
+
+    // test that a client upgrade is rejected if it isn't what the counterparty committed to.
+    #[tokio::test]
+    async fn test_client_upgrade_rejects_bad_proofs() -> anyhow::Result<()> {
+        let (mut state, upgraded_client_state, msg) = upgrading_client().await?;
+        let client_id = msg.client_id.clone();
+        let original = state.get_client_state(&client_id).await?;
+
+        // The proofs are valid, but for each other's values.
+        let swapped = MsgUpgradeClient {
+            proof_upgrade_client: msg.proof_upgrade_consensus_state.clone(),
+            proof_upgrade_consensus_state: msg.proof_upgrade_client.clone(),
+            ..msg.clone()
+        };
+        // The upgraded client state isn't the one which was committed.
+        let tampered = MsgUpgradeClient {
+            client_state: TendermintClientState {
+                latest_height: upgraded_client_state.latest_height().increment(),
+                ..upgraded_client_state
+            }
+            .into(),
+            ..msg
+        };
+
+        for msg in [swapped, tampered] {
+            let upgrade = IbcAction::UpgradeClient(msg);
+            upgrade.check_stateless(()).await?;
+            let mut state_tx = state.try_begin_transaction().unwrap();
+            assert!(upgrade.execute(&mut state_tx).await.is_err());
+            state_tx.apply();
+            assert_eq!(state.get_client_state(&client_id).await?, original);
+        }
+
+        Ok(())
+    }
+
+    /// Set up a state with the stargaze client as `07-tendermint-0`, and a second client for the
+    /// same chain as `07-tendermint-1`, which is a valid substitute for the first: it has been
+    /// updated to a later height, and is active. If `expire_subject` is set, enough time has
+    /// passed that the first client has expired.
+    async fn subject_and_substitute(
+        expire_subject: bool,
+    ) -> anyhow::Result<(Arc<StateDelta<()>>, TendermintClientState)> {
+        let (mut state, _) = stargaze_client().await?;
+        let subject_id = ClientId::from_str("07-tendermint-0").unwrap();
+
+        let msg_create_client_stargaze_raw =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let create_client_action = IbcAction::CreateClient(MsgCreateClient::decode(
+            msg_create_client_stargaze_raw.as_slice(),
+        )?);
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        create_client_action.execute(&mut state_tx).await?;
+        state_tx.apply();
+
+        if expire_subject {
+            let subject = state.get_client_state(&subject_id).await?;
+            let consensus_state = state
+                .get_verified_consensus_state(subject.latest_height(), subject_id.clone())
+                .await?;
+            let now = consensus_state
+                .timestamp
+                .checked_add(subject.trusting_period + Duration::from_secs(24 * 60 * 60))
+                .unwrap();
+            let mut state_tx = state.try_begin_transaction().unwrap();
+            state_tx.put_block_timestamp(now);
+            state_tx.apply();
+        }
+
+        let subject = state.get_client_state(&subject_id).await?;
+        let substitute = TendermintClientState {
+            latest_height: subject.latest_height().increment(),
+            ..subject
+        };
+        put_substitute(&mut state, substitute.clone()).await?;
+
+        Ok((state, substitute))
+    }
+
+    /// Replace the substitute client `07-tendermint-1`, with a current consensus state at its
+    /// latest height.
+    async fn put_substitute(
+        state: &mut Arc<StateDelta<()>>,
+        substitute: TendermintClientState,
+    ) -> anyhow::Result<()> {
+        let substitute_id = ClientId::from_str("07-tendermint-1").unwrap();
+        let consensus_state = state
+            .get_verified_consensus_state(
+                state
+                    .get_client_state(&substitute_id)
+                    .await?
+                    .latest_height(),
+                substitute_id.clone(),
+            )
+            .await?;
+        let now = state.get_block_timestamp().await?;
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx
+            .put_verified_consensus_state(
+                substitute.latest_height(),
+                substitute_id.clone(),
+                TendermintConsensusState::new(
+                    consensus_state.root,
+                    now,
+                    consensus_state.next_validators_hash,
+                ),
+            )
+            .await?;
+        state_tx.put_client(&substitute_id, substitute);
+        state_tx.apply();
+
+        Ok(())
+    }
+
+    // test that an expired client can be recovered by substituting an active client for it.
+    #[tokio::test]
+    async fn test_substitute_expired_client() -> anyhow::Result<()> {
+        let (mut state, substitute) = subject_and_substitute(true).await?;
+        let subject_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let substitute_id = ClientId::from_str("07-tendermint-1").unwrap();
+        let subject = state.get_client_state(&subject_id).await?;
+        assert!(state.client_is_expired(&subject_id, &subject).await?);
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx
+            .substitute_client(&subject_id, &substitute_id)
+            .await?;
+        let (_, events) = state_tx.apply();
+
+        let recovered = state.get_client_state(&subject_id).await?;
+        assert_eq!(recovered.latest_height(), substitute.latest_height());
+        assert!(!recovered.is_frozen());
+        assert!(!state.client_is_expired(&subject_id, &recovered).await?);
+
+        // Relayers are told that the subject client has moved to the substitute's height.
+        assert_eq!(
+            events,
+            vec![event::update_client_proposal(
+                subject_id,
+                substitute_id,
+                substitute.latest_height()
+            )]
+        );
+
+        Ok(())
+    }
+
+    // test that a frozen client can be recovered by substituting an active client for it, even if
+    // it hasn't expired, but an active client can't be.
+    #[tokio::test]
+    async fn test_substitute_requires_frozen_or_expired_subject() -> anyhow::Result<()> {
+        let (mut state, _) = subject_and_substitute(false).await?;
+        let subject_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let substitute_id = ClientId::from_str("07-tendermint-1").unwrap();
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        let result = state_tx
+            .substitute_client(&subject_id, &substitute_id)
+            .await;
+        assert!(result.unwrap_err().to_string().contains("is active"));
+        assert!(state_tx
+            .substitute_client(&subject_id, &subject_id)
+            .await
+            .is_err());
+        drop(state_tx);
+
+        let subject = state.get_client_state(&subject_id).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx
+            .freeze_client(&subject_id, subject.latest_height())
+            .await?;
+        state_tx
+            .substitute_client(&subject_id, &substitute_id)
+            .await?;
+        state_tx.apply();
+        assert!(!state.get_client_state(&subject_id).await?.is_frozen());
+
+        Ok(())
+    }
+
+    // test that a substitute must track the counterparty in the same way as the subject, from a
+    // later height.
+    #[tokio::test]
+    async fn test_substitute_must_match_subject() -> anyhow::Result<()> {
+        let (mut state, substitute) = subject_and_substitute(true).await?;
+        let subject_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let substitute_id = ClientId::from_str("07-tendermint-1").unwrap();
+        let subject = state.get_client_state(&subject_id).await?;
+
+        let other_trust_level = if substitute.trust_level == TrustThreshold::ONE_THIRD {
+            TrustThreshold::TWO_THIRDS
+        } else {
+            TrustThreshold::ONE_THIRD
+        };
+        let mismatched = [
+            TendermintClientState {
+                trust_level: other_trust_level,
+                ..substitute.clone()
+            },
+            TendermintClientState {
+                unbonding_period: substitute.unbonding_period + Duration::from_secs(1),
+                ..substitute.clone()
+            },
+            TendermintClientState {
+                max_clock_drift: substitute.max_clock_drift + Duration::from_secs(1),
+                ..substitute.clone()
+            },
+            TendermintClientState {
+                proof_specs: Vec::new(),
+                ..substitute.clone()
+            },
+            TendermintClientState {
+                upgrade_path: vec!["other".to_string()],
+                ..substitute.clone()
+            },
+        ];
+        for substitute in mismatched {
+            put_substitute(&mut state, substitute).await?;
+            let mut state_tx = state.try_begin_transaction().unwrap();
+            let result = state_tx
+                .substitute_client(&subject_id, &substitute_id)
+                .await;
+            assert!(result.unwrap_err().to_string().contains("does not match"));
+        }
+
+        put_substitute(
+            &mut state,
+            TendermintClientState {
+                latest_height: subject.latest_height(),
+                ..substitute
+            },
+        )
+        .await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        let result = state_tx
+            .substitute_client(&subject_id, &substitute_id)
+            .await;
+        assert!(result.unwrap_err().to_string().contains("not greater"));
+
+        Ok(())
+    }
 }
@@ -116,6 +116,9 @@ pub(crate) mod ics02_validation {
     use ibc_types2::lightclients::tendermint::header::{
         Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
     };
+    use ibc_types2::lightclients::tendermint::misbehaviour::{
+        Misbehaviour as TendermintMisbehaviour, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+    };
 
     pub fn is_tendermint_header_state(header: &Any) -> bool {
         header.type_url.as_str() == TENDERMINT_HEADER_TYPE_URL
@@ -126,6 +129,9 @@ pub(crate) mod ics02_validation {
     pub fn is_tendermint_client_state(client_state: &Any) -> bool {
         client_state.type_url.as_str() == TENDERMINT_CLIENT_STATE_TYPE_URL
     }
+    pub fn is_tendermint_misbehaviour(misbehaviour: &Any) -> bool {
+        misbehaviour.type_url.as_str() == TENDERMINT_MISBEHAVIOUR_TYPE_URL
+    }
 
     pub fn get_tendermint_header(header: Any) -> Result<TendermintHeader> {
         if is_tendermint_header_state(&header) {
@@ -139,6 +145,21 @@ pub(crate) mod ics02_validation {
         }
     }
 
+    pub fn get_tendermint_misbehaviour(misbehaviour: Any) -> Result<TendermintMisbehaviour> {
+        if is_tendermint_misbehaviour(&misbehaviour) {
+            TendermintMisbehaviour::try_from(misbehaviour).map_err(|e| {
+                anyhow!(format!(
+                    "failed to deserialize tendermint misbehaviour: {e}"
+                ))
+            })
+        } else {
+            Err(anyhow!(format!(
+                "expected tendermint misbehaviour, got: {}",
+                misbehaviour.type_url.as_str()
+            )))
+        }
+    }
+
     pub fn get_tendermint_consensus_state(
         consensus_state: Any,
     ) -> Result<TendermintConsensusState> {
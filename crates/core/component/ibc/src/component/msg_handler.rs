@@ -10,9 +10,11 @@ mod connection_open_confirm;
 mod connection_open_init;
 mod connection_open_try;
 mod create_client;
+mod misbehavior;
 mod recv_packet;
 mod timeout;
 mod update_client;
+mod upgrade_client;
 
 use anyhow::Result;
 use async_trait::async_trait;
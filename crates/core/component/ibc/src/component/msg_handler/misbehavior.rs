@@ -0,0 +1,109 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use ibc_types2::{
+    core::client::msgs::MsgSubmitMisbehaviour,
+    lightclients::tendermint::misbehaviour::Misbehaviour as TendermintMisbehaviour,
+};
+use penumbra_storage::StateWrite;
+
+use super::update_client::{
+    client_is_not_expired, client_is_not_frozen, client_is_present, verify_header,
+};
+use crate::component::{client::StateWriteExt as _, client_counter::ics02_validation, MsgHandler};
+
+#[async_trait]
+impl MsgHandler for MsgSubmitMisbehaviour {
+    async fn check_stateless(&self) -> Result<()> {
+        let misbehaviour =
+            ics02_validation::get_tendermint_misbehaviour(self.misbehaviour.clone())?;
+
+        misbehaviour_client_matches(self, &misbehaviour)?;
+        misbehaviour_is_equivocation(&misbehaviour)?;
+
+        Ok(())
+    }
+
+    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(msg = ?self);
+
+        let misbehaviour =
+            ics02_validation::get_tendermint_misbehaviour(self.misbehaviour.clone())?;
+
+        let client_state = client_is_present(&state, &self.client_id).await?;
+
+        client_is_not_frozen(&client_state)?;
+        client_is_not_expired(&state, &self.client_id, &client_state).await?;
+
+        // Both headers must be valid updates to the client, each extending a consensus state we
+        // have already verified: otherwise anyone could freeze a client by inventing headers.
+        verify_header(
+            &state,
+            &self.client_id,
+            &client_state,
+            &misbehaviour.header1,
+        )
+        .await?;
+        verify_header(
+            &state,
+            &self.client_id,
+            &client_state,
+            &misbehaviour.header2,
+        )
+        .await?;
+
+        // The counterparty has signed two different blocks at the same height, so nothing it says
+        // can be trusted anymore: freeze the client at that height.
+        tracing::info!(
+            client_id = %self.client_id,
+            height = %misbehaviour.header1.height(),
+            "freezing client due to misbehavior"
+        );
+        state
+            .freeze_client(&self.client_id, misbehaviour.header1.height())
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn misbehaviour_client_matches(
+    msg: &MsgSubmitMisbehaviour,
+    misbehaviour: &TendermintMisbehaviour,
+) -> anyhow::Result<()> {
+    if msg.client_id != misbehaviour.client_id {
+        Err(anyhow::anyhow!(
+            "misbehaviour is for client {}, but was submitted for client {}",
+            misbehaviour.client_id,
+            msg.client_id
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn misbehaviour_is_equivocation(misbehaviour: &TendermintMisbehaviour) -> anyhow::Result<()> {
+    let header1 = &misbehaviour.header1;
+    let header2 = &misbehaviour.header2;
+
+    if header1.height() != header2.height() {
+        return Err(anyhow::anyhow!(
+            "misbehaviour headers are at different heights ({} and {})",
+            header1.height(),
+            header2.height()
+        ));
+    }
+
+    if header1.signed_header.header().chain_id != header2.signed_header.header().chain_id {
+        return Err(anyhow::anyhow!(
+            "misbehaviour headers are for different chains"
+        ));
+    }
+
+    if header1.signed_header.header().hash() == header2.signed_header.header().hash() {
+        return Err(anyhow::anyhow!(
+            "misbehaviour headers do not conflict: both are for the same block"
+        ));
+    }
+
+    Ok(())
+}
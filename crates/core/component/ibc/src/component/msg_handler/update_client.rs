@@ -39,7 +39,7 @@ impl MsgHandler for MsgUpdateClient {
         if !update_is_already_committed(&state, self).await? {
             tracing::debug!(msg = ?self);
 
-            let client_state = client_is_present(&state, self).await?;
+            let client_state = client_is_present(&state, &self.client_id).await?;
 
             client_is_not_frozen(&client_state)?;
             client_is_not_expired(&state, &self.client_id, &client_state).await?;
@@ -48,69 +48,13 @@ impl MsgHandler for MsgUpdateClient {
 
             let untrusted_header = ics02_validation::get_tendermint_header(self.header.clone())?;
 
-            header_revision_matches_client_state(&trusted_client_state, &untrusted_header)?;
-            header_height_is_consistent(&untrusted_header)?;
-
-            // The (still untrusted) header uses the `trusted_height` field to
-            // specify the trusted anchor data it is extending.
-            let trusted_height = untrusted_header.trusted_height;
-
-            // We use the specified trusted height to query the trusted
-            // consensus state the update extends.
-            let last_trusted_consensus_state = state
-                .get_verified_consensus_state(trusted_height, self.client_id.clone())
-                .await?;
-
-            let last_trusted_consensus_state = last_trusted_consensus_state;
-
-            // We also have to convert from an IBC height, which has two
-            // components, to a Tendermint height, which has only one.
-            let trusted_height = trusted_height
-                .revision_height()
-                .try_into()
-                .context("invalid header height")?;
-
-            let trusted_validator_set =
-                verify_header_validator_set(&untrusted_header, &last_trusted_consensus_state)?;
-
-            // Now we build the trusted and untrusted states to feed to the Tendermint light client.
-
-            let trusted_state = TrustedBlockState {
-                // TODO(erwan): do we need an additional check on `chain_id`
-                chain_id: &trusted_client_state.chain_id.clone().into(),
-                header_time: last_trusted_consensus_state.timestamp,
-                height: trusted_height,
-                next_validators: trusted_validator_set,
-                next_validators_hash: last_trusted_consensus_state.next_validators_hash,
-            };
-
-            let untrusted_state = UntrustedBlockState {
-                signed_header: &untrusted_header.signed_header,
-                validators: &untrusted_header.validator_set,
-                next_validators: None, // TODO: do we need this?
-            };
-
-            let options = trusted_client_state.as_light_client_options()?;
-            let verifier = ProdVerifier::default();
-
-            let verdict = verifier.verify_update_header(
-                untrusted_state,
-                trusted_state,
-                &options,
-                state.get_block_timestamp().await?,
-            );
-
-            match verdict {
-                Verdict::Success => Ok(()),
-                Verdict::NotEnoughTrust(voting_power_tally) => Err(anyhow::anyhow!(
-                    "not enough trust, voting power tally: {:?}",
-                    voting_power_tally
-                )),
-                Verdict::Invalid(detail) => Err(anyhow::anyhow!(
-                    "could not verify tendermint header: invalid: {:?}",
-                    detail
-                )),
-            }?;
+            verify_header(
+                &state,
+                &self.client_id,
+                &trusted_client_state,
+                &untrusted_header,
+            )
+            .await?;
 
             let trusted_header = untrusted_header;
 
@@ -146,6 +90,80 @@ impl MsgHandler for MsgUpdateClient {
     }
 }
 
+/// Verify an untrusted header against the trusted consensus state it claims to extend, using the
+/// Tendermint light client verifier.
+///
+/// This is shared between client updates and misbehavior submissions, both of which must present
+/// headers that would be valid updates to the client.
+pub(crate) async fn verify_header<S: StateRead>(
+    state: S,
+    client_id: &ClientId,
+    trusted_client_state: &TendermintClientState,
+    untrusted_header: &TendermintHeader,
+) -> anyhow::Result<()> {
+    header_revision_matches_client_state(trusted_client_state, untrusted_header)?;
+    header_height_is_consistent(untrusted_header)?;
+
+    // The (still untrusted) header uses the `trusted_height` field to
+    // specify the trusted anchor data it is extending.
+    let trusted_height = untrusted_header.trusted_height;
+
+    // We use the specified trusted height to query the trusted
+    // consensus state the update extends.
+    let last_trusted_consensus_state = state
+        .get_verified_consensus_state(trusted_height, client_id.clone())
+        .await?;
+
+    // We also have to convert from an IBC height, which has two
+    // components, to a Tendermint height, which has only one.
+    let trusted_height = trusted_height
+        .revision_height()
+        .try_into()
+        .context("invalid header height")?;
+
+    let trusted_validator_set =
+        verify_header_validator_set(untrusted_header, &last_trusted_consensus_state)?;
+
+    // Now we build the trusted and untrusted states to feed to the Tendermint light client.
+
+    let trusted_state = TrustedBlockState {
+        // TODO(erwan): do we need an additional check on `chain_id`
+        chain_id: &trusted_client_state.chain_id.clone().into(),
+        header_time: last_trusted_consensus_state.timestamp,
+        height: trusted_height,
+        next_validators: trusted_validator_set,
+        next_validators_hash: last_trusted_consensus_state.next_validators_hash,
+    };
+
+    let untrusted_state = UntrustedBlockState {
+        signed_header: &untrusted_header.signed_header,
+        validators: &untrusted_header.validator_set,
+        next_validators: None, // TODO: do we need this?
+    };
+
+    let options = trusted_client_state.as_light_client_options()?;
+    let verifier = ProdVerifier::default();
+
+    let verdict = verifier.verify_update_header(
+        untrusted_state,
+        trusted_state,
+        &options,
+        state.get_block_timestamp().await?,
+    );
+
+    match verdict {
+        Verdict::Success => Ok(()),
+        Verdict::NotEnoughTrust(voting_power_tally) => Err(anyhow::anyhow!(
+            "not enough trust, voting power tally: {:?}",
+            voting_power_tally
+        )),
+        Verdict::Invalid(detail) => Err(anyhow::anyhow!(
+            "could not verify tendermint header: invalid: {:?}",
+            detail
+        )),
+    }
+}
+
 fn header_is_tendermint(msg: &MsgUpdateClient) -> anyhow::Result<()> {
     if ics02_validation::is_tendermint_header_state(&msg.header) {
         Ok(())
@@ -179,7 +197,7 @@ async fn update_is_already_committed<S: StateRead>(
     }
 }
 
-async fn client_is_not_expired<S: StateRead>(
+pub(crate) async fn client_is_not_expired<S: StateRead>(
     state: S,
     client_id: &ClientId,
     client_state: &TendermintClientState,
@@ -202,16 +220,16 @@ async fn client_is_not_expired<S: StateRead>(
     }
 }
 
-async fn client_is_present<S: StateRead>(
+pub(crate) async fn client_is_present<S: StateRead>(
     state: S,
-    msg: &MsgUpdateClient,
+    client_id: &ClientId,
 ) -> anyhow::Result<TendermintClientState> {
-    state.get_client_type(&msg.client_id).await?;
+    state.get_client_type(client_id).await?;
 
-    state.get_client_state(&msg.client_id).await
+    state.get_client_state(client_id).await
 }
 
-fn client_is_not_frozen(client: &TendermintClientState) -> anyhow::Result<()> {
+pub(crate) fn client_is_not_frozen(client: &TendermintClientState) -> anyhow::Result<()> {
     if client.is_frozen() {
         Err(anyhow::anyhow!("client is frozen"))
     } else {
@@ -0,0 +1,125 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use ibc_types2::{
+    core::{
+        client::msgs::MsgUpgradeClient,
+        commitment::{MerkleProof, MerkleRoot},
+    },
+    lightclients::tendermint::{
+        client_state::ClientState as TendermintClientState,
+        consensus_state::ConsensusState as TendermintConsensusState,
+    },
+};
+use penumbra_storage::StateWrite;
+
+use super::update_client::{client_is_not_expired, client_is_not_frozen, client_is_present};
+use crate::{
+    component::{
+        client::{StateReadExt as _, StateWriteExt as _},
+        client_counter::ics02_validation,
+        proof_verification::verify_client_upgrade,
+        MsgHandler,
+    },
+    event,
+};
+
+// NOTE: this is underspecified.
+// using the same sentinel root as ibc-go for the upgraded consensus state, which can't commit to
+// the counterparty's state root, since the upgraded chain has not yet produced a block:
+// https://github.com/cosmos/ibc-go/blob/main/modules/light-clients/07-tendermint/upgrade.go
+static SENTINEL_UPGRADE_ROOT: &str = "sentinel_root";
+
+#[async_trait]
+impl MsgHandler for MsgUpgradeClient {
+    async fn check_stateless(&self) -> Result<()> {
+        ics02_validation::get_tendermint_client_state(self.client_state.clone())?;
+        ics02_validation::get_tendermint_consensus_state(self.consensus_state.clone())?;
+
+        Ok(())
+    }
+
+    // execute an upgrade of a counterparty client.
+    //
+    // a counterparty chain which is upgrading (for instance, changing its chain ID or revision
+    // number) commits the client and consensus states of the upgraded chain to its own state, under
+    // the upgrade path of its client, using the pre-upgrade chain. we verify those commitments
+    // against the last consensus state we trust for the pre-upgrade chain, and then switch the
+    // client over to the upgraded chain.
+    async fn try_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        tracing::debug!(msg = ?self);
+
+        let client_state = client_is_present(&state, &self.client_id).await?;
+
+        client_is_not_frozen(&client_state)?;
+        client_is_not_expired(&state, &self.client_id, &client_state).await?;
+
+        let upgraded_client_state =
+            ics02_validation::get_tendermint_client_state(self.client_state.clone())?;
+        let upgraded_consensus_state =
+            ics02_validation::get_tendermint_consensus_state(self.consensus_state.clone())?;
+
+        if upgraded_client_state.latest_height() <= client_state.latest_height() {
+            anyhow::bail!(
+                "upgraded client height {} is not greater than current client height {}",
+                upgraded_client_state.latest_height(),
+                client_state.latest_height()
+            );
+        }
+
+        let trusted_consensus_state = state
+            .get_verified_consensus_state(client_state.latest_height(), self.client_id.clone())
+            .await?;
+
+        let proof_upgrade_client = MerkleProof::try_from(self.proof_upgrade_client.clone())
+            .context("invalid upgrade client proof")?;
+        let proof_upgrade_consensus_state =
+            MerkleProof::try_from(self.proof_upgrade_consensus_state.clone())
+                .context("invalid upgrade consensus state proof")?;
+
+        verify_client_upgrade(
+            &client_state,
+            &trusted_consensus_state.root,
+            &proof_upgrade_client,
+            &self.client_state,
+            &proof_upgrade_consensus_state,
+            &self.consensus_state,
+        )?;
+
+        // The counterparty chooses the parameters which describe its chain, but the parameters
+        // which describe how much we trust it (the trust level, trusting period, and clock drift)
+        // remain our own.
+        let next_client_state = TendermintClientState {
+            chain_id: upgraded_client_state.chain_id.clone(),
+            unbonding_period: upgraded_client_state.unbonding_period,
+            latest_height: upgraded_client_state.latest_height(),
+            proof_specs: upgraded_client_state.proof_specs.clone(),
+            upgrade_path: upgraded_client_state.upgrade_path.clone(),
+            frozen_height: None,
+            ..client_state
+        };
+
+        let next_consensus_state = TendermintConsensusState::new(
+            MerkleRoot {
+                hash: SENTINEL_UPGRADE_ROOT.as_bytes().to_vec(),
+            },
+            upgraded_consensus_state.timestamp,
+            upgraded_consensus_state.next_validators_hash,
+        );
+
+        state.put_client(&self.client_id, next_client_state.clone());
+        state
+            .put_verified_consensus_state(
+                next_client_state.latest_height(),
+                self.client_id.clone(),
+                next_consensus_state,
+            )
+            .await?;
+
+        state.record(event::upgrade_client(
+            self.client_id.clone(),
+            next_client_state,
+        ));
+
+        Ok(())
+    }
+}
@@ -44,6 +44,23 @@ pub struct IBCPacket<S: CheckStatus> {
 }
 
 impl IBCPacket<Unchecked> {
+    pub fn new(
+        source_port: PortId,
+        source_channel: ChannelId,
+        timeout_height: Height,
+        timeout_timestamp: u64,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            source_port,
+            source_channel,
+            timeout_height,
+            timeout_timestamp,
+            data,
+            m: std::marker::PhantomData,
+        }
+    }
+
     pub fn assume_checked(self) -> IBCPacket<Checked> {
         IBCPacket {
             source_port: self.source_port,
@@ -150,6 +167,12 @@ pub trait SendPacketWrite: StateWrite {
             .unwrap();
         self.put_send_sequence(&packet.source_channel, &packet.source_port, sequence + 1);
 
+        let channel = self
+            .get_channel(&packet.source_channel, &packet.source_port)
+            .await
+            .unwrap()
+            .expect("channel of a checked packet exists");
+
         // store commitment to the packet data & packet timeout
         let packet = Packet {
             chan_on_a: packet.source_channel,
@@ -157,9 +180,9 @@ pub trait SendPacketWrite: StateWrite {
             sequence: sequence.into(),
 
             // NOTE: the packet commitment is solely a function of the source port and channel, so
-            // these fields do not affect the commitment. Thus, we can set them to empty values.
-            chan_on_b: ChannelId::default(),
-            port_on_b: PortId::default(),
+            // these fields do not affect the commitment, but they are included in the event.
+            chan_on_b: channel.remote.channel_id.clone().unwrap_or_default(),
+            port_on_b: channel.remote.port_id.clone(),
 
             timeout_height_on_b: packet.timeout_height.into(),
             timeout_timestamp_on_b: ibc_types2::timestamp::Timestamp::from_nanoseconds(
@@ -171,6 +194,7 @@ pub trait SendPacketWrite: StateWrite {
         };
 
         self.put_packet_commitment(&packet);
+        self.record(event::send_packet(&packet, &channel));
     }
 }
 
@@ -191,7 +215,7 @@ pub trait WriteAcknowledgement: StateWrite {
                 packet.sequence.into(),
             )
             .await?
-            .is_none();
+            .is_some();
         if exists_prev_ack {
             return Err(anyhow::anyhow!("acknowledgement already exists"));
         }
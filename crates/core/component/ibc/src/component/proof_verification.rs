@@ -1,6 +1,7 @@
 use crate::component::client::StateReadExt;
 
 use core::time::Duration;
+use ibc_proto::google::protobuf::Any;
 use ibc_proto::protobuf::Protobuf;
 use ibc_types2::path::ClientConsensusStatePath;
 use ibc_types2::DomainType;
@@ -221,6 +222,57 @@ pub fn verify_client_consensus_state(
     Ok(())
 }
 
+// NOTE: this is underspecified.
+// using the same key construction as ibc-go:
+// https://github.com/cosmos/ibc-go/blob/main/modules/light-clients/07-tendermint/upgrade.go
+//
+// the upgraded client and consensus states are committed by the counterparty under the last key
+// of the client's upgrade path, namespaced by the height of the last block before the upgrade,
+// which is the latest height of the (trusted, pre-upgrade) client.
+pub fn verify_client_upgrade(
+    client_state: &TendermintClientState,
+    root: &MerkleRoot,
+    proof_upgrade_client: &MerkleProof,
+    upgraded_client_state: &Any,
+    proof_upgrade_consensus_state: &MerkleProof,
+    upgraded_consensus_state: &Any,
+) -> anyhow::Result<()> {
+    let (store_key, upgrade_key) = match client_state.upgrade_path.as_slice() {
+        [store_key, upgrade_key] => (store_key, upgrade_key),
+        [] => anyhow::bail!("client has no upgrade path, so it cannot be upgraded"),
+        _ => anyhow::bail!(
+            "unsupported client upgrade path: {:?}",
+            client_state.upgrade_path
+        ),
+    };
+    let prefix = MerklePrefix {
+        key_prefix: store_key.as_bytes().to_vec(),
+    };
+    let last_height = client_state.latest_height().revision_height();
+
+    let client_path = prefix.apply(vec![format!("{upgrade_key}/{last_height}/upgradedClient")]);
+    proof_upgrade_client.verify_membership(
+        &client_state.proof_specs,
+        root.clone().into(),
+        client_path,
+        upgraded_client_state.encode_to_vec(),
+        0,
+    )?;
+
+    let consensus_path = prefix.apply(vec![format!(
+        "{upgrade_key}/{last_height}/upgradedConsState"
+    )]);
+    proof_upgrade_consensus_state.verify_membership(
+        &client_state.proof_specs,
+        root.clone().into(),
+        consensus_path,
+        upgraded_consensus_state.encode_to_vec(),
+        0,
+    )?;
+
+    Ok(())
+}
+
 #[async_trait]
 pub trait PacketProofVerifier: StateReadExt + inner::Inner {
     async fn verify_packet_recv_proof(
@@ -0,0 +1,435 @@
+use std::fmt::{self, Display, Formatter};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use ibc_types2::core::channel::{ChannelId, PortId};
+use penumbra_asset::asset::DenomMetadata;
+use penumbra_chain::{component::StateReadExt as _, params::ChainParameters};
+use penumbra_num::Amount;
+use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_storage::{StateRead, StateWrite};
+
+use crate::component::state_key;
+
+/// The direction of an ICS-20 transfer, relative to Penumbra.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// A transfer from a counterparty chain to Penumbra.
+    Inbound,
+    /// A transfer from Penumbra to a counterparty chain.
+    Outbound,
+}
+
+impl Direction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Direction::Inbound => "inbound",
+            Direction::Outbound => "outbound",
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// returns the rate limit, if any, on transfers of the denom over the channel in the given
+// direction.
+fn rate_limit(
+    params: &ChainParameters,
+    direction: Direction,
+    channel_id: &ChannelId,
+    denom: &DenomMetadata,
+) -> Option<Amount> {
+    let channel_id = channel_id.to_string();
+    let denom = denom.to_string();
+
+    params
+        .ics20_rate_limits
+        .iter()
+        .find(|limit| limit.channel_id == channel_id && limit.denom == denom)
+        .and_then(|limit| match direction {
+            Direction::Inbound => limit.inbound_limit,
+            Direction::Outbound => limit.outbound_limit,
+        })
+}
+
+// returns whether the flow recorded at `key` happened before the window ending at `height`.
+fn is_expired(key: &str, height: u64, window_blocks: u64) -> Result<bool> {
+    let flow_height: u64 = key
+        .rsplit('/')
+        .next()
+        .expect("key is well-formed")
+        .parse()
+        .context("couldn't parse height of ICS20 flow")?;
+
+    Ok(flow_height.saturating_add(window_blocks) <= height)
+}
+
+#[async_trait]
+pub trait StateReadExt: StateRead {
+    /// The total value of the denom transferred over the channel in the given direction, within
+    /// the rate limit window ending at the current block.
+    async fn ics20_flow(
+        &self,
+        direction: Direction,
+        channel_id: &ChannelId,
+        denom: &DenomMetadata,
+    ) -> Result<Amount> {
+        let window_blocks = self
+            .get_chain_params()
+            .await?
+            .ics20_rate_limit_window_blocks;
+        let height = self.get_block_height().await?;
+
+        let flows: Vec<(String, Amount)> = self
+            .prefix(&state_key::ics20_flow_prefix(
+                direction.as_str(),
+                channel_id,
+                &denom.id(),
+            ))
+            .try_collect()
+            .await?;
+
+        let mut total = Amount::zero();
+        for (key, amount) in flows {
+            if !is_expired(&key, height, window_blocks)? {
+                total = total + amount;
+            }
+        }
+
+        Ok(total)
+    }
+
+    /// Check that a transfer in the given direction is allowed: transfers in that direction must
+    /// be enabled, and the transfer must not take the flow of its denom over its channel past the
+    /// rate limit, if there is one.
+    async fn ics20_flow_check(
+        &self,
+        direction: Direction,
+        channel_id: &ChannelId,
+        denom: &DenomMetadata,
+        amount: Amount,
+    ) -> Result<()> {
+        let params = self.get_chain_params().await?;
+
+        let enabled = match direction {
+            Direction::Inbound => params.inbound_ics20_transfers_enabled,
+            Direction::Outbound => params.outbound_ics20_transfers_enabled,
+        };
+        if !enabled {
+            anyhow::bail!("{direction} ICS20 transfers are disabled");
+        }
+
+        let Some(limit) = rate_limit(&params, direction, channel_id, denom) else {
+            return Ok(());
+        };
+
+        let flow = self.ics20_flow(direction, channel_id, denom).await?;
+        if flow + amount > limit {
+            anyhow::bail!(
+                "{direction} ICS20 transfer of {amount} {denom} over {channel_id} exceeds rate limit: \
+                 {flow} of {limit} already transferred within the window"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub trait StateWriteExt: StateWrite {
+    /// Record a transfer in the given direction against the rate limit for its denom and channel.
+    ///
+    /// Flows are only recorded for rate limited denoms and channels, so a newly added limit only
+    /// counts transfers made after it was added. Returns the height the flow was recorded at, if
+    /// it was recorded.
+    async fn record_ics20_flow(
+        &mut self,
+        direction: Direction,
+        channel_id: &ChannelId,
+        denom: &DenomMetadata,
+        amount: Amount,
+    ) -> Result<Option<u64>> {
+        let params = self.get_chain_params().await?;
+        if rate_limit(&params, direction, channel_id, denom).is_none() {
+            return Ok(None);
+        }
+
+        let height = self.get_block_height().await?;
+        let prefix = state_key::ics20_flow_prefix(direction.as_str(), channel_id, &denom.id());
+        let flows: Vec<(String, Amount)> = self.prefix(&prefix).try_collect().await?;
+
+        // prune the flows which have left the window, so that the recorded flows stay bounded by
+        // the length of the window.
+        for (key, _) in flows {
+            if is_expired(&key, height, params.ics20_rate_limit_window_blocks)? {
+                self.delete(key);
+            }
+        }
+
+        let key = state_key::ics20_flow(direction.as_str(), channel_id, &denom.id(), height);
+        let flow: Amount = self.get(&key).await?.unwrap_or_else(Amount::zero);
+        self.put(key, flow + amount);
+
+        Ok(Some(height))
+    }
+
+    /// Record a transfer in the given direction, carried in the packet with the given sequence
+    /// number on our end of the channel, against the rate limit for its denom and channel.
+    ///
+    /// The packet is remembered until it is resolved, so that if the transfer is refunded, its
+    /// flow can be released with [`release_packet_ics20_flow`](Self::release_packet_ics20_flow).
+    async fn record_packet_ics20_flow(
+        &mut self,
+        direction: Direction,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: u64,
+        denom: &DenomMetadata,
+        amount: Amount,
+    ) -> Result<()> {
+        if let Some(height) = self
+            .record_ics20_flow(direction, channel_id, denom, amount)
+            .await?
+        {
+            self.put_proto(
+                state_key::ics20_packet_flow_height(
+                    direction.as_str(),
+                    port_id,
+                    channel_id,
+                    sequence,
+                ),
+                height,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Release the flow of a refunded transfer, so that it no longer counts against the rate
+    /// limit for its denom and channel.
+    async fn release_packet_ics20_flow(
+        &mut self,
+        direction: Direction,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: u64,
+        denom: &DenomMetadata,
+        amount: Amount,
+    ) -> Result<()> {
+        let height_key =
+            state_key::ics20_packet_flow_height(direction.as_str(), port_id, channel_id, sequence);
+        let Some(height) = self.get_proto::<u64>(&height_key).await? else {
+            return Ok(());
+        };
+        self.delete(height_key);
+
+        // if the flow has already left the window and been pruned, there is nothing to release.
+        let key = state_key::ics20_flow(direction.as_str(), channel_id, &denom.id(), height);
+        let Some(flow) = self.get::<Amount>(&key).await? else {
+            return Ok(());
+        };
+        match flow.checked_sub(&amount) {
+            Some(remaining) if remaining > Amount::zero() => self.put(key, remaining),
+            _ => self.delete(key),
+        }
+
+        Ok(())
+    }
+
+    /// Forget the packet of a transfer which completed successfully: its flow will never be
+    /// released.
+    fn clear_packet_ics20_flow(
+        &mut self,
+        direction: Direction,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: u64,
+    ) {
+        self.delete(state_key::ics20_packet_flow_height(
+            direction.as_str(),
+            port_id,
+            channel_id,
+            sequence,
+        ));
+    }
+}
+
+impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use penumbra_chain::{component::StateWriteExt as _, params::Ics20RateLimit};
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn flows_are_limited_within_the_window() -> anyhow::Result<()> {
+        let channel_id = ChannelId::new(0);
+        let denom: DenomMetadata = "transfer/channel-0/uatom".try_into()?;
+
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_chain_params(ChainParameters {
+            ics20_rate_limit_window_blocks: 10,
+            ics20_rate_limits: vec![Ics20RateLimit {
+                channel_id: channel_id.to_string(),
+                denom: denom.to_string(),
+                inbound_limit: Some(100u64.into()),
+                outbound_limit: None,
+            }],
+            ..Default::default()
+        });
+
+        state_tx.put_block_height(1);
+        state_tx
+            .ics20_flow_check(Direction::Inbound, &channel_id, &denom, 60u64.into())
+            .await?;
+        state_tx
+            .record_ics20_flow(Direction::Inbound, &channel_id, &denom, 60u64.into())
+            .await?;
+
+        // the second transfer would take the inbound flow past the limit, but outbound transfers
+        // aren't limited.
+        state_tx.put_block_height(5);
+        assert!(state_tx
+            .ics20_flow_check(Direction::Inbound, &channel_id, &denom, 60u64.into())
+            .await
+            .is_err());
+        state_tx
+            .ics20_flow_check(Direction::Outbound, &channel_id, &denom, 1_000u64.into())
+            .await?;
+
+        // once the first transfer leaves the window, there is room again.
+        state_tx.put_block_height(11);
+        assert_eq!(
+            state_tx
+                .ics20_flow(Direction::Inbound, &channel_id, &denom)
+                .await?,
+            Amount::zero()
+        );
+        state_tx
+            .ics20_flow_check(Direction::Inbound, &channel_id, &denom, 60u64.into())
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refunded_outbound_flows_are_released() -> anyhow::Result<()> {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+        let denom: DenomMetadata = "upenumbra".try_into()?;
+
+        let mut state = Arc::new(StateDelta::new(()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_chain_params(ChainParameters {
+            ics20_rate_limit_window_blocks: 10,
+            ics20_rate_limits: vec![Ics20RateLimit {
+                channel_id: channel_id.to_string(),
+                denom: denom.to_string(),
+                inbound_limit: None,
+                outbound_limit: Some(100u64.into()),
+            }],
+            ..Default::default()
+        });
+
+        state_tx.put_block_height(1);
+        state_tx
+            .record_packet_ics20_flow(
+                Direction::Outbound,
+                &port_id,
+                &channel_id,
+                1,
+                &denom,
+                60u64.into(),
+            )
+            .await?;
+        state_tx.put_block_height(2);
+        state_tx
+            .record_packet_ics20_flow(
+                Direction::Outbound,
+                &port_id,
+                &channel_id,
+                2,
+                &denom,
+                30u64.into(),
+            )
+            .await?;
+        assert!(state_tx
+            .ics20_flow_check(Direction::Outbound, &channel_id, &denom, 20u64.into())
+            .await
+            .is_err());
+
+        // the first transfer is refunded, which makes room for more.
+        state_tx
+            .release_packet_ics20_flow(
+                Direction::Outbound,
+                &port_id,
+                &channel_id,
+                1,
+                &denom,
+                60u64.into(),
+            )
+            .await?;
+        assert_eq!(
+            state_tx
+                .ics20_flow(Direction::Outbound, &channel_id, &denom)
+                .await?,
+            30u64.into()
+        );
+        state_tx
+            .ics20_flow_check(Direction::Outbound, &channel_id, &denom, 20u64.into())
+            .await?;
+
+        // a flow is only released once, and not at all once its transfer is acknowledged.
+        state_tx
+            .release_packet_ics20_flow(
+                Direction::Outbound,
+                &port_id,
+                &channel_id,
+                1,
+                &denom,
+                60u64.into(),
+            )
+            .await?;
+        state_tx.clear_packet_ics20_flow(Direction::Outbound, &port_id, &channel_id, 2);
+        state_tx
+            .release_packet_ics20_flow(
+                Direction::Outbound,
+                &port_id,
+                &channel_id,
+                2,
+                &denom,
+                30u64.into(),
+            )
+            .await?;
+        assert_eq!(
+            state_tx
+                .ics20_flow(Direction::Outbound, &channel_id, &denom)
+                .await?,
+            30u64.into()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_windows_do_not_overflow() -> anyhow::Result<()> {
+        let denom: DenomMetadata = "upenumbra".try_into()?;
+        let key = state_key::ics20_flow("inbound", &ChannelId::new(0), &denom.id(), 5);
+        assert!(!is_expired(&key, 10, u64::MAX)?);
+        assert!(is_expired(&key, 10, 5)?);
+
+        Ok(())
+    }
+}
@@ -114,3 +114,29 @@ pub fn packet_commitment_by_port(
 pub fn ics20_value_balance(channel_id: &ChannelId, asset_id: &asset::Id) -> String {
     format!("ics20-value-balance/{channel_id}/{asset_id}")
 }
+
+pub fn ics20_forwarded_packet(port_id: &PortId, channel_id: &ChannelId, sequence: u64) -> String {
+    format!("ics20-forwarded-packet/{port_id}/{channel_id}/{sequence}")
+}
+
+pub fn ics20_flow_prefix(direction: &str, channel_id: &ChannelId, asset_id: &asset::Id) -> String {
+    format!("ics20-flow/{direction}/{channel_id}/{asset_id}/")
+}
+
+pub fn ics20_flow(
+    direction: &str,
+    channel_id: &ChannelId,
+    asset_id: &asset::Id,
+    height: u64,
+) -> String {
+    format!("ics20-flow/{direction}/{channel_id}/{asset_id}/{height:020}")
+}
+
+pub fn ics20_packet_flow_height(
+    direction: &str,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: u64,
+) -> String {
+    format!("ics20-packet-flow-height/{direction}/{port_id}/{channel_id}/{sequence}")
+}
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -10,32 +10,44 @@ use ibc_types2::{
             MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
             MsgTimeout,
         },
-        ChannelId, PortId, Version,
+        ChannelId, Packet, PortId, Version,
     },
+    core::client::Height,
     transfer::acknowledgement::TokenTransferAcknowledgement,
 };
 use penumbra_asset::{asset, asset::DenomMetadata, Value};
+use penumbra_chain::component::StateReadExt as _;
 use penumbra_crypto::Address;
+use penumbra_dex::component::StateWriteExt as _;
 use penumbra_num::Amount;
 use penumbra_proto::{
     core::ibc::v1alpha1::FungibleTokenPacketData, StateReadProto, StateWriteProto,
 };
-use penumbra_shielded_pool::component::NoteManager;
-use penumbra_storage::{StateRead, StateWrite};
+use penumbra_shielded_pool::component::{NoteManager, SupplyWrite};
+use penumbra_storage::{StateDelta, StateRead, StateWrite};
 use prost::Message;
 
 use crate::{
     component::{
         app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute},
+        channel::StateReadExt as _,
+        client::StateReadExt as _,
+        connection::StateReadExt as _,
         packet::{
             IBCPacket, SendPacketRead as _, SendPacketWrite as _, Unchecked,
             WriteAcknowledgement as _,
         },
+        rate_limit::{Direction, StateReadExt as _, StateWriteExt as _},
         state_key,
     },
-    Ics20Withdrawal,
+    is_success_acknowledgement, ForwardMetadata, Ics20Withdrawal, SwapMetadata,
 };
 
+/// How many blocks of the next chain a forwarded transfer has to arrive in, before it times out.
+const FORWARD_TIMEOUT_BLOCKS: u64 = 1_000;
+/// How long a forwarded transfer has to arrive on the next chain, before it times out.
+const FORWARD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
 // returns a bool indicating if the provided denom was issued locally or if it was bridged in.
 // this logic is a bit tricky, and adapted from https://github.com/cosmos/ibc/tree/main/spec/app/ics-020-fungible-token-transfer (sendFungibleTokens).
 //
@@ -57,6 +69,14 @@ pub struct Ics20Transfer {}
 #[async_trait]
 pub trait Ics20TransferReadExt: StateRead {
     async fn withdrawal_check(&self, withdrawal: &Ics20Withdrawal) -> Result<()> {
+        self.ics20_flow_check(
+            Direction::Outbound,
+            &withdrawal.source_channel,
+            &withdrawal.denom,
+            withdrawal.amount,
+        )
+        .await?;
+
         // create packet
         let packet: IBCPacket<Unchecked> = withdrawal.clone().into();
 
@@ -71,10 +91,32 @@ impl<T: StateRead + ?Sized> Ics20TransferReadExt for T {}
 
 #[async_trait]
 pub trait Ics20TransferWriteExt: StateWrite {
-    async fn withdrawal_execute(&mut self, withdrawal: &Ics20Withdrawal) {
+    async fn withdrawal_execute(&mut self, withdrawal: &Ics20Withdrawal) -> Result<()> {
         // create packet, assume it's already checked since the component caller contract calls `check` before `execute`
         let checked_packet = IBCPacket::<Unchecked>::from(withdrawal.clone()).assume_checked();
 
+        // other withdrawals earlier in the block may have used up the rate limit since the
+        // withdrawal was checked, so check the flow again against the current state.
+        self.ics20_flow_check(
+            Direction::Outbound,
+            &withdrawal.source_channel,
+            &withdrawal.denom,
+            withdrawal.amount,
+        )
+        .await?;
+        let sequence = self
+            .get_send_sequence(&withdrawal.source_channel, &withdrawal.source_port)
+            .await?;
+        self.record_packet_ics20_flow(
+            Direction::Outbound,
+            &withdrawal.source_port,
+            &withdrawal.source_channel,
+            sequence,
+            &withdrawal.denom,
+            withdrawal.amount,
+        )
+        .await?;
+
         if is_source(
             &withdrawal.source_port,
             &withdrawal.source_channel,
@@ -103,6 +145,8 @@ pub trait Ics20TransferWriteExt: StateWrite {
         }
 
         self.send_packet_execute(checked_packet).await;
+
+        Ok(())
     }
 }
 
@@ -187,219 +231,546 @@ impl AppHandlerCheck for Ics20Transfer {
     }
 
     async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
-        let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
-        let denom: asset::DenomMetadata = packet_data.denom.as_str().try_into()?;
-
-        if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom) {
-            // check if we have enough balance to refund tokens to sender
-            let value_balance: Amount = state
-                .get(&state_key::ics20_value_balance(
-                    &msg.packet.chan_on_a,
-                    &denom.id(),
-                ))
-                .await?
-                .unwrap_or_else(Amount::zero);
-
-            let amount_penumbra: Amount = packet_data.amount.try_into()?;
-            if value_balance < amount_penumbra {
-                return Err(anyhow::anyhow!(
-                    "insufficient balance to refund tokens to sender"
-                ));
-            }
-        }
-
-        Ok(())
+        refund_check(state, &msg.packet).await
     }
 
     async fn acknowledge_packet_check<S: StateRead>(
-        _state: S,
-        _msg: &MsgAcknowledgement,
+        state: S,
+        msg: &MsgAcknowledgement,
     ) -> Result<()> {
+        let ack: Vec<u8> = msg.acknowledgement.clone().into();
+        if !is_success_acknowledgement(&ack) {
+            refund_check(state, &msg.packet).await?;
+        }
+
         Ok(())
     }
 }
 
+/// What became of a transfer we received.
+enum Received {
+    /// The transfer was delivered to an address on Penumbra.
+    Delivered,
+    /// The transfer was forwarded on to another chain, as instructed by its memo. It will be
+    /// acknowledged once the forwarded transfer is.
+    Forwarded,
+}
+
 // the main entry point for ICS20 transfer packet handling
 async fn recv_transfer_packet_inner<S: StateWrite>(
     mut state: S,
-    msg: &MsgRecvPacket,
-) -> Result<()> {
-    // parse if we are source or dest, and mint or burn accordingly
-    //
-    // see this part of the spec for this logic:
-    //
-    // https://github.com/cosmos/ibc/tree/main/spec/app/ics-020-fungible-token-transfer (onRecvPacket)
-    //
-    let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
-    let denom: asset::DenomMetadata = packet_data
-        .denom
-        .as_str()
-        .try_into()
-        .context("couldnt decode denom in ICS20 transfer")?;
-    let receiver_amount: Amount = packet_data
+    packet: &Packet,
+) -> Result<Received> {
+    let packet_data = FungibleTokenPacketData::decode(packet.data.as_slice())?;
+    let amount: Amount = packet_data
         .amount
+        .clone()
         .try_into()
         .context("couldnt decode amount in ICS20 transfer")?;
+
+    if let Some(forward) = ForwardMetadata::from_memo(&packet_data.memo)? {
+        forward_transfer(&mut state, packet, &packet_data, amount, forward).await?;
+        return Ok(Received::Forwarded);
+    }
+
     let receiver_address = Address::from_str(&packet_data.receiver)?;
 
-    // NOTE: here we assume we are chain A.
+    if let Some(swap) = SwapMetadata::from_memo(&packet_data.memo)? {
+        let denom = receive_tokens(&mut state, packet, &packet_data, amount, false).await?;
+
+        // the tokens enter Penumbra now, even though nobody holds them until the swap executes:
+        // its outputs are paid out of the DEX's reserves, so they don't change the supply.
+        state
+            .update_token_supply(&denom.id(), amount.value() as i128)
+            .await?;
+
+        // rather than minting the tokens to the receiver, swap them in this block's batch, and
+        // pay the outputs of the swap to the receiver instead.
+        state
+            .swap_deposit(
+                denom.value(amount),
+                swap.output.id(),
+                receiver_address,
+                penumbra_chain::NoteSource::Ics20Transfer,
+            )
+            .context("failed to swap ICS20 transfer")?;
+
+        return Ok(Received::Delivered);
+    }
+
+    let denom = receive_tokens(&mut state, packet, &packet_data, amount, false).await?;
+
+    state
+        .mint_note(
+            denom.value(amount),
+            &receiver_address,
+            penumbra_chain::NoteSource::Ics20Transfer,
+        )
+        .await
+        .context("failed to mint notes in ibc transfer")?;
+
+    Ok(Received::Delivered)
+}
+
+// returns the denomination that the tokens in an inbound transfer have on Penumbra, and whether we
+// are the source chain for them.
+//
+// see this part of the spec for this logic:
+//
+// https://github.com/cosmos/ibc/tree/main/spec/app/ics-020-fungible-token-transfer (onRecvPacket)
+fn received_denom(
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+) -> Result<(DenomMetadata, bool)> {
+    let denom: asset::DenomMetadata = packet_data
+        .denom
+        .as_str()
+        .try_into()
+        .context("couldnt decode denom in ICS20 transfer")?;
 
-    // 2. check if we are the source chain for the denom.
-    if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom) {
-        // mint tokens to receiver in the amount of packet_data.amount in the denom of denom (with
-        // the source removed, since we're the source)
+    if !is_source(&packet.port_on_a, &packet.chan_on_a, &denom) {
+        // the counterparty prefixed the denom with its end of the channel when it received the
+        // tokens from us, so we are the source: remove the prefix to recover our denom.
         let prefix = format!(
             "{source_port}/{source_chan}/",
-            source_port = msg.packet.port_on_a,
-            source_chan = msg.packet.chan_on_a
+            source_port = packet.port_on_a,
+            source_chan = packet.chan_on_a
         );
 
         let unprefixed_denom: asset::DenomMetadata = packet_data
             .denom
-            .replace(&prefix, "")
+            .replacen(&prefix, "", 1)
             .as_str()
             .try_into()
             .context("couldnt decode denom in ICS20 transfer")?;
 
-        let value: Value = Value {
-            amount: receiver_amount,
-            asset_id: unprefixed_denom.id(),
-        };
+        Ok((unprefixed_denom, true))
+    } else {
+        // create new denom:
+        //
+        // prefix = "{packet.destPort}/{packet.destChannel}/"
+        // prefixedDenomination = prefix + data.denom
+        let prefixed_denomination = format!(
+            "{}/{}/{}",
+            packet.port_on_b, packet.chan_on_b, packet_data.denom
+        );
 
-        // assume AppHandlerCheck has already been called, and we have enough balance to mint tokens to receiver
-        // check if we have enough balance to unescrow tokens to receiver
-        let value_balance: Amount = state
-            .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
-                &unprefixed_denom.id(),
-            ))
-            .await?
-            .unwrap_or_else(Amount::zero);
+        let prefixed_denom: asset::DenomMetadata = prefixed_denomination
+            .as_str()
+            .try_into()
+            .context("couldnt decode denom in ICS20 transfer")?;
 
-        if value_balance < receiver_amount {
-            // error text here is from the ics20 spec
-            return Err(anyhow::anyhow!("transfer coins failed"));
-        }
+        Ok((prefixed_denom, false))
+    }
+}
+
+// accounts for the tokens in an inbound transfer, returning their denomination on Penumbra.
+//
+// a forwarded transfer may still fail, so its packet is remembered along with its flow, so that
+// the flow can be released by `unreceive_tokens` if it does.
+async fn receive_tokens<S: StateWrite>(
+    state: &mut S,
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+    amount: Amount,
+    forwarded: bool,
+) -> Result<DenomMetadata> {
+    let (denom, we_are_source) = received_denom(packet, packet_data)?;
 
+    state
+        .ics20_flow_check(Direction::Inbound, &packet.chan_on_b, &denom, amount)
+        .await?;
+    if forwarded {
         state
-            .mint_note(
-                value,
-                &receiver_address,
-                penumbra_chain::NoteSource::Ics20Transfer, // TODO
+            .record_packet_ics20_flow(
+                Direction::Inbound,
+                &packet.port_on_b,
+                &packet.chan_on_b,
+                packet.sequence.into(),
+                &denom,
+                amount,
             )
-            .await
-            .unwrap();
+            .await?;
+    } else {
+        state
+            .record_ics20_flow(Direction::Inbound, &packet.chan_on_b, &denom, amount)
+            .await?;
+    }
 
-        // update the value balance
+    if we_are_source {
+        // we escrowed these tokens on our end of the channel when we sent them, so unescrow them.
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
-                &unprefixed_denom.id(),
+                &packet.chan_on_b,
+                &denom.id(),
             ))
             .await?
             .unwrap_or_else(Amount::zero);
 
-        // note: this arithmetic was checked above, but we do it again anyway.
-        let new_value_balance = value_balance.checked_sub(&receiver_amount).unwrap();
+        // error text here is from the ics20 spec
+        let new_value_balance = value_balance
+            .checked_sub(&amount)
+            .ok_or_else(|| anyhow::anyhow!("transfer coins failed"))?;
         state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_a, &denom.id()),
+            state_key::ics20_value_balance(&packet.chan_on_b, &denom.id()),
             new_value_balance,
         );
     } else {
-        // create new denom:
-        //
-        // prefix = "{packet.destPort}/{packet.destChannel}/"
-        // prefixedDenomination = prefix + data.denom
-        //
-        // then mint that denom to packet_data.receiver in packet_data.amount
-        // no value balance to update here since this is an exogenous denom
-        let prefixed_denomination = format!(
-            "{}/{}/{}",
-            msg.packet.port_on_b, msg.packet.chan_on_b, packet_data.denom
+        // no value balance to update here since this is an exogenous denom: the voucher denom is
+        // minted by whoever ends up holding the tokens.
+    }
+
+    Ok(denom)
+}
+
+// undoes `receive_tokens`, for a transfer we received but are about to fail.
+async fn unreceive_tokens<S: StateWrite>(
+    state: &mut S,
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+    amount: Amount,
+) -> Result<()> {
+    let (denom, we_are_source) = received_denom(packet, packet_data)?;
+
+    // the transfer is being failed, so it no longer counts against the inbound rate limit.
+    state
+        .release_packet_ics20_flow(
+            Direction::Inbound,
+            &packet.port_on_b,
+            &packet.chan_on_b,
+            packet.sequence.into(),
+            &denom,
+            amount,
+        )
+        .await?;
+
+    if we_are_source {
+        // put the tokens back into escrow on our end of the channel.
+        let value_balance: Amount = state
+            .get(&state_key::ics20_value_balance(
+                &packet.chan_on_b,
+                &denom.id(),
+            ))
+            .await?
+            .unwrap_or_else(Amount::zero);
+
+        state.put(
+            state_key::ics20_value_balance(&packet.chan_on_b, &denom.id()),
+            value_balance + amount,
         );
+    }
 
-        let denom: asset::DenomMetadata = prefixed_denomination.as_str().try_into().unwrap();
-        let value = Value {
-            amount: receiver_amount,
-            asset_id: denom.id(),
-        };
+    Ok(())
+}
 
-        state
-            .mint_note(
-                value,
-                &receiver_address,
-                penumbra_chain::NoteSource::Ics20Transfer,
-            )
-            .await
-            .context("failed to mint notes in ibc transfer")?;
+// forwards an inbound transfer on to another chain, rather than delivering it on Penumbra.
+//
+// the tokens are received as usual, and then sent on in a new transfer over the channel given in
+// the memo. we don't acknowledge the inbound transfer until the forwarded transfer is
+// acknowledged or times out, so that if the forwarded transfer fails, we can fail the inbound
+// transfer too, and the original sender is refunded by their own chain.
+async fn forward_transfer<S: StateWrite>(
+    state: &mut S,
+    packet: &Packet,
+    packet_data: &FungibleTokenPacketData,
+    amount: Amount,
+    forward: ForwardMetadata,
+) -> Result<()> {
+    if forward.port != PortId::transfer() {
+        anyhow::bail!("transfers can only be forwarded over the transfer port");
     }
 
+    let denom = receive_tokens(state, packet, packet_data, amount, true).await?;
+
+    // the forwarded transfer leaves Penumbra, so it counts against the outbound rate limit of the
+    // channel it is forwarded over, as well as the inbound rate limit of the channel it came in on.
+    state
+        .ics20_flow_check(Direction::Outbound, &forward.channel, &denom, amount)
+        .await?;
+
+    let forwarded_data = FungibleTokenPacketData {
+        denom: denom.to_string(),
+        amount: amount.to_string(),
+        // the receiver on Penumbra is the intermediate address on the route, so it is the sender
+        // of the forwarded transfer.
+        sender: packet_data.receiver.clone(),
+        receiver: forward.receiver.clone(),
+        memo: forward.next.clone().unwrap_or_default(),
+    };
+
+    let (timeout_height, timeout_timestamp) = forward_timeout(state, &forward).await?;
+    let forwarded_packet = state
+        .send_packet_check(IBCPacket::new(
+            forward.port.clone(),
+            forward.channel.clone(),
+            timeout_height,
+            timeout_timestamp,
+            forwarded_data.encode_to_vec(),
+        ))
+        .await
+        .context("couldn't forward ICS20 transfer")?;
+
+    if is_source(&forward.port, &forward.channel, &denom) {
+        // we are the source. add the value balance to the escrow channel.
+        let value_balance: Amount = state
+            .get(&state_key::ics20_value_balance(
+                &forward.channel,
+                &denom.id(),
+            ))
+            .await?
+            .unwrap_or_else(Amount::zero);
+
+        state.put(
+            state_key::ics20_value_balance(&forward.channel, &denom.id()),
+            value_balance + amount,
+        );
+    } else {
+        // receiver is the source. the vouchers we would burn were never minted, so there is
+        // nothing to do here.
+    }
+
+    // remember which transfer the forwarded transfer came from, so we can acknowledge it later.
+    let sequence = state
+        .get_send_sequence(&forward.channel, &forward.port)
+        .await?;
+    state
+        .record_packet_ics20_flow(
+            Direction::Outbound,
+            &forward.port,
+            &forward.channel,
+            sequence,
+            &denom,
+            amount,
+        )
+        .await?;
+    state.send_packet_execute(forwarded_packet).await;
+    state.put(
+        state_key::ics20_forwarded_packet(&forward.port, &forward.channel, sequence),
+        packet.clone(),
+    );
+
+    tracing::debug!(
+        src_channel = %packet.chan_on_b,
+        dst_channel = %forward.channel,
+        sequence,
+        "forwarded ICS20 transfer"
+    );
+
     Ok(())
 }
 
-// see: https://github.com/cosmos/ibc/blob/8326e26e7e1188b95c32481ff00348a705b23700/spec/app/ics-020-fungible-token-transfer/README.md?plain=1#L297
-async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
-    let packet_data = FungibleTokenPacketData::decode(msg.packet.data.as_slice())?;
-    let denom: asset::DenomMetadata = packet_data // CRITICAL: verify that this denom is validated in upstream timeout handling
-        .denom
-        .as_str()
-        .try_into()
-        .context("couldn't decode denom in ics20 transfer timeout")?;
-    // receiver was source chain, mint vouchers back to sender
-    let amount: Amount = packet_data
-        .amount
-        .try_into()
-        .context("couldn't decode amount in ics20 transfer timeout")?;
+// we don't know the height of the chain a transfer is forwarded to, so the forwarded transfer
+// times out relative to the latest height and time we have verified for it.
+async fn forward_timeout<S: StateRead>(
+    state: &S,
+    forward: &ForwardMetadata,
+) -> Result<(Height, u64)> {
+    let channel = state
+        .get_channel(&forward.channel, &forward.port)
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "channel {} on port {} does not exist",
+                forward.channel,
+                forward.port
+            )
+        })?;
+    let connection = state
+        .get_connection(&channel.connection_hops[0])
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!("connection {} does not exist", channel.connection_hops[0])
+        })?;
+    let client_state = state.get_client_state(&connection.client_id).await?;
+
+    let latest_height = client_state.latest_height();
+    let timeout_height = Height::new(
+        latest_height.revision_number(),
+        latest_height.revision_height() + FORWARD_TIMEOUT_BLOCKS,
+    )?;
+
+    let now = state.get_block_timestamp().await?;
+    let timeout_timestamp =
+        u64::try_from(now.unix_timestamp_nanos())? + FORWARD_TIMEOUT.as_nanos() as u64;
+
+    Ok((timeout_height, timeout_timestamp))
+}
+
+// returns the transfer that an outbound packet forwarded, if it was forwarding one.
+async fn forwarded_packet<S: StateRead>(state: &S, packet: &Packet) -> Result<Option<Packet>> {
+    state
+        .get(&state_key::ics20_forwarded_packet(
+            &packet.port_on_a,
+            &packet.chan_on_a,
+            packet.sequence.into(),
+        ))
+        .await
+}
+
+// acknowledges the transfer that a forwarded transfer came from, now that the forwarded transfer
+// has succeeded or failed.
+async fn resolve_forwarded_packet<S: StateWrite>(
+    state: &mut S,
+    packet: &Packet,
+    inbound_packet: &Packet,
+    success: bool,
+) -> Result<()> {
+    state.delete(state_key::ics20_forwarded_packet(
+        &packet.port_on_a,
+        &packet.chan_on_a,
+        packet.sequence.into(),
+    ));
+
+    let ack: Vec<u8> = if success {
+        // the inbound transfer succeeded along with the forwarded one, so its flow stays.
+        state.clear_packet_ics20_flow(
+            Direction::Inbound,
+            &inbound_packet.port_on_b,
+            &inbound_packet.chan_on_b,
+            inbound_packet.sequence.into(),
+        );
+        TokenTransferAcknowledgement::success().into()
+    } else {
+        // the forwarded transfer failed, so fail the inbound transfer too. first we return the
+        // tokens to how they were before we received them, so that the original sender's chain
+        // can refund them.
+        unescrow_refunded_tokens(state, packet).await?;
 
-    let receiver = Address::from_str(&packet_data.receiver)
-        .context("couldn't decode receiver address in ics20 timeout")?;
+        let inbound_data = FungibleTokenPacketData::decode(inbound_packet.data.as_slice())?;
+        let amount: Amount = inbound_data
+            .amount
+            .clone()
+            .try_into()
+            .context("couldn't decode amount in forwarded ics20 transfer")?;
+        unreceive_tokens(state, inbound_packet, &inbound_data, amount).await?;
 
-    let value: Value = Value {
-        amount,
-        asset_id: denom.id(),
+        TokenTransferAcknowledgement::Error("forwarded transfer failed".to_string()).into()
     };
 
-    if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom) {
-        // sender was source chain, unescrow tokens back to sender
+    state
+        .write_acknowledgement(inbound_packet, &ack)
+        .await
+        .context("failed to acknowledge forwarded ics20 transfer")
+}
+
+// checks that we can refund the tokens in a failed outbound transfer.
+async fn refund_check<S: StateRead>(state: S, packet: &Packet) -> Result<()> {
+    let packet_data = FungibleTokenPacketData::decode(packet.data.as_slice())?;
+    let denom: asset::DenomMetadata = packet_data.denom.as_str().try_into()?;
+
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &denom) {
+        // check if we have enough balance to refund tokens to sender
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
+                &packet.chan_on_a,
                 &denom.id(),
             ))
             .await?
             .unwrap_or_else(Amount::zero);
 
-        if value_balance < amount {
+        let amount_penumbra: Amount = packet_data.amount.try_into()?;
+        if value_balance < amount_penumbra {
             return Err(anyhow::anyhow!(
-                "couldn't return coins in timeout: not enough value balance"
+                "insufficient balance to refund tokens to sender"
             ));
         }
+    }
 
-        state
-            .mint_note(value, &receiver, penumbra_chain::NoteSource::Ics20Transfer)
-            .await
-            .context("couldn't mint note in timeout_packet_inner")?;
+    Ok(())
+}
 
-        // update the value balance
+// takes the tokens in a failed outbound transfer back out of escrow, if we escrowed them,
+// returning their value.
+async fn unescrow_refunded_tokens<S: StateWrite>(state: &mut S, packet: &Packet) -> Result<Value> {
+    let packet_data = FungibleTokenPacketData::decode(packet.data.as_slice())?;
+    let denom: asset::DenomMetadata = packet_data // CRITICAL: verify that this denom is validated in upstream timeout handling
+        .denom
+        .as_str()
+        .try_into()
+        .context("couldn't decode denom in ics20 transfer refund")?;
+    let amount: Amount = packet_data
+        .amount
+        .try_into()
+        .context("couldn't decode amount in ics20 transfer refund")?;
+
+    // the transfer never left, so it no longer counts against the outbound rate limit.
+    state
+        .release_packet_ics20_flow(
+            Direction::Outbound,
+            &packet.port_on_a,
+            &packet.chan_on_a,
+            packet.sequence.into(),
+            &denom,
+            amount,
+        )
+        .await?;
+
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &denom) {
+        // sender was source chain, unescrow tokens
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
+                &packet.chan_on_a,
                 &denom.id(),
             ))
             .await?
             .unwrap_or_else(Amount::zero);
 
-        // note: this arithmetic was checked above, but we do it again anyway.
-        let new_value_balance = value_balance.checked_sub(&amount).unwrap();
+        let new_value_balance = value_balance.checked_sub(&amount).ok_or_else(|| {
+            anyhow::anyhow!("couldn't return coins in refund: not enough value balance")
+        })?;
         state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_a, &denom.id()),
+            state_key::ics20_value_balance(&packet.chan_on_a, &denom.id()),
             new_value_balance,
         );
     } else {
-        state
-            .mint_note(value, &receiver, penumbra_chain::NoteSource::Ics20Transfer) // NOTE: should this be Ics20TransferTimeout?
-            .await
-            .context("failed to mint return voucher in ics20 transfer timeout")?;
+        // receiver was source chain, so the vouchers were burned when they were sent: they are
+        // minted back below.
+    }
+
+    Ok(denom.value(amount))
+}
+
+// refunds the tokens in a failed outbound transfer: either one which timed out, or one which the
+// counterparty acknowledged with an error.
+//
+// see: https://github.com/cosmos/ibc/blob/8326e26e7e1188b95c32481ff00348a705b23700/spec/app/ics-020-fungible-token-transfer/README.md?plain=1#L297
+async fn refund_packet_inner<S: StateWrite>(mut state: S, packet: &Packet) -> Result<()> {
+    if let Some(inbound_packet) = forwarded_packet(&state, packet).await? {
+        return resolve_forwarded_packet(&mut state, packet, &inbound_packet, false).await;
+    }
+
+    let packet_data = FungibleTokenPacketData::decode(packet.data.as_slice())?;
+    let sender = Address::from_str(&packet_data.sender)
+        .context("couldn't decode sender address in ics20 refund")?;
+
+    let value = unescrow_refunded_tokens(&mut state, packet).await?;
+
+    state
+        .mint_note(value, &sender, penumbra_chain::NoteSource::Ics20Transfer) // NOTE: should this be Ics20TransferTimeout?
+        .await
+        .context("failed to mint refund in ics20 transfer")?;
+
+    Ok(())
+}
+
+async fn acknowledge_packet_inner<S: StateWrite>(
+    mut state: S,
+    packet: &Packet,
+    ack: &[u8],
+) -> Result<()> {
+    if !is_success_acknowledgement(ack) {
+        return refund_packet_inner(&mut state, packet).await;
+    }
+
+    state.clear_packet_ics20_flow(
+        Direction::Outbound,
+        &packet.port_on_a,
+        &packet.chan_on_a,
+        packet.sequence.into(),
+    );
+
+    if let Some(inbound_packet) = forwarded_packet(&state, packet).await? {
+        resolve_forwarded_packet(&mut state, packet, &inbound_packet, true).await?;
     }
 
     Ok(())
@@ -416,10 +787,23 @@ impl AppHandlerExecute for Ics20Transfer {
     async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
     async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) {
         // recv packet should never fail a transaction, but it should record a failure acknowledgement.
-        let ack: Vec<u8> = match recv_transfer_packet_inner(&mut state, msg).await {
-            Ok(_) => {
-                // record packet acknowledgement without error
-                TokenTransferAcknowledgement::success().into()
+        //
+        // we receive the transfer in its own state transaction, so that none of its effects are
+        // kept if it fails partway through.
+        let mut recv_state = StateDelta::new(&mut state);
+        let ack: Vec<u8> = match recv_transfer_packet_inner(&mut recv_state, &msg.packet).await {
+            Ok(received) => {
+                let (_, events) = recv_state.apply();
+                for event in events {
+                    state.record(event);
+                }
+
+                match received {
+                    // record packet acknowledgement without error
+                    Received::Delivered => TokenTransferAcknowledgement::success().into(),
+                    // the packet is acknowledged when the forwarded transfer is
+                    Received::Forwarded => return,
+                }
             }
             Err(e) => {
                 // record packet acknowledgement with error
@@ -436,13 +820,652 @@ impl AppHandlerExecute for Ics20Transfer {
 
     async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) {
         // timeouts should never fail
-        timeout_packet_inner(&mut state, msg)
+        refund_packet_inner(&mut state, &msg.packet)
             .await
             .context("critical: failed to timeout packet")
             .unwrap();
     }
 
-    async fn acknowledge_packet_execute<S: StateWrite>(_state: S, _msg: &MsgAcknowledgement) {}
+    async fn acknowledge_packet_execute<S: StateWrite>(mut state: S, msg: &MsgAcknowledgement) {
+        // acknowledgements should never fail
+        let ack: Vec<u8> = msg.acknowledgement.clone().into();
+        acknowledge_packet_inner(&mut state, &msg.packet, &ack)
+            .await
+            .context("critical: failed to acknowledge packet")
+            .unwrap();
+    }
 }
 
 impl AppHandler for Ics20Transfer {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use ibc_types2::{
+        core::{
+            channel::{channel::State as ChannelState, ChannelEnd, Counterparty},
+            client::{msgs::MsgCreateClient, ClientId},
+            connection::{
+                ConnectionEnd, ConnectionId, Counterparty as ConnectionCounterparty,
+                State as ConnectionState, Version as ConnectionVersion,
+            },
+        },
+        timestamp::Timestamp,
+        DomainType,
+    };
+    use penumbra_chain::{
+        component::{StateWriteExt as _, PENUMBRA_COMMITMENT_PREFIX},
+        params::{ChainParameters, Ics20RateLimit},
+        test_keys,
+    };
+    use penumbra_component::{ActionHandler, Component as _};
+    use penumbra_dex::{
+        component::{Dex, PositionManager as _, StateReadExt as _},
+        lp::{position::Position, Reserves},
+        DirectedTradingPair, TradingPair,
+    };
+    use penumbra_shielded_pool::component::SupplyRead as _;
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+    use rand_core::OsRng;
+    use tendermint::{v0_34::abci, Time};
+
+    use crate::{
+        component::{channel::StateWriteExt as _, connection::StateWriteExt as _},
+        IbcAction,
+    };
+
+    const AMOUNT: u64 = 300;
+
+    // sets up a client, a connection, and two open transfer channels (channel-0 and channel-1),
+    // both connected to channel-5 on the counterparty.
+    async fn setup() -> Result<Arc<StateDelta<()>>> {
+        let mut state = Arc::new(StateDelta::new(()));
+
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_chain_params(Default::default());
+        state_tx.put_block_height(1);
+        state_tx.put_epoch_by_height(
+            1,
+            penumbra_chain::Epoch {
+                index: 0,
+                start_height: 0,
+            },
+        );
+        state_tx.put_block_timestamp(Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?);
+        state_tx.apply();
+
+        let msg_create_client_raw =
+            base64::decode(include_str!("./test/create_client.msg").replace('\n', "")).unwrap();
+        let create_client_action = IbcAction::CreateClient(
+            MsgCreateClient::decode(msg_create_client_raw.as_slice()).unwrap(),
+        );
+        create_client_action.check_stateless(()).await?;
+        create_client_action.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        create_client_action.execute(&mut state_tx).await?;
+
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        state_tx
+            .put_new_connection(
+                &ConnectionId::new(0),
+                ConnectionEnd {
+                    state: ConnectionState::Open,
+                    client_id: client_id.clone(),
+                    counterparty: ConnectionCounterparty {
+                        client_id,
+                        connection_id: Some(ConnectionId::new(0)),
+                        prefix: PENUMBRA_COMMITMENT_PREFIX.clone(),
+                    },
+                    versions: vec![ConnectionVersion::default()],
+                    delay_period: Duration::ZERO,
+                },
+            )
+            .await?;
+
+        for channel_id in [ChannelId::new(0), ChannelId::new(1)] {
+            state_tx.put_channel(
+                &channel_id,
+                &PortId::transfer(),
+                ChannelEnd {
+                    state: ChannelState::Open,
+                    ordering: ChannelOrder::Unordered,
+                    remote: Counterparty {
+                        port_id: PortId::transfer(),
+                        channel_id: Some(ChannelId::new(5)),
+                    },
+                    connection_hops: vec![ConnectionId::new(0)],
+                    version: Version::new("ics20-1".to_string()),
+                },
+            );
+            state_tx.put_send_sequence(&channel_id, &PortId::transfer(), 1);
+        }
+        state_tx.apply();
+
+        Ok(state)
+    }
+
+    // a transfer from channel-5 on the counterparty to channel-0 on Penumbra.
+    fn inbound_packet(denom: &str, memo: String) -> Packet {
+        let data = FungibleTokenPacketData {
+            denom: denom.to_string(),
+            amount: AMOUNT.to_string(),
+            sender: "cosmos1sender".to_string(),
+            receiver: test_keys::ADDRESS_0_STR.to_string(),
+            memo,
+        };
+
+        packet(
+            (PortId::transfer(), ChannelId::new(5)),
+            (PortId::transfer(), ChannelId::new(0)),
+            1,
+            data,
+        )
+    }
+
+    // a transfer from `channel` on Penumbra to channel-5 on the counterparty.
+    fn outbound_packet(channel: ChannelId, sequence: u64, data: FungibleTokenPacketData) -> Packet {
+        packet(
+            (PortId::transfer(), channel),
+            (PortId::transfer(), ChannelId::new(5)),
+            sequence,
+            data,
+        )
+    }
+
+    fn packet(
+        (port_on_a, chan_on_a): (PortId, ChannelId),
+        (port_on_b, chan_on_b): (PortId, ChannelId),
+        sequence: u64,
+        data: FungibleTokenPacketData,
+    ) -> Packet {
+        Packet {
+            sequence: sequence.into(),
+            port_on_a,
+            chan_on_a,
+            port_on_b,
+            chan_on_b,
+            data: data.encode_to_vec(),
+            timeout_height_on_b: Height::new(0, 1_000).unwrap().into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(0).unwrap(),
+        }
+    }
+
+    fn withdrawal_data(denom: &str) -> FungibleTokenPacketData {
+        FungibleTokenPacketData {
+            denom: denom.to_string(),
+            amount: AMOUNT.to_string(),
+            sender: test_keys::ADDRESS_0_STR.to_string(),
+            receiver: "cosmos1receiver".to_string(),
+            memo: "".to_string(),
+        }
+    }
+
+    fn denom(denom: &str) -> DenomMetadata {
+        denom.try_into().unwrap()
+    }
+
+    async fn value_balance<S: StateRead>(state: &S, channel: u64, denom: &str) -> Amount {
+        state
+            .get(&state_key::ics20_value_balance(
+                &ChannelId::new(channel),
+                &self::denom(denom).id(),
+            ))
+            .await
+            .unwrap()
+            .unwrap_or_else(Amount::zero)
+    }
+
+    fn put_value_balance<S: StateWrite>(state: &mut S, channel: u64, denom: &str, amount: u64) {
+        state.put(
+            state_key::ics20_value_balance(&ChannelId::new(channel), &self::denom(denom).id()),
+            Amount::from(amount),
+        );
+    }
+
+    async fn supply<S: StateRead>(state: &S, denom: &str) -> u64 {
+        state
+            .token_supply(&self::denom(denom).id())
+            .await
+            .unwrap()
+            .unwrap_or(0)
+    }
+
+    async fn pending_notes<S: StateWrite>(state: &S) -> usize {
+        state.pending_note_payloads().await.len()
+    }
+
+    async fn ack<S: StateRead>(state: &S, packet: &Packet) -> Option<Vec<u8>> {
+        state
+            .get_packet_acknowledgement(
+                &packet.port_on_b,
+                &packet.chan_on_b,
+                packet.sequence.into(),
+            )
+            .await
+            .unwrap()
+    }
+
+    fn error_ack() -> Vec<u8> {
+        TokenTransferAcknowledgement::Error("transfer failed".to_string()).into()
+    }
+
+    #[tokio::test]
+    async fn receive_as_source_unescrows_tokens() -> Result<()> {
+        let mut state = setup().await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        put_value_balance(&mut state_tx, 0, "upenumbra", 1_000);
+
+        // the counterparty prefixed our denom with its end of the channel.
+        let packet = inbound_packet("transfer/channel-5/upenumbra", "".to_string());
+        let notes = pending_notes(&state_tx).await;
+        assert!(matches!(
+            recv_transfer_packet_inner(&mut state_tx, &packet).await?,
+            Received::Delivered
+        ));
+
+        assert_eq!(
+            value_balance(&state_tx, 0, "upenumbra").await,
+            700u64.into()
+        );
+        assert_eq!(supply(&state_tx, "upenumbra").await, AMOUNT);
+        assert_eq!(pending_notes(&state_tx).await, notes + 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn receive_as_source_cannot_exceed_escrow() -> Result<()> {
+        let mut state = setup().await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        put_value_balance(&mut state_tx, 0, "upenumbra", AMOUNT - 1);
+
+        let packet = inbound_packet("transfer/channel-5/upenumbra", "".to_string());
+        assert!(recv_transfer_packet_inner(&mut state_tx, &packet)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn receive_as_sink_mints_vouchers() -> Result<()> {
+        let mut state = setup().await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+
+        let packet = inbound_packet("uatom", "".to_string());
+        let notes = pending_notes(&state_tx).await;
+        assert!(matches!(
+            recv_transfer_packet_inner(&mut state_tx, &packet).await?,
+            Received::Delivered
+        ));
+
+        // the vouchers are prefixed with our end of the channel, and nothing is escrowed.
+        assert_eq!(
+            value_balance(&state_tx, 0, "transfer/channel-0/uatom").await,
+            Amount::zero()
+        );
+        assert_eq!(supply(&state_tx, "transfer/channel-0/uatom").await, AMOUNT);
+        assert_eq!(supply(&state_tx, "uatom").await, 0);
+        assert_eq!(pending_notes(&state_tx).await, notes + 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn receive_with_swap_memo_swaps_vouchers() -> Result<()> {
+        let mut state = setup().await?;
+        let voucher = denom("transfer/channel-0/uatom");
+        let upenumbra = denom("upenumbra");
+
+        // someone is selling upenumbra for the vouchers at 1:1, without a fee, so the swap fills.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx
+            .put_position(Position::new(
+                OsRng,
+                DirectedTradingPair::new(voucher.id(), upenumbra.id()),
+                0,
+                1u64.into(),
+                1u64.into(),
+                Reserves {
+                    r1: Amount::zero(),
+                    r2: 1_000u64.into(),
+                },
+            ))
+            .await?;
+        state_tx.apply();
+
+        let swap = SwapMetadata {
+            output: upenumbra.clone(),
+        };
+        let packet = inbound_packet("uatom", swap.to_memo());
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        let notes = pending_notes(&state_tx).await;
+        assert!(matches!(
+            recv_transfer_packet_inner(&mut state_tx, &packet).await?,
+            Received::Delivered
+        ));
+        state_tx.apply();
+
+        let end_block = abci::request::EndBlock { height: 1 };
+        Dex::end_block(&mut state, &end_block).await;
+
+        // the swap filled against the position, and its output was paid to the receiver.
+        let output_data = state
+            .output_data(1, TradingPair::new(voucher.id(), upenumbra.id()))
+            .await?
+            .expect("the deposit was swapped in this block's batch");
+        let input = if output_data.trading_pair.asset_1() == voucher.id() {
+            (AMOUNT.into(), Amount::zero())
+        } else {
+            (Amount::zero(), AMOUNT.into())
+        };
+        let (lambda_1, lambda_2) = output_data.pro_rata_outputs(input);
+        let output = if output_data.trading_pair.asset_1() == upenumbra.id() {
+            lambda_1
+        } else {
+            lambda_2
+        };
+        assert!(output > Amount::zero());
+        assert_eq!(pending_notes(state.as_ref()).await, notes + 1);
+
+        // the vouchers entered Penumbra when they were received, even though they went to the
+        // DEX rather than the receiver, while the upenumbra paid out came from the DEX's reserves,
+        // so there is no more of it than before.
+        assert_eq!(
+            supply(state.as_ref(), "transfer/channel-0/uatom").await,
+            AMOUNT
+        );
+        assert_eq!(supply(state.as_ref(), "upenumbra").await, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn timeout_refunds_escrowed_tokens() -> Result<()> {
+        let mut state = setup().await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        put_value_balance(&mut state_tx, 0, "upenumbra", 1_000);
+
+        let packet = outbound_packet(ChannelId::new(0), 1, withdrawal_data("upenumbra"));
+        let notes = pending_notes(&state_tx).await;
+        refund_packet_inner(&mut state_tx, &packet).await?;
+
+        assert_eq!(
+            value_balance(&state_tx, 0, "upenumbra").await,
+            700u64.into()
+        );
+        assert_eq!(supply(&state_tx, "upenumbra").await, AMOUNT);
+        assert_eq!(pending_notes(&state_tx).await, notes + 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn timeout_refunds_burned_vouchers() -> Result<()> {
+        let mut state = setup().await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+
+        let packet = outbound_packet(
+            ChannelId::new(0),
+            1,
+            withdrawal_data("transfer/channel-0/uatom"),
+        );
+        let notes = pending_notes(&state_tx).await;
+        refund_packet_inner(&mut state_tx, &packet).await?;
+
+        assert_eq!(
+            value_balance(&state_tx, 0, "transfer/channel-0/uatom").await,
+            Amount::zero()
+        );
+        assert_eq!(supply(&state_tx, "transfer/channel-0/uatom").await, AMOUNT);
+        assert_eq!(pending_notes(&state_tx).await, notes + 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn error_acknowledgement_refunds_tokens() -> Result<()> {
+        let mut state = setup().await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        put_value_balance(&mut state_tx, 0, "upenumbra", 1_000);
+
+        let packet = outbound_packet(ChannelId::new(0), 1, withdrawal_data("upenumbra"));
+
+        // a successful acknowledgement leaves the tokens in escrow.
+        let notes = pending_notes(&state_tx).await;
+        let success: Vec<u8> = TokenTransferAcknowledgement::success().into();
+        acknowledge_packet_inner(&mut state_tx, &packet, &success).await?;
+        assert_eq!(
+            value_balance(&state_tx, 0, "upenumbra").await,
+            1_000u64.into()
+        );
+        assert_eq!(supply(&state_tx, "upenumbra").await, 0);
+        assert_eq!(pending_notes(&state_tx).await, notes);
+
+        // an error acknowledgement refunds them.
+        acknowledge_packet_inner(&mut state_tx, &packet, &error_ack()).await?;
+        assert_eq!(
+            value_balance(&state_tx, 0, "upenumbra").await,
+            700u64.into()
+        );
+        assert_eq!(supply(&state_tx, "upenumbra").await, AMOUNT);
+        assert_eq!(pending_notes(&state_tx).await, notes + 1);
+
+        Ok(())
+    }
+
+    fn forward_metadata() -> ForwardMetadata {
+        ForwardMetadata {
+            receiver: "osmo1receiver".to_string(),
+            port: PortId::transfer(),
+            channel: ChannelId::new(1),
+            next: None,
+        }
+    }
+
+    // receives a transfer of uatom on channel-0 which is forwarded over channel-1, returning the
+    // inbound packet and the forwarded packet.
+    async fn forward<S: StateWrite>(state: &mut S) -> Result<(Packet, Packet)> {
+        let inbound = inbound_packet("uatom", forward_metadata().to_memo());
+
+        let notes = pending_notes(&*state).await;
+        assert!(matches!(
+            recv_transfer_packet_inner(&mut *state, &inbound).await?,
+            Received::Forwarded
+        ));
+
+        // nothing is delivered on Penumbra: the vouchers are escrowed on the next channel instead.
+        assert_eq!(pending_notes(&*state).await, notes);
+        assert_eq!(supply(&*state, "transfer/channel-0/uatom").await, 0);
+        assert_eq!(
+            value_balance(&*state, 1, "transfer/channel-0/uatom").await,
+            AMOUNT.into()
+        );
+
+        // the inbound transfer isn't acknowledged until the forwarded transfer is.
+        assert_eq!(ack(&*state, &inbound).await, None);
+
+        let forwarded = outbound_packet(
+            ChannelId::new(1),
+            1,
+            FungibleTokenPacketData {
+                denom: "transfer/channel-0/uatom".to_string(),
+                amount: AMOUNT.to_string(),
+                sender: test_keys::ADDRESS_0_STR.to_string(),
+                receiver: forward_metadata().receiver,
+                memo: "".to_string(),
+            },
+        );
+        assert!(state
+            .get_packet_commitment_by_id(&ChannelId::new(1), &PortId::transfer(), 1)
+            .await?
+            .is_some());
+        assert_eq!(
+            forwarded_packet(&*state, &forwarded).await?,
+            Some(inbound.clone())
+        );
+
+        Ok((inbound, forwarded))
+    }
+
+    #[tokio::test]
+    async fn successful_forward_acknowledges_inbound_transfer() -> Result<()> {
+        let mut state = setup().await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+
+        let (inbound, forwarded) = forward(&mut state_tx).await?;
+
+        let notes = pending_notes(&state_tx).await;
+        let success: Vec<u8> = TokenTransferAcknowledgement::success().into();
+        acknowledge_packet_inner(&mut state_tx, &forwarded, &success).await?;
+
+        assert_eq!(ack(&state_tx, &inbound).await, Some(success));
+        assert_eq!(forwarded_packet(&state_tx, &forwarded).await?, None);
+        assert_eq!(
+            value_balance(&state_tx, 1, "transfer/channel-0/uatom").await,
+            AMOUNT.into()
+        );
+        assert_eq!(pending_notes(&state_tx).await, notes);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_forward_fails_inbound_transfer() -> Result<()> {
+        for timeout in [false, true] {
+            let mut state = setup().await?;
+            let mut state_tx = state.try_begin_transaction().unwrap();
+
+            let (inbound, forwarded) = forward(&mut state_tx).await?;
+
+            let notes = pending_notes(&state_tx).await;
+            if timeout {
+                refund_packet_inner(&mut state_tx, &forwarded).await?;
+            } else {
+                acknowledge_packet_inner(&mut state_tx, &forwarded, &error_ack()).await?;
+            }
+
+            // the inbound transfer is failed rather than refunded on Penumbra, so that the
+            // original sender's chain refunds it.
+            let inbound_ack = ack(&state_tx, &inbound)
+                .await
+                .expect("inbound transfer is acknowledged");
+            assert!(!is_success_acknowledgement(&inbound_ack));
+            assert_eq!(forwarded_packet(&state_tx, &forwarded).await?, None);
+            assert_eq!(
+                value_balance(&state_tx, 1, "transfer/channel-0/uatom").await,
+                Amount::zero()
+            );
+            assert_eq!(supply(&state_tx, "transfer/channel-0/uatom").await, 0);
+            assert_eq!(pending_notes(&state_tx).await, notes);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_forward_releases_inbound_flow() -> Result<()> {
+        for success in [false, true] {
+            let mut state = setup().await?;
+            let mut state_tx = state.try_begin_transaction().unwrap();
+            state_tx.put_chain_params(ChainParameters {
+                ics20_rate_limits: vec![Ics20RateLimit {
+                    channel_id: ChannelId::new(0).to_string(),
+                    denom: "transfer/channel-0/uatom".to_string(),
+                    inbound_limit: Some(1_000u64.into()),
+                    outbound_limit: None,
+                }],
+                ..Default::default()
+            });
+
+            let (_, forwarded) = forward(&mut state_tx).await?;
+            assert_eq!(
+                state_tx
+                    .ics20_flow(
+                        Direction::Inbound,
+                        &ChannelId::new(0),
+                        &denom("transfer/channel-0/uatom")
+                    )
+                    .await?,
+                AMOUNT.into()
+            );
+
+            if success {
+                let ack: Vec<u8> = TokenTransferAcknowledgement::success().into();
+                acknowledge_packet_inner(&mut state_tx, &forwarded, &ack).await?;
+            } else {
+                refund_packet_inner(&mut state_tx, &forwarded).await?;
+            }
+
+            // a failed forward fails the inbound transfer too, so it no longer counts against the
+            // inbound rate limit, while a successful one still does.
+            let expected = if success {
+                AMOUNT.into()
+            } else {
+                Amount::zero()
+            };
+            assert_eq!(
+                state_tx
+                    .ics20_flow(
+                        Direction::Inbound,
+                        &ChannelId::new(0),
+                        &denom("transfer/channel-0/uatom")
+                    )
+                    .await?,
+                expected
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_forward_of_our_tokens_returns_them_to_escrow() -> Result<()> {
+        let mut state = setup().await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        put_value_balance(&mut state_tx, 0, "upenumbra", 1_000);
+
+        // our tokens come back over channel-0, and are forwarded out over channel-1.
+        let inbound = inbound_packet("transfer/channel-5/upenumbra", forward_metadata().to_memo());
+        recv_transfer_packet_inner(&mut state_tx, &inbound).await?;
+        assert_eq!(
+            value_balance(&state_tx, 0, "upenumbra").await,
+            700u64.into()
+        );
+        assert_eq!(
+            value_balance(&state_tx, 1, "upenumbra").await,
+            AMOUNT.into()
+        );
+
+        let forwarded = outbound_packet(
+            ChannelId::new(1),
+            1,
+            FungibleTokenPacketData {
+                denom: "upenumbra".to_string(),
+                amount: AMOUNT.to_string(),
+                sender: test_keys::ADDRESS_0_STR.to_string(),
+                receiver: forward_metadata().receiver,
+                memo: "".to_string(),
+            },
+        );
+        refund_packet_inner(&mut state_tx, &forwarded).await?;
+
+        // the inbound leg is unreceived: the tokens go back into escrow on channel-0.
+        assert_eq!(
+            value_balance(&state_tx, 0, "upenumbra").await,
+            1_000u64.into()
+        );
+        assert_eq!(
+            value_balance(&state_tx, 1, "upenumbra").await,
+            Amount::zero()
+        );
+        assert_eq!(supply(&state_tx, "upenumbra").await, 0);
+        assert!(!is_success_acknowledgement(
+            &ack(&state_tx, &inbound).await.unwrap()
+        ));
+
+        Ok(())
+    }
+}
@@ -4,12 +4,12 @@ use ibc_types2::lightclients::tendermint::header::Header as TendermintHeader;
 use ibc_types2::{
     core::{
         channel::{ChannelEnd, ChannelId, Packet, PortId},
-        client::ClientId,
+        client::{ClientId, Height},
         connection::{ConnectionEnd, ConnectionId, Counterparty},
     },
     lightclients::tendermint::TENDERMINT_CLIENT_TYPE,
 };
-use tendermint::abci::{Event, EventAttributeIndexExt};
+use tendermint::abci::{Event, EventAttribute, EventAttributeIndexExt};
 
 pub fn create_client(client_id: ClientId, client_state: TendermintClientState) -> Event {
     Event::new(
@@ -23,6 +23,18 @@ pub fn create_client(client_id: ClientId, client_state: TendermintClientState) -
     )
 }
 
+pub fn upgrade_client(client_id: ClientId, client_state: TendermintClientState) -> Event {
+    Event::new(
+        "upgrade_client",
+        vec![
+            ("client_id", client_id.to_string()).index(),
+            // BUG: impl Display for ClientType is wrong and doesn't match as_str
+            ("client_type", TENDERMINT_CLIENT_TYPE.to_owned()).index(),
+            ("consensus_height", client_state.latest_height().to_string()).index(),
+        ],
+    )
+}
+
 pub fn update_client(client_id: ClientId, header: TendermintHeader) -> Event {
     // AYFK
     // lol
@@ -42,6 +54,35 @@ pub fn update_client(client_id: ClientId, header: TendermintHeader) -> Event {
     )
 }
 
+pub fn client_misbehaviour(client_id: ClientId, frozen_height: Height) -> Event {
+    Event::new(
+        "client_misbehaviour",
+        vec![
+            ("client_id", client_id.to_string()).index(),
+            // BUG: impl Display for ClientType is wrong and doesn't match as_str
+            ("client_type", TENDERMINT_CLIENT_TYPE.to_owned()).index(),
+            ("consensus_height", frozen_height.to_string()).index(),
+        ],
+    )
+}
+
+pub fn update_client_proposal(
+    subject_client_id: ClientId,
+    substitute_client_id: ClientId,
+    consensus_height: Height,
+) -> Event {
+    Event::new(
+        "update_client_proposal",
+        vec![
+            ("subject_client_id", subject_client_id.to_string()).index(),
+            ("substitute_client_id", substitute_client_id.to_string()).index(),
+            // BUG: impl Display for ClientType is wrong and doesn't match as_str
+            ("client_type", TENDERMINT_CLIENT_TYPE.to_owned()).index(),
+            ("consensus_height", consensus_height.to_string()).index(),
+        ],
+    )
+}
+
 pub fn connection_open_init(
     connection_id: &ConnectionId,
     client_id: &ClientId,
@@ -301,23 +342,62 @@ pub fn channel_close_confirm(
     )
 }
 
-// TODO: add packet send
+// Packet timeouts are reported the way ibc-go reports them, so that relayers can reconstruct
+// packets from their events: the timeout height as `{revision}-{height}`, with `0-0` meaning no
+// timeout height, and the timeout timestamp in nanoseconds, with `0` meaning no timeout timestamp.
+fn timeout_height(packet: &Packet) -> String {
+    format!(
+        "{}-{}",
+        packet.timeout_height_on_b.commitment_revision_number(),
+        packet.timeout_height_on_b.commitment_revision_height()
+    )
+}
+
+fn timeout_timestamp(packet: &Packet) -> String {
+    packet.timeout_timestamp_on_b.nanoseconds().to_string()
+}
+
+// The timeout attributes packet events had before they followed ibc-go, in their old format, kept
+// so that existing consumers of them keep working. New consumers should use the
+// `packet_timeout_height` and `packet_timeout_timestamp` attributes.
+fn legacy_timeout_attributes(packet: &Packet) -> [EventAttribute; 2] {
+    [
+        ("timeout_height", packet.timeout_height_on_b.to_string()).index(),
+        (
+            "timeout_timestamp",
+            packet.timeout_timestamp_on_b.to_string(),
+        )
+            .index(),
+    ]
+}
+
+pub fn send_packet(packet: &Packet, channel: &ChannelEnd) -> Event {
+    Event::new(
+        "send_packet",
+        vec![
+            ("packet_data_hex", hex::encode(packet.data.clone())).index(),
+            ("packet_timeout_height", timeout_height(packet)).index(),
+            ("packet_timeout_timestamp", timeout_timestamp(packet)).index(),
+            ("packet_sequence", packet.sequence.to_string()).index(),
+            ("packet_src_port", packet.port_on_a.to_string()).index(),
+            ("packet_src_channel", packet.chan_on_a.to_string()).index(),
+            ("packet_dst_port", packet.port_on_b.to_string()).index(),
+            ("packet_dst_channel", packet.chan_on_b.to_string()).index(),
+            ("packet_channel_ordering", channel.ordering.to_string()).index(),
+            ("packet_connection", channel.connection_hops[0].to_string()).index(),
+        ]
+        .into_iter()
+        .chain(legacy_timeout_attributes(packet)),
+    )
+}
 
 pub fn receive_packet(packet: &Packet, channel: &ChannelEnd) -> Event {
     Event::new(
         "recv_packet",
         vec![
             ("packet_data_hex", hex::encode(packet.data.clone())).index(),
-            (
-                "packet_timeout_height",
-                packet.timeout_height_on_b.to_string(),
-            )
-                .index(),
-            (
-                "packet_timeout_timestamp",
-                packet.timeout_timestamp_on_b.to_string(),
-            )
-                .index(),
+            ("packet_timeout_height", timeout_height(packet)).index(),
+            ("packet_timeout_timestamp", timeout_timestamp(packet)).index(),
             ("packet_sequence", packet.sequence.to_string()).index(),
             ("packet_src_port", packet.port_on_a.to_string()).index(),
             ("packet_src_channel", packet.chan_on_a.to_string()).index(),
@@ -325,7 +405,9 @@ pub fn receive_packet(packet: &Packet, channel: &ChannelEnd) -> Event {
             ("packet_dst_channel", packet.chan_on_b.to_string()).index(),
             ("packet_channel_ordering", channel.ordering.to_string()).index(),
             ("packet_connection", channel.connection_hops[0].to_string()).index(),
-        ],
+        ]
+        .into_iter()
+        .chain(legacy_timeout_attributes(packet)),
     )
 }
 
@@ -334,16 +416,8 @@ pub fn acknowledge_packet(packet: &Packet, channel: &ChannelEnd) -> Event {
         "acknowledge_packet",
         vec![
             ("packet_data_hex", hex::encode(packet.data.clone())).index(),
-            (
-                "packet_timeout_height",
-                packet.timeout_height_on_b.to_string(),
-            )
-                .index(),
-            (
-                "packet_timeout_timestamp",
-                packet.timeout_timestamp_on_b.to_string(),
-            )
-                .index(),
+            ("packet_timeout_height", timeout_height(packet)).index(),
+            ("packet_timeout_timestamp", timeout_timestamp(packet)).index(),
             ("packet_sequence", packet.sequence.to_string()).index(),
             ("packet_src_port", packet.port_on_a.to_string()).index(),
             ("packet_src_channel", packet.chan_on_a.to_string()).index(),
@@ -351,7 +425,9 @@ pub fn acknowledge_packet(packet: &Packet, channel: &ChannelEnd) -> Event {
             ("packet_dst_channel", packet.chan_on_b.to_string()).index(),
             ("packet_channel_ordering", channel.ordering.to_string()).index(),
             ("packet_connection", channel.connection_hops[0].to_string()).index(),
-        ],
+        ]
+        .into_iter()
+        .chain(legacy_timeout_attributes(packet)),
     )
 }
 
@@ -359,20 +435,27 @@ pub fn write_acknowledgement(packet: &Packet, ack_bytes: &[u8]) -> Event {
     Event::new(
         "write_acknowledgement",
         vec![
+            ("packet_data_hex", hex::encode(packet.data.clone())).index(),
+            ("packet_timeout_height", timeout_height(packet)).index(),
+            ("packet_timeout_timestamp", timeout_timestamp(packet)).index(),
+            ("packet_sequence", packet.sequence.to_string()).index(),
+            ("packet_src_port", packet.port_on_a.to_string()).index(),
+            ("packet_src_channel", packet.chan_on_a.to_string()).index(),
+            ("packet_dst_port", packet.port_on_b.to_string()).index(),
+            ("packet_dst_channel", packet.chan_on_b.to_string()).index(),
+            ("packet_ack_hex", hex::encode(ack_bytes)).index(),
+            // The attributes this event had before it followed ibc-go, kept so that existing
+            // consumers of it keep working. New consumers should use the attributes above.
             ("packet_data", hex::encode(packet.data.clone())).index(),
-            ("timeout_height", packet.timeout_height_on_b.to_string()).index(),
-            (
-                "timeout_timestamp",
-                packet.timeout_timestamp_on_b.to_string(),
-            )
-                .index(),
             ("sequence", packet.sequence.to_string()).index(),
             ("src_port", packet.port_on_a.to_string()).index(),
             ("src_channel", packet.chan_on_a.to_string()).index(),
             ("dst_port", packet.port_on_b.to_string()).index(),
             ("dst_channel", packet.chan_on_b.to_string()).index(),
             ("acknowledgement", hex::encode(ack_bytes)).index(),
-        ],
+        ]
+        .into_iter()
+        .chain(legacy_timeout_attributes(packet)),
     )
 }
 
@@ -381,16 +464,8 @@ pub fn timeout_packet(packet: &Packet, channel: &ChannelEnd) -> Event {
         "timeout_packet",
         vec![
             ("packet_data_hex", hex::encode(packet.data.clone())).index(),
-            (
-                "packet_timeout_height",
-                packet.timeout_height_on_b.to_string(),
-            )
-                .index(),
-            (
-                "packet_timeout_timestamp",
-                packet.timeout_timestamp_on_b.to_string(),
-            )
-                .index(),
+            ("packet_timeout_height", timeout_height(packet)).index(),
+            ("packet_timeout_timestamp", timeout_timestamp(packet)).index(),
             ("packet_sequence", packet.sequence.to_string()).index(),
             ("packet_src_port", packet.port_on_a.to_string()).index(),
             ("packet_src_channel", packet.chan_on_a.to_string()).index(),
@@ -398,6 +473,8 @@ pub fn timeout_packet(packet: &Packet, channel: &ChannelEnd) -> Event {
             ("packet_dst_channel", packet.chan_on_b.to_string()).index(),
             ("packet_channel_ordering", channel.ordering.to_string()).index(),
             ("packet_connection", channel.connection_hops[0].to_string()).index(),
-        ],
+        ]
+        .into_iter()
+        .chain(legacy_timeout_attributes(packet)),
     )
 }
@@ -3,7 +3,7 @@ use ibc_types2::core::{
         MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
         MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
     },
-    client::msgs::{MsgCreateClient, MsgUpdateClient},
+    client::msgs::{MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient},
     connection::msgs::{
         MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit, MsgConnectionOpenTry,
     },
@@ -21,6 +21,8 @@ use serde::{Deserialize, Serialize};
 pub enum IbcAction {
     CreateClient(MsgCreateClient),
     UpdateClient(MsgUpdateClient),
+    SubmitMisbehavior(MsgSubmitMisbehaviour),
+    UpgradeClient(MsgUpgradeClient),
     ConnectionOpenInit(MsgConnectionOpenInit),
     ConnectionOpenTry(MsgConnectionOpenTry),
     ConnectionOpenAck(MsgConnectionOpenAck),
@@ -58,6 +60,12 @@ impl IbcAction {
             IbcAction::UpdateClient(msg) => {
                 tracing::info_span!(parent: parent, "UpdateClient", client_id = %msg.client_id)
             }
+            IbcAction::SubmitMisbehavior(msg) => {
+                tracing::info_span!(parent: parent, "SubmitMisbehavior", client_id = %msg.client_id)
+            }
+            IbcAction::UpgradeClient(msg) => {
+                tracing::info_span!(parent: parent, "UpgradeClient", client_id = %msg.client_id)
+            }
             IbcAction::ConnectionOpenInit(msg) => {
                 tracing::info_span!(parent: parent, "ConnectionOpenInit", client_id = %msg.client_id_on_a)
             }
@@ -131,6 +139,14 @@ impl TryFrom<pb::IbcAction> for IbcAction {
                 let msg = MsgUpdateClient::decode(raw_action_bytes)?;
                 IbcAction::UpdateClient(msg)
             }
+            MsgSubmitMisbehaviour::TYPE_URL => {
+                let msg = MsgSubmitMisbehaviour::decode(raw_action_bytes)?;
+                IbcAction::SubmitMisbehavior(msg)
+            }
+            MsgUpgradeClient::TYPE_URL => {
+                let msg = MsgUpgradeClient::decode(raw_action_bytes)?;
+                IbcAction::UpgradeClient(msg)
+            }
             MsgConnectionOpenInit::TYPE_URL => {
                 let msg = MsgConnectionOpenInit::decode(raw_action_bytes)?;
                 IbcAction::ConnectionOpenInit(msg)
@@ -199,6 +215,14 @@ impl From<IbcAction> for pb::IbcAction {
                 type_url: MsgUpdateClient::TYPE_URL.to_string(),
                 value: msg.encode_to_vec().into(),
             },
+            IbcAction::SubmitMisbehavior(msg) => pbjson_types::Any {
+                type_url: MsgSubmitMisbehaviour::TYPE_URL.to_string(),
+                value: msg.encode_to_vec().into(),
+            },
+            IbcAction::UpgradeClient(msg) => pbjson_types::Any {
+                type_url: MsgUpgradeClient::TYPE_URL.to_string(),
+                value: msg.encode_to_vec().into(),
+            },
             IbcAction::ConnectionOpenInit(msg) => pbjson_types::Any {
                 type_url: MsgConnectionOpenInit::TYPE_URL.to_string(),
                 value: msg.encode_to_vec().into(),
@@ -0,0 +1,28 @@
+/// Returns whether an ICS-20 acknowledgement reports that the transfer succeeded.
+///
+/// ICS-20 acknowledgements are JSON: `{"result":"AQ=="}` on success, and `{"error":"..."}` on
+/// failure.
+pub fn is_success_acknowledgement(ack: &[u8]) -> bool {
+    serde_json::from_slice::<serde_json::Value>(ack)
+        .map(|ack| ack.get("result").is_some())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_types2::transfer::acknowledgement::TokenTransferAcknowledgement;
+
+    use super::*;
+
+    #[test]
+    fn acknowledgements_are_classified() {
+        let success: Vec<u8> = TokenTransferAcknowledgement::success().into();
+        assert!(is_success_acknowledgement(&success));
+
+        let error: Vec<u8> = TokenTransferAcknowledgement::Error("no".to_string()).into();
+        assert!(!is_success_acknowledgement(&error));
+
+        assert!(!is_success_acknowledgement(b"AQ=="));
+        assert!(!is_success_acknowledgement(b""));
+    }
+}
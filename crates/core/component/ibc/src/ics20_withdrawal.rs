@@ -37,6 +37,9 @@ pub struct Ics20Withdrawal {
     pub source_port: PortId,
     // the source channel used for the withdrawal
     pub source_channel: ChannelId,
+    // an optional memo to include in the transfer packet, interpreted by the destination chain
+    // (for instance, to forward the transfer on to another chain)
+    pub memo: String,
 }
 impl Ics20Withdrawal {
     pub fn value(&self) -> Value {
@@ -96,6 +99,10 @@ impl EffectingData for Ics20Withdrawal {
         state.update(&self.return_address.to_vec());
         state.update(&self.timeout_height.to_le_bytes());
         state.update(&self.timeout_time.to_le_bytes());
+
+        let memo_hash = blake2b_simd::Params::default().hash(self.memo.as_bytes());
+        state.update(memo_hash.as_bytes());
+
         EffectHash(*state.finalize().as_array())
     }
 }
@@ -120,6 +127,7 @@ impl From<Ics20Withdrawal> for pb::Ics20Withdrawal {
             timeout_time: w.timeout_time,
             source_channel: w.source_channel.to_string(),
             source_port: w.source_port.to_string(),
+            memo: w.memo,
         }
     }
 }
@@ -148,6 +156,7 @@ impl TryFrom<pb::Ics20Withdrawal> for Ics20Withdrawal {
             timeout_time: s.timeout_time,
             source_channel: ChannelId::from_str(&s.source_channel)?,
             source_port: PortId::from_str(&s.source_port)?,
+            memo: s.memo,
         })
     }
 }
@@ -156,9 +165,10 @@ impl From<Ics20Withdrawal> for pb::FungibleTokenPacketData {
     fn from(w: Ics20Withdrawal) -> Self {
         pb::FungibleTokenPacketData {
             amount: w.value().amount.to_string(),
-            denom: w.value().asset_id.to_string(), // NOTE: should this be a `Denom` instead?
+            denom: w.denom.to_string(),
             receiver: w.destination_chain_address,
             sender: w.return_address.to_string(),
+            memo: w.memo,
         }
     }
 }
@@ -12,9 +12,15 @@ pub mod event;
 
 mod ibc_action;
 mod ibc_token;
+mod ics20_acknowledgement;
 mod ics20_withdrawal;
+mod packet_forward;
+mod packet_swap;
 mod version;
 
 pub use ibc_action::IbcAction;
 pub use ibc_token::IbcToken;
+pub use ics20_acknowledgement::is_success_acknowledgement;
 pub use ics20_withdrawal::Ics20Withdrawal;
+pub use packet_forward::ForwardMetadata;
+pub use packet_swap::SwapMetadata;
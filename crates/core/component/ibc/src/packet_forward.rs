@@ -0,0 +1,145 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use ibc_types2::core::channel::{ChannelId, PortId};
+use serde::{Deserialize, Serialize};
+
+/// Instructions, carried in the memo of an inbound ICS20 transfer, to forward the transfer on to
+/// another chain instead of delivering it on Penumbra.
+///
+/// These use the same memo format as the packet-forward middleware used by Cosmos SDK chains, so
+/// that a transfer can be routed through Penumbra in the same way as through any other chain:
+///
+/// ```json
+/// {"forward":{"receiver":"osmo1...","port":"transfer","channel":"channel-1"}}
+/// ```
+///
+/// The optional `next` field holds the memo for the forwarded transfer, which can in turn ask the
+/// next chain to forward it again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardMetadata {
+    /// The address on the next chain to send the transfer to.
+    pub receiver: String,
+    /// The port of the channel to forward the transfer over.
+    pub port: PortId,
+    /// The channel to forward the transfer over.
+    pub channel: ChannelId,
+    /// The memo to include in the forwarded transfer, if any.
+    pub next: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ForwardMemo {
+    forward: RawForwardMetadata,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawForwardMetadata {
+    receiver: String,
+    port: String,
+    channel: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next: Option<serde_json::Value>,
+}
+
+impl ForwardMetadata {
+    /// Parse forwarding instructions from the memo of a transfer.
+    ///
+    /// Memos are free-form, so a memo which is not JSON, or which does not have a `forward` key, is
+    /// not an error: it just doesn't ask for the transfer to be forwarded. A memo which does ask
+    /// for forwarding, but with malformed instructions, is an error.
+    pub fn from_memo(memo: &str) -> Result<Option<Self>> {
+        let memo: serde_json::Value = match serde_json::from_str(memo) {
+            Ok(memo) => memo,
+            Err(_) => return Ok(None),
+        };
+        let forward = match memo.get("forward") {
+            Some(forward) => forward.clone(),
+            None => return Ok(None),
+        };
+
+        let raw: RawForwardMetadata =
+            serde_json::from_value(forward).context("invalid forwarding instructions in memo")?;
+
+        if raw.receiver.is_empty() {
+            anyhow::bail!("forwarding instructions are missing a receiver");
+        }
+
+        Ok(Some(ForwardMetadata {
+            receiver: raw.receiver,
+            port: PortId::from_str(&raw.port).context("invalid port in forwarding instructions")?,
+            channel: ChannelId::from_str(&raw.channel)
+                .context("invalid channel in forwarding instructions")?,
+            // The next memo may be given either as a JSON object, or as an already-encoded string.
+            next: match raw.next {
+                None => None,
+                Some(serde_json::Value::String(next)) => Some(next),
+                Some(next) => Some(next.to_string()),
+            },
+        }))
+    }
+
+    /// Encode these forwarding instructions as the memo of a transfer.
+    pub fn to_memo(&self) -> String {
+        let memo = ForwardMemo {
+            forward: RawForwardMetadata {
+                receiver: self.receiver.clone(),
+                port: self.port.to_string(),
+                channel: self.channel.to_string(),
+                next: self.next.clone().map(|next| {
+                    serde_json::from_str(&next).unwrap_or(serde_json::Value::String(next))
+                }),
+            },
+        };
+
+        serde_json::to_string(&memo).expect("forwarding instructions can be serialized")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memos_without_forwarding_instructions_are_ignored() {
+        assert_eq!(ForwardMetadata::from_memo("").unwrap(), None);
+        assert_eq!(
+            ForwardMetadata::from_memo("thanks for the coffee").unwrap(),
+            None
+        );
+        assert_eq!(
+            ForwardMetadata::from_memo(r#"{"wasm":{"contract":"osmo1"}}"#).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn malformed_forwarding_instructions_are_rejected() {
+        assert!(ForwardMetadata::from_memo(r#"{"forward":{"receiver":"osmo1"}}"#).is_err());
+        assert!(ForwardMetadata::from_memo(
+            r#"{"forward":{"receiver":"","port":"transfer","channel":"channel-1"}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn forwarding_instructions_round_trip() {
+        let memo = r#"{"forward":{"receiver":"osmo1","port":"transfer","channel":"channel-1","next":{"forward":{"receiver":"cosmos1","port":"transfer","channel":"channel-0"}}}}"#;
+
+        let forward = ForwardMetadata::from_memo(memo).unwrap().unwrap();
+        assert_eq!(forward.receiver, "osmo1");
+        assert_eq!(forward.channel, ChannelId::new(1));
+
+        let next = ForwardMetadata::from_memo(forward.next.as_deref().unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(next.receiver, "cosmos1");
+        assert_eq!(next.channel, ChannelId::new(0));
+        assert_eq!(next.next, None);
+
+        assert_eq!(
+            ForwardMetadata::from_memo(&forward.to_memo()).unwrap(),
+            Some(forward)
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use anyhow::{Context, Result};
+use penumbra_asset::asset::DenomMetadata;
+use serde::{Deserialize, Serialize};
+
+/// Instructions, carried in the memo of an inbound ICS20 transfer, to swap the transferred tokens
+/// into another asset on arrival, rather than delivering them as they are:
+///
+/// ```json
+/// {"swap":{"output":"upenumbra"}}
+/// ```
+///
+/// The swap is executed in the batch for the block in which the transfer is received, and its
+/// outputs are delivered to the transfer's receiver.
+#[derive(Debug, Clone)]
+pub struct SwapMetadata {
+    /// The asset to swap the transferred tokens into.
+    pub output: DenomMetadata,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SwapMemo {
+    swap: RawSwapMetadata,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawSwapMetadata {
+    output: String,
+}
+
+impl SwapMetadata {
+    /// Parse swap instructions from the memo of a transfer.
+    ///
+    /// As with [`ForwardMetadata::from_memo`](crate::ForwardMetadata::from_memo), a memo which
+    /// does not ask for a swap is not an error, but a memo which asks for a swap with malformed
+    /// instructions is.
+    pub fn from_memo(memo: &str) -> Result<Option<Self>> {
+        let memo: serde_json::Value = match serde_json::from_str(memo) {
+            Ok(memo) => memo,
+            Err(_) => return Ok(None),
+        };
+        let swap = match memo.get("swap") {
+            Some(swap) => swap.clone(),
+            None => return Ok(None),
+        };
+
+        let raw: RawSwapMetadata =
+            serde_json::from_value(swap).context("invalid swap instructions in memo")?;
+
+        Ok(Some(SwapMetadata {
+            output: raw
+                .output
+                .as_str()
+                .try_into()
+                .context("invalid output denom in swap instructions")?,
+        }))
+    }
+
+    /// Encode these swap instructions as the memo of a transfer.
+    pub fn to_memo(&self) -> String {
+        let memo = SwapMemo {
+            swap: RawSwapMetadata {
+                output: self.output.to_string(),
+            },
+        };
+
+        serde_json::to_string(&memo).expect("swap instructions can be serialized")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap_instructions_round_trip() {
+        let swap = SwapMetadata::from_memo(r#"{"swap":{"output":"upenumbra"}}"#)
+            .unwrap()
+            .unwrap();
+        assert_eq!(swap.output.to_string(), "upenumbra");
+
+        let parsed = SwapMetadata::from_memo(&swap.to_memo()).unwrap().unwrap();
+        assert_eq!(parsed.output.id(), swap.output.id());
+
+        assert!(SwapMetadata::from_memo("").unwrap().is_none());
+        assert!(SwapMetadata::from_memo(r#"{"swap":{}}"#).is_err());
+    }
+}
@@ -39,6 +39,18 @@ pub trait StateReadExt: StateRead {
         }
     }
 
+    /// The snapshot of the state commitment tree taken at the end of the given epoch, along with
+    /// the height of the last block in that epoch, if one was recorded.
+    async fn state_commitment_tree_frontier(
+        &self,
+        epoch_index: u64,
+    ) -> Result<Option<(u64, tct::Tree)>> {
+        self.nonconsensus_get_raw(state_key::frontier_by_epoch(epoch_index).as_bytes())
+            .await?
+            .map(|bytes| bincode::deserialize(&bytes).map_err(Into::into))
+            .transpose()
+    }
+
     async fn anchor_by_height(&self, height: u64) -> Result<Option<tct::Root>> {
         self.get(&state_key::anchor_by_height(height)).await
     }
@@ -179,6 +191,14 @@ trait StateWriteExt: StateWrite {
         if let Some(epoch_root) = epoch_root {
             let index = self.epoch().await.expect("epoch must be set").index;
             self.set_sct_epoch_anchor(index, epoch_root);
+
+            // Keep a snapshot of the tree as of the end of each epoch, so that new clients can
+            // start syncing from there rather than from genesis. Since the tree forgets all of
+            // its commitments, this is only its frontier and the roots of its complete subtrees.
+            self.nonconsensus_put_raw(
+                state_key::frontier_by_epoch(index).into_bytes(),
+                bincode::serialize(&(height, &sct)).unwrap(),
+            );
         }
 
         self.put_state_commitment_tree(sct);
@@ -187,3 +207,55 @@ trait StateWriteExt: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use penumbra_chain::{component::StateWriteExt as _, Epoch};
+    use penumbra_crypto::Fq;
+    use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn epoch_ends_snapshot_the_tree() -> Result<()> {
+        let mut state = Arc::new(StateDelta::new(()));
+        let epoch = Epoch {
+            index: 3,
+            start_height: 30,
+        };
+
+        // A block in the middle of the epoch doesn't take a snapshot.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(31);
+        state_tx.put_epoch_by_height(31, epoch);
+        state_tx
+            .add_sct_commitment(tct::StateCommitment(Fq::from(1u64)), None)
+            .await?;
+        state_tx.end_sct_block(false).await?;
+        state_tx.apply();
+        assert!(state.state_commitment_tree_frontier(3).await?.is_none());
+
+        // The last block of the epoch does.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(32);
+        state_tx.put_epoch_by_height(32, epoch);
+        state_tx
+            .add_sct_commitment(tct::StateCommitment(Fq::from(2u64)), None)
+            .await?;
+        state_tx.end_sct_block(true).await?;
+        state_tx.apply();
+
+        let (height, tree) = state
+            .state_commitment_tree_frontier(3)
+            .await?
+            .expect("snapshot was taken at the end of the epoch");
+        assert_eq!(height, 32);
+        assert_eq!(Some(tree.root()), state.anchor_by_height(32).await?);
+        assert_eq!(tree.root(), state.state_commitment_tree().await.root());
+        assert!(state.state_commitment_tree_frontier(2).await?.is_none());
+
+        Ok(())
+    }
+}
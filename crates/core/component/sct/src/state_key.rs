@@ -34,6 +34,12 @@ pub fn epoch_anchor_by_index(index: u64) -> String {
     format!("sct/epoch_anchor/{index}")
 }
 
+/// A snapshot of the state commitment tree taken at the end of the given epoch, stored in
+/// nonconsensus storage.
+pub fn frontier_by_epoch(index: u64) -> String {
+    format!("sct/frontier_by_epoch/{index:020}")
+}
+
 pub fn note_source(note_commitment: &note::StateCommitment) -> String {
     format!("sct/note_source/{note_commitment}")
 }
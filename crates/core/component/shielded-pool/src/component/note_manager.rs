@@ -31,6 +31,22 @@ pub trait NoteManager: StateWrite {
     ) -> Result<()> {
         tracing::debug!(?value, ?address, "minting tokens");
 
+        self.update_token_supply(&value.asset_id, value.amount.value() as i128)
+            .await?;
+        self.create_public_note(value, address, source).await
+    }
+
+    /// Create a new (public) note in the shielded pool, without changing the token supply.
+    ///
+    /// This is for value which is already accounted for in the supply, but held by the chain
+    /// outside the shielded pool, such as the outputs of swaps paid out of the DEX's reserves.
+    #[instrument(skip(self, value, address, source))]
+    async fn create_public_note(
+        &mut self,
+        value: Value,
+        address: &Address,
+        source: NoteSource,
+    ) -> Result<()> {
         // These notes are public, so we don't need a blinding factor for
         // privacy, but since the note commitments are determined by the note
         // contents, we need to have unique (deterministic) blinding factors for
@@ -56,9 +72,6 @@ pub trait NoteManager: StateWrite {
             .try_into()?;
 
         let note = Note::from_parts(*address, value, Rseed(rseed_bytes))?;
-        // Now record the note and update the total supply:
-        self.update_token_supply(&value.asset_id, value.amount.value() as i128)
-            .await?;
         self.add_note_payload(note.payload(), source).await;
 
         Ok(())
@@ -58,6 +58,39 @@ impl ActionHandler for validator::Definition {
                     current_seq
                 ));
             }
+
+            // Check that the change to the validator's total commission, if any, is
+            // within the per-epoch limit.
+            let old_bps = existing_v.funding_streams.total_rate_bps();
+            let new_bps = v.validator.funding_streams.total_rate_bps();
+            let reannounces_pending = state
+                .pending_commission(&v.validator.identity_key)
+                .await?
+                .map(|p| p.funding_streams == v.validator.funding_streams)
+                .unwrap_or(false);
+            if old_bps != new_bps && !reannounces_pending {
+                let max_change_bps = state.get_chain_params().await?.max_commission_change_bps;
+                let change_bps = u64::from(old_bps.abs_diff(new_bps));
+                if change_bps > max_change_bps {
+                    return Err(anyhow::anyhow!(
+                        "commission change of {}bps exceeds the maximum of {}bps per epoch",
+                        change_bps,
+                        max_change_bps
+                    ));
+                }
+
+                let current_epoch = state.get_current_epoch().await?;
+                if state
+                    .last_commission_change_epoch(&v.validator.identity_key)
+                    .await?
+                    == Some(current_epoch.index)
+                {
+                    return Err(anyhow::anyhow!(
+                        "validator commission was already changed in epoch {}",
+                        current_epoch.index
+                    ));
+                }
+            }
         }
 
         // Check whether the consensus key has already been used by another validator.
@@ -40,7 +40,7 @@ use crate::{
     metrics,
     rate::{BaseRateData, RateData},
     state_key,
-    validator::{self, Validator},
+    validator::{self, PendingCommission, Validator},
     CurrentConsensusKeys, DelegationChanges, Penalty, Uptime, {DelegationToken, IdentityKey},
 };
 use crate::{Delegate, Undelegate};
@@ -336,6 +336,12 @@ pub(crate) trait StakingImpl: StateWriteExt {
         self.set_base_rates(current_base_rate.clone(), next_base_rate.clone())
             .await;
 
+        // Apply any announced commission increases that take effect in the upcoming epoch
+        // before computing the validators' rates, so that the rates for the upcoming epoch
+        // reflect the new commission from the epoch it was announced for.
+        self.apply_pending_commissions(epoch_to_end.index + 1)
+            .await?;
+
         let validator_list = self.validator_list().await?;
         for validator in &validator_list {
             // The old epoch's "next rate" is now the "current rate"...
@@ -410,21 +416,6 @@ pub(crate) trait StakingImpl: StateWriteExt {
                 .await?
                 .expect("delegation token should be known");
 
-            // Calculate the voting power in the newly beginning epoch
-            let voting_power =
-                current_rate.voting_power(delegation_token_supply.into(), &current_base_rate);
-            tracing::debug!(?voting_power);
-
-            // Update the state of the validator within the validator set
-            // with the newly starting epoch's calculated voting rate and power.
-            self.set_validator_rates(
-                &validator.identity_key,
-                current_rate.clone(),
-                next_rate.clone(),
-            );
-            self.set_validator_power(&validator.identity_key, voting_power)
-                .await?;
-
             // Only Active validators produce commission rewards
             // The validator *may* drop out of Active state during the next epoch,
             // but the commission rewards for the ending epoch in which it was Active
@@ -461,16 +452,60 @@ pub(crate) trait StakingImpl: StateWriteExt {
                             })
                             .await?;
                         }
+                        // If the reward is to be restaked, delegate it to the validator at the
+                        // rate for the newly beginning epoch, and mint the resulting delegation
+                        // tokens to the address.
+                        Recipient::Delegation(address) => {
+                            let delegation_amount =
+                                current_rate.delegation_amount(commission_reward_amount.into());
+                            self.update_token_supply(
+                                &DelegationToken::from(validator.identity_key).id(),
+                                delegation_amount as i128,
+                            )
+                            .await?;
+                            self.mint_note(
+                                Value {
+                                    amount: delegation_amount.into(),
+                                    asset_id: DelegationToken::from(validator.identity_key).id(),
+                                },
+                                &address,
+                                NoteSource::FundingStreamReward {
+                                    epoch_index: epoch_to_end.index,
+                                },
+                            )
+                            .await?;
+                        }
                     }
                 }
             }
 
+            // Calculate the voting power in the newly beginning epoch, including any rewards
+            // restaked above.
+            let next_delegation_token_supply = self
+                .token_supply(&DelegationToken::from(validator.identity_key).id())
+                .await?
+                .expect("delegation token should be known");
+            let voting_power =
+                current_rate.voting_power(next_delegation_token_supply.into(), &current_base_rate);
+            tracing::debug!(?voting_power);
+
+            // Update the state of the validator within the validator set
+            // with the newly starting epoch's calculated voting rate and power.
+            self.set_validator_rates(
+                &validator.identity_key,
+                current_rate.clone(),
+                next_rate.clone(),
+            );
+            self.set_validator_power(&validator.identity_key, voting_power)
+                .await?;
+
             // rename to curr_rate so it lines up with next_rate (same # chars)
             let delegation_denom = DelegationToken::from(&validator.identity_key).denom();
             tracing::debug!(curr_rate = ?current_rate);
             tracing::debug!(?next_rate);
             tracing::debug!(?delegation_delta);
             tracing::debug!(?delegation_token_supply);
+            tracing::debug!(?next_delegation_token_supply);
             tracing::debug!(?delegation_denom);
         }
 
@@ -486,6 +521,36 @@ pub(crate) trait StakingImpl: StateWriteExt {
         Ok(())
     }
 
+    /// Called during `end_epoch`. Replaces the funding streams of every validator
+    /// whose pending commission takes effect at or before `epoch_index`.
+    async fn apply_pending_commissions(&mut self, epoch_index: u64) -> Result<()> {
+        for id in self.validator_identity_list().await? {
+            let Some(pending) = self.pending_commission(&id).await? else {
+                continue;
+            };
+            if pending.effective_epoch_index > epoch_index {
+                continue;
+            }
+
+            let mut validator = self
+                .validator(&id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("validator with pending commission not found"))?;
+            tracing::debug!(
+                ?id,
+                old_commission_bps = validator.funding_streams.total_rate_bps(),
+                new_commission_bps = pending.funding_streams.total_rate_bps(),
+                "applying pending commission"
+            );
+            validator.funding_streams = pending.funding_streams;
+
+            self.put(state_key::validators::by_id(&id), validator);
+            self.clear_pending_commission(&id);
+        }
+
+        Ok(())
+    }
+
     /// Called during `end_epoch`. Will perform state transitions to validators based
     /// on changes to voting power that occurred in this epoch.
     async fn set_active_and_inactive_validators(&mut self) -> Result<()> {
@@ -812,14 +877,14 @@ pub(crate) trait StakingImpl: StateWriteExt {
 
     // Used for updating an existing validator's definition.
     #[tracing::instrument(skip(self, validator), fields(id = ?validator.identity_key))]
-    async fn update_validator(&mut self, validator: Validator) -> Result<()> {
+    async fn update_validator(&mut self, mut validator: Validator) -> Result<()> {
         tracing::debug!(?validator);
-        let id = &validator.identity_key;
+        let id = validator.identity_key.clone();
 
         // Get the current state, so we can determine whether this update
         // triggers a state transition.
         let cur_state = self
-            .validator_state(id)
+            .validator_state(&id)
             .await?
             .ok_or_else(|| anyhow::anyhow!("updated validator not found in JMT"))?;
 
@@ -828,15 +893,15 @@ pub(crate) trait StakingImpl: StateWriteExt {
         match (cur_state, validator.enabled) {
             (Disabled, true) => {
                 // The operator has enabled their validator, so set it to Inactive.
-                self.set_validator_state(id, Inactive).await?;
+                self.set_validator_state(&id, Inactive).await?;
             }
             (Jailed, true) => {
                 // Treat updates to jailed validators as unjail requests.
-                self.set_validator_state(id, Inactive).await?;
+                self.set_validator_state(&id, Inactive).await?;
             }
             (Active | Inactive | Jailed | Disabled, false) => {
                 // The operator has disabled their validator.
-                self.set_validator_state(id, Disabled).await?;
+                self.set_validator_state(&id, Disabled).await?;
             }
             (Active | Inactive, true) => {
                 // This validator update does not affect the validator's state.
@@ -846,12 +911,69 @@ pub(crate) trait StakingImpl: StateWriteExt {
             }
         }
 
+        // Commission increases don't apply immediately, so this may replace the
+        // new funding streams with the existing ones.
+        self.update_commission(&mut validator).await?;
+
         // Update the consensus key lookup, in case the validator rotated their
         // consensus key.
         self.register_consensus_key(&validator.identity_key, &validator.consensus_key)
             .await;
 
-        self.put(state_key::validators::by_id(id), validator);
+        self.put(state_key::validators::by_id(&id), validator);
+
+        Ok(())
+    }
+
+    /// Handles the commission change (if any) made by a redefinition of an existing validator.
+    ///
+    /// Commission decreases, and changes of funding stream recipients that keep the total
+    /// commission the same, take effect immediately and cancel any pending increase. Commission
+    /// increases are recorded as a [`PendingCommission`] which takes effect once it has been
+    /// announced for `commission_increase_delay_epochs` full epochs; until then, the validator
+    /// keeps its existing funding streams.
+    async fn update_commission(&mut self, validator: &mut Validator) -> Result<()> {
+        let id = validator.identity_key.clone();
+        let existing = self
+            .validator(&id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("updated validator not found in JMT"))?;
+        let pending = self.pending_commission(&id).await?;
+        let current_epoch = self.get_current_epoch().await?;
+
+        let old_bps = existing.funding_streams.total_rate_bps();
+        let new_bps = validator.funding_streams.total_rate_bps();
+
+        if new_bps > old_bps {
+            let new_funding_streams =
+                std::mem::replace(&mut validator.funding_streams, existing.funding_streams);
+
+            // Re-uploading a definition with the already-announced increase (e.g., to change the
+            // validator's description) shouldn't restart the announcement delay.
+            if pending.map(|p| p.funding_streams) == Some(new_funding_streams.clone()) {
+                return Ok(());
+            }
+
+            let delay = self
+                .get_chain_params()
+                .await?
+                .commission_increase_delay_epochs;
+            let pending = PendingCommission {
+                funding_streams: new_funding_streams,
+                effective_epoch_index: current_epoch.index + 1 + delay,
+            };
+            tracing::debug!(?pending, old_bps, new_bps, "announcing commission increase");
+            self.set_pending_commission(&id, pending);
+            self.set_last_commission_change_epoch(&id, current_epoch.index);
+        } else {
+            if pending.is_some() {
+                tracing::debug!("cancelling pending commission increase");
+                self.clear_pending_commission(&id);
+            }
+            if new_bps != old_bps {
+                self.set_last_commission_change_epoch(&id, current_epoch.index);
+            }
+        }
 
         Ok(())
     }
@@ -1104,16 +1226,38 @@ pub trait StateReadExt: StateRead {
         let validator = self.validator(identity_key).await?;
         let status = self.validator_status(identity_key).await?;
         let rate_data = self.next_validator_rate(identity_key).await?;
+        let pending_commission = self.pending_commission(identity_key).await?;
         match (validator, status, rate_data) {
             (Some(validator), Some(status), Some(rate_data)) => Ok(Some(validator::Info {
                 validator,
                 status,
                 rate_data,
+                pending_commission,
             })),
             _ => Ok(None),
         }
     }
 
+    /// The commission increase announced by the validator that has not yet taken effect, if any.
+    async fn pending_commission(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<PendingCommission>> {
+        self.get(&state_key::pending_commission_by_validator(identity_key))
+            .await
+    }
+
+    /// The index of the last epoch in which the validator changed its total commission, if any.
+    async fn last_commission_change_epoch(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<u64>> {
+        self.get_proto(&state_key::last_commission_change_by_validator(
+            identity_key,
+        ))
+        .await
+    }
+
     fn validator_state(
         &self,
         identity_key: &IdentityKey,
@@ -1395,6 +1539,24 @@ pub trait StateWriteExt: StateWrite {
         );
     }
 
+    fn set_pending_commission(&mut self, identity_key: &IdentityKey, pending: PendingCommission) {
+        self.put(
+            state_key::pending_commission_by_validator(identity_key),
+            pending,
+        );
+    }
+
+    fn clear_pending_commission(&mut self, identity_key: &IdentityKey) {
+        self.delete(state_key::pending_commission_by_validator(identity_key));
+    }
+
+    fn set_last_commission_change_epoch(&mut self, identity_key: &IdentityKey, epoch_index: u64) {
+        self.put_proto(
+            state_key::last_commission_change_by_validator(identity_key),
+            epoch_index,
+        );
+    }
+
     fn set_validator_uptime(&mut self, identity_key: &IdentityKey, uptime: Uptime) {
         self.put(state_key::uptime_by_validator(identity_key), uptime);
     }
@@ -1410,3 +1572,6 @@ pub trait StateWriteExt: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use decaf377_rdsa::{SigningKey, SpendAuth};
+use penumbra_chain::{component::StateWriteExt as _, Epoch};
+use penumbra_proto::{DomainType, StateWriteProto as _};
+use penumbra_shielded_pool::component::{SupplyRead as _, SupplyWrite as _};
+use penumbra_storage::{ArcStateDeltaExt, StateDelta};
+use rand_core::OsRng;
+
+use super::{StakingImpl as _, StateReadExt as _, StateWriteExt as _};
+use crate::{
+    action_handler::ActionHandler,
+    rate::{BaseRateData, RateData},
+    validator, DelegationToken, FundingStream, FundingStreams, GovernanceKey, IdentityKey,
+};
+
+/// A validator whose whole commission goes to the DAO.
+fn validator(sk: &SigningKey<SpendAuth>, commission_bps: u16) -> validator::Validator {
+    let consensus_key = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+
+    validator::Validator {
+        identity_key: IdentityKey(sk.into()),
+        governance_key: GovernanceKey(sk.into()),
+        consensus_key: tendermint::PublicKey::from_raw_ed25519(&consensus_key.to_bytes()).unwrap(),
+        name: "test".to_string(),
+        website: String::new(),
+        description: String::new(),
+        enabled: true,
+        funding_streams: with_commission(commission_bps),
+        sequence_number: 0,
+    }
+}
+
+fn with_commission(commission_bps: u16) -> FundingStreams {
+    vec![FundingStream::ToDao {
+        rate_bps: commission_bps,
+    }]
+    .try_into()
+    .unwrap()
+}
+
+/// A state at height 1 of epoch 0, holding the given validator.
+fn state(validator: &validator::Validator) -> Arc<StateDelta<()>> {
+    let mut state = Arc::new(StateDelta::new(()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_chain_params(Default::default());
+    state_tx.put_block_height(1);
+    state_tx.put_epoch_by_height(
+        1,
+        Epoch {
+            index: 0,
+            start_height: 0,
+        },
+    );
+    state_tx.put(
+        crate::state_key::validators::by_id(&validator.identity_key),
+        validator.clone(),
+    );
+    state_tx.apply();
+    state
+}
+
+fn definition(
+    sk: &SigningKey<SpendAuth>,
+    validator: validator::Validator,
+) -> validator::Definition {
+    let auth_sig = sk.sign(OsRng, &validator.encode_to_vec());
+    validator::Definition {
+        validator,
+        auth_sig,
+    }
+}
+
+#[tokio::test]
+async fn commission_changes_are_capped() -> anyhow::Result<()> {
+    let sk = SigningKey::<SpendAuth>::new(OsRng);
+    let existing = validator(&sk, 500);
+    let mut state = state(&existing);
+    let max_change_bps = 500;
+
+    let redefine = |commission_bps| {
+        let mut validator = existing.clone();
+        validator.sequence_number += 1;
+        validator.funding_streams = with_commission(commission_bps);
+        definition(&sk, validator)
+    };
+
+    // Changes of up to the maximum in either direction are allowed...
+    redefine(500 + max_change_bps)
+        .check_stateful(state.clone())
+        .await?;
+    redefine(0).check_stateful(state.clone()).await?;
+    // ... but larger ones are not.
+    assert!(redefine(500 + max_change_bps + 1)
+        .check_stateful(state.clone())
+        .await
+        .is_err());
+
+    // Only one change is allowed per epoch, though a definition which doesn't change the
+    // commission is still allowed.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.set_last_commission_change_epoch(&existing.identity_key, 0);
+    state_tx.apply();
+    assert!(redefine(600).check_stateful(state.clone()).await.is_err());
+    redefine(500).check_stateful(state.clone()).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn commission_increases_are_delayed() -> anyhow::Result<()> {
+    let sk = SigningKey::<SpendAuth>::new(OsRng);
+    let existing = validator(&sk, 500);
+    let id = existing.identity_key;
+    let mut state = state(&existing);
+
+    let mut updated = existing.clone();
+    updated.funding_streams = with_commission(800);
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.update_commission(&mut updated).await?;
+    state_tx.apply();
+
+    // The validator keeps its existing commission, and the increase is announced for after a full
+    // epoch has passed.
+    assert_eq!(updated.funding_streams, with_commission(500));
+    let pending = state
+        .pending_commission(&id)
+        .await?
+        .expect("increase is pending");
+    assert_eq!(pending.funding_streams, with_commission(800));
+    assert_eq!(pending.effective_epoch_index, 2);
+    assert_eq!(state.last_commission_change_epoch(&id).await?, Some(0));
+
+    // Nothing changes at the end of epoch 0...
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.apply_pending_commissions(1).await?;
+    state_tx.apply();
+    assert_eq!(
+        state.validator(&id).await?.unwrap().funding_streams,
+        with_commission(500)
+    );
+
+    // ... but at the end of epoch 1, the increase takes effect for epoch 2.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.apply_pending_commissions(2).await?;
+    state_tx.apply();
+    assert_eq!(
+        state.validator(&id).await?.unwrap().funding_streams,
+        with_commission(800)
+    );
+    assert!(state.pending_commission(&id).await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn commission_decreases_cancel_pending_increases() -> anyhow::Result<()> {
+    let sk = SigningKey::<SpendAuth>::new(OsRng);
+    let existing = validator(&sk, 500);
+    let id = existing.identity_key;
+    let mut state = state(&existing);
+
+    let mut increased = existing.clone();
+    increased.funding_streams = with_commission(800);
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.update_commission(&mut increased).await?;
+    state_tx.apply();
+    assert!(state.pending_commission(&id).await?.is_some());
+
+    // A decrease takes effect immediately, and cancels the pending increase.
+    let mut decreased = existing.clone();
+    decreased.funding_streams = with_commission(400);
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.update_commission(&mut decreased).await?;
+    state_tx.apply();
+    assert_eq!(decreased.funding_streams, with_commission(400));
+    assert!(state.pending_commission(&id).await?.is_none());
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.apply_pending_commissions(2).await?;
+    state_tx.apply();
+    assert_eq!(
+        state.validator(&id).await?.unwrap().funding_streams,
+        existing.funding_streams
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn restaked_rewards_count_towards_next_voting_power() -> anyhow::Result<()> {
+    let sk = SigningKey::<SpendAuth>::new(OsRng);
+    let mut validator = validator(&sk, 0);
+    validator.funding_streams = vec![FundingStream::ToDelegation {
+        address: *penumbra_chain::test_keys::ADDRESS_0,
+        rate_bps: 1000,
+    }]
+    .try_into()?;
+    let id = validator.identity_key;
+    let delegation_token = DelegationToken::from(id).id();
+    let epoch = Epoch {
+        index: 0,
+        start_height: 0,
+    };
+
+    let mut state = Arc::new(StateDelta::new(()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_chain_params(Default::default());
+    state_tx.put_block_height(1);
+    state_tx.put_epoch_by_height(1, epoch);
+    for height in 0..=1u64 {
+        state_tx
+            .set_delegation_changes(height.try_into().unwrap(), Default::default())
+            .await;
+    }
+    state_tx
+        .set_base_rates(
+            BaseRateData {
+                epoch_index: 0,
+                base_reward_rate: 3_0000,
+                base_exchange_rate: 1_0000_0000,
+            },
+            BaseRateData {
+                epoch_index: 1,
+                base_reward_rate: 3_0000,
+                base_exchange_rate: 1_0003_0000,
+            },
+        )
+        .await;
+    let rate = |epoch_index| RateData {
+        identity_key: id,
+        epoch_index,
+        validator_reward_rate: 0,
+        validator_exchange_rate: 1_0000_0000,
+    };
+    state_tx
+        .add_validator_inner(
+            validator,
+            rate(0),
+            rate(1),
+            validator::State::Active,
+            validator::BondingState::Bonded,
+            1,
+        )
+        .await?;
+    state_tx
+        .update_token_supply(&delegation_token, 1_000_000_000)
+        .await?;
+    state_tx.end_epoch(epoch).await?;
+    state_tx.apply();
+
+    // The commission was restaked, increasing the supply of delegation tokens...
+    let supply = state.token_supply(&delegation_token).await?.unwrap();
+    assert!(supply > 1_000_000_000);
+
+    // ... and the voting power for the new epoch already includes it.
+    let current_rate = state.current_validator_rate(&id).await?.unwrap();
+    let current_base_rate = state.current_base_rate().await?;
+    assert_eq!(
+        state.validator_power(&id).await?,
+        Some(current_rate.voting_power(supply.into(), &current_base_rate))
+    );
+
+    Ok(())
+}
@@ -22,6 +22,16 @@ pub enum FundingStream {
         /// validator's total staking reward that goes to this funding stream.
         rate_bps: u16,
     },
+    /// A funding stream whose reward is automatically re-delegated to the validator, rather than
+    /// being paid out in liquid staking tokens.
+    ToDelegation {
+        /// The destination address for the delegation tokens.
+        address: Address,
+
+        /// The portion (in terms of [basis points](https://en.wikipedia.org/wiki/Basis_point)) of the
+        /// validator's total staking reward that goes to this funding stream.
+        rate_bps: u16,
+    },
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -29,6 +39,7 @@ pub enum FundingStream {
 pub enum Recipient {
     Address(Address),
     Dao,
+    Delegation(Address),
 }
 
 impl FundingStream {
@@ -36,6 +47,7 @@ impl FundingStream {
         match self {
             FundingStream::ToAddress { rate_bps, .. } => *rate_bps,
             FundingStream::ToDao { rate_bps } => *rate_bps,
+            FundingStream::ToDelegation { rate_bps, .. } => *rate_bps,
         }
     }
 
@@ -43,6 +55,7 @@ impl FundingStream {
         match self {
             FundingStream::ToAddress { address, .. } => Recipient::Address(*address),
             FundingStream::ToDao { .. } => Recipient::Dao,
+            FundingStream::ToDelegation { address, .. } => Recipient::Delegation(*address),
         }
     }
 }
@@ -91,6 +104,12 @@ impl From<FundingStream> for pb::FundingStream {
                         rate_bps: rate_bps.into(),
                     },
                 )),
+                FundingStream::ToDelegation { address, rate_bps } => Some(
+                    pb::funding_stream::Recipient::ToDelegation(pb::funding_stream::ToDelegation {
+                        address: address.to_string(),
+                        rate_bps: rate_bps.into(),
+                    }),
+                ),
             },
         }
     }
@@ -132,6 +151,22 @@ impl TryFrom<pb::FundingStream> for FundingStream {
                 }
                 Ok(FundingStream::ToDao { rate_bps })
             }
+            pb::funding_stream::Recipient::ToDelegation(to_delegation) => {
+                let address = to_delegation
+                    .address
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid funding stream address: {}", e))?;
+                let rate_bps = to_delegation
+                    .rate_bps
+                    .try_into()
+                    .map_err(|e| anyhow::anyhow!("invalid funding stream rate: {}", e))?;
+                if rate_bps > 10_000 {
+                    return Err(anyhow::anyhow!(
+                        "funding stream rate exceeds 100% (10,000bps)"
+                    ));
+                }
+                Ok(FundingStream::ToDelegation { address, rate_bps })
+            }
         }
     }
 }
@@ -157,6 +192,11 @@ impl FundingStreams {
     pub fn iter(&self) -> impl Iterator<Item = &FundingStream> {
         self.funding_streams.iter()
     }
+
+    /// The total commission of these funding streams, in basis points.
+    pub fn total_rate_bps(&self) -> u16 {
+        self.funding_streams.iter().map(|fs| fs.rate_bps()).sum()
+    }
 }
 
 impl TryFrom<Vec<FundingStream>> for FundingStreams {
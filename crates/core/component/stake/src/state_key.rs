@@ -58,6 +58,14 @@ pub fn uptime_by_validator(id: &IdentityKey) -> String {
     format!("staking/validator_uptime/{id}")
 }
 
+pub fn pending_commission_by_validator(id: &IdentityKey) -> String {
+    format!("staking/validator_pending_commission/{id}")
+}
+
+pub fn last_commission_change_by_validator(id: &IdentityKey) -> String {
+    format!("staking/validator_last_commission_change/{id}")
+}
+
 pub fn slashed_validators(height: u64) -> String {
     format!("staking/slashed_validators/{height}")
 }
@@ -11,12 +11,14 @@ use crate::{FundingStream, FundingStreams, GovernanceKey, IdentityKey};
 mod bonding;
 mod definition;
 mod info;
+mod pending_commission;
 mod state;
 mod status;
 
 pub use bonding::State as BondingState;
 pub use definition::Definition;
 pub use info::Info;
+pub use pending_commission::PendingCommission;
 pub use state::State;
 pub use status::Status;
 
@@ -162,6 +164,9 @@ pub enum FundingStreamToml {
         #[serde_as(as = "DisplayFromStr")]
         address: Address,
         rate_bps: u16,
+        /// Whether the reward is re-delegated to the validator and paid out in delegation tokens.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        restake: bool,
     },
     Dao {
         recipient: DAO,
@@ -177,13 +182,20 @@ pub struct DAO;
 impl From<FundingStream> for FundingStreamToml {
     fn from(f: FundingStream) -> Self {
         match f {
-            FundingStream::ToAddress { address, rate_bps } => {
-                FundingStreamToml::Address { address, rate_bps }
-            }
+            FundingStream::ToAddress { address, rate_bps } => FundingStreamToml::Address {
+                address,
+                rate_bps,
+                restake: false,
+            },
             FundingStream::ToDao { rate_bps } => FundingStreamToml::Dao {
                 rate_bps,
                 recipient: DAO,
             },
+            FundingStream::ToDelegation { address, rate_bps } => FundingStreamToml::Address {
+                address,
+                rate_bps,
+                restake: true,
+            },
         }
     }
 }
@@ -191,9 +203,16 @@ impl From<FundingStream> for FundingStreamToml {
 impl From<FundingStreamToml> for FundingStream {
     fn from(f: FundingStreamToml) -> Self {
         match f {
-            FundingStreamToml::Address { address, rate_bps } => {
-                FundingStream::ToAddress { address, rate_bps }
-            }
+            FundingStreamToml::Address {
+                address,
+                rate_bps,
+                restake: false,
+            } => FundingStream::ToAddress { address, rate_bps },
+            FundingStreamToml::Address {
+                address,
+                rate_bps,
+                restake: true,
+            } => FundingStream::ToDelegation { address, rate_bps },
             FundingStreamToml::Dao { rate_bps, .. } => FundingStream::ToDao { rate_bps },
         }
     }
@@ -3,7 +3,7 @@ use penumbra_proto::{
 };
 use serde::{Deserialize, Serialize};
 
-use super::{Status, Validator};
+use super::{PendingCommission, Status, Validator};
 use crate::rate::RateData;
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -12,6 +12,8 @@ pub struct Info {
     pub validator: Validator,
     pub status: Status,
     pub rate_data: RateData,
+    /// An announced commission increase that has not yet taken effect, if any.
+    pub pending_commission: Option<PendingCommission>,
 }
 
 impl TypeUrl for Info {
@@ -28,6 +30,7 @@ impl From<Info> for pb::ValidatorInfo {
             validator: Some(v.validator.into()),
             status: Some(v.status.into()),
             rate_data: Some(v.rate_data.into()),
+            pending_commission: v.pending_commission.map(Into::into),
         }
     }
 }
@@ -56,6 +59,7 @@ impl TryFrom<pb::ValidatorInfo> for Info {
                 .rate_data
                 .ok_or_else(|| anyhow::anyhow!("missing rate_data field in proto"))?
                 .try_into()?,
+            pending_commission: v.pending_commission.map(TryInto::try_into).transpose()?,
         })
     }
 }
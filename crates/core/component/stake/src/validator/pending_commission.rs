@@ -0,0 +1,50 @@
+use penumbra_proto::{core::stake::v1alpha1 as pb, DomainType, TypeUrl};
+use serde::{Deserialize, Serialize};
+
+use crate::{FundingStream, FundingStreams};
+
+/// A commission increase announced by a validator, which has not yet taken effect.
+///
+/// Decreases in commission take effect immediately, but increases are delayed
+/// by the `commission_increase_delay_epochs` chain parameter, so that delegators
+/// have the opportunity to undelegate before they apply.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::PendingCommission", into = "pb::PendingCommission")]
+pub struct PendingCommission {
+    /// The funding streams that will replace the validator's current funding streams.
+    pub funding_streams: FundingStreams,
+    /// The index of the epoch in which the new funding streams take effect.
+    pub effective_epoch_index: u64,
+}
+
+impl TypeUrl for PendingCommission {
+    const TYPE_URL: &'static str = "/penumbra.core.stake.v1alpha1.PendingCommission";
+}
+
+impl DomainType for PendingCommission {
+    type Proto = pb::PendingCommission;
+}
+
+impl From<PendingCommission> for pb::PendingCommission {
+    fn from(v: PendingCommission) -> Self {
+        pb::PendingCommission {
+            funding_streams: v.funding_streams.into_iter().map(Into::into).collect(),
+            effective_epoch_index: v.effective_epoch_index,
+        }
+    }
+}
+
+impl TryFrom<pb::PendingCommission> for PendingCommission {
+    type Error = anyhow::Error;
+    fn try_from(v: pb::PendingCommission) -> Result<Self, Self::Error> {
+        Ok(PendingCommission {
+            funding_streams: v
+                .funding_streams
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<FundingStream>, _>>()?
+                .try_into()?,
+            effective_epoch_index: v.effective_epoch_index,
+        })
+    }
+}
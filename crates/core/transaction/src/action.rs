@@ -12,7 +12,7 @@ mod validator_vote;
 use crate::{ActionView, IsAction, TransactionPerspective};
 
 pub use crate::proposal::{Proposal, ProposalKind, ProposalPayload};
-pub use crate::vote::Vote;
+pub use crate::vote::{SplitVote, Vote};
 pub use delegator_vote::{DelegatorVote, DelegatorVoteBody};
 pub use proposal_deposit_claim::ProposalDepositClaim;
 pub use proposal_submit::ProposalSubmit;
@@ -10,7 +10,7 @@ use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType, TypeUrl};
 use penumbra_tct as tct;
 
 use crate::{
-    view::action_view::DelegatorVoteView, vote::Vote, Action, ActionView, IsAction,
+    view::action_view::DelegatorVoteView, vote::SplitVote, Action, ActionView, IsAction,
     TransactionPerspective,
 };
 
@@ -52,8 +52,8 @@ pub struct DelegatorVoteBody {
     pub proposal: u64,
     /// The start position of the proposal in the TCT.
     pub start_position: tct::Position,
-    /// The vote on the proposal.
-    pub vote: Vote, // With flow encryption, this will be a triple of flow ciphertexts
+    /// The vote on the proposal, possibly split between several options.
+    pub vote: SplitVote, // With flow encryption, this will be a triple of flow ciphertexts
     /// The value of the staked note being used to vote.
     pub value: Value, // With flow encryption, this will be a triple of balance commitments, and a public denomination
     /// The unbonded amount equivalent to the value above
@@ -66,10 +66,12 @@ pub struct DelegatorVoteBody {
 
 impl From<DelegatorVoteBody> for pb::DelegatorVoteBody {
     fn from(value: DelegatorVoteBody) -> Self {
+        let (vote, split_vote) = value.vote.to_proto_fields();
         pb::DelegatorVoteBody {
             proposal: value.proposal,
             start_position: value.start_position.into(),
-            vote: Some(value.vote.into()),
+            vote,
+            split_vote,
             value: Some(value.value.into()),
             unbonded_amount: Some(value.unbonded_amount.into()),
             nullifier: value.nullifier.to_bytes().into(),
@@ -88,10 +90,8 @@ impl TryFrom<pb::DelegatorVoteBody> for DelegatorVoteBody {
                 .start_position
                 .try_into()
                 .context("invalid start position in `DelegatorVote`")?,
-            vote: msg
-                .vote
-                .ok_or_else(|| anyhow::anyhow!("missing vote in `DelegatorVote`"))?
-                .try_into()?,
+            vote: SplitVote::try_from_proto_fields(msg.vote, msg.split_vote)
+                .context("invalid vote in `DelegatorVote`")?,
             value: msg
                 .value
                 .ok_or_else(|| anyhow::anyhow!("missing value in `DelegatorVote`"))?
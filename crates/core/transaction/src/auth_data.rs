@@ -3,10 +3,12 @@ use penumbra_crypto::{
     EffectHash,
 };
 use penumbra_proto::{core::transaction::v1alpha1 as pb, DomainType, TypeUrl};
+use serde::{Deserialize, Serialize};
 
 /// Authorization data returned in response to a
 /// [`TransactionDescription`](crate::TransactionDescription).
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::AuthorizationData", into = "pb::AuthorizationData")]
 pub struct AuthorizationData {
     /// The computed authorization hash for the approved transaction.
     pub effect_hash: EffectHash,
@@ -13,7 +13,7 @@ use penumbra_stake::{Delegate, Undelegate, UndelegateClaimBody};
 use crate::{
     action::{
         DelegatorVote, DelegatorVoteBody, Proposal, ProposalDepositClaim, ProposalSubmit,
-        ProposalWithdraw, ValidatorVote, ValidatorVoteBody, Vote,
+        ProposalWithdraw, SplitVote, ValidatorVote, ValidatorVoteBody, Vote,
     },
     plan::TransactionPlan,
     proposal, Action, Transaction, TransactionBody,
@@ -407,6 +407,26 @@ impl EffectingData for Vote {
     }
 }
 
+impl EffectingData for SplitVote {
+    fn effect_hash(&self) -> EffectHash {
+        // A split vote which assigns all its weight to one option has the same effect as a plain
+        // vote, so it has the same effect hash.
+        if let Some(vote) = self.single() {
+            return vote.effect_hash();
+        }
+
+        let mut state = blake2b_simd::Params::default()
+            .personal(b"PAH:split_vote")
+            .to_state();
+
+        state.update(&self.yes_bps().to_le_bytes());
+        state.update(&self.no_bps().to_le_bytes());
+        state.update(&self.abstain_bps().to_le_bytes());
+
+        EffectHash(state.finalize().as_array().clone())
+    }
+}
+
 impl EffectingData for ValidatorVoteBody {
     fn effect_hash(&self) -> EffectHash {
         let mut state = blake2b_simd::Params::default()
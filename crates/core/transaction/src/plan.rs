@@ -25,10 +25,12 @@ mod auth;
 mod build;
 mod clue;
 mod memo;
+mod review;
 
 pub use action::{ActionPlan, DelegatorVotePlan};
 pub use clue::CluePlan;
 pub use memo::MemoPlan;
+pub use review::PlanReview;
 
 /// A declaration of a planned [`Transaction`](crate::Transaction),
 /// for use in transaction authorization and creation.
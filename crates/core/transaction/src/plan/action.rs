@@ -74,6 +74,34 @@ pub enum ActionPlan {
 }
 
 impl ActionPlan {
+    /// A short description of the kind of action this is.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ActionPlan::Spend(_) => "spend",
+            ActionPlan::Output(_) => "output",
+            ActionPlan::Delegate(_) => "delegation",
+            ActionPlan::Undelegate(_) => "undelegation",
+            ActionPlan::UndelegateClaim(_) => "undelegation claim",
+            ActionPlan::ValidatorDefinition(_) => "validator definition",
+            ActionPlan::Swap(_) => "swap",
+            ActionPlan::SwapClaim(_) => "swap claim",
+            ActionPlan::IbcAction(_) => "IBC action",
+            ActionPlan::ProposalSubmit(_) => "proposal submission",
+            ActionPlan::ProposalWithdraw(_) => "proposal withdrawal",
+            ActionPlan::DelegatorVote(_) => "delegator vote",
+            ActionPlan::ValidatorVote(_) => "validator vote",
+            ActionPlan::ProposalDepositClaim(_) => "proposal deposit claim",
+            ActionPlan::PositionOpen(_) => "liquidity position opening",
+            ActionPlan::PositionClose(_) => "liquidity position closing",
+            ActionPlan::PositionWithdraw(_) => "liquidity position withdrawal",
+            ActionPlan::PositionRewardClaim(_) => "liquidity position reward claim",
+            ActionPlan::DaoSpend(_) => "DAO spend",
+            ActionPlan::DaoOutput(_) => "DAO output",
+            ActionPlan::DaoDeposit(_) => "DAO deposit",
+            ActionPlan::Withdrawal(_) => "ICS-20 withdrawal",
+        }
+    }
+
     pub fn balance(&self) -> Balance {
         use ActionPlan::*;
 
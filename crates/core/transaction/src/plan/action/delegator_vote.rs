@@ -1,3 +1,4 @@
+use anyhow::Context;
 use ark_ff::UniformRand;
 use decaf377::{FieldExt, Fq, Fr};
 use decaf377_rdsa::{Signature, SpendAuth};
@@ -11,7 +12,10 @@ use penumbra_tct as tct;
 use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 
-use crate::action::{DelegatorVote, DelegatorVoteBody, Vote};
+use crate::{
+    action::{DelegatorVote, DelegatorVoteBody},
+    vote::SplitVote,
+};
 
 /// A plan to vote as a delegator.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,8 +25,8 @@ pub struct DelegatorVotePlan {
     pub proposal: u64,
     /// The start position of the proposal.
     pub start_position: tct::Position,
-    /// The vote to cast.
-    pub vote: Vote,
+    /// The vote to cast, possibly split between several options.
+    pub vote: SplitVote,
     /// A staked note that was spendable before the proposal started.
     pub staked_note: Note,
     /// The unbonded amount corresponding to the staked note.
@@ -44,7 +48,7 @@ impl DelegatorVotePlan {
         rng: &mut R,
         proposal: u64,
         start_position: tct::Position,
-        vote: Vote,
+        vote: SplitVote,
         staked_note: Note,
         position: tct::Position,
         unbonded_amount: Amount,
@@ -134,9 +138,11 @@ impl DelegatorVotePlan {
 
 impl From<DelegatorVotePlan> for pb::DelegatorVotePlan {
     fn from(inner: DelegatorVotePlan) -> Self {
+        let (vote, split_vote) = inner.vote.to_proto_fields();
         pb::DelegatorVotePlan {
             proposal: inner.proposal,
-            vote: Some(inner.vote.into()),
+            vote,
+            split_vote,
             start_position: inner.start_position.into(),
             staked_note: Some(inner.staked_note.into()),
             unbonded_amount: Some(inner.unbonded_amount.into()),
@@ -164,10 +170,8 @@ impl TryFrom<pb::DelegatorVotePlan> for DelegatorVotePlan {
         Ok(DelegatorVotePlan {
             proposal: value.proposal,
             start_position: value.start_position.into(),
-            vote: value
-                .vote
-                .ok_or_else(|| anyhow::anyhow!("missing vote in `DelegatorVotePlan`"))?
-                .try_into()?,
+            vote: SplitVote::try_from_proto_fields(value.vote, value.split_vote)
+                .context("invalid vote in `DelegatorVotePlan`")?,
             staked_note: value
                 .staked_note
                 .ok_or_else(|| anyhow::anyhow!("missing staked note in `DelegatorVotePlan`"))?
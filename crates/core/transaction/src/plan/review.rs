@@ -0,0 +1,526 @@
+use std::{collections::BTreeMap, fmt};
+
+use penumbra_asset::{asset, Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_crypto::{AddressView, FullViewingKey, NoteView};
+use penumbra_dex::swap::SwapPlaintext;
+use penumbra_ibc::Ics20Withdrawal;
+use penumbra_stake::{Delegate, Undelegate};
+
+use super::{ActionPlan, TransactionPlan};
+use crate::TransactionPerspective;
+
+/// A human-readable summary of a [`TransactionPlan`], as seen by a particular
+/// [`FullViewingKey`], for review before the plan is authorized.
+///
+/// The [`fmt::Display`] implementation renders the review as text.
+#[derive(Clone, Debug)]
+pub struct PlanReview {
+    pub chain_id: String,
+    pub expiry_height: u64,
+    pub fee: Value,
+    /// The notes spent by the transaction.
+    pub spends: Vec<NoteView>,
+    /// The notes created by the transaction's outputs.
+    pub outputs: Vec<NoteView>,
+    /// The swaps submitted by the transaction, with the addresses their outputs will be
+    /// claimed to in a later transaction.
+    pub swaps: Vec<(SwapPlaintext, AddressView)>,
+    /// The notes created by the transaction's swap claims.
+    pub swap_claims: Vec<NoteView>,
+    pub delegations: Vec<Delegate>,
+    pub undelegations: Vec<Undelegate>,
+    /// The ICS-20 transfers of funds out of Penumbra made by the transaction.
+    pub ics20_withdrawals: Vec<Ics20Withdrawal>,
+    /// Descriptions of any other actions in the transaction.
+    pub other_actions: Vec<&'static str>,
+    pub memo: Option<String>,
+    /// The net change in the balance of each of the viewer's accounts.
+    pub balance_changes: BTreeMap<u32, Balance>,
+    denoms: asset::Cache,
+}
+
+impl TransactionPlan {
+    /// Constructs the [`TransactionPerspective`] of this plan for the given
+    /// [`FullViewingKey`], with the given cache used to name assets.
+    ///
+    /// Since the plan hasn't been built, payload keys and the transaction ID are left unset.
+    pub fn perspective(
+        &self,
+        fvk: &FullViewingKey,
+        denoms: asset::Cache,
+    ) -> TransactionPerspective {
+        let mut txp = TransactionPerspective {
+            denoms,
+            ..Default::default()
+        };
+
+        let mut addresses = Vec::new();
+        for action in &self.actions {
+            match action {
+                ActionPlan::Spend(spend) => {
+                    let commitment = spend.note.commit();
+                    txp.spend_nullifiers.insert(
+                        fvk.derive_nullifier(spend.position, &commitment),
+                        spend.note.clone(),
+                    );
+                    addresses.push(spend.note.address());
+                }
+                ActionPlan::Output(output) => {
+                    let note = output.output_note();
+                    txp.advice_notes.insert(note.commit(), note);
+                    addresses.push(output.dest_address);
+                }
+                ActionPlan::Swap(swap) => {
+                    addresses.push(swap.swap_plaintext.claim_address);
+                }
+                ActionPlan::SwapClaim(claim) => {
+                    let (note_1, note_2) = claim.swap_plaintext.output_notes(&claim.output_data);
+                    txp.advice_notes.insert(note_1.commit(), note_1);
+                    txp.advice_notes.insert(note_2.commit(), note_2);
+                    addresses.push(claim.swap_plaintext.claim_address);
+                }
+                _ => {}
+            }
+        }
+
+        for address in addresses {
+            if let view @ AddressView::Visible { .. } = fvk.view_address(address) {
+                if !txp.address_views.contains(&view) {
+                    txp.address_views.push(view);
+                }
+            }
+        }
+
+        txp
+    }
+
+    /// Summarizes this plan from the point of view of the given [`FullViewingKey`], for review
+    /// before authorization.
+    pub fn review(&self, fvk: &FullViewingKey, denoms: asset::Cache) -> PlanReview {
+        let txp = self.perspective(fvk, denoms);
+
+        let mut review = PlanReview {
+            chain_id: self.chain_id.clone(),
+            expiry_height: self.expiry_height,
+            fee: self.fee.0,
+            spends: Vec::new(),
+            outputs: Vec::new(),
+            swaps: Vec::new(),
+            swap_claims: Vec::new(),
+            delegations: Vec::new(),
+            undelegations: Vec::new(),
+            ics20_withdrawals: Vec::new(),
+            other_actions: Vec::new(),
+            memo: self
+                .memo_plan
+                .as_ref()
+                .map(|memo| memo.plaintext.text.clone())
+                .filter(|text| !text.is_empty()),
+            balance_changes: BTreeMap::new(),
+            denoms: txp.denoms.clone(),
+        };
+
+        for action in &self.actions {
+            match action {
+                ActionPlan::Spend(spend) => {
+                    let view = txp.view_note(spend.note.clone());
+                    if let AddressView::Visible { index, .. } = &view.address {
+                        *review.balance_changes.entry(index.account).or_default() -=
+                            view.value.value();
+                    }
+                    review.spends.push(view);
+                }
+                ActionPlan::Output(output) => {
+                    let view = txp.view_note(output.output_note());
+                    if let AddressView::Visible { index, .. } = &view.address {
+                        *review.balance_changes.entry(index.account).or_default() +=
+                            view.value.value();
+                    }
+                    review.outputs.push(view);
+                }
+                ActionPlan::Swap(swap) => review.swaps.push((
+                    swap.swap_plaintext.clone(),
+                    fvk.view_address(swap.swap_plaintext.claim_address),
+                )),
+                ActionPlan::SwapClaim(claim) => {
+                    let (note_1, note_2) = claim.swap_plaintext.output_notes(&claim.output_data);
+                    for note in [note_1, note_2] {
+                        // One of the two outputs is usually empty.
+                        if note.amount().value() == 0 {
+                            continue;
+                        }
+                        let view = txp.view_note(note);
+                        if let AddressView::Visible { index, .. } = &view.address {
+                            *review.balance_changes.entry(index.account).or_default() +=
+                                view.value.value();
+                        }
+                        review.swap_claims.push(view);
+                    }
+                }
+                ActionPlan::Delegate(delegate) => review.delegations.push(delegate.clone()),
+                ActionPlan::Undelegate(undelegate) => review.undelegations.push(undelegate.clone()),
+                ActionPlan::Withdrawal(withdrawal) => {
+                    review.ics20_withdrawals.push(withdrawal.clone())
+                }
+                other => review.other_actions.push(other.kind()),
+            }
+        }
+
+        review
+    }
+}
+
+impl PlanReview {
+    fn describe_address(address: &AddressView) -> String {
+        match address {
+            AddressView::Visible { index, .. } if index.is_ephemeral() => {
+                format!("your account {} (one-time address)", index.account)
+            }
+            AddressView::Visible { index, .. } => format!("your account {}", index.account),
+            AddressView::Opaque { address } => address.to_string(),
+        }
+    }
+
+    fn format_value(&self, value: Value) -> String {
+        value.format(&self.denoms)
+    }
+}
+
+impl fmt::Display for PlanReview {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Transaction on chain {}", self.chain_id)?;
+        if self.expiry_height != 0 {
+            writeln!(f, "Expires at height {}", self.expiry_height)?;
+        }
+        writeln!(f, "Fee: {}", self.format_value(self.fee))?;
+
+        if !self.spends.is_empty() {
+            writeln!(f, "Spends:")?;
+            for spend in &self.spends {
+                writeln!(
+                    f,
+                    "  {} from {}",
+                    self.format_value(spend.value.value()),
+                    Self::describe_address(&spend.address)
+                )?;
+            }
+        }
+
+        // Group outputs by recipient, so that change is easy to tell apart from payments.
+        let mut recipients: Vec<(String, Vec<String>)> = Vec::new();
+        for output in &self.outputs {
+            let recipient = Self::describe_address(&output.address);
+            let value = self.format_value(output.value.value());
+            match recipients.iter_mut().find(|(r, _)| *r == recipient) {
+                Some((_, values)) => values.push(value),
+                None => recipients.push((recipient, vec![value])),
+            }
+        }
+        if !recipients.is_empty() {
+            writeln!(f, "Outputs:")?;
+            for (recipient, values) in recipients {
+                writeln!(f, "  to {}: {}", recipient, values.join(", "))?;
+            }
+        }
+
+        if !self.swaps.is_empty() {
+            writeln!(f, "Swaps:")?;
+            for (swap, claim_address) in &self.swaps {
+                let pair = &swap.trading_pair;
+                let (input, from, to) = if swap.delta_1_i.value() == 0 {
+                    (swap.delta_2_i, pair.asset_2(), pair.asset_1())
+                } else {
+                    (swap.delta_1_i, pair.asset_1(), pair.asset_2())
+                };
+                let to = self
+                    .denoms
+                    .get(&to)
+                    .map_or_else(|| to.to_string(), |denom| denom.to_string());
+                writeln!(
+                    f,
+                    "  {} for {}, claimed to {} with fee {}",
+                    self.format_value(Value {
+                        amount: input,
+                        asset_id: from,
+                    }),
+                    to,
+                    Self::describe_address(claim_address),
+                    self.format_value(swap.claim_fee.0),
+                )?;
+            }
+        }
+
+        if !self.swap_claims.is_empty() {
+            writeln!(f, "Swap claims:")?;
+            for claim in &self.swap_claims {
+                writeln!(
+                    f,
+                    "  {} to {}",
+                    self.format_value(claim.value.value()),
+                    Self::describe_address(&claim.address)
+                )?;
+            }
+        }
+
+        if !self.delegations.is_empty() {
+            writeln!(f, "Delegations:")?;
+            for delegation in &self.delegations {
+                writeln!(
+                    f,
+                    "  {} to {}",
+                    self.format_value(Value {
+                        amount: delegation.unbonded_amount,
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    }),
+                    delegation.validator_identity
+                )?;
+            }
+        }
+
+        if !self.undelegations.is_empty() {
+            writeln!(f, "Undelegations:")?;
+            for undelegation in &self.undelegations {
+                writeln!(
+                    f,
+                    "  {} from {}",
+                    self.format_value(Value {
+                        amount: undelegation.delegation_amount,
+                        asset_id: undelegation.delegation_token().id(),
+                    }),
+                    undelegation.validator_identity
+                )?;
+            }
+        }
+
+        if !self.ics20_withdrawals.is_empty() {
+            writeln!(f, "ICS-20 withdrawals:")?;
+            for withdrawal in &self.ics20_withdrawals {
+                writeln!(
+                    f,
+                    "  {} to {} on {}, over {}",
+                    self.format_value(withdrawal.value()),
+                    withdrawal.destination_chain_address,
+                    withdrawal.destination_chain_id,
+                    withdrawal.source_channel,
+                )?;
+            }
+        }
+
+        if !self.other_actions.is_empty() {
+            writeln!(f, "Other actions:")?;
+            for action in &self.other_actions {
+                writeln!(f, "  {action}")?;
+            }
+        }
+
+        if let Some(memo) = &self.memo {
+            writeln!(f, "Memo: {memo}")?;
+        }
+
+        if !self.balance_changes.is_empty() {
+            writeln!(f, "Net balance change:")?;
+            for (account, balance) in &self.balance_changes {
+                let changes = balance
+                    .provided()
+                    .map(|value| format!("+{}", self.format_value(value)))
+                    .chain(
+                        balance
+                            .required()
+                            .map(|value| format!("-{}", self.format_value(value))),
+                    )
+                    .collect::<Vec<_>>();
+                if changes.is_empty() {
+                    writeln!(f, "  account {account}: no change")?;
+                } else {
+                    writeln!(f, "  account {account}: {}", changes.join(", "))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decaf377_rdsa::{SigningKey, SpendAuth};
+    use ibc_types2::core::channel::{ChannelId, PortId};
+    use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_crypto::{
+        keys::{SeedPhrase, SpendKey},
+        Address, AddressView, Note,
+    };
+    use penumbra_dex::{
+        swap::{SwapPlaintext, SwapPlan},
+        TradingPair,
+    };
+    use penumbra_fee::Fee;
+    use penumbra_ibc::Ics20Withdrawal;
+    use penumbra_shielded_pool::{OutputPlan, SpendPlan};
+    use penumbra_stake::{Delegate, IdentityKey, Undelegate};
+    use rand_core::OsRng;
+
+    use crate::plan::{ActionPlan, TransactionPlan};
+
+    fn plan(actions: Vec<ActionPlan>) -> TransactionPlan {
+        TransactionPlan {
+            expiry_height: 0,
+            fee: Fee::from_staking_token_amount(1000u64.into()),
+            chain_id: "penumbra-test".to_string(),
+            actions,
+            clue_plans: vec![],
+            memo_plan: None,
+        }
+    }
+
+    #[test]
+    fn review_tracks_balance_changes_per_account() {
+        let sk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let fvk = sk.full_viewing_key();
+        let (account_0, _) = fvk.incoming().payment_address(0u32.into());
+        let (account_1, _) = fvk.incoming().payment_address(1u32.into());
+        let recipient = Address::dummy(&mut OsRng);
+
+        let upenumbra = |amount: u64| Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+
+        let plan = TransactionPlan {
+            expiry_height: 0,
+            fee: Fee::from_staking_token_amount(1000u64.into()),
+            chain_id: "penumbra-test".to_string(),
+            actions: vec![
+                SpendPlan::new(
+                    &mut OsRng,
+                    Note::generate(&mut OsRng, &account_0, upenumbra(10000)),
+                    0u64.into(),
+                )
+                .into(),
+                OutputPlan::new(&mut OsRng, upenumbra(4000), recipient).into(),
+                OutputPlan::new(&mut OsRng, upenumbra(5000), account_1).into(),
+            ],
+            clue_plans: vec![],
+            memo_plan: None,
+        };
+
+        let review = plan.review(fvk, asset::Cache::with_known_assets());
+
+        assert_eq!(review.spends.len(), 1);
+        assert_eq!(review.outputs.len(), 2);
+
+        let account_0_change = &review.balance_changes[&0];
+        assert_eq!(
+            account_0_change.required().collect::<Vec<_>>(),
+            vec![upenumbra(10000)]
+        );
+        assert_eq!(account_0_change.provided().count(), 0);
+
+        let account_1_change = &review.balance_changes[&1];
+        assert_eq!(
+            account_1_change.provided().collect::<Vec<_>>(),
+            vec![upenumbra(5000)]
+        );
+
+        let rendered = review.to_string();
+        assert!(rendered.contains(&format!("to {recipient}: 4mpenumbra")));
+        assert!(rendered.contains("to your account 1: 5mpenumbra"));
+        assert!(rendered.contains("account 0: -10mpenumbra"));
+    }
+
+    #[test]
+    fn review_shows_swaps_and_where_they_are_claimed() {
+        let sk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let fvk = sk.full_viewing_key();
+        let (account_2, _) = fvk.incoming().payment_address(2u32.into());
+        let gm = asset::REGISTRY.parse_denom("ugm").unwrap().id();
+
+        // Swap 1penumbra for gm, whichever side of the pair the staking token is on.
+        let pair = TradingPair::new(*STAKING_TOKEN_ASSET_ID, gm);
+        let (delta_1, delta_2) = if pair.asset_1() == *STAKING_TOKEN_ASSET_ID {
+            (1_000_000u64.into(), 0u64.into())
+        } else {
+            (0u64.into(), 1_000_000u64.into())
+        };
+        let swap_plaintext = SwapPlaintext::new(
+            &mut OsRng,
+            pair,
+            delta_1,
+            delta_2,
+            Fee::from_staking_token_amount(2000u64.into()),
+            account_2,
+        );
+
+        let review = plan(vec![SwapPlan::new(&mut OsRng, swap_plaintext).into()])
+            .review(fvk, asset::Cache::with_known_assets());
+
+        assert_eq!(review.swaps.len(), 1);
+        assert!(matches!(
+            &review.swaps[0].1,
+            AddressView::Visible { index, .. } if index.account == 2
+        ));
+
+        let rendered = review.to_string();
+        assert!(rendered.contains("1penumbra for "));
+        assert!(rendered.contains("claimed to your account 2 with fee 2mpenumbra"));
+    }
+
+    #[test]
+    fn review_shows_delegations_and_undelegations() {
+        let sk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let fvk = sk.full_viewing_key();
+        let validator = IdentityKey(SigningKey::<SpendAuth>::new(OsRng).into());
+
+        let review = plan(vec![
+            Delegate {
+                validator_identity: validator,
+                epoch_index: 0,
+                unbonded_amount: 2_000_000u64.into(),
+                delegation_amount: 2_000_000u64.into(),
+            }
+            .into(),
+            Undelegate {
+                validator_identity: validator,
+                start_epoch_index: 0,
+                unbonded_amount: 1_000_000u64.into(),
+                delegation_amount: 1_000_000u64.into(),
+            }
+            .into(),
+        ])
+        .review(fvk, asset::Cache::with_known_assets());
+
+        assert_eq!(review.delegations.len(), 1);
+        assert_eq!(review.undelegations.len(), 1);
+
+        let rendered = review.to_string();
+        assert!(rendered.contains(&format!("Delegations:\n  2penumbra to {validator}")));
+        assert!(rendered.contains(&format!("from {validator}")));
+    }
+
+    #[test]
+    fn review_shows_where_ics20_withdrawals_are_sent() {
+        let sk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0);
+        let fvk = sk.full_viewing_key();
+        let (return_address, _) = fvk.incoming().payment_address(0u32.into());
+
+        let review = plan(vec![ActionPlan::Withdrawal(Ics20Withdrawal {
+            destination_chain_id: "osmosis-1".to_string(),
+            amount: 3_000_000u64.into(),
+            denom: asset::REGISTRY.parse_denom("upenumbra").unwrap(),
+            destination_chain_address: "osmo1recipient".to_string(),
+            return_address,
+            timeout_height: 100,
+            timeout_time: 0,
+            source_port: PortId::transfer(),
+            source_channel: ChannelId::new(4),
+            memo: String::new(),
+        })])
+        .review(fvk, asset::Cache::with_known_assets());
+
+        assert_eq!(review.ics20_withdrawals.len(), 1);
+        assert!(review.other_actions.is_empty());
+        assert!(review
+            .to_string()
+            .contains("3penumbra to osmo1recipient on osmosis-1, over channel-4"));
+    }
+}
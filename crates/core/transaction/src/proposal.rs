@@ -3,7 +3,8 @@ use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-use penumbra_chain::params::ChainParameters;
+use penumbra_chain::params::{change::ParameterChange, ChainParameters};
+use penumbra_dao::DaoGrant;
 use penumbra_proto::{core::governance::v1alpha1 as pb, DomainType, TypeUrl};
 
 use crate::plan::TransactionPlan;
@@ -91,6 +92,36 @@ impl From<Proposal> for pb::Proposal {
                     }),
                 });
             }
+            ProposalPayload::UpgradePlan { height, version } => {
+                proposal.upgrade_plan = Some(pb::proposal::UpgradePlan { height, version });
+            }
+            ProposalPayload::PartialParameterChange { changes } => {
+                proposal.partial_parameter_change = Some(pb::proposal::PartialParameterChange {
+                    changes: changes
+                        .into_iter()
+                        .map(|change| pb::proposal::partial_parameter_change::Change {
+                            parameter: change.parameter,
+                            old_value: change.old_value,
+                            new_value: change.new_value,
+                        })
+                        .collect(),
+                });
+            }
+            ProposalPayload::DaoGrant { grant } => {
+                proposal.dao_grant = Some(grant.into());
+            }
+            ProposalPayload::DaoGrantCancel { grant_id } => {
+                proposal.dao_grant_cancel = Some(pb::proposal::DaoGrantCancel { grant_id });
+            }
+            ProposalPayload::IbcClientSubstitution {
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                proposal.ibc_client_substitution = Some(pb::proposal::IbcClientSubstitution {
+                    subject_client_id,
+                    substitute_client_id,
+                });
+            }
         }
         proposal
     }
@@ -142,6 +173,36 @@ impl TryFrom<pb::Proposal> for Proposal {
                         TransactionPlan::decode(transaction_plan.value)?
                     },
                 }
+            } else if let Some(upgrade_plan) = inner.upgrade_plan {
+                ProposalPayload::UpgradePlan {
+                    height: upgrade_plan.height,
+                    version: upgrade_plan.version,
+                }
+            } else if let Some(partial_parameter_change) = inner.partial_parameter_change {
+                ProposalPayload::PartialParameterChange {
+                    changes: partial_parameter_change
+                        .changes
+                        .into_iter()
+                        .map(|change| ParameterChange {
+                            parameter: change.parameter,
+                            old_value: change.old_value,
+                            new_value: change.new_value,
+                        })
+                        .collect(),
+                }
+            } else if let Some(dao_grant) = inner.dao_grant {
+                ProposalPayload::DaoGrant {
+                    grant: dao_grant.try_into()?,
+                }
+            } else if let Some(dao_grant_cancel) = inner.dao_grant_cancel {
+                ProposalPayload::DaoGrantCancel {
+                    grant_id: dao_grant_cancel.grant_id,
+                }
+            } else if let Some(ibc_client_substitution) = inner.ibc_client_substitution {
+                ProposalPayload::IbcClientSubstitution {
+                    subject_client_id: ibc_client_substitution.subject_client_id,
+                    substitute_client_id: ibc_client_substitution.substitute_client_id,
+                }
             } else {
                 return Err(anyhow::anyhow!(
                     "missing proposal payload or unknown proposal type"
@@ -175,6 +236,21 @@ pub enum ProposalKind {
     /// A DAO spend proposal.
     #[cfg_attr(feature = "clap", clap(display_order = 400))]
     DaoSpend,
+    /// An upgrade plan proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 500))]
+    UpgradePlan,
+    /// A partial parameter change proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 600))]
+    PartialParameterChange,
+    /// A DAO grant proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 700))]
+    DaoGrant,
+    /// A DAO grant cancellation proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 800))]
+    DaoGrantCancel,
+    /// An IBC client substitution proposal.
+    #[cfg_attr(feature = "clap", clap(display_order = 900))]
+    IbcClientSubstitution,
 }
 
 impl FromStr for ProposalKind {
@@ -186,6 +262,11 @@ impl FromStr for ProposalKind {
             "emergency" => Ok(ProposalKind::Emergency),
             "parameterchange" => Ok(ProposalKind::ParameterChange),
             "daospend" => Ok(ProposalKind::DaoSpend),
+            "upgradeplan" => Ok(ProposalKind::UpgradePlan),
+            "partialparameterchange" => Ok(ProposalKind::PartialParameterChange),
+            "daogrant" => Ok(ProposalKind::DaoGrant),
+            "daograntcancel" => Ok(ProposalKind::DaoGrantCancel),
+            "ibcclientsubstitution" => Ok(ProposalKind::IbcClientSubstitution),
             _ => Err(anyhow::anyhow!("invalid proposal kind: {}", s)),
         }
     }
@@ -199,6 +280,11 @@ impl Proposal {
             ProposalPayload::Emergency { .. } => ProposalKind::Emergency,
             ProposalPayload::ParameterChange { .. } => ProposalKind::ParameterChange,
             ProposalPayload::DaoSpend { .. } => ProposalKind::DaoSpend,
+            ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
+            ProposalPayload::PartialParameterChange { .. } => ProposalKind::PartialParameterChange,
+            ProposalPayload::DaoGrant { .. } => ProposalKind::DaoGrant,
+            ProposalPayload::DaoGrantCancel { .. } => ProposalKind::DaoGrantCancel,
+            ProposalPayload::IbcClientSubstitution { .. } => ProposalKind::IbcClientSubstitution,
         }
     }
 }
@@ -244,6 +330,50 @@ pub enum ProposalPayload {
         /// action.
         transaction_plan: TransactionPlan,
     },
+    /// An upgrade plan proposal schedules a coordinated upgrade of the software run by the chain.
+    UpgradePlan {
+        /// The height of the last block to be processed before the upgrade.
+        ///
+        /// When the proposal is passed, nodes will halt after committing this block.
+        height: u64,
+        /// The software version which will process blocks after the upgrade height.
+        ///
+        /// Nodes which are not running this version will refuse to process any further blocks.
+        version: String,
+    },
+    /// A partial parameter change proposal describes a change to only some of the chain
+    /// parameters, which should take effect when the proposal is passed.
+    PartialParameterChange {
+        /// The parameters to change, each with its expected old value.
+        ///
+        /// Even if the proposal passes, the update will not be applied if any of the named
+        /// parameters does not have its expected old value. Parameters which are not named may
+        /// change freely in the meantime.
+        changes: Vec<ParameterChange>,
+    },
+    /// A DAO grant proposal schedules recurring transparent outputs from the DAO to a single
+    /// recipient, paid automatically at epoch boundaries once the proposal is passed.
+    DaoGrant {
+        /// The grant to schedule.
+        ///
+        /// The first tranche is paid at the end of the epoch in which the proposal passes. The
+        /// grant is identified by the ID of this proposal.
+        grant: DaoGrant,
+    },
+    /// A DAO grant cancellation proposal stops any further tranches of a previously passed DAO
+    /// grant from being paid.
+    DaoGrantCancel {
+        /// The ID of the grant to cancel, which is the ID of the proposal that created it.
+        grant_id: u64,
+    },
+    /// An IBC client substitution proposal recovers a frozen or expired IBC client, by replacing
+    /// its state with that of a healthy substitute client for the same counterparty chain.
+    IbcClientSubstitution {
+        /// The ID of the frozen or expired client to recover.
+        subject_client_id: String,
+        /// The ID of the active client whose state will replace that of the subject client.
+        substitute_client_id: String,
+    },
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
@@ -263,6 +393,26 @@ pub enum ProposalPayloadToml {
     DaoSpend {
         transaction: String,
     },
+    UpgradePlan {
+        height: u64,
+        version: String,
+    },
+    PartialParameterChange {
+        changes: Vec<ParameterChange>,
+    },
+    DaoGrant {
+        value: String,
+        address: String,
+        period_epochs: u64,
+        count: u64,
+    },
+    DaoGrantCancel {
+        grant_id: u64,
+    },
+    IbcClientSubstitution {
+        subject_client_id: String,
+        substitute_client_id: String,
+    },
 }
 
 impl TryFrom<ProposalPayloadToml> for ProposalPayload {
@@ -284,6 +434,37 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
                 ))
                 .context("couldn't decode transaction plan from proto")?,
             },
+            ProposalPayloadToml::UpgradePlan { height, version } => {
+                ProposalPayload::UpgradePlan { height, version }
+            }
+            ProposalPayloadToml::PartialParameterChange { changes } => {
+                ProposalPayload::PartialParameterChange { changes }
+            }
+            ProposalPayloadToml::DaoGrant {
+                value,
+                address,
+                period_epochs,
+                count,
+            } => ProposalPayload::DaoGrant {
+                grant: DaoGrant {
+                    value: value.parse().context("couldn't parse DAO grant value")?,
+                    address: address
+                        .parse()
+                        .context("couldn't parse DAO grant address")?,
+                    period_epochs,
+                    count,
+                },
+            },
+            ProposalPayloadToml::DaoGrantCancel { grant_id } => {
+                ProposalPayload::DaoGrantCancel { grant_id }
+            }
+            ProposalPayloadToml::IbcClientSubstitution {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayload::IbcClientSubstitution {
+                subject_client_id,
+                substitute_client_id,
+            },
         })
     }
 }
@@ -304,6 +485,29 @@ impl From<ProposalPayload> for ProposalPayloadToml {
                     transaction_plan.encode_to_vec(),
                 ),
             },
+            ProposalPayload::UpgradePlan { height, version } => {
+                ProposalPayloadToml::UpgradePlan { height, version }
+            }
+            ProposalPayload::PartialParameterChange { changes } => {
+                ProposalPayloadToml::PartialParameterChange { changes }
+            }
+            ProposalPayload::DaoGrant { grant } => ProposalPayloadToml::DaoGrant {
+                // Written in base units with the asset ID, which can always be parsed back
+                value: format!("{}{}", grant.value.amount, grant.value.asset_id),
+                address: grant.address.to_string(),
+                period_epochs: grant.period_epochs,
+                count: grant.count,
+            },
+            ProposalPayload::DaoGrantCancel { grant_id } => {
+                ProposalPayloadToml::DaoGrantCancel { grant_id }
+            }
+            ProposalPayload::IbcClientSubstitution {
+                subject_client_id,
+                substitute_client_id,
+            } => ProposalPayloadToml::IbcClientSubstitution {
+                subject_client_id,
+                substitute_client_id,
+            },
         }
     }
 }
@@ -324,6 +528,65 @@ impl ProposalPayload {
     pub fn is_dao_spend(&self) -> bool {
         matches!(self, ProposalPayload::DaoSpend { .. })
     }
+
+    pub fn is_upgrade_plan(&self) -> bool {
+        matches!(self, ProposalPayload::UpgradePlan { .. })
+    }
+
+    pub fn is_partial_parameter_change(&self) -> bool {
+        matches!(self, ProposalPayload::PartialParameterChange { .. })
+    }
+
+    pub fn is_dao_grant(&self) -> bool {
+        matches!(self, ProposalPayload::DaoGrant { .. })
+    }
+
+    pub fn is_dao_grant_cancel(&self) -> bool {
+        matches!(self, ProposalPayload::DaoGrantCancel { .. })
+    }
+
+    pub fn is_ibc_client_substitution(&self) -> bool {
+        matches!(self, ProposalPayload::IbcClientSubstitution { .. })
+    }
+}
+
+/// A software upgrade scheduled by a passed upgrade plan proposal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::proposal::UpgradePlan",
+    into = "pb::proposal::UpgradePlan"
+)]
+pub struct UpgradePlan {
+    /// The height of the last block to be processed before the upgrade.
+    pub height: u64,
+    /// The software version which will process blocks after the upgrade height.
+    pub version: String,
+}
+
+impl From<UpgradePlan> for pb::proposal::UpgradePlan {
+    fn from(plan: UpgradePlan) -> Self {
+        pb::proposal::UpgradePlan {
+            height: plan.height,
+            version: plan.version,
+        }
+    }
+}
+
+impl From<pb::proposal::UpgradePlan> for UpgradePlan {
+    fn from(msg: pb::proposal::UpgradePlan) -> Self {
+        UpgradePlan {
+            height: msg.height,
+            version: msg.version,
+        }
+    }
+}
+
+impl TypeUrl for UpgradePlan {
+    const TYPE_URL: &'static str = "/penumbra.core.governance.v1alpha1.Proposal.UpgradePlan";
+}
+
+impl DomainType for UpgradePlan {
+    type Proto = pb::proposal::UpgradePlan;
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,6 +84,232 @@ impl TryFrom<pb::Vote> for Vote {
     }
 }
 
+/// A vote on a proposal which divides its voting power between several options.
+///
+/// The weight of each option is given in basis points, and the weights always sum to 10000. A
+/// [`Vote`] is equivalent to a [`SplitVote`] that assigns all of its weight to a single option.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(try_from = "pb::SplitVote", into = "pb::SplitVote")]
+pub struct SplitVote {
+    yes_bps: u16,
+    no_bps: u16,
+    abstain_bps: u16,
+}
+
+impl SplitVote {
+    /// The total weight of a split vote, in basis points.
+    pub const TOTAL_BPS: u16 = 10_000;
+
+    /// Create a new split vote from the weight of each option, in basis points.
+    ///
+    /// Returns an error if the weights do not sum to exactly 10000.
+    pub fn new(yes_bps: u16, no_bps: u16, abstain_bps: u16) -> anyhow::Result<Self> {
+        let total = yes_bps as u32 + no_bps as u32 + abstain_bps as u32;
+        if total != Self::TOTAL_BPS as u32 {
+            return Err(anyhow!(
+                "split vote weights must sum to {} bps, but sum to {} bps",
+                Self::TOTAL_BPS,
+                total
+            ));
+        }
+        Ok(Self {
+            yes_bps,
+            no_bps,
+            abstain_bps,
+        })
+    }
+
+    /// The weight of the vote in favor of the proposal, in basis points.
+    pub fn yes_bps(&self) -> u16 {
+        self.yes_bps
+    }
+
+    /// The weight of the vote against the proposal, in basis points.
+    pub fn no_bps(&self) -> u16 {
+        self.no_bps
+    }
+
+    /// The weight of the vote abstaining from the proposal, in basis points.
+    pub fn abstain_bps(&self) -> u16 {
+        self.abstain_bps
+    }
+
+    /// If all of the weight of this split vote is assigned to a single option, return that option.
+    pub fn single(&self) -> Option<Vote> {
+        match (self.yes_bps, self.no_bps, self.abstain_bps) {
+            (Self::TOTAL_BPS, 0, 0) => Some(Vote::Yes),
+            (0, Self::TOTAL_BPS, 0) => Some(Vote::No),
+            (0, 0, Self::TOTAL_BPS) => Some(Vote::Abstain),
+            _ => None,
+        }
+    }
+
+    /// Divide the given voting power between the options of this split vote.
+    ///
+    /// The returned powers always sum to exactly `power`: any remainder left over from rounding
+    /// down is assigned to the option with the greatest weight (preferring yes, then no, then
+    /// abstain in case of a tie).
+    pub fn split_power(&self, power: u64) -> [(Vote, u64); 3] {
+        let share = |bps: u16| ((power as u128 * bps as u128) / Self::TOTAL_BPS as u128) as u64;
+
+        let mut powers = [
+            (Vote::Yes, share(self.yes_bps)),
+            (Vote::No, share(self.no_bps)),
+            (Vote::Abstain, share(self.abstain_bps)),
+        ];
+
+        let remainder = power - powers.iter().map(|(_, power)| power).sum::<u64>();
+        let largest = [self.yes_bps, self.no_bps, self.abstain_bps]
+            .into_iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, bps)| *bps)
+            .map(|(i, _)| i)
+            .expect("there are three options");
+        powers[largest].1 += remainder;
+
+        powers
+    }
+}
+
+impl From<Vote> for SplitVote {
+    fn from(vote: Vote) -> Self {
+        let mut split = Self {
+            yes_bps: 0,
+            no_bps: 0,
+            abstain_bps: 0,
+        };
+        *match vote {
+            Vote::Yes => &mut split.yes_bps,
+            Vote::No => &mut split.no_bps,
+            Vote::Abstain => &mut split.abstain_bps,
+        } = Self::TOTAL_BPS;
+        split
+    }
+}
+
+impl FromStr for SplitVote {
+    type Err = anyhow::Error;
+
+    /// Parse a split vote from either a single vote (e.g. `yes`) or a comma-separated list of
+    /// percentages for each option (e.g. `60% yes, 40% no`). Options which are not mentioned are
+    /// assigned no weight.
+    fn from_str(s: &str) -> anyhow::Result<SplitVote> {
+        if let Ok(vote) = s.parse::<Vote>() {
+            return Ok(vote.into());
+        }
+
+        let (mut yes_bps, mut no_bps, mut abstain_bps) = (0u16, 0u16, 0u16);
+        for part in s.split(',') {
+            let (percent, vote) = part
+                .trim()
+                .split_once('%')
+                .ok_or_else(|| anyhow!("invalid split vote: {}", s))?;
+            let bps = percent
+                .trim()
+                .parse::<u16>()
+                .ok()
+                .and_then(|percent| percent.checked_mul(100))
+                .ok_or_else(|| anyhow!("invalid percentage in split vote: {}", s))?;
+            let weight = match vote.trim().parse::<Vote>()? {
+                Vote::Yes => &mut yes_bps,
+                Vote::No => &mut no_bps,
+                Vote::Abstain => &mut abstain_bps,
+            };
+            *weight = weight
+                .checked_add(bps)
+                .ok_or_else(|| anyhow!("invalid split vote: {}", s))?;
+        }
+
+        SplitVote::new(yes_bps, no_bps, abstain_bps)
+    }
+}
+
+impl Display for SplitVote {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), fmt::Error> {
+        if let Some(vote) = self.single() {
+            return write!(f, "{vote}");
+        }
+
+        let mut first = true;
+        for (vote, bps) in [
+            (Vote::Yes, self.yes_bps),
+            (Vote::No, self.no_bps),
+            (Vote::Abstain, self.abstain_bps),
+        ] {
+            if bps == 0 {
+                continue;
+            }
+            if !first {
+                write!(f, ", ")?;
+            }
+            first = false;
+            if bps % 100 == 0 {
+                write!(f, "{}% {vote}", bps / 100)?;
+            } else {
+                write!(f, "{}.{:02}% {vote}", bps / 100, bps % 100)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<SplitVote> for pb::SplitVote {
+    fn from(value: SplitVote) -> Self {
+        pb::SplitVote {
+            yes_bps: value.yes_bps.into(),
+            no_bps: value.no_bps.into(),
+            abstain_bps: value.abstain_bps.into(),
+        }
+    }
+}
+
+impl TryFrom<pb::SplitVote> for SplitVote {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::SplitVote) -> Result<Self, Self::Error> {
+        SplitVote::new(
+            msg.yes_bps.try_into()?,
+            msg.no_bps.try_into()?,
+            msg.abstain_bps.try_into()?,
+        )
+    }
+}
+
+impl TypeUrl for SplitVote {
+    const TYPE_URL: &'static str = "/penumbra.core.governance.v1alpha1.SplitVote";
+}
+
+impl DomainType for SplitVote {
+    type Proto = pb::SplitVote;
+}
+
+impl SplitVote {
+    /// Encode this split vote into the pair of `vote` and `split_vote` fields used by delegator
+    /// votes: a split vote which assigns all its weight to a single option is encoded as a plain
+    /// vote, for compatibility with clients which do not understand split votes.
+    pub(crate) fn to_proto_fields(self) -> (Option<pb::Vote>, Option<pb::SplitVote>) {
+        match self.single() {
+            Some(vote) => (Some(vote.into()), None),
+            None => (None, Some(self.into())),
+        }
+    }
+
+    /// Decode a split vote from the pair of `vote` and `split_vote` fields used by delegator
+    /// votes, exactly one of which must be set.
+    pub(crate) fn try_from_proto_fields(
+        vote: Option<pb::Vote>,
+        split_vote: Option<pb::SplitVote>,
+    ) -> anyhow::Result<Self> {
+        match (vote, split_vote) {
+            (Some(vote), None) => Ok(Vote::try_from(vote)?.into()),
+            (None, Some(split_vote)) => split_vote.try_into(),
+            (None, None) => Err(anyhow!("missing vote")),
+            (Some(_), Some(_)) => Err(anyhow!("both vote and split vote are set")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use proptest::proptest;
@@ -95,6 +321,24 @@ mod test {
             let vote2 = super::Vote::try_from(pb_vote).unwrap();
             assert_eq!(vote, vote2);
         }
+
+        #[test]
+        fn split_vote_power_sums_to_total(yes in 0u16..=10_000, no in 0u16..=10_000, power: u64) {
+            let no = no.min(super::SplitVote::TOTAL_BPS - yes);
+            let abstain = super::SplitVote::TOTAL_BPS - yes - no;
+            let split = super::SplitVote::new(yes, no, abstain).unwrap();
+            let total: u128 = split.split_power(power).iter().map(|(_, p)| *p as u128).sum();
+            assert_eq!(total, power as u128);
+        }
+
+        #[test]
+        fn split_vote_roundtrip_string(yes in 0u16..=100, no in 0u16..=100) {
+            let no = no.min(100 - yes);
+            let abstain = 100 - yes - no;
+            let split = super::SplitVote::new(yes * 100, no * 100, abstain * 100).unwrap();
+            let split2: super::SplitVote = split.to_string().parse().unwrap();
+            assert_eq!(split, split2);
+        }
     }
 }
 
@@ -1,6 +1,7 @@
 //! A basic software key management system that stores keys in memory but
 //! presents as an asynchronous signer.
 
+use penumbra_asset::asset;
 use penumbra_proto::custody::v1alpha1::{self as pb, AuthorizeResponse};
 use penumbra_transaction::AuthorizationData;
 use rand_core::OsRng;
@@ -28,6 +29,14 @@ impl SoftKms {
     #[tracing::instrument(skip(self, request), name = "softhsm_sign")]
     pub fn sign(&self, request: &AuthorizeRequest) -> anyhow::Result<AuthorizationData> {
         tracing::debug!(?request.plan);
+        // Present the plan in human-readable form, so that whoever is operating the KMS can see
+        // what is being signed. The review reveals amounts and counterparties, so it's only
+        // logged at debug level.
+        let review = request.plan.review(
+            self.config.spend_key.full_viewing_key(),
+            asset::Cache::with_known_assets(),
+        );
+        tracing::debug!("reviewing transaction plan:\n{review}");
 
         for policy in &self.config.auth_policy {
             policy.check(request)?;
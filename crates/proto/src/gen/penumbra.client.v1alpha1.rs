@@ -108,6 +108,29 @@ pub struct ValidatorInfoResponse {
         super::super::core::stake::v1alpha1::ValidatorInfo,
     >,
 }
+/// Requests a range of compact blocks, filtered by fuzzy message detection.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetectedCompactBlockRangeRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The start height of the range.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// The end height of the range, defaults to the latest block height.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+    /// If set, keeps the connection alive past `end_height`,
+    /// streaming new compact blocks as they are created.
+    #[prost(bool, tag = "4")]
+    pub keep_alive: bool,
+    /// The detection keys for the client's addresses, as 32-byte encodings.
+    ///
+    /// Servers may limit how many keys they accept: `pd` accepts at most 1024.
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub detection_keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionByNoteRequest {
@@ -484,6 +507,87 @@ pub struct ProposalRateDataResponse {
     #[prost(message, optional, tag = "1")]
     pub rate_data: ::core::option::Option<super::super::core::stake::v1alpha1::RateData>,
 }
+/// Requests the upgrade plan scheduled by the most recently passed upgrade proposal, if any.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpgradePlanRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpgradePlanResponse {
+    /// The scheduled upgrade plan, if any upgrade has been scheduled.
+    #[prost(message, optional, tag = "1")]
+    pub upgrade_plan: ::core::option::Option<
+        super::super::core::governance::v1alpha1::proposal::UpgradePlan,
+    >,
+    /// The current block height, so that clients can tell whether the upgrade is still pending.
+    #[prost(uint64, tag = "2")]
+    pub current_height: u64,
+}
+/// Requests a snapshot of the state commitment tree, so that a wallet created at a known height
+/// (its "birthday") can skip syncing the blocks before it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateCommitmentTreeFrontierRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The height of the wallet birthday. The snapshot returned is the latest one taken before this
+    /// height.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateCommitmentTreeFrontierResponse {
+    /// The height of the last block included in the snapshot; clients should begin syncing from the
+    /// following block.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The bincode-serialized state commitment tree as of `height`, with all commitments forgotten.
+    #[prost(bytes = "vec", tag = "2")]
+    pub tree: ::prost::alloc::vec::Vec<u8>,
+    /// The root of the tree as of `height`.
+    #[prost(message, optional, tag = "3")]
+    pub anchor: ::core::option::Option<super::super::core::crypto::v1alpha1::MerkleRoot>,
+    /// The current FMD parameters, which would otherwise have been found in the skipped blocks.
+    #[prost(message, optional, tag = "4")]
+    pub fmd_parameters: ::core::option::Option<
+        super::super::core::chain::v1alpha1::FmdParameters,
+    >,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEventsRequest {
+    /// The expected chain id (empty string if no expectation).
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The height from which to replay past events.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// If set, only events for this proposal are returned.
+    #[prost(uint64, optional, tag = "3")]
+    pub proposal_id: ::core::option::Option<u64>,
+    /// If set, keeps the connection alive after replaying past events,
+    /// streaming new events as blocks are committed.
+    #[prost(bool, tag = "4")]
+    pub keep_alive: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEventsResponse {
+    /// The height of the block in which the event occurred.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The event.
+    #[prost(message, optional, tag = "2")]
+    pub event: ::core::option::Option<
+        super::super::core::governance::v1alpha1::ProposalEvent,
+    >,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SimulateTradeRequest {
@@ -620,6 +724,9 @@ pub struct TxResult {
     pub gas_used: u64,
     #[prost(message, repeated, tag = "4")]
     pub tags: ::prost::alloc::vec::Vec<Tag>,
+    /// The result code of the transaction: zero if it was executed successfully.
+    #[prost(uint32, tag = "5")]
+    pub code: u32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -775,6 +882,23 @@ pub struct GetBlockByHeightResponse {
     #[prost(message, optional, tag = "2")]
     pub block: ::core::option::Option<super::super::super::tendermint::types::Block>,
 }
+/// GetBlockResultsRequest is the request type for the Query/GetBlockResults RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockResultsRequest {
+    #[prost(int64, tag = "1")]
+    pub height: i64,
+}
+/// GetBlockResultsResponse is the response type for the Query/GetBlockResults RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockResultsResponse {
+    #[prost(int64, tag = "1")]
+    pub height: i64,
+    /// The results of the block's transactions, in the order they appear in the block.
+    #[prost(message, repeated, tag = "2")]
+    pub txs_results: ::prost::alloc::vec::Vec<TxResult>,
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod oblivious_query_service_client {
@@ -957,6 +1081,110 @@ pub mod oblivious_query_service_client {
 }
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
+pub mod detection_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Methods for delegating the scanning of compact blocks to a detection server.
+    ///
+    /// A client hands the server the fuzzy message detection keys for its addresses,
+    /// and the server uses them to examine the clues on each transaction, so that the
+    /// client only needs to trial-decrypt the notes from transactions which were
+    /// flagged.  This reveals to the server which transactions were flagged, which
+    /// includes the client's transactions along with some false positives, at a rate
+    /// set by the chain's FMD precision.
+    #[derive(Debug, Clone)]
+    pub struct DetectionServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl DetectionServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> DetectionServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> DetectionServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            DetectionServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Streams compact blocks in which the note payloads of transactions that were
+        /// not flagged by any of the detection keys are rolled up into their commitments.
+        pub async fn detected_compact_block_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DetectedCompactBlockRangeRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::CompactBlockRangeResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.DetectionService/DetectedCompactBlockRange",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
 pub mod specific_query_service_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
@@ -1405,6 +1633,71 @@ pub mod specific_query_service_client {
             );
             self.inner.server_streaming(request.into_request(), path, codec).await
         }
+        pub async fn upgrade_plan(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpgradePlanRequest>,
+        ) -> Result<tonic::Response<super::UpgradePlanResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/UpgradePlan",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Returns a stream of `ProposalEventsResponse`s.
+        pub async fn proposal_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProposalEventsRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ProposalEventsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/ProposalEvents",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        /// Returns a snapshot of the state commitment tree from which a new wallet can begin syncing.
+        pub async fn state_commitment_tree_frontier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StateCommitmentTreeFrontierRequest>,
+        ) -> Result<
+            tonic::Response<super::StateCommitmentTreeFrontierResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.SpecificQueryService/StateCommitmentTreeFrontier",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         /// Simulate routing and trade execution.
         pub async fn simulate_trade(
             &mut self,
@@ -1668,6 +1961,27 @@ pub mod tendermint_proxy_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// GetBlockResults queries the results of executing the transactions in the block at the given
+        /// height.
+        pub async fn get_block_results(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockResultsRequest>,
+        ) -> Result<tonic::Response<super::GetBlockResultsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.client.v1alpha1.TendermintProxyService/GetBlockResults",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -2020,6 +2334,180 @@ pub mod oblivious_query_service_server {
 }
 /// Generated server implementations.
 #[cfg(feature = "rpc")]
+pub mod detection_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with DetectionServiceServer.
+    #[async_trait]
+    pub trait DetectionService: Send + Sync + 'static {
+        /// Server streaming response type for the DetectedCompactBlockRange method.
+        type DetectedCompactBlockRangeStream: futures_core::Stream<
+                Item = Result<super::CompactBlockRangeResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Streams compact blocks in which the note payloads of transactions that were
+        /// not flagged by any of the detection keys are rolled up into their commitments.
+        async fn detected_compact_block_range(
+            &self,
+            request: tonic::Request<super::DetectedCompactBlockRangeRequest>,
+        ) -> Result<
+            tonic::Response<Self::DetectedCompactBlockRangeStream>,
+            tonic::Status,
+        >;
+    }
+    /// Methods for delegating the scanning of compact blocks to a detection server.
+    ///
+    /// A client hands the server the fuzzy message detection keys for its addresses,
+    /// and the server uses them to examine the clues on each transaction, so that the
+    /// client only needs to trial-decrypt the notes from transactions which were
+    /// flagged.  This reveals to the server which transactions were flagged, which
+    /// includes the client's transactions along with some false positives, at a rate
+    /// set by the chain's FMD precision.
+    #[derive(Debug)]
+    pub struct DetectionServiceServer<T: DetectionService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: DetectionService> DetectionServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for DetectionServiceServer<T>
+    where
+        T: DetectionService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.client.v1alpha1.DetectionService/DetectedCompactBlockRange" => {
+                    #[allow(non_camel_case_types)]
+                    struct DetectedCompactBlockRangeSvc<T: DetectionService>(pub Arc<T>);
+                    impl<
+                        T: DetectionService,
+                    > tonic::server::ServerStreamingService<
+                        super::DetectedCompactBlockRangeRequest,
+                    > for DetectedCompactBlockRangeSvc<T> {
+                        type Response = super::CompactBlockRangeResponse;
+                        type ResponseStream = T::DetectedCompactBlockRangeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::DetectedCompactBlockRangeRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).detected_compact_block_range(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DetectedCompactBlockRangeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: DetectionService> Clone for DetectionServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: DetectionService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: DetectionService> tonic::server::NamedService for DetectionServiceServer<T> {
+        const NAME: &'static str = "penumbra.client.v1alpha1.DetectionService";
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
 pub mod specific_query_service_server {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
@@ -2145,6 +2633,29 @@ pub mod specific_query_service_server {
             &self,
             request: tonic::Request<super::ProposalRateDataRequest>,
         ) -> Result<tonic::Response<Self::ProposalRateDataStream>, tonic::Status>;
+        async fn upgrade_plan(
+            &self,
+            request: tonic::Request<super::UpgradePlanRequest>,
+        ) -> Result<tonic::Response<super::UpgradePlanResponse>, tonic::Status>;
+        /// Server streaming response type for the ProposalEvents method.
+        type ProposalEventsStream: futures_core::Stream<
+                Item = Result<super::ProposalEventsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Returns a stream of `ProposalEventsResponse`s.
+        async fn proposal_events(
+            &self,
+            request: tonic::Request<super::ProposalEventsRequest>,
+        ) -> Result<tonic::Response<Self::ProposalEventsStream>, tonic::Status>;
+        /// Returns a snapshot of the state commitment tree from which a new wallet can begin syncing.
+        async fn state_commitment_tree_frontier(
+            &self,
+            request: tonic::Request<super::StateCommitmentTreeFrontierRequest>,
+        ) -> Result<
+            tonic::Response<super::StateCommitmentTreeFrontierResponse>,
+            tonic::Status,
+        >;
         /// Simulate routing and trade execution.
         async fn simulate_trade(
             &self,
@@ -2969,6 +3480,132 @@ pub mod specific_query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.client.v1alpha1.SpecificQueryService/UpgradePlan" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpgradePlanSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<super::UpgradePlanRequest>
+                    for UpgradePlanSvc<T> {
+                        type Response = super::UpgradePlanResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpgradePlanRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).upgrade_plan(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpgradePlanSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/ProposalEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProposalEventsSvc<T: SpecificQueryService>(pub Arc<T>);
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::ServerStreamingService<super::ProposalEventsRequest>
+                    for ProposalEventsSvc<T> {
+                        type Response = super::ProposalEventsResponse;
+                        type ResponseStream = T::ProposalEventsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProposalEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).proposal_events(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ProposalEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.client.v1alpha1.SpecificQueryService/StateCommitmentTreeFrontier" => {
+                    #[allow(non_camel_case_types)]
+                    struct StateCommitmentTreeFrontierSvc<T: SpecificQueryService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: SpecificQueryService,
+                    > tonic::server::UnaryService<
+                        super::StateCommitmentTreeFrontierRequest,
+                    > for StateCommitmentTreeFrontierSvc<T> {
+                        type Response = super::StateCommitmentTreeFrontierResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::StateCommitmentTreeFrontierRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).state_commitment_tree_frontier(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StateCommitmentTreeFrontierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.client.v1alpha1.SpecificQueryService/SimulateTrade" => {
                     #[allow(non_camel_case_types)]
                     struct SimulateTradeSvc<T: SpecificQueryService>(pub Arc<T>);
@@ -3168,6 +3805,12 @@ pub mod tendermint_proxy_service_server {
             &self,
             request: tonic::Request<super::GetBlockByHeightRequest>,
         ) -> Result<tonic::Response<super::GetBlockByHeightResponse>, tonic::Status>;
+        /// GetBlockResults queries the results of executing the transactions in the block at the given
+        /// height.
+        async fn get_block_results(
+            &self,
+            request: tonic::Request<super::GetBlockResultsRequest>,
+        ) -> Result<tonic::Response<super::GetBlockResultsResponse>, tonic::Status>;
     }
     /// Defines the gRPC query service for proxying requests to an upstream Tendermint RPC.
     #[derive(Debug)]
@@ -3463,6 +4106,46 @@ pub mod tendermint_proxy_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.client.v1alpha1.TendermintProxyService/GetBlockResults" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockResultsSvc<T: TendermintProxyService>(pub Arc<T>);
+                    impl<
+                        T: TendermintProxyService,
+                    > tonic::server::UnaryService<super::GetBlockResultsRequest>
+                    for GetBlockResultsSvc<T> {
+                        type Response = super::GetBlockResultsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockResultsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).get_block_results(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlockResultsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -2180,6 +2180,177 @@ impl<'de> serde::Deserialize<'de> for DenomMetadataByIdResponse {
         deserializer.deserialize_struct("penumbra.client.v1alpha1.DenomMetadataByIdResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for DetectedCompactBlockRangeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.keep_alive {
+            len += 1;
+        }
+        if !self.detection_keys.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.DetectedCompactBlockRangeRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.start_height != 0 {
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if self.keep_alive {
+            struct_ser.serialize_field("keepAlive", &self.keep_alive)?;
+        }
+        if !self.detection_keys.is_empty() {
+            struct_ser.serialize_field("detectionKeys", &self.detection_keys.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DetectedCompactBlockRangeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "keep_alive",
+            "keepAlive",
+            "detection_keys",
+            "detectionKeys",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            StartHeight,
+            EndHeight,
+            KeepAlive,
+            DetectionKeys,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "keepAlive" | "keep_alive" => Ok(GeneratedField::KeepAlive),
+                            "detectionKeys" | "detection_keys" => Ok(GeneratedField::DetectionKeys),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DetectedCompactBlockRangeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.DetectedCompactBlockRangeRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<DetectedCompactBlockRangeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut keep_alive__ = None;
+                let mut detection_keys__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::KeepAlive => {
+                            if keep_alive__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keepAlive"));
+                            }
+                            keep_alive__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::DetectionKeys => {
+                            if detection_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("detectionKeys"));
+                            }
+                            detection_keys__ = 
+                                Some(map.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                    }
+                }
+                Ok(DetectedCompactBlockRangeRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    keep_alive: keep_alive__.unwrap_or_default(),
+                    detection_keys: detection_keys__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.DetectedCompactBlockRangeRequest", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for EpochByHeightRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2352,19 +2523,205 @@ impl<'de> serde::Deserialize<'de> for EpochByHeightResponse {
                             if epoch__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("epoch"));
                             }
-                            epoch__ = map.next_value()?;
+                            epoch__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(EpochByHeightResponse {
+                    epoch: epoch__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.EpochByHeightResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetBlockByHeightRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.GetBlockByHeightRequest", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetBlockByHeightRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetBlockByHeightRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.GetBlockByHeightRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetBlockByHeightRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GetBlockByHeightRequest {
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.GetBlockByHeightRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetBlockResultsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.GetBlockResultsRequest", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetBlockResultsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetBlockResultsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.GetBlockResultsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetBlockResultsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(EpochByHeightResponse {
-                    epoch: epoch__,
+                Ok(GetBlockResultsRequest {
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.EpochByHeightResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.GetBlockResultsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GetBlockByHeightRequest {
+impl serde::Serialize for GetBlockResultsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2375,14 +2732,20 @@ impl serde::Serialize for GetBlockByHeightRequest {
         if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.GetBlockByHeightRequest", len)?;
+        if !self.txs_results.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.GetBlockResultsResponse", len)?;
         if self.height != 0 {
             struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
+        if !self.txs_results.is_empty() {
+            struct_ser.serialize_field("txsResults", &self.txs_results)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetBlockByHeightRequest {
+impl<'de> serde::Deserialize<'de> for GetBlockResultsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2390,11 +2753,14 @@ impl<'de> serde::Deserialize<'de> for GetBlockByHeightRequest {
     {
         const FIELDS: &[&str] = &[
             "height",
+            "txs_results",
+            "txsResults",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Height,
+            TxsResults,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2417,6 +2783,7 @@ impl<'de> serde::Deserialize<'de> for GetBlockByHeightRequest {
                     {
                         match value {
                             "height" => Ok(GeneratedField::Height),
+                            "txsResults" | "txs_results" => Ok(GeneratedField::TxsResults),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2426,17 +2793,18 @@ impl<'de> serde::Deserialize<'de> for GetBlockByHeightRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetBlockByHeightRequest;
+            type Value = GetBlockResultsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.GetBlockByHeightRequest")
+                formatter.write_str("struct penumbra.client.v1alpha1.GetBlockResultsResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetBlockByHeightRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetBlockResultsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut height__ = None;
+                let mut txs_results__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -2447,14 +2815,21 @@ impl<'de> serde::Deserialize<'de> for GetBlockByHeightRequest {
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::TxsResults => {
+                            if txs_results__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txsResults"));
+                            }
+                            txs_results__ = Some(map.next_value()?);
+                        }
                     }
                 }
-                Ok(GetBlockByHeightRequest {
+                Ok(GetBlockResultsResponse {
                     height: height__.unwrap_or_default(),
+                    txs_results: txs_results__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.GetBlockByHeightRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.GetBlockResultsResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for GetStatusRequest {
@@ -4686,13 +5061,273 @@ impl<'de> serde::Deserialize<'de> for PrefixValueResponse {
                         }
                     }
                 }
-                Ok(PrefixValueResponse {
-                    key: key__.unwrap_or_default(),
-                    value: value__.unwrap_or_default(),
+                Ok(PrefixValueResponse {
+                    key: key__.unwrap_or_default(),
+                    value: value__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.PrefixValueResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalEventsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.proposal_id.is_some() {
+            len += 1;
+        }
+        if self.keep_alive {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalEventsRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.start_height != 0 {
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if let Some(v) = self.proposal_id.as_ref() {
+            struct_ser.serialize_field("proposalId", ToString::to_string(&v).as_str())?;
+        }
+        if self.keep_alive {
+            struct_ser.serialize_field("keepAlive", &self.keep_alive)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalEventsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "start_height",
+            "startHeight",
+            "proposal_id",
+            "proposalId",
+            "keep_alive",
+            "keepAlive",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            StartHeight,
+            ProposalId,
+            KeepAlive,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "keepAlive" | "keep_alive" => Ok(GeneratedField::KeepAlive),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalEventsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalEventsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalEventsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut start_height__ = None;
+                let mut proposal_id__ = None;
+                let mut keep_alive__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::KeepAlive => {
+                            if keep_alive__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keepAlive"));
+                            }
+                            keep_alive__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ProposalEventsRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                    proposal_id: proposal_id__,
+                    keep_alive: keep_alive__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalEventsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalEventsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.ProposalEventsResponse", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            struct_ser.serialize_field("event", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalEventsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "event",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Event,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "event" => Ok(GeneratedField::Event),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalEventsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.ProposalEventsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalEventsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut event__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Event => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("event"));
+                            }
+                            event__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ProposalEventsResponse {
+                    height: height__.unwrap_or_default(),
+                    event: event__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.PrefixValueResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.ProposalEventsResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for ProposalInfoRequest {
@@ -5717,51 +6352,309 @@ impl serde::Serialize for SpreadResponse {
         if self.best_2_to_1_position.is_some() {
             len += 1;
         }
-        if self.approx_effective_price_1_to_2 != 0. {
+        if self.approx_effective_price_1_to_2 != 0. {
+            len += 1;
+        }
+        if self.approx_effective_price_2_to_1 != 0. {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SpreadResponse", len)?;
+        if let Some(v) = self.best_1_to_2_position.as_ref() {
+            struct_ser.serialize_field("best1To2Position", v)?;
+        }
+        if let Some(v) = self.best_2_to_1_position.as_ref() {
+            struct_ser.serialize_field("best2To1Position", v)?;
+        }
+        if self.approx_effective_price_1_to_2 != 0. {
+            struct_ser.serialize_field("approxEffectivePrice1To2", &self.approx_effective_price_1_to_2)?;
+        }
+        if self.approx_effective_price_2_to_1 != 0. {
+            struct_ser.serialize_field("approxEffectivePrice2To1", &self.approx_effective_price_2_to_1)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SpreadResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "best_1_to_2_position",
+            "best1To2Position",
+            "best_2_to_1_position",
+            "best2To1Position",
+            "approx_effective_price_1_to_2",
+            "approxEffectivePrice1To2",
+            "approx_effective_price_2_to_1",
+            "approxEffectivePrice2To1",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Best1To2Position,
+            Best2To1Position,
+            ApproxEffectivePrice1To2,
+            ApproxEffectivePrice2To1,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "best1To2Position" | "best_1_to_2_position" => Ok(GeneratedField::Best1To2Position),
+                            "best2To1Position" | "best_2_to_1_position" => Ok(GeneratedField::Best2To1Position),
+                            "approxEffectivePrice1To2" | "approx_effective_price_1_to_2" => Ok(GeneratedField::ApproxEffectivePrice1To2),
+                            "approxEffectivePrice2To1" | "approx_effective_price_2_to_1" => Ok(GeneratedField::ApproxEffectivePrice2To1),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SpreadResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.SpreadResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SpreadResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut best_1_to_2_position__ = None;
+                let mut best_2_to_1_position__ = None;
+                let mut approx_effective_price_1_to_2__ = None;
+                let mut approx_effective_price_2_to_1__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Best1To2Position => {
+                            if best_1_to_2_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("best1To2Position"));
+                            }
+                            best_1_to_2_position__ = map.next_value()?;
+                        }
+                        GeneratedField::Best2To1Position => {
+                            if best_2_to_1_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("best2To1Position"));
+                            }
+                            best_2_to_1_position__ = map.next_value()?;
+                        }
+                        GeneratedField::ApproxEffectivePrice1To2 => {
+                            if approx_effective_price_1_to_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice1To2"));
+                            }
+                            approx_effective_price_1_to_2__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ApproxEffectivePrice2To1 => {
+                            if approx_effective_price_2_to_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice2To1"));
+                            }
+                            approx_effective_price_2_to_1__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(SpreadResponse {
+                    best_1_to_2_position: best_1_to_2_position__,
+                    best_2_to_1_position: best_2_to_1_position__,
+                    approx_effective_price_1_to_2: approx_effective_price_1_to_2__.unwrap_or_default(),
+                    approx_effective_price_2_to_1: approx_effective_price_2_to_1__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.SpreadResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StateCommitmentTreeFrontierRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.StateCommitmentTreeFrontierRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StateCommitmentTreeFrontierRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StateCommitmentTreeFrontierRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.StateCommitmentTreeFrontierRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StateCommitmentTreeFrontierRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(StateCommitmentTreeFrontierRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.StateCommitmentTreeFrontierRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StateCommitmentTreeFrontierResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.tree.is_empty() {
+            len += 1;
+        }
+        if self.anchor.is_some() {
             len += 1;
         }
-        if self.approx_effective_price_2_to_1 != 0. {
+        if self.fmd_parameters.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.SpreadResponse", len)?;
-        if let Some(v) = self.best_1_to_2_position.as_ref() {
-            struct_ser.serialize_field("best1To2Position", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.StateCommitmentTreeFrontierResponse", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if let Some(v) = self.best_2_to_1_position.as_ref() {
-            struct_ser.serialize_field("best2To1Position", v)?;
+        if !self.tree.is_empty() {
+            struct_ser.serialize_field("tree", pbjson::private::base64::encode(&self.tree).as_str())?;
         }
-        if self.approx_effective_price_1_to_2 != 0. {
-            struct_ser.serialize_field("approxEffectivePrice1To2", &self.approx_effective_price_1_to_2)?;
+        if let Some(v) = self.anchor.as_ref() {
+            struct_ser.serialize_field("anchor", v)?;
         }
-        if self.approx_effective_price_2_to_1 != 0. {
-            struct_ser.serialize_field("approxEffectivePrice2To1", &self.approx_effective_price_2_to_1)?;
+        if let Some(v) = self.fmd_parameters.as_ref() {
+            struct_ser.serialize_field("fmdParameters", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpreadResponse {
+impl<'de> serde::Deserialize<'de> for StateCommitmentTreeFrontierResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "best_1_to_2_position",
-            "best1To2Position",
-            "best_2_to_1_position",
-            "best2To1Position",
-            "approx_effective_price_1_to_2",
-            "approxEffectivePrice1To2",
-            "approx_effective_price_2_to_1",
-            "approxEffectivePrice2To1",
+            "height",
+            "tree",
+            "anchor",
+            "fmd_parameters",
+            "fmdParameters",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Best1To2Position,
-            Best2To1Position,
-            ApproxEffectivePrice1To2,
-            ApproxEffectivePrice2To1,
+            Height,
+            Tree,
+            Anchor,
+            FmdParameters,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5783,10 +6676,10 @@ impl<'de> serde::Deserialize<'de> for SpreadResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "best1To2Position" | "best_1_to_2_position" => Ok(GeneratedField::Best1To2Position),
-                            "best2To1Position" | "best_2_to_1_position" => Ok(GeneratedField::Best2To1Position),
-                            "approxEffectivePrice1To2" | "approx_effective_price_1_to_2" => Ok(GeneratedField::ApproxEffectivePrice1To2),
-                            "approxEffectivePrice2To1" | "approx_effective_price_2_to_1" => Ok(GeneratedField::ApproxEffectivePrice2To1),
+                            "height" => Ok(GeneratedField::Height),
+                            "tree" => Ok(GeneratedField::Tree),
+                            "anchor" => Ok(GeneratedField::Anchor),
+                            "fmdParameters" | "fmd_parameters" => Ok(GeneratedField::FmdParameters),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5796,61 +6689,61 @@ impl<'de> serde::Deserialize<'de> for SpreadResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpreadResponse;
+            type Value = StateCommitmentTreeFrontierResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.client.v1alpha1.SpreadResponse")
+                formatter.write_str("struct penumbra.client.v1alpha1.StateCommitmentTreeFrontierResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<SpreadResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StateCommitmentTreeFrontierResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut best_1_to_2_position__ = None;
-                let mut best_2_to_1_position__ = None;
-                let mut approx_effective_price_1_to_2__ = None;
-                let mut approx_effective_price_2_to_1__ = None;
+                let mut height__ = None;
+                let mut tree__ = None;
+                let mut anchor__ = None;
+                let mut fmd_parameters__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Best1To2Position => {
-                            if best_1_to_2_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("best1To2Position"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            best_1_to_2_position__ = map.next_value()?;
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Best2To1Position => {
-                            if best_2_to_1_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("best2To1Position"));
+                        GeneratedField::Tree => {
+                            if tree__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tree"));
                             }
-                            best_2_to_1_position__ = map.next_value()?;
+                            tree__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::ApproxEffectivePrice1To2 => {
-                            if approx_effective_price_1_to_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice1To2"));
+                        GeneratedField::Anchor => {
+                            if anchor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchor"));
                             }
-                            approx_effective_price_1_to_2__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            anchor__ = map.next_value()?;
                         }
-                        GeneratedField::ApproxEffectivePrice2To1 => {
-                            if approx_effective_price_2_to_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice2To1"));
+                        GeneratedField::FmdParameters => {
+                            if fmd_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fmdParameters"));
                             }
-                            approx_effective_price_2_to_1__ = 
-                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            fmd_parameters__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(SpreadResponse {
-                    best_1_to_2_position: best_1_to_2_position__,
-                    best_2_to_1_position: best_2_to_1_position__,
-                    approx_effective_price_1_to_2: approx_effective_price_1_to_2__.unwrap_or_default(),
-                    approx_effective_price_2_to_1: approx_effective_price_2_to_1__.unwrap_or_default(),
+                Ok(StateCommitmentTreeFrontierResponse {
+                    height: height__.unwrap_or_default(),
+                    tree: tree__.unwrap_or_default(),
+                    anchor: anchor__,
+                    fmd_parameters: fmd_parameters__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.client.v1alpha1.SpreadResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.StateCommitmentTreeFrontierResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for SwapExecutionRequest {
@@ -6874,6 +7767,9 @@ impl serde::Serialize for TxResult {
         if !self.tags.is_empty() {
             len += 1;
         }
+        if self.code != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.TxResult", len)?;
         if !self.log.is_empty() {
             struct_ser.serialize_field("log", &self.log)?;
@@ -6887,6 +7783,9 @@ impl serde::Serialize for TxResult {
         if !self.tags.is_empty() {
             struct_ser.serialize_field("tags", &self.tags)?;
         }
+        if self.code != 0 {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
         struct_ser.end()
     }
 }
@@ -6903,6 +7802,7 @@ impl<'de> serde::Deserialize<'de> for TxResult {
             "gas_used",
             "gasUsed",
             "tags",
+            "code",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -6911,6 +7811,7 @@ impl<'de> serde::Deserialize<'de> for TxResult {
             GasWanted,
             GasUsed,
             Tags,
+            Code,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -6936,6 +7837,7 @@ impl<'de> serde::Deserialize<'de> for TxResult {
                             "gasWanted" | "gas_wanted" => Ok(GeneratedField::GasWanted),
                             "gasUsed" | "gas_used" => Ok(GeneratedField::GasUsed),
                             "tags" => Ok(GeneratedField::Tags),
+                            "code" => Ok(GeneratedField::Code),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -6959,6 +7861,7 @@ impl<'de> serde::Deserialize<'de> for TxResult {
                 let mut gas_wanted__ = None;
                 let mut gas_used__ = None;
                 let mut tags__ = None;
+                let mut code__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Log => {
@@ -6989,6 +7892,14 @@ impl<'de> serde::Deserialize<'de> for TxResult {
                             }
                             tags__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(TxResult {
@@ -6996,12 +7907,217 @@ impl<'de> serde::Deserialize<'de> for TxResult {
                     gas_wanted: gas_wanted__.unwrap_or_default(),
                     gas_used: gas_used__.unwrap_or_default(),
                     tags: tags__.unwrap_or_default(),
+                    code: code__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.client.v1alpha1.TxResult", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for UpgradePlanRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.UpgradePlanRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpgradePlanRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpgradePlanRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.UpgradePlanRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpgradePlanRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(UpgradePlanRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.UpgradePlanRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpgradePlanResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.upgrade_plan.is_some() {
+            len += 1;
+        }
+        if self.current_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.client.v1alpha1.UpgradePlanResponse", len)?;
+        if let Some(v) = self.upgrade_plan.as_ref() {
+            struct_ser.serialize_field("upgradePlan", v)?;
+        }
+        if self.current_height != 0 {
+            struct_ser.serialize_field("currentHeight", ToString::to_string(&self.current_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpgradePlanResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "upgrade_plan",
+            "upgradePlan",
+            "current_height",
+            "currentHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UpgradePlan,
+            CurrentHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
+                            "currentHeight" | "current_height" => Ok(GeneratedField::CurrentHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpgradePlanResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.client.v1alpha1.UpgradePlanResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpgradePlanResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut upgrade_plan__ = None;
+                let mut current_height__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UpgradePlan => {
+                            if upgrade_plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradePlan"));
+                            }
+                            upgrade_plan__ = map.next_value()?;
+                        }
+                        GeneratedField::CurrentHeight => {
+                            if current_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentHeight"));
+                            }
+                            current_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(UpgradePlanResponse {
+                    upgrade_plan: upgrade_plan__,
+                    current_height: current_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.client.v1alpha1.UpgradePlanResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ValidatorInfoRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -29,6 +29,18 @@ pub struct ChainParameters {
     /// The maximum number of blocks in the window each validator can miss signing without slashing.
     #[prost(uint64, tag = "12")]
     pub missed_blocks_maximum: u64,
+    /// The maximum change in a validator's total commission allowed per epoch, in basis points.
+    ///
+    /// Unset on chains whose parameters were stored before this was added, in which case it defaults
+    /// to 500.
+    #[prost(uint64, optional, tag = "13")]
+    pub max_commission_change_bps: ::core::option::Option<u64>,
+    /// The number of full epochs a commission increase must be announced for before it takes effect.
+    ///
+    /// Unset on chains whose parameters were stored before this was added, in which case it defaults
+    /// to 1.
+    #[prost(uint64, optional, tag = "14")]
+    pub commission_increase_delay_epochs: ::core::option::Option<u64>,
     /// Whether IBC (forming connections, processing IBC packets) is enabled.
     #[prost(bool, tag = "6")]
     pub ibc_enabled: bool,
@@ -60,6 +72,33 @@ pub struct ChainParameters {
     /// Whether DAO spend proposals are enabled.
     #[prost(bool, tag = "25")]
     pub dao_spend_proposals_enabled: bool,
+    /// The length, in blocks, of the rolling window over which ICS-20 rate limits are enforced.
+    ///
+    /// Unset on chains whose parameters were stored before this was added, in which case it defaults
+    /// to 17280.
+    #[prost(uint64, optional, tag = "26")]
+    pub ics20_rate_limit_window_blocks: ::core::option::Option<u64>,
+    /// Limits on the value of ICS-20 transfers of particular denoms over particular channels.
+    #[prost(message, repeated, tag = "27")]
+    pub ics20_rate_limits: ::prost::alloc::vec::Vec<Ics20RateLimit>,
+}
+/// A limit on the total value of ICS-20 transfers of one denom over one channel, in each direction,
+/// within the rolling rate limit window.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimit {
+    /// The channel on Penumbra's end that the limit applies to, e.g. `channel-0`.
+    #[prost(string, tag = "1")]
+    pub channel_id: ::prost::alloc::string::String,
+    /// The denom, as it is known on Penumbra, that the limit applies to.
+    #[prost(string, tag = "2")]
+    pub denom: ::prost::alloc::string::String,
+    /// The most that may be transferred in to Penumbra within the window, if limited.
+    #[prost(message, optional, tag = "3")]
+    pub inbound_limit: ::core::option::Option<super::super::crypto::v1alpha1::Amount>,
+    /// The most that may be transferred out of Penumbra within the window, if limited.
+    #[prost(message, optional, tag = "4")]
+    pub outbound_limit: ::core::option::Option<super::super::crypto::v1alpha1::Amount>,
 }
 /// The ratio between two numbers, used in governance to describe vote thresholds and quorums.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -33,6 +33,12 @@ impl serde::Serialize for ChainParameters {
         if self.missed_blocks_maximum != 0 {
             len += 1;
         }
+        if self.max_commission_change_bps.is_some() {
+            len += 1;
+        }
+        if self.commission_increase_delay_epochs.is_some() {
+            len += 1;
+        }
         if self.ibc_enabled {
             len += 1;
         }
@@ -60,6 +66,12 @@ impl serde::Serialize for ChainParameters {
         if self.dao_spend_proposals_enabled {
             len += 1;
         }
+        if self.ics20_rate_limit_window_blocks.is_some() {
+            len += 1;
+        }
+        if !self.ics20_rate_limits.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.chain.v1alpha1.ChainParameters", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -88,6 +100,12 @@ impl serde::Serialize for ChainParameters {
         if self.missed_blocks_maximum != 0 {
             struct_ser.serialize_field("missedBlocksMaximum", ToString::to_string(&self.missed_blocks_maximum).as_str())?;
         }
+        if let Some(v) = self.max_commission_change_bps.as_ref() {
+            struct_ser.serialize_field("maxCommissionChangeBps", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.commission_increase_delay_epochs.as_ref() {
+            struct_ser.serialize_field("commissionIncreaseDelayEpochs", ToString::to_string(&v).as_str())?;
+        }
         if self.ibc_enabled {
             struct_ser.serialize_field("ibcEnabled", &self.ibc_enabled)?;
         }
@@ -115,6 +133,12 @@ impl serde::Serialize for ChainParameters {
         if self.dao_spend_proposals_enabled {
             struct_ser.serialize_field("daoSpendProposalsEnabled", &self.dao_spend_proposals_enabled)?;
         }
+        if let Some(v) = self.ics20_rate_limit_window_blocks.as_ref() {
+            struct_ser.serialize_field("ics20RateLimitWindowBlocks", ToString::to_string(&v).as_str())?;
+        }
+        if !self.ics20_rate_limits.is_empty() {
+            struct_ser.serialize_field("ics20RateLimits", &self.ics20_rate_limits)?;
+        }
         struct_ser.end()
     }
 }
@@ -143,6 +167,10 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             "signedBlocksWindowLen",
             "missed_blocks_maximum",
             "missedBlocksMaximum",
+            "max_commission_change_bps",
+            "maxCommissionChangeBps",
+            "commission_increase_delay_epochs",
+            "commissionIncreaseDelayEpochs",
             "ibc_enabled",
             "ibcEnabled",
             "inbound_ics20_transfers_enabled",
@@ -161,6 +189,10 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             "proposalSlashThreshold",
             "dao_spend_proposals_enabled",
             "daoSpendProposalsEnabled",
+            "ics20_rate_limit_window_blocks",
+            "ics20RateLimitWindowBlocks",
+            "ics20_rate_limits",
+            "ics20RateLimits",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -174,6 +206,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             SlashingPenaltyDowntime,
             SignedBlocksWindowLen,
             MissedBlocksMaximum,
+            MaxCommissionChangeBps,
+            CommissionIncreaseDelayEpochs,
             IbcEnabled,
             InboundIcs20TransfersEnabled,
             OutboundIcs20TransfersEnabled,
@@ -183,6 +217,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
             ProposalPassThreshold,
             ProposalSlashThreshold,
             DaoSpendProposalsEnabled,
+            Ics20RateLimitWindowBlocks,
+            Ics20RateLimits,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -213,6 +249,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                             "slashingPenaltyDowntime" | "slashing_penalty_downtime" => Ok(GeneratedField::SlashingPenaltyDowntime),
                             "signedBlocksWindowLen" | "signed_blocks_window_len" => Ok(GeneratedField::SignedBlocksWindowLen),
                             "missedBlocksMaximum" | "missed_blocks_maximum" => Ok(GeneratedField::MissedBlocksMaximum),
+                            "maxCommissionChangeBps" | "max_commission_change_bps" => Ok(GeneratedField::MaxCommissionChangeBps),
+                            "commissionIncreaseDelayEpochs" | "commission_increase_delay_epochs" => Ok(GeneratedField::CommissionIncreaseDelayEpochs),
                             "ibcEnabled" | "ibc_enabled" => Ok(GeneratedField::IbcEnabled),
                             "inboundIcs20TransfersEnabled" | "inbound_ics20_transfers_enabled" => Ok(GeneratedField::InboundIcs20TransfersEnabled),
                             "outboundIcs20TransfersEnabled" | "outbound_ics20_transfers_enabled" => Ok(GeneratedField::OutboundIcs20TransfersEnabled),
@@ -222,6 +260,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                             "proposalPassThreshold" | "proposal_pass_threshold" => Ok(GeneratedField::ProposalPassThreshold),
                             "proposalSlashThreshold" | "proposal_slash_threshold" => Ok(GeneratedField::ProposalSlashThreshold),
                             "daoSpendProposalsEnabled" | "dao_spend_proposals_enabled" => Ok(GeneratedField::DaoSpendProposalsEnabled),
+                            "ics20RateLimitWindowBlocks" | "ics20_rate_limit_window_blocks" => Ok(GeneratedField::Ics20RateLimitWindowBlocks),
+                            "ics20RateLimits" | "ics20_rate_limits" => Ok(GeneratedField::Ics20RateLimits),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -250,6 +290,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                 let mut slashing_penalty_downtime__ = None;
                 let mut signed_blocks_window_len__ = None;
                 let mut missed_blocks_maximum__ = None;
+                let mut max_commission_change_bps__ = None;
+                let mut commission_increase_delay_epochs__ = None;
                 let mut ibc_enabled__ = None;
                 let mut inbound_ics20_transfers_enabled__ = None;
                 let mut outbound_ics20_transfers_enabled__ = None;
@@ -259,6 +301,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                 let mut proposal_pass_threshold__ = None;
                 let mut proposal_slash_threshold__ = None;
                 let mut dao_spend_proposals_enabled__ = None;
+                let mut ics20_rate_limit_window_blocks__ = None;
+                let mut ics20_rate_limits__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -331,6 +375,22 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::MaxCommissionChangeBps => {
+                            if max_commission_change_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxCommissionChangeBps"));
+                            }
+                            max_commission_change_bps__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::CommissionIncreaseDelayEpochs => {
+                            if commission_increase_delay_epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commissionIncreaseDelayEpochs"));
+                            }
+                            commission_increase_delay_epochs__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
                         GeneratedField::IbcEnabled => {
                             if ibc_enabled__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("ibcEnabled"));
@@ -387,6 +447,20 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                             }
                             dao_spend_proposals_enabled__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Ics20RateLimitWindowBlocks => {
+                            if ics20_rate_limit_window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20RateLimitWindowBlocks"));
+                            }
+                            ics20_rate_limit_window_blocks__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Ics20RateLimits => {
+                            if ics20_rate_limits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20RateLimits"));
+                            }
+                            ics20_rate_limits__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(ChainParameters {
@@ -399,6 +473,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                     slashing_penalty_downtime: slashing_penalty_downtime__.unwrap_or_default(),
                     signed_blocks_window_len: signed_blocks_window_len__.unwrap_or_default(),
                     missed_blocks_maximum: missed_blocks_maximum__.unwrap_or_default(),
+                    max_commission_change_bps: max_commission_change_bps__,
+                    commission_increase_delay_epochs: commission_increase_delay_epochs__,
                     ibc_enabled: ibc_enabled__.unwrap_or_default(),
                     inbound_ics20_transfers_enabled: inbound_ics20_transfers_enabled__.unwrap_or_default(),
                     outbound_ics20_transfers_enabled: outbound_ics20_transfers_enabled__.unwrap_or_default(),
@@ -408,6 +484,8 @@ impl<'de> serde::Deserialize<'de> for ChainParameters {
                     proposal_pass_threshold: proposal_pass_threshold__.unwrap_or_default(),
                     proposal_slash_threshold: proposal_slash_threshold__.unwrap_or_default(),
                     dao_spend_proposals_enabled: dao_spend_proposals_enabled__.unwrap_or_default(),
+                    ics20_rate_limit_window_blocks: ics20_rate_limit_window_blocks__,
+                    ics20_rate_limits: ics20_rate_limits__.unwrap_or_default(),
                 })
             }
         }
@@ -1128,6 +1206,151 @@ impl<'de> serde::Deserialize<'de> for genesis_app_state::Allocation {
         deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.GenesisAppState.Allocation", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Ics20RateLimit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.channel_id.is_empty() {
+            len += 1;
+        }
+        if !self.denom.is_empty() {
+            len += 1;
+        }
+        if self.inbound_limit.is_some() {
+            len += 1;
+        }
+        if self.outbound_limit.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.chain.v1alpha1.Ics20RateLimit", len)?;
+        if !self.channel_id.is_empty() {
+            struct_ser.serialize_field("channelId", &self.channel_id)?;
+        }
+        if !self.denom.is_empty() {
+            struct_ser.serialize_field("denom", &self.denom)?;
+        }
+        if let Some(v) = self.inbound_limit.as_ref() {
+            struct_ser.serialize_field("inboundLimit", v)?;
+        }
+        if let Some(v) = self.outbound_limit.as_ref() {
+            struct_ser.serialize_field("outboundLimit", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "channel_id",
+            "channelId",
+            "denom",
+            "inbound_limit",
+            "inboundLimit",
+            "outbound_limit",
+            "outboundLimit",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChannelId,
+            Denom,
+            InboundLimit,
+            OutboundLimit,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+                            "denom" => Ok(GeneratedField::Denom),
+                            "inboundLimit" | "inbound_limit" => Ok(GeneratedField::InboundLimit),
+                            "outboundLimit" | "outbound_limit" => Ok(GeneratedField::OutboundLimit),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.chain.v1alpha1.Ics20RateLimit")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Ics20RateLimit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut channel_id__ = None;
+                let mut denom__ = None;
+                let mut inbound_limit__ = None;
+                let mut outbound_limit__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChannelId => {
+                            if channel_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channelId"));
+                            }
+                            channel_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
+                            }
+                            denom__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::InboundLimit => {
+                            if inbound_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inboundLimit"));
+                            }
+                            inbound_limit__ = map.next_value()?;
+                        }
+                        GeneratedField::OutboundLimit => {
+                            if outbound_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outboundLimit"));
+                            }
+                            outbound_limit__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(Ics20RateLimit {
+                    channel_id: channel_id__.unwrap_or_default(),
+                    denom: denom__.unwrap_or_default(),
+                    inbound_limit: inbound_limit__,
+                    outbound_limit: outbound_limit__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.chain.v1alpha1.Ics20RateLimit", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for KnownAssets {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
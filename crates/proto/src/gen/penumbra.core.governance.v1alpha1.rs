@@ -104,6 +104,11 @@ pub struct DelegatorVoteBody {
     /// The randomized validating key for the spend authorization signature.
     #[prost(bytes = "vec", tag = "7")]
     pub rk: ::prost::alloc::vec::Vec<u8>,
+    /// The split vote, if the voting power is divided between several options.
+    ///
+    /// If this is set, `vote` must be unset.
+    #[prost(message, optional, tag = "8")]
+    pub split_vote: ::core::option::Option<SplitVote>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -135,6 +140,11 @@ pub struct DelegatorVotePlan {
     /// The second blinding factor to use for the ZK delegator vote proof.
     #[prost(bytes = "vec", tag = "9")]
     pub proof_blinding_s: ::prost::alloc::vec::Vec<u8>,
+    /// The split vote to cast, if the voting power is divided between several options.
+    ///
+    /// If this is set, `vote` must be unset.
+    #[prost(message, optional, tag = "10")]
+    pub split_vote: ::core::option::Option<SplitVote>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -214,6 +224,22 @@ pub mod vote {
         }
     }
 }
+/// A vote which divides its voting power between several options.
+///
+/// The weights are given in basis points, and must sum to 10000.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplitVote {
+    /// The weight of the vote in favor of the proposal.
+    #[prost(uint32, tag = "1")]
+    pub yes_bps: u32,
+    /// The weight of the vote against the proposal.
+    #[prost(uint32, tag = "2")]
+    pub no_bps: u32,
+    /// The weight of the vote abstaining from the proposal.
+    #[prost(uint32, tag = "3")]
+    pub abstain_bps: u32,
+}
 /// The current state of a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -308,6 +334,119 @@ pub mod proposal_outcome {
         Slashed(Slashed),
     }
 }
+/// What became of a proposal's deposit, recorded when voting on the proposal concluded.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalDepositOutcome {
+    /// The amount of the deposit.
+    #[prost(message, optional, tag = "1")]
+    pub amount: ::core::option::Option<super::super::crypto::v1alpha1::Amount>,
+    /// The height at which voting on the proposal concluded.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(oneof = "proposal_deposit_outcome::Outcome", tags = "3, 4")]
+    pub outcome: ::core::option::Option<proposal_deposit_outcome::Outcome>,
+}
+/// Nested message and enum types in `ProposalDepositOutcome`.
+pub mod proposal_deposit_outcome {
+    /// The deposit is returned to the proposer when they claim it.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Refundable {}
+    /// The deposit was slashed, and sent to the DAO.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SlashedToDao {}
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Outcome {
+        #[prost(message, tag = "3")]
+        Refundable(Refundable),
+        #[prost(message, tag = "4")]
+        SlashedToDao(SlashedToDao),
+    }
+}
+/// An event in the lifecycle of a governance proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEvent {
+    /// The proposal this event concerns.
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    /// The kind of event.
+    #[prost(oneof = "proposal_event::Event", tags = "2, 3, 4, 5, 6, 7, 8, 9")]
+    pub event: ::core::option::Option<proposal_event::Event>,
+}
+/// Nested message and enum types in `ProposalEvent`.
+pub mod proposal_event {
+    /// The proposal was submitted.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Submitted {
+        /// The submitted proposal.
+        #[prost(message, optional, tag = "1")]
+        pub proposal: ::core::option::Option<super::Proposal>,
+    }
+    /// Voting began on the proposal.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct VotingStarted {
+        /// The height at which voting started.
+        #[prost(uint64, tag = "1")]
+        pub start_height: u64,
+        /// The height at which voting will end.
+        #[prost(uint64, tag = "2")]
+        pub end_height: u64,
+    }
+    /// A vote was cast on the proposal, either by a validator or by one of its delegators.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct VoteCast {
+        /// The identity key of the validator who voted, or to whom the voting delegator is delegated.
+        #[prost(message, optional, tag = "1")]
+        pub identity_key: ::core::option::Option<
+            super::super::super::crypto::v1alpha1::IdentityKey,
+        >,
+        /// If this is a validator vote, the vote of the validator.
+        #[prost(message, optional, tag = "2")]
+        pub validator_vote: ::core::option::Option<super::Vote>,
+        /// If this is a delegator vote, the voting power cast for each option.
+        #[prost(message, optional, tag = "3")]
+        pub delegator_tally: ::core::option::Option<super::Tally>,
+    }
+    /// The running tally of the proposal changed.
+    ///
+    /// Validator votes are counted immediately, but delegator votes are only counted at the end of
+    /// each epoch, and at the end of voting.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TallyUpdated {
+        /// The current tally.
+        #[prost(message, optional, tag = "1")]
+        pub tally: ::core::option::Option<super::Tally>,
+    }
+    /// The kind of event.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "2")]
+        Submitted(Submitted),
+        #[prost(message, tag = "3")]
+        VotingStarted(VotingStarted),
+        #[prost(message, tag = "4")]
+        VoteCast(VoteCast),
+        #[prost(message, tag = "5")]
+        TallyUpdated(TallyUpdated),
+        #[prost(message, tag = "6")]
+        Passed(super::proposal_outcome::Passed),
+        #[prost(message, tag = "7")]
+        Failed(super::proposal_outcome::Failed),
+        #[prost(message, tag = "8")]
+        Slashed(super::proposal_outcome::Slashed),
+        #[prost(message, tag = "9")]
+        Withdrawn(super::proposal_state::Withdrawn),
+    }
+}
 /// A tally of votes on a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -344,6 +483,18 @@ pub struct Proposal {
     pub parameter_change: ::core::option::Option<proposal::ParameterChange>,
     #[prost(message, optional, tag = "8")]
     pub dao_spend: ::core::option::Option<proposal::DaoSpend>,
+    #[prost(message, optional, tag = "9")]
+    pub upgrade_plan: ::core::option::Option<proposal::UpgradePlan>,
+    #[prost(message, optional, tag = "10")]
+    pub partial_parameter_change: ::core::option::Option<
+        proposal::PartialParameterChange,
+    >,
+    #[prost(message, optional, tag = "11")]
+    pub dao_grant: ::core::option::Option<proposal::DaoGrant>,
+    #[prost(message, optional, tag = "12")]
+    pub dao_grant_cancel: ::core::option::Option<proposal::DaoGrantCancel>,
+    #[prost(message, optional, tag = "13")]
+    pub ibc_client_substitution: ::core::option::Option<proposal::IbcClientSubstitution>,
 }
 /// Nested message and enum types in `Proposal`.
 pub mod proposal {
@@ -389,6 +540,36 @@ pub mod proposal {
             super::super::super::chain::v1alpha1::ChainParameters,
         >,
     }
+    /// A partial parameter change proposal describes a change to only some of the chain parameters,
+    /// which should take effect when the proposal is passed.
+    ///
+    /// Unlike a full `ParameterChange`, this only conflicts with other changes to the same
+    /// parameters, so multiple partial parameter change proposals can be in flight at once.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PartialParameterChange {
+        /// The parameters to change. Each parameter may be named at most once.
+        #[prost(message, repeated, tag = "1")]
+        pub changes: ::prost::alloc::vec::Vec<partial_parameter_change::Change>,
+    }
+    /// Nested message and enum types in `PartialParameterChange`.
+    pub mod partial_parameter_change {
+        /// A change to a single chain parameter.
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Change {
+            /// The name of the chain parameter, e.g. `proposal_voting_blocks`.
+            #[prost(string, tag = "1")]
+            pub parameter: ::prost::alloc::string::String,
+            /// The expected value of the parameter before the change: even if the proposal passes, the
+            /// update will not be applied if the parameter does not have this value.
+            #[prost(string, tag = "2")]
+            pub old_value: ::prost::alloc::string::String,
+            /// The value the parameter will be set to.
+            #[prost(string, tag = "3")]
+            pub new_value: ::prost::alloc::string::String,
+        }
+    }
     /// A DAO spend proposal describes zero or more transactions to execute on behalf of the DAO, with
     /// access to its funds, and zero or more scheduled transactions from previous passed proposals to
     /// cancel.
@@ -401,4 +582,67 @@ pub mod proposal {
         #[prost(message, optional, tag = "2")]
         pub transaction_plan: ::core::option::Option<::pbjson_types::Any>,
     }
+    /// A DAO grant proposal schedules recurring transparent outputs from the DAO to a single
+    /// recipient, paid automatically at epoch boundaries once the proposal is passed.
+    ///
+    /// The first tranche is paid at the end of the epoch in which the proposal passes, and each
+    /// subsequent tranche `period_epochs` epochs after the previous one, until `count` tranches have
+    /// been paid or the grant is cancelled.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DaoGrant {
+        /// The value paid to the recipient in each tranche.
+        #[prost(message, optional, tag = "1")]
+        pub value: ::core::option::Option<super::super::super::crypto::v1alpha1::Value>,
+        /// The recipient of the grant.
+        #[prost(message, optional, tag = "2")]
+        pub address: ::core::option::Option<
+            super::super::super::crypto::v1alpha1::Address,
+        >,
+        /// The number of epochs between successive tranches.
+        #[prost(uint64, tag = "3")]
+        pub period_epochs: u64,
+        /// The total number of tranches to pay.
+        #[prost(uint64, tag = "4")]
+        pub count: u64,
+    }
+    /// A DAO grant cancellation proposal stops any further tranches of a previously passed DAO grant
+    /// from being paid.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DaoGrantCancel {
+        /// The ID of the grant to cancel, which is the ID of the proposal that created it.
+        #[prost(uint64, tag = "1")]
+        pub grant_id: u64,
+    }
+    /// An IBC client substitution proposal recovers a frozen or expired IBC client, by replacing its
+    /// state with that of a healthy substitute client for the same counterparty chain.
+    ///
+    /// When passed, the subject client takes on the latest height, consensus state, and chain ID of
+    /// the substitute, and is unfrozen, so that connections and channels built on the subject client
+    /// can be used again.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct IbcClientSubstitution {
+        /// The ID of the frozen or expired client to recover.
+        #[prost(string, tag = "1")]
+        pub subject_client_id: ::prost::alloc::string::String,
+        /// The ID of the active client whose state will replace that of the subject client.
+        #[prost(string, tag = "2")]
+        pub substitute_client_id: ::prost::alloc::string::String,
+    }
+    /// An upgrade plan proposal schedules a coordinated upgrade of the software run by the chain.
+    ///
+    /// When passed, nodes will halt after committing the block at `height`, and will refuse to
+    /// process any further blocks unless they are running the software version named by `version`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UpgradePlan {
+        /// The height of the last block to be processed before the upgrade.
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+        /// The software version which will process blocks after the upgrade height.
+        #[prost(string, tag = "2")]
+        pub version: ::prost::alloc::string::String,
+    }
 }
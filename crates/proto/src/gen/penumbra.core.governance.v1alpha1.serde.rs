@@ -443,6 +443,9 @@ impl serde::Serialize for DelegatorVoteBody {
         if !self.rk.is_empty() {
             len += 1;
         }
+        if self.split_vote.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.DelegatorVoteBody", len)?;
         if self.proposal != 0 {
             struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
@@ -465,6 +468,9 @@ impl serde::Serialize for DelegatorVoteBody {
         if !self.rk.is_empty() {
             struct_ser.serialize_field("rk", pbjson::private::base64::encode(&self.rk).as_str())?;
         }
+        if let Some(v) = self.split_vote.as_ref() {
+            struct_ser.serialize_field("splitVote", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -484,6 +490,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             "unbondedAmount",
             "nullifier",
             "rk",
+            "split_vote",
+            "splitVote",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -495,6 +503,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             UnbondedAmount,
             Nullifier,
             Rk,
+            SplitVote,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -523,6 +532,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                             "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
                             "nullifier" => Ok(GeneratedField::Nullifier),
                             "rk" => Ok(GeneratedField::Rk),
+                            "splitVote" | "split_vote" => Ok(GeneratedField::SplitVote),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -549,6 +559,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                 let mut unbonded_amount__ = None;
                 let mut nullifier__ = None;
                 let mut rk__ = None;
+                let mut split_vote__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -601,6 +612,12 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::SplitVote => {
+                            if split_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("splitVote"));
+                            }
+                            split_vote__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(DelegatorVoteBody {
@@ -611,6 +628,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                     unbonded_amount: unbonded_amount__,
                     nullifier: nullifier__.unwrap_or_default(),
                     rk: rk__.unwrap_or_default(),
+                    split_vote: split_vote__,
                 })
             }
         }
@@ -652,6 +670,9 @@ impl serde::Serialize for DelegatorVotePlan {
         if !self.proof_blinding_s.is_empty() {
             len += 1;
         }
+        if self.split_vote.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.DelegatorVotePlan", len)?;
         if self.proposal != 0 {
             struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
@@ -680,6 +701,9 @@ impl serde::Serialize for DelegatorVotePlan {
         if !self.proof_blinding_s.is_empty() {
             struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
         }
+        if let Some(v) = self.split_vote.as_ref() {
+            struct_ser.serialize_field("splitVote", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -705,6 +729,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             "proofBlindingR",
             "proof_blinding_s",
             "proofBlindingS",
+            "split_vote",
+            "splitVote",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -718,6 +744,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             Randomizer,
             ProofBlindingR,
             ProofBlindingS,
+            SplitVote,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -748,6 +775,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                             "randomizer" => Ok(GeneratedField::Randomizer),
                             "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
                             "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "splitVote" | "split_vote" => Ok(GeneratedField::SplitVote),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -776,6 +804,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                 let mut randomizer__ = None;
                 let mut proof_blinding_r__ = None;
                 let mut proof_blinding_s__ = None;
+                let mut split_vote__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -844,6 +873,12 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                                 Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::SplitVote => {
+                            if split_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("splitVote"));
+                            }
+                            split_vote__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(DelegatorVotePlan {
@@ -856,6 +891,7 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                     randomizer: randomizer__.unwrap_or_default(),
                     proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
                     proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                    split_vote: split_vote__,
                 })
             }
         }
@@ -891,6 +927,21 @@ impl serde::Serialize for Proposal {
         if self.dao_spend.is_some() {
             len += 1;
         }
+        if self.upgrade_plan.is_some() {
+            len += 1;
+        }
+        if self.partial_parameter_change.is_some() {
+            len += 1;
+        }
+        if self.dao_grant.is_some() {
+            len += 1;
+        }
+        if self.dao_grant_cancel.is_some() {
+            len += 1;
+        }
+        if self.ibc_client_substitution.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal", len)?;
         if self.id != 0 {
             struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
@@ -913,6 +964,21 @@ impl serde::Serialize for Proposal {
         if let Some(v) = self.dao_spend.as_ref() {
             struct_ser.serialize_field("daoSpend", v)?;
         }
+        if let Some(v) = self.upgrade_plan.as_ref() {
+            struct_ser.serialize_field("upgradePlan", v)?;
+        }
+        if let Some(v) = self.partial_parameter_change.as_ref() {
+            struct_ser.serialize_field("partialParameterChange", v)?;
+        }
+        if let Some(v) = self.dao_grant.as_ref() {
+            struct_ser.serialize_field("daoGrant", v)?;
+        }
+        if let Some(v) = self.dao_grant_cancel.as_ref() {
+            struct_ser.serialize_field("daoGrantCancel", v)?;
+        }
+        if let Some(v) = self.ibc_client_substitution.as_ref() {
+            struct_ser.serialize_field("ibcClientSubstitution", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -932,6 +998,16 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             "parameterChange",
             "dao_spend",
             "daoSpend",
+            "upgrade_plan",
+            "upgradePlan",
+            "partial_parameter_change",
+            "partialParameterChange",
+            "dao_grant",
+            "daoGrant",
+            "dao_grant_cancel",
+            "daoGrantCancel",
+            "ibc_client_substitution",
+            "ibcClientSubstitution",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -943,6 +1019,11 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             Emergency,
             ParameterChange,
             DaoSpend,
+            UpgradePlan,
+            PartialParameterChange,
+            DaoGrant,
+            DaoGrantCancel,
+            IbcClientSubstitution,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -971,6 +1052,11 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             "emergency" => Ok(GeneratedField::Emergency),
                             "parameterChange" | "parameter_change" => Ok(GeneratedField::ParameterChange),
                             "daoSpend" | "dao_spend" => Ok(GeneratedField::DaoSpend),
+                            "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
+                            "partialParameterChange" | "partial_parameter_change" => Ok(GeneratedField::PartialParameterChange),
+                            "daoGrant" | "dao_grant" => Ok(GeneratedField::DaoGrant),
+                            "daoGrantCancel" | "dao_grant_cancel" => Ok(GeneratedField::DaoGrantCancel),
+                            "ibcClientSubstitution" | "ibc_client_substitution" => Ok(GeneratedField::IbcClientSubstitution),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -997,6 +1083,11 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                 let mut emergency__ = None;
                 let mut parameter_change__ = None;
                 let mut dao_spend__ = None;
+                let mut upgrade_plan__ = None;
+                let mut partial_parameter_change__ = None;
+                let mut dao_grant__ = None;
+                let mut dao_grant_cancel__ = None;
+                let mut ibc_client_substitution__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1043,6 +1134,36 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             }
                             dao_spend__ = map.next_value()?;
                         }
+                        GeneratedField::UpgradePlan => {
+                            if upgrade_plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradePlan"));
+                            }
+                            upgrade_plan__ = map.next_value()?;
+                        }
+                        GeneratedField::PartialParameterChange => {
+                            if partial_parameter_change__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partialParameterChange"));
+                            }
+                            partial_parameter_change__ = map.next_value()?;
+                        }
+                        GeneratedField::DaoGrant => {
+                            if dao_grant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("daoGrant"));
+                            }
+                            dao_grant__ = map.next_value()?;
+                        }
+                        GeneratedField::DaoGrantCancel => {
+                            if dao_grant_cancel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("daoGrantCancel"));
+                            }
+                            dao_grant_cancel__ = map.next_value()?;
+                        }
+                        GeneratedField::IbcClientSubstitution => {
+                            if ibc_client_substitution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcClientSubstitution"));
+                            }
+                            ibc_client_substitution__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(Proposal {
@@ -1053,12 +1174,258 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                     emergency: emergency__,
                     parameter_change: parameter_change__,
                     dao_spend: dao_spend__,
+                    upgrade_plan: upgrade_plan__,
+                    partial_parameter_change: partial_parameter_change__,
+                    dao_grant: dao_grant__,
+                    dao_grant_cancel: dao_grant_cancel__,
+                    ibc_client_substitution: ibc_client_substitution__,
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::DaoGrant {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.address.is_some() {
+            len += 1;
+        }
+        if self.period_epochs != 0 {
+            len += 1;
+        }
+        if self.count != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.DaoGrant", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        if self.period_epochs != 0 {
+            struct_ser.serialize_field("periodEpochs", ToString::to_string(&self.period_epochs).as_str())?;
+        }
+        if self.count != 0 {
+            struct_ser.serialize_field("count", ToString::to_string(&self.count).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::DaoGrant {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "address",
+            "period_epochs",
+            "periodEpochs",
+            "count",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            Address,
+            PeriodEpochs,
+            Count,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "address" => Ok(GeneratedField::Address),
+                            "periodEpochs" | "period_epochs" => Ok(GeneratedField::PeriodEpochs),
+                            "count" => Ok(GeneratedField::Count),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::DaoGrant;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.DaoGrant")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::DaoGrant, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut address__ = None;
+                let mut period_epochs__ = None;
+                let mut count__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map.next_value()?;
+                        }
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map.next_value()?;
+                        }
+                        GeneratedField::PeriodEpochs => {
+                            if period_epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("periodEpochs"));
+                            }
+                            period_epochs__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Count => {
+                            if count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("count"));
+                            }
+                            count__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(proposal::DaoGrant {
+                    value: value__,
+                    address: address__,
+                    period_epochs: period_epochs__.unwrap_or_default(),
+                    count: count__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.DaoGrant", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::DaoGrantCancel {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.grant_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.DaoGrantCancel", len)?;
+        if self.grant_id != 0 {
+            struct_ser.serialize_field("grantId", ToString::to_string(&self.grant_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::DaoGrantCancel {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grant_id",
+            "grantId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GrantId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grantId" | "grant_id" => Ok(GeneratedField::GrantId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::DaoGrantCancel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.DaoGrantCancel")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::DaoGrantCancel, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grant_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::GrantId => {
+                            if grant_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantId"));
+                            }
+                            grant_id__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(proposal::DaoGrantCancel {
+                    grant_id: grant_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.DaoGrantCancel", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::DaoSpend {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1148,10 +1515,1367 @@ impl<'de> serde::Deserialize<'de> for proposal::DaoSpend {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.DaoSpend", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.DaoSpend", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::Emergency {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.halt_chain {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.Emergency", len)?;
+        if self.halt_chain {
+            struct_ser.serialize_field("haltChain", &self.halt_chain)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::Emergency {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "halt_chain",
+            "haltChain",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            HaltChain,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "haltChain" | "halt_chain" => Ok(GeneratedField::HaltChain),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::Emergency;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.Emergency")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::Emergency, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut halt_chain__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::HaltChain => {
+                            if halt_chain__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("haltChain"));
+                            }
+                            halt_chain__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::Emergency {
+                    halt_chain: halt_chain__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.Emergency", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::IbcClientSubstitution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.subject_client_id.is_empty() {
+            len += 1;
+        }
+        if !self.substitute_client_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.IbcClientSubstitution", len)?;
+        if !self.subject_client_id.is_empty() {
+            struct_ser.serialize_field("subjectClientId", &self.subject_client_id)?;
+        }
+        if !self.substitute_client_id.is_empty() {
+            struct_ser.serialize_field("substituteClientId", &self.substitute_client_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::IbcClientSubstitution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "subject_client_id",
+            "subjectClientId",
+            "substitute_client_id",
+            "substituteClientId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SubjectClientId,
+            SubstituteClientId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "subjectClientId" | "subject_client_id" => Ok(GeneratedField::SubjectClientId),
+                            "substituteClientId" | "substitute_client_id" => Ok(GeneratedField::SubstituteClientId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::IbcClientSubstitution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.IbcClientSubstitution")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::IbcClientSubstitution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut subject_client_id__ = None;
+                let mut substitute_client_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::SubjectClientId => {
+                            if subject_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("subjectClientId"));
+                            }
+                            subject_client_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SubstituteClientId => {
+                            if substitute_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("substituteClientId"));
+                            }
+                            substitute_client_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::IbcClientSubstitution {
+                    subject_client_id: subject_client_id__.unwrap_or_default(),
+                    substitute_client_id: substitute_client_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.IbcClientSubstitution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::ParameterChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.old_parameters.is_some() {
+            len += 1;
+        }
+        if self.new_parameters.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.ParameterChange", len)?;
+        if let Some(v) = self.old_parameters.as_ref() {
+            struct_ser.serialize_field("oldParameters", v)?;
+        }
+        if let Some(v) = self.new_parameters.as_ref() {
+            struct_ser.serialize_field("newParameters", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "old_parameters",
+            "oldParameters",
+            "new_parameters",
+            "newParameters",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            OldParameters,
+            NewParameters,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "oldParameters" | "old_parameters" => Ok(GeneratedField::OldParameters),
+                            "newParameters" | "new_parameters" => Ok(GeneratedField::NewParameters),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::ParameterChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.ParameterChange")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::ParameterChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut old_parameters__ = None;
+                let mut new_parameters__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::OldParameters => {
+                            if old_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldParameters"));
+                            }
+                            old_parameters__ = map.next_value()?;
+                        }
+                        GeneratedField::NewParameters => {
+                            if new_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newParameters"));
+                            }
+                            new_parameters__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(proposal::ParameterChange {
+                    old_parameters: old_parameters__,
+                    new_parameters: new_parameters__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::PartialParameterChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.changes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.PartialParameterChange", len)?;
+        if !self.changes.is_empty() {
+            struct_ser.serialize_field("changes", &self.changes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::PartialParameterChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "changes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Changes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "changes" => Ok(GeneratedField::Changes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::PartialParameterChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.PartialParameterChange")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::PartialParameterChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut changes__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Changes => {
+                            if changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("changes"));
+                            }
+                            changes__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::PartialParameterChange {
+                    changes: changes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.PartialParameterChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::partial_parameter_change::Change {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.parameter.is_empty() {
+            len += 1;
+        }
+        if !self.old_value.is_empty() {
+            len += 1;
+        }
+        if !self.new_value.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.PartialParameterChange.Change", len)?;
+        if !self.parameter.is_empty() {
+            struct_ser.serialize_field("parameter", &self.parameter)?;
+        }
+        if !self.old_value.is_empty() {
+            struct_ser.serialize_field("oldValue", &self.old_value)?;
+        }
+        if !self.new_value.is_empty() {
+            struct_ser.serialize_field("newValue", &self.new_value)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::partial_parameter_change::Change {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "parameter",
+            "old_value",
+            "oldValue",
+            "new_value",
+            "newValue",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Parameter,
+            OldValue,
+            NewValue,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "parameter" => Ok(GeneratedField::Parameter),
+                            "oldValue" | "old_value" => Ok(GeneratedField::OldValue),
+                            "newValue" | "new_value" => Ok(GeneratedField::NewValue),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::partial_parameter_change::Change;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.PartialParameterChange.Change")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::partial_parameter_change::Change, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut parameter__ = None;
+                let mut old_value__ = None;
+                let mut new_value__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Parameter => {
+                            if parameter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameter"));
+                            }
+                            parameter__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::OldValue => {
+                            if old_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldValue"));
+                            }
+                            old_value__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::NewValue => {
+                            if new_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newValue"));
+                            }
+                            new_value__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::partial_parameter_change::Change {
+                    parameter: parameter__.unwrap_or_default(),
+                    old_value: old_value__.unwrap_or_default(),
+                    new_value: new_value__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.PartialParameterChange.Change", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::Signaling {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.commit.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.Signaling", len)?;
+        if let Some(v) = self.commit.as_ref() {
+            struct_ser.serialize_field("commit", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::Signaling {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "commit",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Commit,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "commit" => Ok(GeneratedField::Commit),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::Signaling;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.Signaling")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::Signaling, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut commit__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Commit => {
+                            if commit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commit"));
+                            }
+                            commit__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(proposal::Signaling {
+                    commit: commit__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.Signaling", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::UpgradePlan {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.UpgradePlan", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::UpgradePlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "version",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Version,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::UpgradePlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.UpgradePlan")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::UpgradePlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(proposal::UpgradePlan {
+                    height: height__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.UpgradePlan", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalDepositClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal != 0 {
+            len += 1;
+        }
+        if self.deposit_amount.is_some() {
+            len += 1;
+        }
+        if self.outcome.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositClaim", len)?;
+        if self.proposal != 0 {
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
+        if let Some(v) = self.deposit_amount.as_ref() {
+            struct_ser.serialize_field("depositAmount", v)?;
+        }
+        if let Some(v) = self.outcome.as_ref() {
+            struct_ser.serialize_field("outcome", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalDepositClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "deposit_amount",
+            "depositAmount",
+            "outcome",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            DepositAmount,
+            Outcome,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "depositAmount" | "deposit_amount" => Ok(GeneratedField::DepositAmount),
+                            "outcome" => Ok(GeneratedField::Outcome),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalDepositClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalDepositClaim")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalDepositClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut deposit_amount__ = None;
+                let mut outcome__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DepositAmount => {
+                            if deposit_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositAmount"));
+                            }
+                            deposit_amount__ = map.next_value()?;
+                        }
+                        GeneratedField::Outcome => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outcome"));
+                            }
+                            outcome__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ProposalDepositClaim {
+                    proposal: proposal__.unwrap_or_default(),
+                    deposit_amount: deposit_amount__,
+                    outcome: outcome__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositClaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalDepositOutcome {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.outcome.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositOutcome", len)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.outcome.as_ref() {
+            match v {
+                proposal_deposit_outcome::Outcome::Refundable(v) => {
+                    struct_ser.serialize_field("refundable", v)?;
+                }
+                proposal_deposit_outcome::Outcome::SlashedToDao(v) => {
+                    struct_ser.serialize_field("slashedToDao", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalDepositOutcome {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "height",
+            "refundable",
+            "slashed_to_dao",
+            "slashedToDao",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            Height,
+            Refundable,
+            SlashedToDao,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "height" => Ok(GeneratedField::Height),
+                            "refundable" => Ok(GeneratedField::Refundable),
+                            "slashedToDao" | "slashed_to_dao" => Ok(GeneratedField::SlashedToDao),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalDepositOutcome;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalDepositOutcome")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalDepositOutcome, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut height__ = None;
+                let mut outcome__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Refundable => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refundable"));
+                            }
+                            outcome__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_deposit_outcome::Outcome::Refundable)
+;
+                        }
+                        GeneratedField::SlashedToDao => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashedToDao"));
+                            }
+                            outcome__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_deposit_outcome::Outcome::SlashedToDao)
+;
+                        }
+                    }
+                }
+                Ok(ProposalDepositOutcome {
+                    amount: amount__,
+                    height: height__.unwrap_or_default(),
+                    outcome: outcome__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositOutcome", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_deposit_outcome::Refundable {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositOutcome.Refundable", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_deposit_outcome::Refundable {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_deposit_outcome::Refundable;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalDepositOutcome.Refundable")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal_deposit_outcome::Refundable, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(proposal_deposit_outcome::Refundable {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositOutcome.Refundable", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal_deposit_outcome::SlashedToDao {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositOutcome.SlashedToDao", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_deposit_outcome::SlashedToDao {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_deposit_outcome::SlashedToDao;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalDepositOutcome.SlashedToDao")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal_deposit_outcome::SlashedToDao, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(proposal_deposit_outcome::SlashedToDao {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositOutcome.SlashedToDao", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent", len)?;
+        if self.proposal_id != 0 {
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            match v {
+                proposal_event::Event::Submitted(v) => {
+                    struct_ser.serialize_field("submitted", v)?;
+                }
+                proposal_event::Event::VotingStarted(v) => {
+                    struct_ser.serialize_field("votingStarted", v)?;
+                }
+                proposal_event::Event::VoteCast(v) => {
+                    struct_ser.serialize_field("voteCast", v)?;
+                }
+                proposal_event::Event::TallyUpdated(v) => {
+                    struct_ser.serialize_field("tallyUpdated", v)?;
+                }
+                proposal_event::Event::Passed(v) => {
+                    struct_ser.serialize_field("passed", v)?;
+                }
+                proposal_event::Event::Failed(v) => {
+                    struct_ser.serialize_field("failed", v)?;
+                }
+                proposal_event::Event::Slashed(v) => {
+                    struct_ser.serialize_field("slashed", v)?;
+                }
+                proposal_event::Event::Withdrawn(v) => {
+                    struct_ser.serialize_field("withdrawn", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+            "submitted",
+            "voting_started",
+            "votingStarted",
+            "vote_cast",
+            "voteCast",
+            "tally_updated",
+            "tallyUpdated",
+            "passed",
+            "failed",
+            "slashed",
+            "withdrawn",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            Submitted,
+            VotingStarted,
+            VoteCast,
+            TallyUpdated,
+            Passed,
+            Failed,
+            Slashed,
+            Withdrawn,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "submitted" => Ok(GeneratedField::Submitted),
+                            "votingStarted" | "voting_started" => Ok(GeneratedField::VotingStarted),
+                            "voteCast" | "vote_cast" => Ok(GeneratedField::VoteCast),
+                            "tallyUpdated" | "tally_updated" => Ok(GeneratedField::TallyUpdated),
+                            "passed" => Ok(GeneratedField::Passed),
+                            "failed" => Ok(GeneratedField::Failed),
+                            "slashed" => Ok(GeneratedField::Slashed),
+                            "withdrawn" => Ok(GeneratedField::Withdrawn),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalEvent")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                let mut event__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Submitted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitted"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::Submitted)
+;
+                        }
+                        GeneratedField::VotingStarted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingStarted"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::VotingStarted)
+;
+                        }
+                        GeneratedField::VoteCast => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteCast"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::VoteCast)
+;
+                        }
+                        GeneratedField::TallyUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tallyUpdated"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::TallyUpdated)
+;
+                        }
+                        GeneratedField::Passed => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("passed"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::Passed)
+;
+                        }
+                        GeneratedField::Failed => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("failed"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::Failed)
+;
+                        }
+                        GeneratedField::Slashed => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("slashed"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::Slashed)
+;
+                        }
+                        GeneratedField::Withdrawn => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawn"));
+                            }
+                            event__ = map.next_value::<::std::option::Option<_>>()?.map(proposal_event::Event::Withdrawn)
+;
+                        }
+                    }
+                }
+                Ok(ProposalEvent {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    event: event__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::Emergency {
+impl serde::Serialize for proposal_event::Submitted {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1159,30 +2883,29 @@ impl serde::Serialize for proposal::Emergency {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.halt_chain {
+        if self.proposal.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.Emergency", len)?;
-        if self.halt_chain {
-            struct_ser.serialize_field("haltChain", &self.halt_chain)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.Submitted", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::Emergency {
+impl<'de> serde::Deserialize<'de> for proposal_event::Submitted {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "halt_chain",
-            "haltChain",
+            "proposal",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            HaltChain,
+            Proposal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1204,7 +2927,7 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
                         E: serde::de::Error,
                     {
                         match value {
-                            "haltChain" | "halt_chain" => Ok(GeneratedField::HaltChain),
+                            "proposal" => Ok(GeneratedField::Proposal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1214,36 +2937,36 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::Emergency;
+            type Value = proposal_event::Submitted;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.Emergency")
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalEvent.Submitted")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::Emergency, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal_event::Submitted, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut halt_chain__ = None;
+                let mut proposal__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::HaltChain => {
-                            if halt_chain__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("haltChain"));
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
                             }
-                            halt_chain__ = Some(map.next_value()?);
+                            proposal__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(proposal::Emergency {
-                    halt_chain: halt_chain__.unwrap_or_default(),
+                Ok(proposal_event::Submitted {
+                    proposal: proposal__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.Emergency", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.Submitted", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::ParameterChange {
+impl serde::Serialize for proposal_event::TallyUpdated {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1251,39 +2974,29 @@ impl serde::Serialize for proposal::ParameterChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.old_parameters.is_some() {
-            len += 1;
-        }
-        if self.new_parameters.is_some() {
+        if self.tally.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.ParameterChange", len)?;
-        if let Some(v) = self.old_parameters.as_ref() {
-            struct_ser.serialize_field("oldParameters", v)?;
-        }
-        if let Some(v) = self.new_parameters.as_ref() {
-            struct_ser.serialize_field("newParameters", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.TallyUpdated", len)?;
+        if let Some(v) = self.tally.as_ref() {
+            struct_ser.serialize_field("tally", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
+impl<'de> serde::Deserialize<'de> for proposal_event::TallyUpdated {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "old_parameters",
-            "oldParameters",
-            "new_parameters",
-            "newParameters",
+            "tally",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            OldParameters,
-            NewParameters,
+            Tally,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1305,8 +3018,7 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "oldParameters" | "old_parameters" => Ok(GeneratedField::OldParameters),
-                            "newParameters" | "new_parameters" => Ok(GeneratedField::NewParameters),
+                            "tally" => Ok(GeneratedField::Tally),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1316,44 +3028,36 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::ParameterChange;
+            type Value = proposal_event::TallyUpdated;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.ParameterChange")
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalEvent.TallyUpdated")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::ParameterChange, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal_event::TallyUpdated, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut old_parameters__ = None;
-                let mut new_parameters__ = None;
+                let mut tally__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::OldParameters => {
-                            if old_parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("oldParameters"));
-                            }
-                            old_parameters__ = map.next_value()?;
-                        }
-                        GeneratedField::NewParameters => {
-                            if new_parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newParameters"));
+                        GeneratedField::Tally => {
+                            if tally__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tally"));
                             }
-                            new_parameters__ = map.next_value()?;
+                            tally__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(proposal::ParameterChange {
-                    old_parameters: old_parameters__,
-                    new_parameters: new_parameters__,
+                Ok(proposal_event::TallyUpdated {
+                    tally: tally__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.TallyUpdated", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::Signaling {
+impl serde::Serialize for proposal_event::VoteCast {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1361,29 +3065,48 @@ impl serde::Serialize for proposal::Signaling {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.commit.is_some() {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.Proposal.Signaling", len)?;
-        if let Some(v) = self.commit.as_ref() {
-            struct_ser.serialize_field("commit", v)?;
+        if self.validator_vote.is_some() {
+            len += 1;
+        }
+        if self.delegator_tally.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.VoteCast", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.validator_vote.as_ref() {
+            struct_ser.serialize_field("validatorVote", v)?;
+        }
+        if let Some(v) = self.delegator_tally.as_ref() {
+            struct_ser.serialize_field("delegatorTally", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::Signaling {
+impl<'de> serde::Deserialize<'de> for proposal_event::VoteCast {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "commit",
+            "identity_key",
+            "identityKey",
+            "validator_vote",
+            "validatorVote",
+            "delegator_tally",
+            "delegatorTally",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Commit,
+            IdentityKey,
+            ValidatorVote,
+            DelegatorTally,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1405,7 +3128,9 @@ impl<'de> serde::Deserialize<'de> for proposal::Signaling {
                         E: serde::de::Error,
                     {
                         match value {
-                            "commit" => Ok(GeneratedField::Commit),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "validatorVote" | "validator_vote" => Ok(GeneratedField::ValidatorVote),
+                            "delegatorTally" | "delegator_tally" => Ok(GeneratedField::DelegatorTally),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1415,36 +3140,52 @@ impl<'de> serde::Deserialize<'de> for proposal::Signaling {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::Signaling;
+            type Value = proposal_event::VoteCast;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.governance.v1alpha1.Proposal.Signaling")
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalEvent.VoteCast")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal::Signaling, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal_event::VoteCast, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut commit__ = None;
+                let mut identity_key__ = None;
+                let mut validator_vote__ = None;
+                let mut delegator_tally__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Commit => {
-                            if commit__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("commit"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            commit__ = map.next_value()?;
+                            identity_key__ = map.next_value()?;
+                        }
+                        GeneratedField::ValidatorVote => {
+                            if validator_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorVote"));
+                            }
+                            validator_vote__ = map.next_value()?;
+                        }
+                        GeneratedField::DelegatorTally => {
+                            if delegator_tally__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorTally"));
+                            }
+                            delegator_tally__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(proposal::Signaling {
-                    commit: commit__,
+                Ok(proposal_event::VoteCast {
+                    identity_key: identity_key__,
+                    validator_vote: validator_vote__,
+                    delegator_tally: delegator_tally__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.Proposal.Signaling", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.VoteCast", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalDepositClaim {
+impl serde::Serialize for proposal_event::VotingStarted {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1452,46 +3193,39 @@ impl serde::Serialize for ProposalDepositClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal != 0 {
-            len += 1;
-        }
-        if self.deposit_amount.is_some() {
+        if self.start_height != 0 {
             len += 1;
         }
-        if self.outcome.is_some() {
+        if self.end_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositClaim", len)?;
-        if self.proposal != 0 {
-            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
-        }
-        if let Some(v) = self.deposit_amount.as_ref() {
-            struct_ser.serialize_field("depositAmount", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.VotingStarted", len)?;
+        if self.start_height != 0 {
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
         }
-        if let Some(v) = self.outcome.as_ref() {
-            struct_ser.serialize_field("outcome", v)?;
+        if self.end_height != 0 {
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalDepositClaim {
+impl<'de> serde::Deserialize<'de> for proposal_event::VotingStarted {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
-            "deposit_amount",
-            "depositAmount",
-            "outcome",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
-            DepositAmount,
-            Outcome,
+            StartHeight,
+            EndHeight,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1513,9 +3247,8 @@ impl<'de> serde::Deserialize<'de> for ProposalDepositClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
-                            "depositAmount" | "deposit_amount" => Ok(GeneratedField::DepositAmount),
-                            "outcome" => Ok(GeneratedField::Outcome),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1525,51 +3258,45 @@ impl<'de> serde::Deserialize<'de> for ProposalDepositClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalDepositClaim;
+            type Value = proposal_event::VotingStarted;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalDepositClaim")
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.ProposalEvent.VotingStarted")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<ProposalDepositClaim, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<proposal_event::VotingStarted, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
-                let mut deposit_amount__ = None;
-                let mut outcome__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
                             }
-                            proposal__ = 
+                            start_height__ = 
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::DepositAmount => {
-                            if deposit_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("depositAmount"));
-                            }
-                            deposit_amount__ = map.next_value()?;
-                        }
-                        GeneratedField::Outcome => {
-                            if outcome__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outcome"));
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
                             }
-                            outcome__ = map.next_value()?;
+                            end_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(ProposalDepositClaim {
-                    proposal: proposal__.unwrap_or_default(),
-                    deposit_amount: deposit_amount__,
-                    outcome: outcome__,
+                Ok(proposal_event::VotingStarted {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalDepositClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalEvent.VotingStarted", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for ProposalOutcome {
@@ -2647,6 +4374,140 @@ impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
         deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.ProposalWithdraw", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for SplitVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.yes_bps != 0 {
+            len += 1;
+        }
+        if self.no_bps != 0 {
+            len += 1;
+        }
+        if self.abstain_bps != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.governance.v1alpha1.SplitVote", len)?;
+        if self.yes_bps != 0 {
+            struct_ser.serialize_field("yesBps", &self.yes_bps)?;
+        }
+        if self.no_bps != 0 {
+            struct_ser.serialize_field("noBps", &self.no_bps)?;
+        }
+        if self.abstain_bps != 0 {
+            struct_ser.serialize_field("abstainBps", &self.abstain_bps)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SplitVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "yes_bps",
+            "yesBps",
+            "no_bps",
+            "noBps",
+            "abstain_bps",
+            "abstainBps",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            YesBps,
+            NoBps,
+            AbstainBps,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "yesBps" | "yes_bps" => Ok(GeneratedField::YesBps),
+                            "noBps" | "no_bps" => Ok(GeneratedField::NoBps),
+                            "abstainBps" | "abstain_bps" => Ok(GeneratedField::AbstainBps),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SplitVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.governance.v1alpha1.SplitVote")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SplitVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut yes_bps__ = None;
+                let mut no_bps__ = None;
+                let mut abstain_bps__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::YesBps => {
+                            if yes_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("yesBps"));
+                            }
+                            yes_bps__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NoBps => {
+                            if no_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noBps"));
+                            }
+                            no_bps__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AbstainBps => {
+                            if abstain_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("abstainBps"));
+                            }
+                            abstain_bps__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(SplitVote {
+                    yes_bps: yes_bps__.unwrap_or_default(),
+                    no_bps: no_bps__.unwrap_or_default(),
+                    abstain_bps: abstain_bps__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.governance.v1alpha1.SplitVote", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Tally {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
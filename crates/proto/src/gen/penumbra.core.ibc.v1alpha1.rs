@@ -45,6 +45,9 @@ pub struct FungibleTokenPacketData {
     /// the recipient address on the destination chain
     #[prost(string, tag = "4")]
     pub receiver: ::prost::alloc::string::String,
+    /// an optional memo, interpreted by the destination chain
+    #[prost(string, tag = "5")]
+    pub memo: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -79,6 +82,10 @@ pub struct Ics20Withdrawal {
     /// the source channel used for the withdrawal
     #[prost(string, tag = "9")]
     pub source_channel: ::prost::alloc::string::String,
+    /// an optional memo to include in the transfer packet, e.g. to have the
+    /// destination chain forward the transfer on to another chain
+    #[prost(string, tag = "10")]
+    pub memo: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -535,6 +535,9 @@ impl serde::Serialize for FungibleTokenPacketData {
         if !self.receiver.is_empty() {
             len += 1;
         }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.ibc.v1alpha1.FungibleTokenPacketData", len)?;
         if !self.denom.is_empty() {
             struct_ser.serialize_field("denom", &self.denom)?;
@@ -548,6 +551,9 @@ impl serde::Serialize for FungibleTokenPacketData {
         if !self.receiver.is_empty() {
             struct_ser.serialize_field("receiver", &self.receiver)?;
         }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
         struct_ser.end()
     }
 }
@@ -562,6 +568,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
             "amount",
             "sender",
             "receiver",
+            "memo",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -570,6 +577,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
             Amount,
             Sender,
             Receiver,
+            Memo,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -595,6 +603,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                             "amount" => Ok(GeneratedField::Amount),
                             "sender" => Ok(GeneratedField::Sender),
                             "receiver" => Ok(GeneratedField::Receiver),
+                            "memo" => Ok(GeneratedField::Memo),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -618,6 +627,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                 let mut amount__ = None;
                 let mut sender__ = None;
                 let mut receiver__ = None;
+                let mut memo__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Denom => {
@@ -644,6 +654,12 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                             }
                             receiver__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(FungibleTokenPacketData {
@@ -651,6 +667,7 @@ impl<'de> serde::Deserialize<'de> for FungibleTokenPacketData {
                     amount: amount__.unwrap_or_default(),
                     sender: sender__.unwrap_or_default(),
                     receiver: receiver__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
                 })
             }
         }
@@ -784,6 +801,9 @@ impl serde::Serialize for Ics20Withdrawal {
         if !self.source_channel.is_empty() {
             len += 1;
         }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.ibc.v1alpha1.Ics20Withdrawal", len)?;
         if !self.destination_chain_id.is_empty() {
             struct_ser.serialize_field("destinationChainId", &self.destination_chain_id)?;
@@ -812,6 +832,9 @@ impl serde::Serialize for Ics20Withdrawal {
         if !self.source_channel.is_empty() {
             struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
         }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
         struct_ser.end()
     }
 }
@@ -838,6 +861,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
             "sourcePort",
             "source_channel",
             "sourceChannel",
+            "memo",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -851,6 +875,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
             TimeoutTime,
             SourcePort,
             SourceChannel,
+            Memo,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -881,6 +906,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                             "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
                             "sourcePort" | "source_port" => Ok(GeneratedField::SourcePort),
                             "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
+                            "memo" => Ok(GeneratedField::Memo),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -909,6 +935,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                 let mut timeout_time__ = None;
                 let mut source_port__ = None;
                 let mut source_channel__ = None;
+                let mut memo__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::DestinationChainId => {
@@ -969,6 +996,12 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                             }
                             source_channel__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(Ics20Withdrawal {
@@ -981,6 +1014,7 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                     timeout_time: timeout_time__.unwrap_or_default(),
                     source_port: source_port__.unwrap_or_default(),
                     source_channel: source_channel__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
                 })
             }
         }
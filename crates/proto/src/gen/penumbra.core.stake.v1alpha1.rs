@@ -52,7 +52,7 @@ pub struct ValidatorList {
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FundingStream {
     /// The recipient of the funding stream.
-    #[prost(oneof = "funding_stream::Recipient", tags = "1, 2")]
+    #[prost(oneof = "funding_stream::Recipient", tags = "1, 2, 3")]
     pub recipient: ::core::option::Option<funding_stream::Recipient>,
 }
 /// Nested message and enum types in `FundingStream`.
@@ -76,6 +76,19 @@ pub mod funding_stream {
         #[prost(uint32, tag = "2")]
         pub rate_bps: u32,
     }
+    /// A funding stream whose reward is automatically re-delegated to the
+    /// validator, and sent to the destination address as delegation tokens.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ToDelegation {
+        /// The destination address for the delegation tokens.
+        #[prost(string, tag = "1")]
+        pub address: ::prost::alloc::string::String,
+        /// The portion of the staking reward for the entire delegation pool
+        /// allocated to this funding stream, specified in basis points.
+        #[prost(uint32, tag = "2")]
+        pub rate_bps: u32,
+    }
     /// The recipient of the funding stream.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -84,6 +97,8 @@ pub mod funding_stream {
         ToAddress(ToAddress),
         #[prost(message, tag = "2")]
         ToDao(ToDao),
+        #[prost(message, tag = "3")]
+        ToDelegation(ToDelegation),
     }
 }
 /// Describes the reward and exchange rates and voting power for a validator in some epoch.
@@ -249,6 +264,21 @@ pub struct ValidatorInfo {
     pub status: ::core::option::Option<ValidatorStatus>,
     #[prost(message, optional, tag = "3")]
     pub rate_data: ::core::option::Option<RateData>,
+    /// An announced commission increase that has not yet taken effect, if any.
+    #[prost(message, optional, tag = "4")]
+    pub pending_commission: ::core::option::Option<PendingCommission>,
+}
+/// A commission change announced by a validator, which takes effect at the
+/// start of the given epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingCommission {
+    /// The funding streams that will replace the validator's current funding streams.
+    #[prost(message, repeated, tag = "1")]
+    pub funding_streams: ::prost::alloc::vec::Vec<FundingStream>,
+    /// The index of the epoch in which the new funding streams take effect.
+    #[prost(uint64, tag = "2")]
+    pub effective_epoch_index: u64,
 }
 /// A transaction action (re)defining a validator.
 #[allow(clippy::derive_partial_eq_without_eq)]
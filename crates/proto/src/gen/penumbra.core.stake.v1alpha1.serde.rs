@@ -692,6 +692,9 @@ impl serde::Serialize for FundingStream {
                 funding_stream::Recipient::ToDao(v) => {
                     struct_ser.serialize_field("toDao", v)?;
                 }
+                funding_stream::Recipient::ToDelegation(v) => {
+                    struct_ser.serialize_field("toDelegation", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -708,12 +711,15 @@ impl<'de> serde::Deserialize<'de> for FundingStream {
             "toAddress",
             "to_dao",
             "toDao",
+            "to_delegation",
+            "toDelegation",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             ToAddress,
             ToDao,
+            ToDelegation,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -737,6 +743,7 @@ impl<'de> serde::Deserialize<'de> for FundingStream {
                         match value {
                             "toAddress" | "to_address" => Ok(GeneratedField::ToAddress),
                             "toDao" | "to_dao" => Ok(GeneratedField::ToDao),
+                            "toDelegation" | "to_delegation" => Ok(GeneratedField::ToDelegation),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -771,6 +778,13 @@ impl<'de> serde::Deserialize<'de> for FundingStream {
                                 return Err(serde::de::Error::duplicate_field("toDao"));
                             }
                             recipient__ = map.next_value::<::std::option::Option<_>>()?.map(funding_stream::Recipient::ToDao)
+;
+                        }
+                        GeneratedField::ToDelegation => {
+                            if recipient__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("toDelegation"));
+                            }
+                            recipient__ = map.next_value::<::std::option::Option<_>>()?.map(funding_stream::Recipient::ToDelegation)
 ;
                         }
                     }
@@ -988,6 +1002,117 @@ impl<'de> serde::Deserialize<'de> for funding_stream::ToDao {
         deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.FundingStream.ToDao", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for funding_stream::ToDelegation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.address.is_empty() {
+            len += 1;
+        }
+        if self.rate_bps != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.FundingStream.ToDelegation", len)?;
+        if !self.address.is_empty() {
+            struct_ser.serialize_field("address", &self.address)?;
+        }
+        if self.rate_bps != 0 {
+            struct_ser.serialize_field("rateBps", &self.rate_bps)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for funding_stream::ToDelegation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address",
+            "rate_bps",
+            "rateBps",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Address,
+            RateBps,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            "rateBps" | "rate_bps" => Ok(GeneratedField::RateBps),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = funding_stream::ToDelegation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.FundingStream.ToDelegation")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<funding_stream::ToDelegation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address__ = None;
+                let mut rate_bps__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::RateBps => {
+                            if rate_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateBps"));
+                            }
+                            rate_bps__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(funding_stream::ToDelegation {
+                    address: address__.unwrap_or_default(),
+                    rate_bps: rate_bps__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.FundingStream.ToDelegation", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Penalty {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1081,6 +1206,118 @@ impl<'de> serde::Deserialize<'de> for Penalty {
         deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.Penalty", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for PendingCommission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.funding_streams.is_empty() {
+            len += 1;
+        }
+        if self.effective_epoch_index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.PendingCommission", len)?;
+        if !self.funding_streams.is_empty() {
+            struct_ser.serialize_field("fundingStreams", &self.funding_streams)?;
+        }
+        if self.effective_epoch_index != 0 {
+            struct_ser.serialize_field("effectiveEpochIndex", ToString::to_string(&self.effective_epoch_index).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingCommission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "funding_streams",
+            "fundingStreams",
+            "effective_epoch_index",
+            "effectiveEpochIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FundingStreams,
+            EffectiveEpochIndex,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fundingStreams" | "funding_streams" => Ok(GeneratedField::FundingStreams),
+                            "effectiveEpochIndex" | "effective_epoch_index" => Ok(GeneratedField::EffectiveEpochIndex),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingCommission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.stake.v1alpha1.PendingCommission")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PendingCommission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut funding_streams__ = None;
+                let mut effective_epoch_index__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::FundingStreams => {
+                            if funding_streams__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingStreams"));
+                            }
+                            funding_streams__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::EffectiveEpochIndex => {
+                            if effective_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("effectiveEpochIndex"));
+                            }
+                            effective_epoch_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(PendingCommission {
+                    funding_streams: funding_streams__.unwrap_or_default(),
+                    effective_epoch_index: effective_epoch_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.stake.v1alpha1.PendingCommission", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for RateData {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2342,6 +2579,9 @@ impl serde::Serialize for ValidatorInfo {
         if self.rate_data.is_some() {
             len += 1;
         }
+        if self.pending_commission.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.stake.v1alpha1.ValidatorInfo", len)?;
         if let Some(v) = self.validator.as_ref() {
             struct_ser.serialize_field("validator", v)?;
@@ -2352,6 +2592,9 @@ impl serde::Serialize for ValidatorInfo {
         if let Some(v) = self.rate_data.as_ref() {
             struct_ser.serialize_field("rateData", v)?;
         }
+        if let Some(v) = self.pending_commission.as_ref() {
+            struct_ser.serialize_field("pendingCommission", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2366,6 +2609,8 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
             "status",
             "rate_data",
             "rateData",
+            "pending_commission",
+            "pendingCommission",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2373,6 +2618,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
             Validator,
             Status,
             RateData,
+            PendingCommission,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2397,6 +2643,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
                             "validator" => Ok(GeneratedField::Validator),
                             "status" => Ok(GeneratedField::Status),
                             "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "pendingCommission" | "pending_commission" => Ok(GeneratedField::PendingCommission),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2419,6 +2666,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
                 let mut validator__ = None;
                 let mut status__ = None;
                 let mut rate_data__ = None;
+                let mut pending_commission__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Validator => {
@@ -2439,12 +2687,19 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
                             }
                             rate_data__ = map.next_value()?;
                         }
+                        GeneratedField::PendingCommission => {
+                            if pending_commission__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingCommission"));
+                            }
+                            pending_commission__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(ValidatorInfo {
                     validator: validator__,
                     status: status__,
                     rate_data: rate_data__,
+                    pending_commission: pending_commission__,
                 })
             }
         }
@@ -61,6 +61,16 @@ pub struct TransactionPlannerRequest {
     pub account_group_id: ::core::option::Option<
         super::super::core::crypto::v1alpha1::AccountGroupId,
     >,
+    /// The strategy used to choose which notes to spend.
+    #[prost(
+        enumeration = "transaction_planner_request::NoteSelectionStrategy",
+        tag = "15"
+    )]
+    pub note_selection_strategy: i32,
+    /// If set, pad the plan with dummy spends and outputs so that it has as many of one as of the
+    /// other, rounded up to a power of two, so that transactions are harder to tell apart by shape.
+    #[prost(bool, tag = "16")]
+    pub uniform_arity: bool,
     /// Request contents
     #[prost(message, repeated, tag = "20")]
     pub outputs: ::prost::alloc::vec::Vec<transaction_planner_request::Output>,
@@ -130,6 +140,62 @@ pub mod transaction_planner_request {
             super::super::super::core::stake::v1alpha1::RateData,
         >,
     }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum NoteSelectionStrategy {
+        /// Use the view service's default strategy, which is to spend the oldest notes first.
+        Unspecified = 0,
+        /// Spend the oldest notes first.
+        OldestFirst = 1,
+        /// Spend the largest notes first, minimizing the number of notes spent.
+        MinimizeNotes = 2,
+        /// Prefer spending notes received at a single address, so as not to link addresses together.
+        AvoidMixingAddresses = 3,
+    }
+    impl NoteSelectionStrategy {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                NoteSelectionStrategy::Unspecified => {
+                    "NOTE_SELECTION_STRATEGY_UNSPECIFIED"
+                }
+                NoteSelectionStrategy::OldestFirst => {
+                    "NOTE_SELECTION_STRATEGY_OLDEST_FIRST"
+                }
+                NoteSelectionStrategy::MinimizeNotes => {
+                    "NOTE_SELECTION_STRATEGY_MINIMIZE_NOTES"
+                }
+                NoteSelectionStrategy::AvoidMixingAddresses => {
+                    "NOTE_SELECTION_STRATEGY_AVOID_MIXING_ADDRESSES"
+                }
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "NOTE_SELECTION_STRATEGY_UNSPECIFIED" => Some(Self::Unspecified),
+                "NOTE_SELECTION_STRATEGY_OLDEST_FIRST" => Some(Self::OldestFirst),
+                "NOTE_SELECTION_STRATEGY_MINIMIZE_NOTES" => Some(Self::MinimizeNotes),
+                "NOTE_SELECTION_STRATEGY_AVOID_MIXING_ADDRESSES" => {
+                    Some(Self::AvoidMixingAddresses)
+                }
+                _ => None,
+            }
+        }
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -525,6 +591,77 @@ pub struct TransactionInfo {
     pub view: ::core::option::Option<
         super::super::core::transaction::v1alpha1::TransactionView,
     >,
+    /// The status of each ICS-20 withdrawal in the transaction, in the order of their actions.
+    #[prost(message, repeated, tag = "6")]
+    pub ics20_withdrawals: ::prost::alloc::vec::Vec<Ics20WithdrawalRecord>,
+}
+/// The status of an outbound ICS-20 transfer made by this wallet.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20WithdrawalRecord {
+    /// The index of the `Ics20Withdrawal` action within its transaction.
+    #[prost(uint64, tag = "1")]
+    pub action_index: u64,
+    /// The channel the transfer was sent over.
+    #[prost(string, tag = "2")]
+    pub source_channel: ::prost::alloc::string::String,
+    /// The sequence number of the transfer's packet on that channel.
+    #[prost(uint64, tag = "3")]
+    pub sequence: u64,
+    /// The height at which the transfer's packet times out.
+    #[prost(uint64, tag = "8")]
+    pub timeout_height: u64,
+    /// The time at which the transfer's packet times out, in nanoseconds since the unix epoch.
+    #[prost(uint64, tag = "9")]
+    pub timeout_time: u64,
+    /// What has become of the transfer so far.
+    #[prost(oneof = "ics20_withdrawal_record::Status", tags = "4, 5, 6, 7")]
+    pub status: ::core::option::Option<ics20_withdrawal_record::Status>,
+}
+/// Nested message and enum types in `Ics20WithdrawalRecord`.
+pub mod ics20_withdrawal_record {
+    /// The transfer has been neither acknowledged nor timed out yet.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Pending {}
+    /// The counterparty acknowledged that it received the transfer.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Acknowledged {
+        /// The height at which the acknowledgement was processed.
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+    }
+    /// The transfer timed out or failed on the counterparty, and was refunded to its return address.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Refunded {
+        /// The height at which the refund was made.
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+    }
+    /// The transfer's packet timed out without its acknowledgement or timeout being seen, so what
+    /// became of it is unknown.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Unknown {
+        /// The height at which the transfer stopped being looked for.
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+    }
+    /// What has become of the transfer so far.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Status {
+        #[prost(message, tag = "4")]
+        Pending(Pending),
+        #[prost(message, tag = "5")]
+        Acknowledged(Acknowledged),
+        #[prost(message, tag = "6")]
+        Refunded(Refunded),
+        #[prost(message, tag = "7")]
+        Unknown(Unknown),
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -635,6 +772,59 @@ pub struct OwnedPositionIdsResponse {
         super::super::core::dex::v1alpha1::PositionId,
     >,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaymentRequestRequest {
+    /// The account to be paid into.
+    #[prost(uint32, tag = "1")]
+    pub account: u32,
+    /// The value to request.
+    #[prost(message, optional, tag = "2")]
+    pub value: ::core::option::Option<super::super::core::crypto::v1alpha1::Value>,
+    /// A reference for the payment, which the payer should include in their memo.
+    #[prost(string, tag = "3")]
+    pub memo: ::prost::alloc::string::String,
+    /// The height after which the request should no longer be paid, or 0 if it doesn't expire.
+    #[prost(uint64, tag = "4")]
+    pub expiry_height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaymentRequestResponse {
+    /// The payment request, as a `penumbra:` URI.
+    #[prost(string, tag = "1")]
+    pub uri: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaymentRequestsRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaymentRequestsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub payment_requests: ::prost::alloc::vec::Vec<PaymentRequestRecord>,
+}
+/// A payment request made by this view service, along with what has been received towards it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaymentRequestRecord {
+    /// The payment request, as a `penumbra:` URI.
+    #[prost(string, tag = "1")]
+    pub uri: ::prost::alloc::string::String,
+    /// The index of the ephemeral address the request asks to be paid at.
+    #[prost(message, optional, tag = "2")]
+    pub address_index: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::AddressIndex,
+    >,
+    /// The amount of the requested asset received at that address so far.
+    #[prost(message, optional, tag = "3")]
+    pub amount_received: ::core::option::Option<
+        super::super::core::crypto::v1alpha1::Amount,
+    >,
+    /// The height at which the request was paid in full, if it has been.
+    #[prost(uint64, optional, tag = "4")]
+    pub height_paid: ::core::option::Option<u64>,
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod view_protocol_service_client {
@@ -1185,6 +1375,48 @@ pub mod view_protocol_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// Request a payment to a fresh ephemeral address, so that the notes paying the request can be
+        /// told apart from any others.
+        pub async fn payment_request(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PaymentRequestRequest>,
+        ) -> Result<tonic::Response<super::PaymentRequestResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/PaymentRequest",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Query for the payment requests made by this view service, and what has been received towards
+        /// each of them.
+        pub async fn payment_requests(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PaymentRequestsRequest>,
+        ) -> Result<tonic::Response<super::PaymentRequestsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1alpha1.ViewProtocolService/PaymentRequests",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -1444,6 +1676,18 @@ pub mod view_protocol_service_server {
             &self,
             request: tonic::Request<super::AuthorizeAndBuildRequest>,
         ) -> Result<tonic::Response<super::AuthorizeAndBuildResponse>, tonic::Status>;
+        /// Request a payment to a fresh ephemeral address, so that the notes paying the request can be
+        /// told apart from any others.
+        async fn payment_request(
+            &self,
+            request: tonic::Request<super::PaymentRequestRequest>,
+        ) -> Result<tonic::Response<super::PaymentRequestResponse>, tonic::Status>;
+        /// Query for the payment requests made by this view service, and what has been received towards
+        /// each of them.
+        async fn payment_requests(
+            &self,
+            request: tonic::Request<super::PaymentRequestsRequest>,
+        ) -> Result<tonic::Response<super::PaymentRequestsResponse>, tonic::Status>;
     }
     /// The view protocol is used by a view client, who wants to do some
     /// transaction-related actions, to request data from a view service, which is
@@ -2391,6 +2635,86 @@ pub mod view_protocol_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1alpha1.ViewProtocolService/PaymentRequest" => {
+                    #[allow(non_camel_case_types)]
+                    struct PaymentRequestSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::UnaryService<super::PaymentRequestRequest>
+                    for PaymentRequestSvc<T> {
+                        type Response = super::PaymentRequestResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PaymentRequestRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).payment_request(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PaymentRequestSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1alpha1.ViewProtocolService/PaymentRequests" => {
+                    #[allow(non_camel_case_types)]
+                    struct PaymentRequestsSvc<T: ViewProtocolService>(pub Arc<T>);
+                    impl<
+                        T: ViewProtocolService,
+                    > tonic::server::UnaryService<super::PaymentRequestsRequest>
+                    for PaymentRequestsSvc<T> {
+                        type Response = super::PaymentRequestsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PaymentRequestsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).payment_requests(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PaymentRequestsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
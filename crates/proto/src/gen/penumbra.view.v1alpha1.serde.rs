@@ -1635,7 +1635,7 @@ impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.FMDParametersResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IndexByAddressRequest {
+impl serde::Serialize for Ics20WithdrawalRecord {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1643,29 +1643,92 @@ impl serde::Serialize for IndexByAddressRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address.is_some() {
+        if self.action_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.IndexByAddressRequest", len)?;
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        if !self.source_channel.is_empty() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if self.timeout_height != 0 {
+            len += 1;
+        }
+        if self.timeout_time != 0 {
+            len += 1;
+        }
+        if self.status.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord", len)?;
+        if self.action_index != 0 {
+            struct_ser.serialize_field("actionIndex", ToString::to_string(&self.action_index).as_str())?;
+        }
+        if !self.source_channel.is_empty() {
+            struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
+        }
+        if self.sequence != 0 {
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if self.timeout_height != 0 {
+            struct_ser.serialize_field("timeoutHeight", ToString::to_string(&self.timeout_height).as_str())?;
+        }
+        if self.timeout_time != 0 {
+            struct_ser.serialize_field("timeoutTime", ToString::to_string(&self.timeout_time).as_str())?;
+        }
+        if let Some(v) = self.status.as_ref() {
+            match v {
+                ics20_withdrawal_record::Status::Pending(v) => {
+                    struct_ser.serialize_field("pending", v)?;
+                }
+                ics20_withdrawal_record::Status::Acknowledged(v) => {
+                    struct_ser.serialize_field("acknowledged", v)?;
+                }
+                ics20_withdrawal_record::Status::Refunded(v) => {
+                    struct_ser.serialize_field("refunded", v)?;
+                }
+                ics20_withdrawal_record::Status::Unknown(v) => {
+                    struct_ser.serialize_field("unknown", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
+impl<'de> serde::Deserialize<'de> for Ics20WithdrawalRecord {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address",
+            "action_index",
+            "actionIndex",
+            "source_channel",
+            "sourceChannel",
+            "sequence",
+            "timeout_height",
+            "timeoutHeight",
+            "timeout_time",
+            "timeoutTime",
+            "pending",
+            "acknowledged",
+            "refunded",
+            "unknown",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Address,
+            ActionIndex,
+            SourceChannel,
+            Sequence,
+            TimeoutHeight,
+            TimeoutTime,
+            Pending,
+            Acknowledged,
+            Refunded,
+            Unknown,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1687,7 +1750,15 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "address" => Ok(GeneratedField::Address),
+                            "actionIndex" | "action_index" => Ok(GeneratedField::ActionIndex),
+                            "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
+                            "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
+                            "pending" => Ok(GeneratedField::Pending),
+                            "acknowledged" => Ok(GeneratedField::Acknowledged),
+                            "refunded" => Ok(GeneratedField::Refunded),
+                            "unknown" => Ok(GeneratedField::Unknown),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1697,36 +1768,106 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IndexByAddressRequest;
+            type Value = Ics20WithdrawalRecord;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.IndexByAddressRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.Ics20WithdrawalRecord")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<IndexByAddressRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Ics20WithdrawalRecord, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address__ = None;
+                let mut action_index__ = None;
+                let mut source_channel__ = None;
+                let mut sequence__ = None;
+                let mut timeout_height__ = None;
+                let mut timeout_time__ = None;
+                let mut status__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::ActionIndex => {
+                            if action_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionIndex"));
                             }
-                            address__ = map.next_value()?;
+                            action_index__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SourceChannel => {
+                            if source_channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceChannel"));
+                            }
+                            source_channel__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TimeoutHeight => {
+                            if timeout_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
+                            }
+                            timeout_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TimeoutTime => {
+                            if timeout_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutTime"));
+                            }
+                            timeout_time__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Pending => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pending"));
+                            }
+                            status__ = map.next_value::<::std::option::Option<_>>()?.map(ics20_withdrawal_record::Status::Pending)
+;
+                        }
+                        GeneratedField::Acknowledged => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("acknowledged"));
+                            }
+                            status__ = map.next_value::<::std::option::Option<_>>()?.map(ics20_withdrawal_record::Status::Acknowledged)
+;
+                        }
+                        GeneratedField::Refunded => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refunded"));
+                            }
+                            status__ = map.next_value::<::std::option::Option<_>>()?.map(ics20_withdrawal_record::Status::Refunded)
+;
+                        }
+                        GeneratedField::Unknown => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unknown"));
+                            }
+                            status__ = map.next_value::<::std::option::Option<_>>()?.map(ics20_withdrawal_record::Status::Unknown)
+;
                         }
                     }
                 }
-                Ok(IndexByAddressRequest {
-                    address: address__,
+                Ok(Ics20WithdrawalRecord {
+                    action_index: action_index__.unwrap_or_default(),
+                    source_channel: source_channel__.unwrap_or_default(),
+                    sequence: sequence__.unwrap_or_default(),
+                    timeout_height: timeout_height__.unwrap_or_default(),
+                    timeout_time: timeout_time__.unwrap_or_default(),
+                    status: status__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.IndexByAddressRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IndexByAddressResponse {
+impl serde::Serialize for ics20_withdrawal_record::Acknowledged {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1734,30 +1875,29 @@ impl serde::Serialize for IndexByAddressResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address_index.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.IndexByAddressResponse", len)?;
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord.Acknowledged", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
+impl<'de> serde::Deserialize<'de> for ics20_withdrawal_record::Acknowledged {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address_index",
-            "addressIndex",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AddressIndex,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1779,7 +1919,7 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1789,85 +1929,60 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IndexByAddressResponse;
+            type Value = ics20_withdrawal_record::Acknowledged;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.IndexByAddressResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.Ics20WithdrawalRecord.Acknowledged")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<IndexByAddressResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ics20_withdrawal_record::Acknowledged, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address_index__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            address_index__ = map.next_value()?;
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(IndexByAddressResponse {
-                    address_index: address_index__,
+                Ok(ics20_withdrawal_record::Acknowledged {
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.IndexByAddressResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord.Acknowledged", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NoteByCommitmentRequest {
+impl serde::Serialize for ics20_withdrawal_record::Pending {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.note_commitment.is_some() {
-            len += 1;
-        }
-        if self.await_detection {
-            len += 1;
-        }
-        if self.account_group_id.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NoteByCommitmentRequest", len)?;
-        if let Some(v) = self.note_commitment.as_ref() {
-            struct_ser.serialize_field("noteCommitment", v)?;
-        }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
-        }
-        if let Some(v) = self.account_group_id.as_ref() {
-            struct_ser.serialize_field("accountGroupId", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord.Pending", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
+impl<'de> serde::Deserialize<'de> for ics20_withdrawal_record::Pending {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_commitment",
-            "noteCommitment",
-            "await_detection",
-            "awaitDetection",
-            "account_group_id",
-            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteCommitment,
-            AwaitDetection,
-            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1888,12 +2003,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
-                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
-                        }
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1901,52 +2011,27 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentRequest;
+            type Value = ics20_withdrawal_record::Pending;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NoteByCommitmentRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.Ics20WithdrawalRecord.Pending")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ics20_withdrawal_record::Pending, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut await_detection__ = None;
-                let mut account_group_id__ = None;
-                while let Some(k) = map.next_key()? {
-                    match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map.next_value()?;
-                        }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
-                            }
-                            await_detection__ = Some(map.next_value()?);
-                        }
-                        GeneratedField::AccountGroupId => {
-                            if account_group_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
-                            }
-                            account_group_id__ = map.next_value()?;
-                        }
-                    }
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(NoteByCommitmentRequest {
-                    note_commitment: note_commitment__,
-                    await_detection: await_detection__.unwrap_or_default(),
-                    account_group_id: account_group_id__,
+                Ok(ics20_withdrawal_record::Pending {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord.Pending", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NoteByCommitmentResponse {
+impl serde::Serialize for ics20_withdrawal_record::Refunded {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1954,30 +2039,29 @@ impl serde::Serialize for NoteByCommitmentResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.spendable_note.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NoteByCommitmentResponse", len)?;
-        if let Some(v) = self.spendable_note.as_ref() {
-            struct_ser.serialize_field("spendableNote", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord.Refunded", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
+impl<'de> serde::Deserialize<'de> for ics20_withdrawal_record::Refunded {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spendable_note",
-            "spendableNote",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SpendableNote,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1999,7 +2083,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2009,36 +2093,38 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentResponse;
+            type Value = ics20_withdrawal_record::Refunded;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NoteByCommitmentResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.Ics20WithdrawalRecord.Refunded")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ics20_withdrawal_record::Refunded, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spendable_note__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::SpendableNote => {
-                            if spendable_note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spendableNote"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            spendable_note__ = map.next_value()?;
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(NoteByCommitmentResponse {
-                    spendable_note: spendable_note__,
+                Ok(ics20_withdrawal_record::Refunded {
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord.Refunded", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesForVotingRequest {
+impl serde::Serialize for ics20_withdrawal_record::Unknown {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2046,48 +2132,29 @@ impl serde::Serialize for NotesForVotingRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.votable_at_height != 0 {
-            len += 1;
-        }
-        if self.address_index.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.account_group_id.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesForVotingRequest", len)?;
-        if self.votable_at_height != 0 {
-            struct_ser.serialize_field("votableAtHeight", ToString::to_string(&self.votable_at_height).as_str())?;
-        }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
-        }
-        if let Some(v) = self.account_group_id.as_ref() {
-            struct_ser.serialize_field("accountGroupId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord.Unknown", len)?;
+        if self.height != 0 {
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
+impl<'de> serde::Deserialize<'de> for ics20_withdrawal_record::Unknown {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "votable_at_height",
-            "votableAtHeight",
-            "address_index",
-            "addressIndex",
-            "account_group_id",
-            "accountGroupId",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            VotableAtHeight,
-            AddressIndex,
-            AccountGroupId,
+            Height,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2109,9 +2176,7 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "votableAtHeight" | "votable_at_height" => Ok(GeneratedField::VotableAtHeight),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2121,54 +2186,38 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesForVotingRequest;
+            type Value = ics20_withdrawal_record::Unknown;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NotesForVotingRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.Ics20WithdrawalRecord.Unknown")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NotesForVotingRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ics20_withdrawal_record::Unknown, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut votable_at_height__ = None;
-                let mut address_index__ = None;
-                let mut account_group_id__ = None;
+                let mut height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::VotableAtHeight => {
-                            if votable_at_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("votableAtHeight"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            votable_at_height__ = 
+                            height__ = 
                                 Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
-                            }
-                            address_index__ = map.next_value()?;
-                        }
-                        GeneratedField::AccountGroupId => {
-                            if account_group_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
-                            }
-                            account_group_id__ = map.next_value()?;
-                        }
                     }
                 }
-                Ok(NotesForVotingRequest {
-                    votable_at_height: votable_at_height__.unwrap_or_default(),
-                    address_index: address_index__,
-                    account_group_id: account_group_id__,
+                Ok(ics20_withdrawal_record::Unknown {
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesForVotingRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.Ics20WithdrawalRecord.Unknown", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesForVotingResponse {
+impl serde::Serialize for IndexByAddressRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2176,39 +2225,29 @@ impl serde::Serialize for NotesForVotingResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_record.is_some() {
-            len += 1;
-        }
-        if self.identity_key.is_some() {
+        if self.address.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesForVotingResponse", len)?;
-        if let Some(v) = self.note_record.as_ref() {
-            struct_ser.serialize_field("noteRecord", v)?;
-        }
-        if let Some(v) = self.identity_key.as_ref() {
-            struct_ser.serialize_field("identityKey", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.IndexByAddressRequest", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
+impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_record",
-            "noteRecord",
-            "identity_key",
-            "identityKey",
+            "address",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteRecord,
-            IdentityKey,
+            Address,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2230,8 +2269,7 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
-                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "address" => Ok(GeneratedField::Address),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2241,44 +2279,36 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesForVotingResponse;
+            type Value = IndexByAddressRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NotesForVotingResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.IndexByAddressRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NotesForVotingResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IndexByAddressRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_record__ = None;
-                let mut identity_key__ = None;
+                let mut address__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::NoteRecord => {
-                            if note_record__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteRecord"));
-                            }
-                            note_record__ = map.next_value()?;
-                        }
-                        GeneratedField::IdentityKey => {
-                            if identity_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
                             }
-                            identity_key__ = map.next_value()?;
+                            address__ = map.next_value()?;
                         }
                     }
                 }
-                Ok(NotesForVotingResponse {
-                    note_record: note_record__,
-                    identity_key: identity_key__,
+                Ok(IndexByAddressRequest {
+                    address: address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesForVotingResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.IndexByAddressRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesRequest {
+impl serde::Serialize for IndexByAddressResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2286,66 +2316,30 @@ impl serde::Serialize for NotesRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.include_spent {
-            len += 1;
-        }
-        if self.asset_id.is_some() {
-            len += 1;
-        }
         if self.address_index.is_some() {
             len += 1;
         }
-        if self.amount_to_spend.is_some() {
-            len += 1;
-        }
-        if self.account_group_id.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesRequest", len)?;
-        if self.include_spent {
-            struct_ser.serialize_field("includeSpent", &self.include_spent)?;
-        }
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
-        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.IndexByAddressResponse", len)?;
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
         }
-        if let Some(v) = self.amount_to_spend.as_ref() {
-            struct_ser.serialize_field("amountToSpend", v)?;
-        }
-        if let Some(v) = self.account_group_id.as_ref() {
-            struct_ser.serialize_field("accountGroupId", v)?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesRequest {
+impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "include_spent",
-            "includeSpent",
-            "asset_id",
-            "assetId",
             "address_index",
             "addressIndex",
-            "amount_to_spend",
-            "amountToSpend",
-            "account_group_id",
-            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IncludeSpent,
-            AssetId,
             AddressIndex,
-            AmountToSpend,
-            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2367,11 +2361,7 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "includeSpent" | "include_spent" => Ok(GeneratedField::IncludeSpent),
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "amountToSpend" | "amount_to_spend" => Ok(GeneratedField::AmountToSpend),
-                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2381,68 +2371,36 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesRequest;
+            type Value = IndexByAddressResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NotesRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.IndexByAddressResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NotesRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<IndexByAddressResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut include_spent__ = None;
-                let mut asset_id__ = None;
                 let mut address_index__ = None;
-                let mut amount_to_spend__ = None;
-                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::IncludeSpent => {
-                            if include_spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeSpent"));
-                            }
-                            include_spent__ = Some(map.next_value()?);
-                        }
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
-                            }
-                            asset_id__ = map.next_value()?;
-                        }
                         GeneratedField::AddressIndex => {
                             if address_index__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("addressIndex"));
                             }
                             address_index__ = map.next_value()?;
                         }
-                        GeneratedField::AmountToSpend => {
-                            if amount_to_spend__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amountToSpend"));
-                            }
-                            amount_to_spend__ = map.next_value()?;
-                        }
-                        GeneratedField::AccountGroupId => {
-                            if account_group_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
-                            }
-                            account_group_id__ = map.next_value()?;
-                        }
                     }
                 }
-                Ok(NotesRequest {
-                    include_spent: include_spent__.unwrap_or_default(),
-                    asset_id: asset_id__,
+                Ok(IndexByAddressResponse {
                     address_index: address_index__,
-                    amount_to_spend: amount_to_spend__,
-                    account_group_id: account_group_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.IndexByAddressResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesResponse {
+impl serde::Serialize for NoteByCommitmentRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2450,30 +2408,48 @@ impl serde::Serialize for NotesResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_record.is_some() {
+        if self.note_commitment.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesResponse", len)?;
-        if let Some(v) = self.note_record.as_ref() {
-            struct_ser.serialize_field("noteRecord", v)?;
+        if self.await_detection {
+            len += 1;
+        }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NoteByCommitmentRequest", len)?;
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesResponse {
+impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_record",
-            "noteRecord",
+            "note_commitment",
+            "noteCommitment",
+            "await_detection",
+            "awaitDetection",
+            "account_group_id",
+            "accountGroupId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteRecord,
+            NoteCommitment,
+            AwaitDetection,
+            AccountGroupId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2495,7 +2471,9 @@ impl<'de> serde::Deserialize<'de> for NotesResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2505,36 +2483,1207 @@ impl<'de> serde::Deserialize<'de> for NotesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesResponse;
+            type Value = NoteByCommitmentRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NotesResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.NoteByCommitmentRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NotesResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_record__ = None;
+                let mut note_commitment__ = None;
+                let mut await_detection__ = None;
+                let mut account_group_id__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::NoteRecord => {
-                            if note_record__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteRecord"));
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(NoteByCommitmentRequest {
+                    note_commitment: note_commitment__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                    account_group_id: account_group_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NoteByCommitmentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spendable_note.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NoteByCommitmentResponse", len)?;
+        if let Some(v) = self.spendable_note.as_ref() {
+            struct_ser.serialize_field("spendableNote", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spendable_note",
+            "spendableNote",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SpendableNote,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NoteByCommitmentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NoteByCommitmentResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spendable_note__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::SpendableNote => {
+                            if spendable_note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spendableNote"));
+                            }
+                            spendable_note__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(NoteByCommitmentResponse {
+                    spendable_note: spendable_note__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotesForVotingRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.votable_at_height != 0 {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesForVotingRequest", len)?;
+        if self.votable_at_height != 0 {
+            struct_ser.serialize_field("votableAtHeight", ToString::to_string(&self.votable_at_height).as_str())?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "votable_at_height",
+            "votableAtHeight",
+            "address_index",
+            "addressIndex",
+            "account_group_id",
+            "accountGroupId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VotableAtHeight,
+            AddressIndex,
+            AccountGroupId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "votableAtHeight" | "votable_at_height" => Ok(GeneratedField::VotableAtHeight),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NotesForVotingRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NotesForVotingRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NotesForVotingRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut votable_at_height__ = None;
+                let mut address_index__ = None;
+                let mut account_group_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::VotableAtHeight => {
+                            if votable_at_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votableAtHeight"));
+                            }
+                            votable_at_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map.next_value()?;
+                        }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(NotesForVotingRequest {
+                    votable_at_height: votable_at_height__.unwrap_or_default(),
+                    address_index: address_index__,
+                    account_group_id: account_group_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesForVotingRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotesForVotingResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_record.is_some() {
+            len += 1;
+        }
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesForVotingResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_record",
+            "noteRecord",
+            "identity_key",
+            "identityKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteRecord,
+            IdentityKey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NotesForVotingResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NotesForVotingResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NotesForVotingResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_record__ = None;
+                let mut identity_key__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
+                            }
+                            note_record__ = map.next_value()?;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(NotesForVotingResponse {
+                    note_record: note_record__,
+                    identity_key: identity_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesForVotingResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.include_spent {
+            len += 1;
+        }
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.amount_to_spend.is_some() {
+            len += 1;
+        }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesRequest", len)?;
+        if self.include_spent {
+            struct_ser.serialize_field("includeSpent", &self.include_spent)?;
+        }
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.amount_to_spend.as_ref() {
+            struct_ser.serialize_field("amountToSpend", v)?;
+        }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "include_spent",
+            "includeSpent",
+            "asset_id",
+            "assetId",
+            "address_index",
+            "addressIndex",
+            "amount_to_spend",
+            "amountToSpend",
+            "account_group_id",
+            "accountGroupId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IncludeSpent,
+            AssetId,
+            AddressIndex,
+            AmountToSpend,
+            AccountGroupId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "includeSpent" | "include_spent" => Ok(GeneratedField::IncludeSpent),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "amountToSpend" | "amount_to_spend" => Ok(GeneratedField::AmountToSpend),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NotesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NotesRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NotesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut include_spent__ = None;
+                let mut asset_id__ = None;
+                let mut address_index__ = None;
+                let mut amount_to_spend__ = None;
+                let mut account_group_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::IncludeSpent => {
+                            if include_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeSpent"));
+                            }
+                            include_spent__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map.next_value()?;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map.next_value()?;
+                        }
+                        GeneratedField::AmountToSpend => {
+                            if amount_to_spend__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amountToSpend"));
+                            }
+                            amount_to_spend__ = map.next_value()?;
+                        }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(NotesRequest {
+                    include_spent: include_spent__.unwrap_or_default(),
+                    asset_id: asset_id__,
+                    address_index: address_index__,
+                    amount_to_spend: amount_to_spend__,
+                    account_group_id: account_group_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_record.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NotesResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_record",
+            "noteRecord",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteRecord,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NotesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NotesResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NotesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_record__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
+                            }
+                            note_record__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(NotesResponse {
+                    note_record: note_record__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NullifierStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.await_detection {
+            len += 1;
+        }
+        if self.account_group_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NullifierStatusRequest", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        }
+        if let Some(v) = self.account_group_id.as_ref() {
+            struct_ser.serialize_field("accountGroupId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "nullifier",
+            "await_detection",
+            "awaitDetection",
+            "account_group_id",
+            "accountGroupId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Nullifier,
+            AwaitDetection,
+            AccountGroupId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NullifierStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NullifierStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NullifierStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut nullifier__ = None;
+                let mut await_detection__ = None;
+                let mut account_group_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::AccountGroupId => {
+                            if account_group_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                            }
+                            account_group_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(NullifierStatusRequest {
+                    nullifier: nullifier__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                    account_group_id: account_group_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NullifierStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spent {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NullifierStatusResponse", len)?;
+        if self.spent {
+            struct_ser.serialize_field("spent", &self.spent)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Spent,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spent" => Ok(GeneratedField::Spent),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NullifierStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.NullifierStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NullifierStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spent__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Spent => {
+                            if spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spent"));
+                            }
+                            spent__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(NullifierStatusResponse {
+                    spent: spent__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_state.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", len)?;
+        if let Some(v) = self.position_state.as_ref() {
+            struct_ser.serialize_field("positionState", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_state",
+            "positionState",
+            "trading_pair",
+            "tradingPair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionState,
+            TradingPair,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_state__ = None;
+                let mut trading_pair__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PositionState => {
+                            if position_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionState"));
+                            }
+                            position_state__ = map.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsRequest {
+                    position_state: position_state__,
+                    trading_pair: trading_pair__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.position_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", len)?;
+        if !self.position_ids.is_empty() {
+            struct_ser.serialize_field("positionIds", &self.position_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_ids",
+            "positionIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionIds,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionIds" | "position_ids" => Ok(GeneratedField::PositionIds),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_ids__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PositionIds => {
+                            if position_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionIds"));
+                            }
+                            position_ids__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsResponse {
+                    position_ids: position_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PaymentRequestRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.uri.is_empty() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.amount_received.is_some() {
+            len += 1;
+        }
+        if self.height_paid.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PaymentRequestRecord", len)?;
+        if !self.uri.is_empty() {
+            struct_ser.serialize_field("uri", &self.uri)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.amount_received.as_ref() {
+            struct_ser.serialize_field("amountReceived", v)?;
+        }
+        if let Some(v) = self.height_paid.as_ref() {
+            struct_ser.serialize_field("heightPaid", ToString::to_string(&v).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PaymentRequestRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "uri",
+            "address_index",
+            "addressIndex",
+            "amount_received",
+            "amountReceived",
+            "height_paid",
+            "heightPaid",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Uri,
+            AddressIndex,
+            AmountReceived,
+            HeightPaid,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "uri" => Ok(GeneratedField::Uri),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "amountReceived" | "amount_received" => Ok(GeneratedField::AmountReceived),
+                            "heightPaid" | "height_paid" => Ok(GeneratedField::HeightPaid),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PaymentRequestRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1alpha1.PaymentRequestRecord")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PaymentRequestRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut uri__ = None;
+                let mut address_index__ = None;
+                let mut amount_received__ = None;
+                let mut height_paid__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Uri => {
+                            if uri__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("uri"));
+                            }
+                            uri__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
                             }
-                            note_record__ = map.next_value()?;
+                            address_index__ = map.next_value()?;
+                        }
+                        GeneratedField::AmountReceived => {
+                            if amount_received__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amountReceived"));
+                            }
+                            amount_received__ = map.next_value()?;
+                        }
+                        GeneratedField::HeightPaid => {
+                            if height_paid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightPaid"));
+                            }
+                            height_paid__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
                         }
                     }
                 }
-                Ok(NotesResponse {
-                    note_record: note_record__,
+                Ok(PaymentRequestRecord {
+                    uri: uri__.unwrap_or_default(),
+                    address_index: address_index__,
+                    amount_received: amount_received__,
+                    height_paid: height_paid__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NotesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PaymentRequestRecord", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusRequest {
+impl serde::Serialize for PaymentRequestRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2542,47 +3691,54 @@ impl serde::Serialize for NullifierStatusRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.nullifier.is_some() {
+        if self.account != 0 {
             len += 1;
         }
-        if self.await_detection {
+        if self.value.is_some() {
             len += 1;
         }
-        if self.account_group_id.is_some() {
+        if !self.memo.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NullifierStatusRequest", len)?;
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
+        if self.expiry_height != 0 {
+            len += 1;
         }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PaymentRequestRequest", len)?;
+        if self.account != 0 {
+            struct_ser.serialize_field("account", &self.account)?;
         }
-        if let Some(v) = self.account_group_id.as_ref() {
-            struct_ser.serialize_field("accountGroupId", v)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        if self.expiry_height != 0 {
+            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
+impl<'de> serde::Deserialize<'de> for PaymentRequestRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "nullifier",
-            "await_detection",
-            "awaitDetection",
-            "account_group_id",
-            "accountGroupId",
+            "account",
+            "value",
+            "memo",
+            "expiry_height",
+            "expiryHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Nullifier,
-            AwaitDetection,
-            AccountGroupId,
+            Account,
+            Value,
+            Memo,
+            ExpiryHeight,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2604,9 +3760,10 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
-                            "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            "account" => Ok(GeneratedField::Account),
+                            "value" => Ok(GeneratedField::Value),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2616,52 +3773,64 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusRequest;
+            type Value = PaymentRequestRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NullifierStatusRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.PaymentRequestRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NullifierStatusRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PaymentRequestRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut nullifier__ = None;
-                let mut await_detection__ = None;
-                let mut account_group_id__ = None;
+                let mut account__ = None;
+                let mut value__ = None;
+                let mut memo__ = None;
+                let mut expiry_height__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
                             }
-                            nullifier__ = map.next_value()?;
+                            account__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            await_detection__ = Some(map.next_value()?);
+                            value__ = map.next_value()?;
                         }
-                        GeneratedField::AccountGroupId => {
-                            if account_group_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("accountGroupId"));
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
                             }
-                            account_group_id__ = map.next_value()?;
+                            memo__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(NullifierStatusRequest {
-                    nullifier: nullifier__,
-                    await_detection: await_detection__.unwrap_or_default(),
-                    account_group_id: account_group_id__,
+                Ok(PaymentRequestRequest {
+                    account: account__.unwrap_or_default(),
+                    value: value__,
+                    memo: memo__.unwrap_or_default(),
+                    expiry_height: expiry_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PaymentRequestRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusResponse {
+impl serde::Serialize for PaymentRequestResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2669,29 +3838,29 @@ impl serde::Serialize for NullifierStatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.spent {
+        if !self.uri.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.NullifierStatusResponse", len)?;
-        if self.spent {
-            struct_ser.serialize_field("spent", &self.spent)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PaymentRequestResponse", len)?;
+        if !self.uri.is_empty() {
+            struct_ser.serialize_field("uri", &self.uri)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
+impl<'de> serde::Deserialize<'de> for PaymentRequestResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spent",
+            "uri",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Spent,
+            Uri,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2713,7 +3882,7 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "spent" => Ok(GeneratedField::Spent),
+                            "uri" => Ok(GeneratedField::Uri),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2723,76 +3892,58 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusResponse;
+            type Value = PaymentRequestResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.NullifierStatusResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.PaymentRequestResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<NullifierStatusResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PaymentRequestResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spent__ = None;
+                let mut uri__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::Spent => {
-                            if spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spent"));
+                        GeneratedField::Uri => {
+                            if uri__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("uri"));
                             }
-                            spent__ = Some(map.next_value()?);
+                            uri__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(NullifierStatusResponse {
-                    spent: spent__.unwrap_or_default(),
+                Ok(PaymentRequestResponse {
+                    uri: uri__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PaymentRequestResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsRequest {
+impl serde::Serialize for PaymentRequestsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.position_state.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", len)?;
-        if let Some(v) = self.position_state.as_ref() {
-            struct_ser.serialize_field("positionState", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PaymentRequestsRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+impl<'de> serde::Deserialize<'de> for PaymentRequestsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_state",
-            "positionState",
-            "trading_pair",
-            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionState,
-            TradingPair,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2813,11 +3964,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
-                        }
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2825,44 +3972,27 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsRequest;
+            type Value = PaymentRequestsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsRequest")
+                formatter.write_str("struct penumbra.view.v1alpha1.PaymentRequestsRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PaymentRequestsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_state__ = None;
-                let mut trading_pair__ = None;
-                while let Some(k) = map.next_key()? {
-                    match k {
-                        GeneratedField::PositionState => {
-                            if position_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionState"));
-                            }
-                            position_state__ = map.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map.next_value()?;
-                        }
-                    }
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(OwnedPositionIdsRequest {
-                    position_state: position_state__,
-                    trading_pair: trading_pair__,
+                Ok(PaymentRequestsRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PaymentRequestsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsResponse {
+impl serde::Serialize for PaymentRequestsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2870,30 +4000,30 @@ impl serde::Serialize for OwnedPositionIdsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.position_ids.is_empty() {
+        if !self.payment_requests.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", len)?;
-        if !self.position_ids.is_empty() {
-            struct_ser.serialize_field("positionIds", &self.position_ids)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.PaymentRequestsResponse", len)?;
+        if !self.payment_requests.is_empty() {
+            struct_ser.serialize_field("paymentRequests", &self.payment_requests)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+impl<'de> serde::Deserialize<'de> for PaymentRequestsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_ids",
-            "positionIds",
+            "payment_requests",
+            "paymentRequests",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionIds,
+            PaymentRequests,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2915,7 +4045,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionIds" | "position_ids" => Ok(GeneratedField::PositionIds),
+                            "paymentRequests" | "payment_requests" => Ok(GeneratedField::PaymentRequests),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2925,33 +4055,33 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsResponse;
+            type Value = PaymentRequestsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1alpha1.OwnedPositionIdsResponse")
+                formatter.write_str("struct penumbra.view.v1alpha1.PaymentRequestsResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<PaymentRequestsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_ids__ = None;
+                let mut payment_requests__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::PositionIds => {
-                            if position_ids__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionIds"));
+                        GeneratedField::PaymentRequests => {
+                            if payment_requests__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paymentRequests"));
                             }
-                            position_ids__ = Some(map.next_value()?);
+                            payment_requests__ = Some(map.next_value()?);
                         }
                     }
                 }
-                Ok(OwnedPositionIdsResponse {
-                    position_ids: position_ids__.unwrap_or_default(),
+                Ok(PaymentRequestsResponse {
+                    payment_requests: payment_requests__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1alpha1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1alpha1.PaymentRequestsResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for SpendableNoteRecord {
@@ -4026,6 +5156,9 @@ impl serde::Serialize for TransactionInfo {
         if self.view.is_some() {
             len += 1;
         }
+        if !self.ics20_withdrawals.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.view.v1alpha1.TransactionInfo", len)?;
         if let Some(v) = self.height.as_ref() {
             struct_ser.serialize_field("height", ToString::to_string(&v).as_str())?;
@@ -4042,6 +5175,9 @@ impl serde::Serialize for TransactionInfo {
         if let Some(v) = self.view.as_ref() {
             struct_ser.serialize_field("view", v)?;
         }
+        if !self.ics20_withdrawals.is_empty() {
+            struct_ser.serialize_field("ics20Withdrawals", &self.ics20_withdrawals)?;
+        }
         struct_ser.end()
     }
 }
@@ -4057,6 +5193,8 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
             "transaction",
             "perspective",
             "view",
+            "ics20_withdrawals",
+            "ics20Withdrawals",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4066,6 +5204,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
             Transaction,
             Perspective,
             View,
+            Ics20Withdrawals,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -4092,6 +5231,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                             "transaction" => Ok(GeneratedField::Transaction),
                             "perspective" => Ok(GeneratedField::Perspective),
                             "view" => Ok(GeneratedField::View),
+                            "ics20Withdrawals" | "ics20_withdrawals" => Ok(GeneratedField::Ics20Withdrawals),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -4116,6 +5256,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                 let mut transaction__ = None;
                 let mut perspective__ = None;
                 let mut view__ = None;
+                let mut ics20_withdrawals__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -4150,6 +5291,12 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                             }
                             view__ = map.next_value()?;
                         }
+                        GeneratedField::Ics20Withdrawals => {
+                            if ics20_withdrawals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20Withdrawals"));
+                            }
+                            ics20_withdrawals__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(TransactionInfo {
@@ -4158,6 +5305,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                     transaction: transaction__,
                     perspective: perspective__,
                     view: view__,
+                    ics20_withdrawals: ics20_withdrawals__.unwrap_or_default(),
                 })
             }
         }
@@ -4573,6 +5721,12 @@ impl serde::Serialize for TransactionPlannerRequest {
         if self.account_group_id.is_some() {
             len += 1;
         }
+        if self.note_selection_strategy != 0 {
+            len += 1;
+        }
+        if self.uniform_arity {
+            len += 1;
+        }
         if !self.outputs.is_empty() {
             len += 1;
         }
@@ -4601,6 +5755,14 @@ impl serde::Serialize for TransactionPlannerRequest {
         if let Some(v) = self.account_group_id.as_ref() {
             struct_ser.serialize_field("accountGroupId", v)?;
         }
+        if self.note_selection_strategy != 0 {
+            let v = transaction_planner_request::NoteSelectionStrategy::from_i32(self.note_selection_strategy)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.note_selection_strategy)))?;
+            struct_ser.serialize_field("noteSelectionStrategy", &v)?;
+        }
+        if self.uniform_arity {
+            struct_ser.serialize_field("uniformArity", &self.uniform_arity)?;
+        }
         if !self.outputs.is_empty() {
             struct_ser.serialize_field("outputs", &self.outputs)?;
         }
@@ -4632,6 +5794,10 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             "memo",
             "account_group_id",
             "accountGroupId",
+            "note_selection_strategy",
+            "noteSelectionStrategy",
+            "uniform_arity",
+            "uniformArity",
             "outputs",
             "swaps",
             "delegations",
@@ -4646,6 +5812,8 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
             Fee,
             Memo,
             AccountGroupId,
+            NoteSelectionStrategy,
+            UniformArity,
             Outputs,
             Swaps,
             Delegations,
@@ -4676,6 +5844,8 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             "fee" => Ok(GeneratedField::Fee),
                             "memo" => Ok(GeneratedField::Memo),
                             "accountGroupId" | "account_group_id" => Ok(GeneratedField::AccountGroupId),
+                            "noteSelectionStrategy" | "note_selection_strategy" => Ok(GeneratedField::NoteSelectionStrategy),
+                            "uniformArity" | "uniform_arity" => Ok(GeneratedField::UniformArity),
                             "outputs" => Ok(GeneratedField::Outputs),
                             "swaps" => Ok(GeneratedField::Swaps),
                             "delegations" => Ok(GeneratedField::Delegations),
@@ -4704,6 +5874,8 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                 let mut fee__ = None;
                 let mut memo__ = None;
                 let mut account_group_id__ = None;
+                let mut note_selection_strategy__ = None;
+                let mut uniform_arity__ = None;
                 let mut outputs__ = None;
                 let mut swaps__ = None;
                 let mut delegations__ = None;
@@ -4737,6 +5909,18 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                             }
                             account_group_id__ = map.next_value()?;
                         }
+                        GeneratedField::NoteSelectionStrategy => {
+                            if note_selection_strategy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteSelectionStrategy"));
+                            }
+                            note_selection_strategy__ = Some(map.next_value::<transaction_planner_request::NoteSelectionStrategy>()? as i32);
+                        }
+                        GeneratedField::UniformArity => {
+                            if uniform_arity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("uniformArity"));
+                            }
+                            uniform_arity__ = Some(map.next_value()?);
+                        }
                         GeneratedField::Outputs => {
                             if outputs__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("outputs"));
@@ -4774,6 +5958,8 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                     fee: fee__,
                     memo: memo__.unwrap_or_default(),
                     account_group_id: account_group_id__,
+                    note_selection_strategy: note_selection_strategy__.unwrap_or_default(),
+                    uniform_arity: uniform_arity__.unwrap_or_default(),
                     outputs: outputs__.unwrap_or_default(),
                     swaps: swaps__.unwrap_or_default(),
                     delegations: delegations__.unwrap_or_default(),
@@ -4894,6 +6080,85 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Delegate {
         deserializer.deserialize_struct("penumbra.view.v1alpha1.TransactionPlannerRequest.Delegate", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for transaction_planner_request::NoteSelectionStrategy {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "NOTE_SELECTION_STRATEGY_UNSPECIFIED",
+            Self::OldestFirst => "NOTE_SELECTION_STRATEGY_OLDEST_FIRST",
+            Self::MinimizeNotes => "NOTE_SELECTION_STRATEGY_MINIMIZE_NOTES",
+            Self::AvoidMixingAddresses => "NOTE_SELECTION_STRATEGY_AVOID_MIXING_ADDRESSES",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::NoteSelectionStrategy {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "NOTE_SELECTION_STRATEGY_UNSPECIFIED",
+            "NOTE_SELECTION_STRATEGY_OLDEST_FIRST",
+            "NOTE_SELECTION_STRATEGY_MINIMIZE_NOTES",
+            "NOTE_SELECTION_STRATEGY_AVOID_MIXING_ADDRESSES",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::NoteSelectionStrategy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(transaction_planner_request::NoteSelectionStrategy::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(transaction_planner_request::NoteSelectionStrategy::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "NOTE_SELECTION_STRATEGY_UNSPECIFIED" => Ok(transaction_planner_request::NoteSelectionStrategy::Unspecified),
+                    "NOTE_SELECTION_STRATEGY_OLDEST_FIRST" => Ok(transaction_planner_request::NoteSelectionStrategy::OldestFirst),
+                    "NOTE_SELECTION_STRATEGY_MINIMIZE_NOTES" => Ok(transaction_planner_request::NoteSelectionStrategy::MinimizeNotes),
+                    "NOTE_SELECTION_STRATEGY_AVOID_MIXING_ADDRESSES" => Ok(transaction_planner_request::NoteSelectionStrategy::AvoidMixingAddresses),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for transaction_planner_request::Output {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -179,10 +179,12 @@ extern crate ibc_types2;
 
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::Channel as RawChannel;
+use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
 
 use ibc_types2::core::channel::ChannelEnd;
+use ibc_types2::core::channel::Packet;
 use ibc_types2::core::client::Height;
 use ibc_types2::core::connection::ConnectionEnd;
 use ibc_types2::lightclients::tendermint::client_state::ClientState;
@@ -193,6 +195,9 @@ impl TypeUrl for ConnectionEnd {
 impl TypeUrl for ChannelEnd {
     const TYPE_URL: &'static str = "/ibc.core.channel.v1.Channel";
 }
+impl TypeUrl for Packet {
+    const TYPE_URL: &'static str = "/ibc.core.channel.v1.Packet";
+}
 impl TypeUrl for Height {
     const TYPE_URL: &'static str = "/ibc.core.client.v1.Height";
 }
@@ -209,6 +214,9 @@ impl DomainType for ConnectionEnd {
 impl DomainType for ChannelEnd {
     type Proto = RawChannel;
 }
+impl DomainType for Packet {
+    type Proto = RawPacket;
+}
 impl DomainType for Height {
     type Proto = RawHeight;
 }
@@ -57,3 +57,4 @@ pub use storage::{Storage, TempStorage};
 pub use write::StateWrite;
 
 pub mod future;
+pub mod proof;
@@ -0,0 +1,153 @@
+//! Verification of proofs about the chain state, for light clients.
+//!
+//! The app hash commits to the state in two layers: the state itself is recorded in a Jellyfish
+//! Merkle Tree, and the app hash is `SHA256("PenumbraAppHash" || jmt_root)`.  A proof about the
+//! value of a key, like the ones returned by pd's `KeyValue` RPC, is accordingly a pair of ICS-23
+//! proofs: a proof of the key's membership (or non-membership) in the JMT, followed by a proof of
+//! the JMT root's membership in the app hash.
+//!
+//! The functions in this module check such proofs against an app hash, such as one taken from a
+//! header verified by a Tendermint light client.  Note that the app hash in the header of a block
+//! commits to the state as of the end of the *previous* block.
+
+use anyhow::Result;
+use ics23::{commitment_proof::Proof, CommitmentProof, ExistenceProof, HostFunctionsManager};
+use sha2::{Digest, Sha256};
+
+use crate::{EscapedByteSlice, RootHash};
+
+/// The key under which the app hash commits to the JMT root.
+pub const APP_HASH_DOMAIN_SEPARATOR: &str = "PenumbraAppHash";
+
+/// The app hash committing to the state with the given JMT root.
+pub fn app_hash(root: &RootHash) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(APP_HASH_DOMAIN_SEPARATOR);
+    h.update(root.0);
+
+    h.finalize().into()
+}
+
+/// The proof spec for computing the app hash from the JMT root.
+///
+/// In ICS-23 terms, this applies a single global prefix to the state.  Having a stable merkle
+/// prefix is currently required for IBC counterparties to verify proofs of the state.
+pub fn app_hash_spec() -> ics23::ProofSpec {
+    ics23::ProofSpec {
+        // the leaf hash is simply H(key || value)
+        leaf_spec: Some(ics23::LeafOp {
+            prefix: vec![],
+            hash: ics23::HashOp::Sha256.into(),
+            length: ics23::LengthOp::NoPrefix.into(),
+            prehash_key: ics23::HashOp::NoHash.into(),
+            prehash_value: ics23::HashOp::NoHash.into(),
+        }),
+        // NOTE: we don't actually use any InnerOps.
+        inner_spec: Some(ics23::InnerSpec {
+            hash: ics23::HashOp::Sha256.into(),
+            child_order: vec![0, 1],
+            child_size: 32,
+            empty_child: vec![],
+            min_prefix_length: 0,
+            max_prefix_length: 0,
+        }),
+        min_depth: 0,
+        max_depth: 1,
+        prehash_key_before_comparison: true,
+    }
+}
+
+/// The proof specs of the two layers of a proof about the state, in order.
+pub fn proof_specs() -> Vec<ics23::ProofSpec> {
+    vec![jmt::ics23_spec(), app_hash_spec()]
+}
+
+/// A proof that the app hash commits to the given JMT root, to follow a proof about the JMT.
+pub fn app_hash_proof(root: &RootHash) -> CommitmentProof {
+    CommitmentProof {
+        proof: Some(Proof::Exist(ExistenceProof {
+            key: APP_HASH_DOMAIN_SEPARATOR.into(),
+            value: root.0.to_vec(),
+            path: vec![],
+            leaf: app_hash_spec().leaf_spec,
+        })),
+    }
+}
+
+/// Verify that the state committed to by `app_hash` maps `key` to `value`.
+pub fn verify_membership(
+    proofs: &[CommitmentProof],
+    app_hash: &[u8],
+    key: &[u8],
+    value: &[u8],
+) -> Result<()> {
+    let (jmt_proof, jmt_root) = verify_jmt_root(proofs, app_hash)?;
+
+    if !ics23::verify_membership::<HostFunctionsManager>(
+        jmt_proof,
+        &jmt::ics23_spec(),
+        &jmt_root,
+        key,
+        value,
+    ) {
+        anyhow::bail!(
+            "invalid proof of membership of key {:?}",
+            EscapedByteSlice(key)
+        );
+    }
+
+    Ok(())
+}
+
+/// Verify that the state committed to by `app_hash` has no value for `key`.
+pub fn verify_non_membership(
+    proofs: &[CommitmentProof],
+    app_hash: &[u8],
+    key: &[u8],
+) -> Result<()> {
+    let (jmt_proof, jmt_root) = verify_jmt_root(proofs, app_hash)?;
+
+    if !ics23::verify_non_membership::<HostFunctionsManager>(
+        jmt_proof,
+        &jmt::ics23_spec(),
+        &jmt_root,
+        key,
+    ) {
+        anyhow::bail!(
+            "invalid proof of non-membership of key {:?}",
+            EscapedByteSlice(key)
+        );
+    }
+
+    Ok(())
+}
+
+// checks the proof that the app hash commits to a JMT root, returning the proof about the JMT
+// along with the root it must be checked against.
+fn verify_jmt_root<'a>(
+    proofs: &'a [CommitmentProof],
+    app_hash: &[u8],
+) -> Result<(&'a CommitmentProof, Vec<u8>)> {
+    let [jmt_proof, root_proof] = proofs else {
+        anyhow::bail!(
+            "expected a proof about the JMT and a proof of its root, got {} proofs",
+            proofs.len()
+        );
+    };
+    let Some(Proof::Exist(root_existence)) = &root_proof.proof else {
+        anyhow::bail!("proof of the JMT root is not an existence proof");
+    };
+    let jmt_root = root_existence.value.clone();
+
+    if !ics23::verify_membership::<HostFunctionsManager>(
+        root_proof,
+        &app_hash_spec(),
+        &app_hash.to_vec(),
+        APP_HASH_DOMAIN_SEPARATOR.as_bytes(),
+        &jmt_root,
+    ) {
+        anyhow::bail!("invalid proof of the JMT root against the app hash");
+    }
+
+    Ok((jmt_proof, jmt_root))
+}
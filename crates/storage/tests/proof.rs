@@ -0,0 +1,98 @@
+use penumbra_storage::{proof, RootHash, StateDelta, StateWrite, TempStorage};
+
+// The test vectors: keys present in the committed state, with their values, and keys absent from
+// it, including keys sharing a prefix with present keys.
+const PRESENT: &[(&str, &[u8])] = &[
+    ("a/aa", b"aa"),
+    ("a/ab", b"ab"),
+    ("a/z", b"z"),
+    ("b/aa", &[0, 1, 2]),
+    ("test", b"test"),
+];
+const ABSENT: &[&str] = &["a", "a/a", "a/aaa", "b/ab", "zzz"];
+
+// commits the test vectors to a fresh storage, returning it along with the root of its state.
+async fn storage_with_vectors() -> anyhow::Result<(TempStorage, RootHash)> {
+    let storage = TempStorage::new().await?;
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    for (key, value) in PRESENT {
+        delta.put_raw(key.to_string(), value.to_vec());
+    }
+    let root = storage.commit(delta).await?;
+
+    Ok((storage, root))
+}
+
+#[tokio::test]
+async fn membership_proofs_verify_against_app_hash() -> anyhow::Result<()> {
+    let (storage, root) = storage_with_vectors().await?;
+    let app_hash = proof::app_hash(&root);
+    let snapshot = storage.latest_snapshot();
+
+    for (key, value) in PRESENT {
+        let (found, jmt_proof) = snapshot.get_with_proof(key.as_bytes().to_vec()).await?;
+        assert_eq!(found.as_deref(), Some(*value));
+        let proofs = [jmt_proof, proof::app_hash_proof(&root)];
+
+        proof::verify_membership(&proofs, &app_hash, key.as_bytes(), value)?;
+
+        // The proof doesn't prove any other value, nor that the key is absent...
+        assert!(proof::verify_membership(&proofs, &app_hash, key.as_bytes(), b"other").is_err());
+        assert!(proof::verify_non_membership(&proofs, &app_hash, key.as_bytes()).is_err());
+        // ...nor anything about any other key, or against any other app hash.
+        assert!(proof::verify_membership(&proofs, &app_hash, b"other", value).is_err());
+        assert!(proof::verify_membership(&proofs, &[0; 32], key.as_bytes(), value).is_err());
+        // Both layers of the proof are needed.
+        assert!(proof::verify_membership(&proofs[..1], &app_hash, key.as_bytes(), value).is_err());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_membership_proofs_verify_against_app_hash() -> anyhow::Result<()> {
+    let (storage, root) = storage_with_vectors().await?;
+    let app_hash = proof::app_hash(&root);
+    let snapshot = storage.latest_snapshot();
+
+    for key in ABSENT {
+        let (found, jmt_proof) = snapshot.get_with_proof(key.as_bytes().to_vec()).await?;
+        assert_eq!(found, None);
+        let proofs = [jmt_proof, proof::app_hash_proof(&root)];
+
+        proof::verify_non_membership(&proofs, &app_hash, key.as_bytes())?;
+
+        assert!(proof::verify_membership(&proofs, &app_hash, key.as_bytes(), b"").is_err());
+        assert!(proof::verify_non_membership(&proofs, &[0; 32], key.as_bytes()).is_err());
+        // The proof doesn't show that a present key is absent.
+        for (present, _) in PRESENT {
+            assert!(proof::verify_non_membership(&proofs, &app_hash, present.as_bytes()).is_err());
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn proofs_of_an_old_state_fail_against_a_new_app_hash() -> anyhow::Result<()> {
+    let (storage, old_root) = storage_with_vectors().await?;
+    let (key, value) = PRESENT[0];
+    let (_, jmt_proof) = storage
+        .latest_snapshot()
+        .get_with_proof(key.as_bytes().to_vec())
+        .await?;
+    let proofs = [jmt_proof, proof::app_hash_proof(&old_root)];
+
+    let mut delta = StateDelta::new(storage.latest_snapshot());
+    delta.put_raw(key.to_string(), b"changed".to_vec());
+    let new_root = storage.commit(delta).await?;
+
+    proof::verify_membership(&proofs, &proof::app_hash(&old_root), key.as_bytes(), value)?;
+    assert!(
+        proof::verify_membership(&proofs, &proof::app_hash(&new_root), key.as_bytes(), value)
+            .is_err()
+    );
+
+    Ok(())
+}
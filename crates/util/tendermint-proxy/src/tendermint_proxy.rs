@@ -13,6 +13,8 @@ use proto::client::v1alpha1::BroadcastTxSyncRequest;
 use proto::client::v1alpha1::BroadcastTxSyncResponse;
 use proto::client::v1alpha1::GetBlockByHeightRequest;
 use proto::client::v1alpha1::GetBlockByHeightResponse;
+use proto::client::v1alpha1::GetBlockResultsRequest;
+use proto::client::v1alpha1::GetBlockResultsResponse;
 use proto::client::v1alpha1::GetStatusRequest;
 use proto::client::v1alpha1::GetStatusResponse;
 use proto::client::v1alpha1::GetTxRequest;
@@ -62,28 +64,7 @@ impl TendermintProxyService for TendermintProxy {
 
         Ok(tonic::Response::new(GetTxResponse {
             tx: tx.into(),
-            tx_result: Some(proto::client::v1alpha1::TxResult {
-                log: rsp.tx_result.log.to_string(),
-                // TODO: validation here, fix mismatch between i64 <> u64
-                gas_wanted: rsp.tx_result.gas_wanted as u64,
-                gas_used: rsp.tx_result.gas_used as u64,
-                tags: rsp
-                    .tx_result
-                    .events
-                    .iter()
-                    .flat_map(|e| {
-                        let a = &e.attributes;
-                        a.iter().map(move |a| {
-                            proto::client::v1alpha1::Tag {
-                                key: a.key.to_string().as_bytes().to_vec(),
-                                value: a.value.to_string().as_bytes().to_vec(),
-                                // TODO: not sure where this index value comes from
-                                index: false,
-                            }
-                        })
-                    })
-                    .collect(),
-            }),
+            tx_result: Some(tx_result(&rsp.tx_result)),
             height: rsp.height.value(),
             index: rsp.index as u64,
             hash: rsp.hash.as_bytes().to_vec(),
@@ -480,6 +461,57 @@ impl TendermintProxyService for TendermintProxy {
             }),
         }))
     }
+
+    async fn get_block_results(
+        &self,
+        req: tonic::Request<GetBlockResultsRequest>,
+    ) -> Result<tonic::Response<GetBlockResultsResponse>, Status> {
+        let client = HttpClient::new(self.tendermint_url.to_string().as_ref()).unwrap();
+
+        let height: Height = req
+            .get_ref()
+            .height
+            .try_into()
+            .map_err(|_| tonic::Status::invalid_argument("invalid height"))?;
+        let res = client.block_results(height).await.map_err(|e| {
+            tonic::Status::unavailable(format!("error querying block results: {e}"))
+        })?;
+
+        Ok(tonic::Response::new(GetBlockResultsResponse {
+            height: res.height.into(),
+            txs_results: res
+                .txs_results
+                .unwrap_or_default()
+                .iter()
+                .map(tx_result)
+                .collect(),
+        }))
+    }
+}
+
+fn tx_result(result: &tendermint::abci::response::DeliverTx) -> proto::client::v1alpha1::TxResult {
+    proto::client::v1alpha1::TxResult {
+        log: result.log.to_string(),
+        // TODO: validation here, fix mismatch between i64 <> u64
+        gas_wanted: result.gas_wanted as u64,
+        gas_used: result.gas_used as u64,
+        tags: result
+            .events
+            .iter()
+            .flat_map(|e| {
+                let a = &e.attributes;
+                a.iter().map(move |a| {
+                    proto::client::v1alpha1::Tag {
+                        key: a.key.to_string().as_bytes().to_vec(),
+                        value: a.value.to_string().as_bytes().to_vec(),
+                        // TODO: not sure where this index value comes from
+                        index: false,
+                    }
+                })
+            })
+            .collect(),
+        code: result.code.value(),
+    }
 }
 
 /// Implements service traits for Tonic gRPC services.
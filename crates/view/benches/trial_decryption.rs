@@ -0,0 +1,81 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rand_core::OsRng;
+
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_chain::NoteSource;
+use penumbra_compact_block::{CompactBlock, StatePayload};
+use penumbra_crypto::{
+    keys::{AddressIndex, SeedPhrase, SpendKey},
+    FullViewingKey, Note,
+};
+use penumbra_view::{trial_decrypt_block, trial_decrypt_blocks};
+
+const BLOCKS: usize = 64;
+const NOTES_PER_BLOCK: usize = 64;
+
+fn generate_fvk() -> FullViewingKey {
+    SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0)
+        .full_viewing_key()
+        .clone()
+}
+
+// builds blocks of notes sent to someone else, which is what most of a busy chain looks like to
+// any one wallet.
+fn create_blocks() -> Vec<CompactBlock> {
+    let (address, _dtk) = generate_fvk().payment_address(AddressIndex::from(0u32));
+    let value = Value {
+        amount: 1u64.into(),
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    };
+
+    (0..BLOCKS)
+        .map(|height| CompactBlock {
+            height: height as u64,
+            state_payloads: (0..NOTES_PER_BLOCK)
+                .map(|_| StatePayload::Note {
+                    source: NoteSource::Unknown,
+                    note: Box::new(Note::generate(&mut OsRng, &address, value).payload()),
+                })
+                .collect(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn bench(c: &mut Criterion) {
+    let fvk = generate_fvk();
+    let blocks = create_blocks();
+
+    let mut group = c.benchmark_group("trial-decryption");
+    // We're already benchmarking batches of blocks, so we don't need as many runs
+    group.sample_size(10);
+    group.throughput(Throughput::Elements((BLOCKS * NOTES_PER_BLOCK) as u64));
+
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            blocks
+                .iter()
+                .flat_map(|block| block.state_payloads.iter())
+                .filter(|payload| match payload {
+                    StatePayload::Note { note, .. } => note.trial_decrypt(&fvk).is_some(),
+                    _ => false,
+                })
+                .count()
+        })
+    });
+    group.bench_function("parallel_within_blocks", |b| {
+        b.iter(|| {
+            blocks
+                .iter()
+                .map(|block| trial_decrypt_block(&fvk, block.clone()))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("parallel_batch", |b| {
+        b.iter(|| trial_decrypt_blocks(&fvk, blocks.clone()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -2,7 +2,10 @@ use std::{collections::BTreeMap, future::Future, pin::Pin};
 
 use anyhow::Result;
 use futures::{FutureExt, Stream, StreamExt, TryStreamExt};
-use penumbra_asset::asset::{self, DenomMetadata, Id};
+use penumbra_asset::{
+    asset::{self, DenomMetadata, Id},
+    Value,
+};
 use penumbra_chain::params::{ChainParameters, FmdParameters};
 use penumbra_crypto::keys::AccountGroupId;
 use penumbra_crypto::Address;
@@ -19,7 +22,10 @@ use penumbra_transaction::{plan::TransactionPlan, Transaction, WitnessData};
 use tonic::codegen::Bytes;
 use tracing::instrument;
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
+use crate::{
+    PaymentRequest, PaymentRequestRecord, SpendableNoteRecord, StatusStreamResponse, SwapRecord,
+    TransactionInfo,
+};
 
 /// The view protocol is used by a view client, who wants to do some
 /// transaction-related actions, to request data from a view service, which is
@@ -252,6 +258,21 @@ pub trait ViewClient {
         &mut self,
         address_index: AddressIndex,
     ) -> Pin<Box<dyn Future<Output = Result<Address>> + Send + 'static>>;
+
+    /// Requests a payment of the given value into the given account, at a fresh address.
+    fn payment_request(
+        &mut self,
+        account: u32,
+        value: Value,
+        memo: Option<String>,
+        expiry_height: Option<u64>,
+    ) -> Pin<Box<dyn Future<Output = Result<PaymentRequest>> + Send + 'static>>;
+
+    /// Queries for the payment requests made by this wallet, and what has been received towards
+    /// each of them.
+    fn payment_requests(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PaymentRequestRecord>>> + Send + 'static>>;
 }
 
 // We need to tell `async_trait` not to add a `Send` bound to the boxed
@@ -671,6 +692,11 @@ where
                     .view
                     .ok_or_else(|| anyhow::anyhow!("missing view"))?
                     .try_into()?,
+                ics20_withdrawals: rsp
+                    .ics20_withdrawals
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_>>()?,
             };
 
             Ok(tx_info)
@@ -720,6 +746,11 @@ where
                             .view
                             .ok_or_else(|| anyhow::anyhow!("missing view"))?
                             .try_into()?,
+                        ics20_withdrawals: tx_rsp
+                            .ics20_withdrawals
+                            .into_iter()
+                            .map(TryInto::try_into)
+                            .collect::<Result<_>>()?,
                     };
 
                     Ok(tx_info)
@@ -802,4 +833,49 @@ where
         }
         .boxed()
     }
+
+    fn payment_request(
+        &mut self,
+        account: u32,
+        value: Value,
+        memo: Option<String>,
+        expiry_height: Option<u64>,
+    ) -> Pin<Box<dyn Future<Output = Result<PaymentRequest>> + Send + 'static>> {
+        let request = pb::PaymentRequestRequest {
+            account,
+            value: Some(value.into()),
+            memo: memo.unwrap_or_default(),
+            expiry_height: expiry_height.unwrap_or_default(),
+        };
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewProtocolServiceClient::payment_request(
+                &mut self2,
+                tonic::Request::new(request),
+            );
+
+            rsp.await?.into_inner().uri.parse()
+        }
+        .boxed()
+    }
+
+    fn payment_requests(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PaymentRequestRecord>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewProtocolServiceClient::payment_requests(
+                &mut self2,
+                tonic::Request::new(pb::PaymentRequestsRequest {}),
+            );
+
+            rsp.await?
+                .into_inner()
+                .payment_requests
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect()
+        }
+        .boxed()
+    }
 }
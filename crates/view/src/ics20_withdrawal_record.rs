@@ -0,0 +1,170 @@
+use penumbra_proto::{view::v1alpha1 as pb, DomainType, TypeUrl};
+use r2d2_sqlite::rusqlite::Row;
+use serde::{Deserialize, Serialize};
+
+/// The status of an outbound ICS-20 transfer made by this wallet.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(
+    try_from = "pb::Ics20WithdrawalRecord",
+    into = "pb::Ics20WithdrawalRecord"
+)]
+pub struct Ics20WithdrawalRecord {
+    /// The index of the `Ics20Withdrawal` action within its transaction.
+    pub action_index: u64,
+    /// The channel the transfer was sent over.
+    pub source_channel: String,
+    /// The sequence number of the transfer's packet on that channel.
+    pub sequence: u64,
+    /// The height at which the transfer's packet times out.
+    pub timeout_height: u64,
+    /// The time at which the transfer's packet times out, in nanoseconds since the unix epoch.
+    pub timeout_time: u64,
+    /// What has become of the transfer so far.
+    pub status: Ics20WithdrawalStatus,
+}
+
+/// What has become of an outbound ICS-20 transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ics20WithdrawalStatus {
+    /// The transfer has been neither acknowledged nor timed out yet.
+    Pending,
+    /// The counterparty acknowledged that it received the transfer, at the given height.
+    Acknowledged { height: u64 },
+    /// The transfer timed out or failed on the counterparty, and was refunded to its return
+    /// address at the given height.
+    Refunded { height: u64 },
+    /// The transfer's packet timed out without its acknowledgement or timeout being seen, so
+    /// what became of it is unknown. It stopped being looked for at the given height.
+    Unknown { height: u64 },
+}
+
+impl Ics20WithdrawalStatus {
+    /// The name of the status, as stored in the database.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Ics20WithdrawalStatus::Pending => "pending",
+            Ics20WithdrawalStatus::Acknowledged { .. } => "acknowledged",
+            Ics20WithdrawalStatus::Refunded { .. } => "refunded",
+            Ics20WithdrawalStatus::Unknown { .. } => "unknown",
+        }
+    }
+
+    /// The height at which the transfer was resolved, if it has been.
+    pub fn height_resolved(&self) -> Option<u64> {
+        match self {
+            Ics20WithdrawalStatus::Pending => None,
+            Ics20WithdrawalStatus::Acknowledged { height }
+            | Ics20WithdrawalStatus::Refunded { height }
+            | Ics20WithdrawalStatus::Unknown { height } => Some(*height),
+        }
+    }
+}
+
+impl std::fmt::Display for Ics20WithdrawalStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ics20WithdrawalStatus::Pending => write!(f, "pending"),
+            Ics20WithdrawalStatus::Acknowledged { height } => {
+                write!(f, "acknowledged at height {height}")
+            }
+            Ics20WithdrawalStatus::Refunded { height } => write!(f, "refunded at height {height}"),
+            Ics20WithdrawalStatus::Unknown { height } => {
+                write!(f, "unknown as of height {height}")
+            }
+        }
+    }
+}
+
+impl TypeUrl for Ics20WithdrawalRecord {
+    const TYPE_URL: &'static str = "/penumbra.view.v1alpha1.Ics20WithdrawalRecord";
+}
+
+impl DomainType for Ics20WithdrawalRecord {
+    type Proto = pb::Ics20WithdrawalRecord;
+}
+
+impl From<Ics20WithdrawalRecord> for pb::Ics20WithdrawalRecord {
+    fn from(msg: Ics20WithdrawalRecord) -> Self {
+        use pb::ics20_withdrawal_record::{Acknowledged, Pending, Refunded, Status, Unknown};
+
+        pb::Ics20WithdrawalRecord {
+            action_index: msg.action_index,
+            source_channel: msg.source_channel,
+            sequence: msg.sequence,
+            timeout_height: msg.timeout_height,
+            timeout_time: msg.timeout_time,
+            status: Some(match msg.status {
+                Ics20WithdrawalStatus::Pending => Status::Pending(Pending {}),
+                Ics20WithdrawalStatus::Acknowledged { height } => {
+                    Status::Acknowledged(Acknowledged { height })
+                }
+                Ics20WithdrawalStatus::Refunded { height } => Status::Refunded(Refunded { height }),
+                Ics20WithdrawalStatus::Unknown { height } => Status::Unknown(Unknown { height }),
+            }),
+        }
+    }
+}
+
+impl TryFrom<pb::Ics20WithdrawalRecord> for Ics20WithdrawalRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::Ics20WithdrawalRecord) -> Result<Self, Self::Error> {
+        use pb::ics20_withdrawal_record::Status;
+
+        Ok(Self {
+            action_index: value.action_index,
+            source_channel: value.source_channel,
+            sequence: value.sequence,
+            timeout_height: value.timeout_height,
+            timeout_time: value.timeout_time,
+            status: match value
+                .status
+                .ok_or_else(|| anyhow::anyhow!("missing status"))?
+            {
+                Status::Pending(_) => Ics20WithdrawalStatus::Pending,
+                Status::Acknowledged(ack) => {
+                    Ics20WithdrawalStatus::Acknowledged { height: ack.height }
+                }
+                Status::Refunded(refund) => Ics20WithdrawalStatus::Refunded {
+                    height: refund.height,
+                },
+                Status::Unknown(unknown) => Ics20WithdrawalStatus::Unknown {
+                    height: unknown.height,
+                },
+            },
+        })
+    }
+}
+
+impl TryFrom<&Row<'_>> for Ics20WithdrawalRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        let status: String = row.get("status")?;
+        let height_resolved: Option<u64> = row.get("height_resolved")?;
+        let resolved = || {
+            height_resolved.ok_or_else(|| anyhow::anyhow!("missing height for {status} withdrawal"))
+        };
+
+        Ok(Self {
+            action_index: row.get("action_index")?,
+            source_channel: row.get("source_channel")?,
+            sequence: row.get("sequence")?,
+            timeout_height: row.get("timeout_height")?,
+            timeout_time: row.get("timeout_time")?,
+            status: match status.as_str() {
+                "pending" => Ics20WithdrawalStatus::Pending,
+                "acknowledged" => Ics20WithdrawalStatus::Acknowledged {
+                    height: resolved()?,
+                },
+                "refunded" => Ics20WithdrawalStatus::Refunded {
+                    height: resolved()?,
+                },
+                "unknown" => Ics20WithdrawalStatus::Unknown {
+                    height: resolved()?,
+                },
+                other => anyhow::bail!("unknown ICS-20 withdrawal status {other}"),
+            },
+        })
+    }
+}
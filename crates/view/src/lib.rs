@@ -1,7 +1,9 @@
 #![recursion_limit = "256"]
 mod client;
+mod ics20_withdrawal_record;
 mod metrics;
 mod note_record;
+mod payment_request;
 mod planner;
 mod service;
 mod status;
@@ -15,10 +17,14 @@ use worker::Worker;
 
 pub use crate::metrics::register_metrics;
 pub use client::ViewClient;
+pub use ics20_withdrawal_record::{Ics20WithdrawalRecord, Ics20WithdrawalStatus};
 pub use note_record::SpendableNoteRecord;
-pub use planner::Planner;
+pub use payment_request::{PaymentRequest, PaymentRequestRecord};
+pub use planner::{Planner, SelectionStrategy};
 pub use service::ViewService;
 pub use status::StatusStreamResponse;
 pub use storage::Storage;
 pub use swap_record::SwapRecord;
+pub use sync::{trial_decrypt_block, trial_decrypt_blocks, DecryptedBlock};
 pub use transaction_info::TransactionInfo;
+pub use worker::DetectionConfig;
@@ -0,0 +1,156 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, Context};
+use penumbra_asset::{asset, Value};
+use penumbra_crypto::{keys::AddressIndex, Address};
+use penumbra_num::Amount;
+use penumbra_proto::{view::v1alpha1 as pb, DomainType, TypeUrl};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// The URI scheme of a payment request.
+const SCHEME: &str = "penumbra";
+
+/// A request for a payment of a particular value to a particular address.
+///
+/// Payment requests are shared as URIs of the form
+/// `penumbra:<address>?asset=<asset id>&amount=<amount>&memo=<memo>&expiry=<height>`, where the
+/// amount is in base units of the asset, and the memo and expiry are optional.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentRequest {
+    /// The address to pay.
+    pub address: Address,
+    /// The value requested.
+    pub value: Value,
+    /// A reference for the payment, which the payer should include in their memo.
+    pub memo: Option<String>,
+    /// The height after which the request should no longer be paid, if any.
+    pub expiry_height: Option<u64>,
+}
+
+impl PaymentRequest {
+    /// Whether the request has expired as of the given height.
+    pub fn is_expired(&self, height: u64) -> bool {
+        self.expiry_height
+            .map_or(false, |expiry_height| height > expiry_height)
+    }
+}
+
+impl fmt::Display for PaymentRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut uri = Url::parse(&format!("{SCHEME}:{}", self.address)).map_err(|_| fmt::Error)?;
+        {
+            let mut query = uri.query_pairs_mut();
+            query
+                .append_pair("asset", &self.value.asset_id.to_string())
+                .append_pair("amount", &self.value.amount.to_string());
+            if let Some(memo) = &self.memo {
+                query.append_pair("memo", memo);
+            }
+            if let Some(expiry_height) = self.expiry_height {
+                query.append_pair("expiry", &expiry_height.to_string());
+            }
+        }
+        write!(f, "{uri}")
+    }
+}
+
+impl FromStr for PaymentRequest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let uri = Url::parse(s).context("payment request is not a valid URI")?;
+        if uri.scheme() != SCHEME {
+            anyhow::bail!("payment request has scheme {}, not {SCHEME}", uri.scheme());
+        }
+        let address = uri.path().parse().context("invalid address")?;
+
+        let mut asset_id = None;
+        let mut amount = None;
+        let mut memo = None;
+        let mut expiry_height = None;
+        for (key, value) in uri.query_pairs() {
+            match key.as_ref() {
+                "asset" => asset_id = Some(asset::Id::from_str(&value).context("invalid asset")?),
+                "amount" => {
+                    amount = Some(Amount::from(
+                        value.parse::<u128>().context("invalid amount")?,
+                    ))
+                }
+                "memo" => memo = Some(value.into_owned()),
+                "expiry" => expiry_height = Some(value.parse().context("invalid expiry")?),
+                // Ignore unknown parameters, so that they can be added in the future.
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            address,
+            value: Value {
+                amount: amount.ok_or_else(|| anyhow!("payment request is missing an amount"))?,
+                asset_id: asset_id.ok_or_else(|| anyhow!("payment request is missing an asset"))?,
+            },
+            memo,
+            expiry_height,
+        })
+    }
+}
+
+/// A payment request made by this view service, along with what has been received towards it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(
+    try_from = "pb::PaymentRequestRecord",
+    into = "pb::PaymentRequestRecord"
+)]
+pub struct PaymentRequestRecord {
+    /// The payment request.
+    pub request: PaymentRequest,
+    /// The index of the ephemeral address the request asks to be paid at.
+    ///
+    /// Since the address is used for nothing else, any notes received at it are taken to be
+    /// payments towards the request.
+    pub address_index: AddressIndex,
+    /// The amount of the requested asset received at the address so far, not counting anything
+    /// received after the request expired.
+    pub amount_received: Amount,
+    /// The height at which the request was paid in full, if it has been.
+    pub height_paid: Option<u64>,
+}
+
+impl TypeUrl for PaymentRequestRecord {
+    const TYPE_URL: &'static str = "/penumbra.view.v1alpha1.PaymentRequestRecord";
+}
+
+impl DomainType for PaymentRequestRecord {
+    type Proto = pb::PaymentRequestRecord;
+}
+
+impl From<PaymentRequestRecord> for pb::PaymentRequestRecord {
+    fn from(msg: PaymentRequestRecord) -> Self {
+        pb::PaymentRequestRecord {
+            uri: msg.request.to_string(),
+            address_index: Some(msg.address_index.into()),
+            amount_received: Some(msg.amount_received.into()),
+            height_paid: msg.height_paid,
+        }
+    }
+}
+
+impl TryFrom<pb::PaymentRequestRecord> for PaymentRequestRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PaymentRequestRecord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            request: value.uri.parse()?,
+            address_index: value
+                .address_index
+                .ok_or_else(|| anyhow!("missing address index"))?
+                .try_into()?,
+            amount_received: value
+                .amount_received
+                .ok_or_else(|| anyhow!("missing amount received"))?
+                .try_into()?,
+            height_paid: value.height_paid,
+        })
+    }
+}
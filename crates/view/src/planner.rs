@@ -4,7 +4,7 @@ use std::{
     mem,
 };
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 
 use penumbra_asset::{asset::DenomMetadata, Balance, Value};
 use penumbra_chain::params::{ChainParameters, FmdParameters};
@@ -33,7 +33,7 @@ use penumbra_stake::{IdentityKey, UndelegateClaimPlan};
 use penumbra_tct as tct;
 use penumbra_transaction::{
     action::{
-        Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote, Vote,
+        Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, SplitVote, ValidatorVote,
     },
     memo::MemoPlaintext,
     plan::{ActionPlan, DelegatorVotePlan, MemoPlan, TransactionPlan},
@@ -44,10 +44,16 @@ use tracing::instrument;
 
 use crate::{SpendableNoteRecord, ViewClient};
 
+mod selection;
+pub use selection::SelectionStrategy;
+
 /// A planner for a [`TransactionPlan`] that can fill in the required spends and change outputs upon
 /// finalization to make a transaction balance.
 pub struct Planner<R: RngCore + CryptoRng> {
     rng: R,
+    // How the planner is configured, which is kept when the planner is finished
+    selection_strategy: SelectionStrategy,
+    uniform_arity: bool,
     balance: Balance,
     vote_intents: BTreeMap<u64, VoteIntent>,
     plan: TransactionPlan,
@@ -60,12 +66,14 @@ struct VoteIntent {
     start_block_height: u64,
     start_position: tct::Position,
     rate_data: BTreeMap<IdentityKey, RateData>,
-    vote: Vote,
+    vote: SplitVote,
 }
 
 impl<R: RngCore + CryptoRng> Debug for Planner<R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Builder")
+            .field("selection_strategy", &self.selection_strategy)
+            .field("uniform_arity", &self.uniform_arity)
             .field("balance", &self.balance)
             .field("plan", &self.plan)
             .finish()
@@ -77,6 +85,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     pub fn new(rng: R) -> Self {
         Self {
             rng,
+            selection_strategy: SelectionStrategy::default(),
+            uniform_arity: false,
             balance: Balance::default(),
             vote_intents: BTreeMap::default(),
             plan: TransactionPlan::default(),
@@ -89,7 +99,28 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         &self.balance
     }
 
+    /// Set the strategy used to choose which notes to spend when balancing the transaction.
+    #[instrument(skip(self))]
+    pub fn selection_strategy(&mut self, selection_strategy: SelectionStrategy) -> &mut Self {
+        self.selection_strategy = selection_strategy;
+        self
+    }
+
+    /// Set whether to pad the transaction with dummy spends and outputs, so that it has as many
+    /// spends as outputs, rounded up to a power of two.
+    ///
+    /// This makes it harder to tell transactions apart by their number of spends and outputs, at
+    /// the cost of making them larger.
+    #[instrument(skip(self))]
+    pub fn uniform_arity(&mut self, uniform_arity: bool) -> &mut Self {
+        self.uniform_arity = uniform_arity;
+        self
+    }
+
     /// Get all the note requests necessary to fulfill the current [`Balance`].
+    ///
+    /// These request every spendable note of each required asset, so that the planner's
+    /// [`SelectionStrategy`] can choose among them.
     pub fn notes_requests(
         &self,
         account_group_id: AccountGroupId,
@@ -98,11 +129,10 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         (
             self.balance
                 .required()
-                .map(|Value { asset_id, amount }| NotesRequest {
+                .map(|Value { asset_id, .. }| NotesRequest {
                     account_group_id: Some(account_group_id.into()),
                     asset_id: Some(asset_id.into()),
                     address_index: Some(source.into()),
-                    amount_to_spend: Some(amount.into()),
                     include_spent: false,
                     ..Default::default()
                 })
@@ -360,6 +390,9 @@ impl<R: RngCore + CryptoRng> Planner<R> {
 
     /// Vote with all possible vote weight on a given proposal.
     ///
+    /// The vote may be a [`SplitVote`], in which case the voting power of every note is divided
+    /// between the options according to the weights of the split vote.
+    ///
     /// Voting twice on the same proposal in the same planner will overwrite the previous vote.
     #[instrument(skip(self, start_position, start_rate_data))]
     pub fn delegator_vote(
@@ -368,14 +401,14 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         start_block_height: u64,
         start_position: tct::Position,
         start_rate_data: BTreeMap<IdentityKey, RateData>,
-        vote: Vote,
+        vote: impl Into<SplitVote>,
     ) -> &mut Self {
         self.vote_intents.insert(
             proposal,
             VoteIntent {
                 start_position,
                 start_block_height,
-                vote,
+                vote: vote.into(),
                 rate_data: start_rate_data,
             },
         );
@@ -392,7 +425,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         &mut self,
         proposal: u64,
         start_position: tct::Position,
-        vote: Vote,
+        vote: impl Into<SplitVote>,
         note: Note,
         position: tct::Position,
         unbonded_amount: Amount,
@@ -401,7 +434,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             &mut self.rng,
             proposal,
             start_position,
-            vote,
+            vote.into(),
             note,
             position,
             unbonded_amount,
@@ -484,9 +517,24 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         // Fill in the chain id based on the view service
         self.plan.chain_id = chain_params.chain_id.clone();
 
-        // Add the required spends to the planner
+        // Add the required spends to the planner, choosing which notes to spend using the
+        // selection strategy
+        let mut notes_by_asset = BTreeMap::<_, Vec<_>>::new();
         for record in spendable_notes {
-            self.spend(record.note, record.position);
+            notes_by_asset
+                .entry(record.note.asset_id())
+                .or_default()
+                .push(record);
+        }
+        for Value { asset_id, amount } in self.balance.required().collect::<Vec<_>>() {
+            let candidates = notes_by_asset.remove(&asset_id).unwrap_or_default();
+            let selected = self
+                .selection_strategy
+                .select(amount, candidates)
+                .with_context(|| format!("could not select notes of asset {asset_id} to spend"))?;
+            for record in selected {
+                self.spend(record.note, record.position);
+            }
         }
         // Add any IBC actions to the planner
         for ibc_action in self.ibc_actions.clone() {
@@ -574,6 +622,22 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             );
         }
 
+        // If requested, pad the transaction with dummy spends and outputs, so that its shape doesn't
+        // reveal how many notes were spent or created
+        let num_spends = self.plan.spend_plans().count();
+        let num_outputs = self.plan.num_outputs();
+        if self.uniform_arity && (num_spends > 0 || num_outputs > 0) {
+            let arity = num_spends.max(num_outputs).max(2).next_power_of_two();
+            for _ in num_spends..arity {
+                let dummy = SpendPlan::dummy(&mut self.rng).into();
+                self.action(dummy);
+            }
+            for _ in num_outputs..arity {
+                let dummy = OutputPlan::dummy(&mut self.rng).into();
+                self.action(dummy);
+            }
+        }
+
         // If there are outputs, we check that a memo has been added. If not, we add a default memo.
         if self.plan.num_outputs() > 0 && self.plan.memo_plan.is_none() {
             self.memo(MemoPlaintext::default())
@@ -0,0 +1,94 @@
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use anyhow::Result;
+use penumbra_num::Amount;
+use penumbra_proto::view::v1alpha1::transaction_planner_request::NoteSelectionStrategy;
+
+use crate::SpendableNoteRecord;
+
+/// A strategy for choosing which notes to spend to cover an amount.
+///
+/// Every strategy spends only as many notes as it needs to; they differ in which notes they spend
+/// first, which determines what the resulting transaction reveals about the wallet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Spend the oldest notes first.
+    #[default]
+    OldestFirst,
+    /// Spend the largest notes first, minimizing the number of notes spent.
+    MinimizeNotes,
+    /// Prefer spending notes received at a single address, since spending notes received at
+    /// different addresses in the same transaction links those addresses together.
+    AvoidMixingAddresses,
+}
+
+impl SelectionStrategy {
+    /// Choose notes from `candidates`, which must all be of the same asset, adding up to at least
+    /// `amount`.
+    ///
+    /// Returns an error if all the candidates together don't add up to `amount`.
+    pub fn select(
+        &self,
+        amount: Amount,
+        mut candidates: Vec<SpendableNoteRecord>,
+    ) -> Result<Vec<SpendableNoteRecord>> {
+        match self {
+            SelectionStrategy::OldestFirst => {
+                candidates.sort_by_key(|record| (record.height_created, record.position));
+            }
+            SelectionStrategy::MinimizeNotes => {
+                candidates.sort_by_key(|record| {
+                    (
+                        Reverse(record.note.amount()),
+                        record.height_created,
+                        record.position,
+                    )
+                });
+            }
+            SelectionStrategy::AvoidMixingAddresses => {
+                let mut totals = BTreeMap::<_, Amount>::new();
+                for record in &candidates {
+                    *totals.entry(record.address_index).or_default() += record.note.amount();
+                }
+
+                // Drawing from the address holding the most first means a single address is used
+                // whenever one can cover the amount, and otherwise as few as possible are mixed.
+                candidates.sort_by_key(|record| {
+                    (
+                        Reverse(totals[&record.address_index]),
+                        record.address_index,
+                        record.height_created,
+                        record.position,
+                    )
+                });
+            }
+        }
+
+        let mut total = Amount::zero();
+        let mut selected = Vec::new();
+        for record in candidates {
+            if total >= amount {
+                break;
+            }
+            total += record.note.amount();
+            selected.push(record);
+        }
+
+        if total < amount {
+            anyhow::bail!("requested amount of {} exceeds total of {}", amount, total);
+        }
+
+        Ok(selected)
+    }
+}
+
+impl From<NoteSelectionStrategy> for SelectionStrategy {
+    fn from(strategy: NoteSelectionStrategy) -> Self {
+        match strategy {
+            NoteSelectionStrategy::Unspecified => SelectionStrategy::default(),
+            NoteSelectionStrategy::OldestFirst => SelectionStrategy::OldestFirst,
+            NoteSelectionStrategy::MinimizeNotes => SelectionStrategy::MinimizeNotes,
+            NoteSelectionStrategy::AvoidMixingAddresses => SelectionStrategy::AvoidMixingAddresses,
+        }
+    }
+}
@@ -41,7 +41,7 @@ use tonic::{async_trait, transport::Channel};
 use tracing::instrument;
 use url::Url;
 
-use crate::{Planner, Storage, Worker};
+use crate::{DetectionConfig, PaymentRequest, PaymentRequestRecord, Planner, Storage, Worker};
 
 /// A service that synchronizes private chain state and responds to queries
 /// about it.
@@ -64,6 +64,8 @@ pub struct ViewService {
     node: Url,
     /// Used to watch for changes to the sync height.
     sync_height_rx: watch::Receiver<u64>,
+    // The detection server the worker syncs from, if any.
+    detection: Option<DetectionConfig>,
 }
 
 impl ViewService {
@@ -78,6 +80,21 @@ impl ViewService {
         Self::new(storage, node).await
     }
 
+    /// Convenience method that calls [`Storage::load_or_initialize_with_birthday`] and then
+    /// [`Self::new`].
+    pub async fn load_or_initialize_with_birthday(
+        storage_path: Option<impl AsRef<Utf8Path>>,
+        fvk: &FullViewingKey,
+        node: Url,
+        birthday: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        let storage =
+            Storage::load_or_initialize_with_birthday(storage_path, fvk, node.clone(), birthday)
+                .await?;
+
+        Self::new(storage, node).await
+    }
+
     /// Constructs a new [`ViewService`], spawning a sync task internally.
     ///
     /// The sync task uses the provided `client` to sync with the chain.
@@ -86,8 +103,18 @@ impl ViewService {
     /// by this method, rather than calling it multiple times.  That way, each clone
     /// will be backed by the same scanning task, rather than each spawning its own.
     pub async fn new(storage: Storage, node: Url) -> Result<Self, anyhow::Error> {
+        Self::new_with_detection(storage, node, None).await
+    }
+
+    /// Constructs a new [`ViewService`] like [`Self::new`], but whose sync task gets its compact
+    /// blocks from a detection server, if one is configured.
+    pub async fn new_with_detection(
+        storage: Storage,
+        node: Url,
+        detection: Option<DetectionConfig>,
+    ) -> Result<Self, anyhow::Error> {
         let (worker, sct, error_slot, sync_height_rx) =
-            Worker::new(storage.clone(), node.clone()).await?;
+            Worker::new(storage.clone(), node.clone(), detection.clone()).await?;
 
         tokio::spawn(worker.run());
 
@@ -101,6 +128,7 @@ impl ViewService {
             sync_height_rx,
             state_commitment_tree: sct,
             node,
+            detection,
         })
     }
 
@@ -356,7 +384,15 @@ impl ViewProtocolService for ViewService {
                     tonic::Status::invalid_argument(format!("Could not parse fee: {e:#}"))
                 })?,
             )
-            .expiry_height(prq.expiry_height);
+            .expiry_height(prq.expiry_height)
+            .selection_strategy(
+                pb::transaction_planner_request::NoteSelectionStrategy::from_i32(
+                    prq.note_selection_strategy,
+                )
+                .ok_or_else(|| tonic::Status::invalid_argument("Unknown note selection strategy"))?
+                .into(),
+            )
+            .uniform_arity(prq.uniform_arity);
 
         for output in prq.outputs {
             let address: penumbra_crypto::Address = output
@@ -668,6 +704,14 @@ impl ViewProtocolService for ViewService {
         // Finally, compute the full TxV from the full TxP:
         let txv = tx.view_from_perspective(&txp);
 
+        let ics20_withdrawals = self
+            .storage
+            .ics20_withdrawals_by_tx(&tx.id().0)
+            .await
+            .map_err(|e| {
+                tonic::Status::internal(format!("Error retrieving ICS-20 withdrawals: {:#}", e))
+            })?;
+
         let response = pb::TransactionInfoByHashResponse {
             tx_info: Some(pb::TransactionInfo {
                 height: Some(height),
@@ -675,6 +719,7 @@ impl ViewProtocolService for ViewService {
                 perspective: Some(txp.into()),
                 transaction: Some(tx.into()),
                 view: Some(txv.into()),
+                ics20_withdrawals: ics20_withdrawals.into_iter().map(Into::into).collect(),
             }),
         };
 
@@ -1277,6 +1322,97 @@ impl ViewProtocolService for ViewService {
         }))
     }
 
+    async fn payment_request(
+        &self,
+        request: tonic::Request<pb::PaymentRequestRequest>,
+    ) -> Result<tonic::Response<pb::PaymentRequestResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pb::PaymentRequestRequest {
+            account,
+            value,
+            memo,
+            expiry_height,
+        } = request.into_inner();
+
+        let value: Value = value
+            .ok_or_else(|| tonic::Status::invalid_argument("Missing value"))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("Could not parse value: {e:#}"))
+            })?;
+
+        let fvk =
+            self.storage.full_viewing_key().await.map_err(|_| {
+                tonic::Status::failed_precondition("Error retrieving full viewing key")
+            })?;
+
+        // Each request is made at a fresh address, so that anything received there can be taken
+        // as a payment towards it.
+        let address_index = AddressIndex::new_ephemeral(account, OsRng);
+        let payment_request = PaymentRequest {
+            address: fvk.payment_address(address_index).0,
+            value,
+            memo: (!memo.is_empty()).then_some(memo),
+            expiry_height: (expiry_height != 0).then_some(expiry_height),
+        };
+
+        // The detection server is given the detection keys of the addresses of all outstanding
+        // requests, so refuse to make a request if that would be more keys than it accepts.
+        if let Some(detection) = &self.detection {
+            let payment_requests = self.storage.payment_requests().await.map_err(|e| {
+                tonic::Status::unavailable(format!("error fetching payment requests: {e:#}"))
+            })?;
+            let height = self
+                .storage
+                .last_sync_height()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unavailable(format!("error fetching sync height: {e:#}"))
+                })?
+                .unwrap_or(0);
+            let new_request = PaymentRequestRecord {
+                request: payment_request.clone(),
+                address_index,
+                amount_received: Amount::zero(),
+                height_paid: None,
+            };
+            detection
+                .address_indices(payment_requests.into_iter().chain([new_request]), height)
+                .map_err(|e| {
+                    tonic::Status::failed_precondition(format!(
+                        "can't make payment request while syncing from a detection server: {e:#}"
+                    ))
+                })?;
+        }
+
+        self.storage
+            .record_payment_request(address_index, &payment_request)
+            .await
+            .map_err(|e| {
+                tonic::Status::unavailable(format!("error recording payment request: {e:#}"))
+            })?;
+
+        Ok(tonic::Response::new(pb::PaymentRequestResponse {
+            uri: payment_request.to_string(),
+        }))
+    }
+
+    async fn payment_requests(
+        &self,
+        _request: tonic::Request<pb::PaymentRequestsRequest>,
+    ) -> Result<tonic::Response<pb::PaymentRequestsResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let payment_requests = self.storage.payment_requests().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error getting payment requests: {e:#}"))
+        })?;
+
+        Ok(tonic::Response::new(pb::PaymentRequestsResponse {
+            payment_requests: payment_requests.into_iter().map(Into::into).collect(),
+        }))
+    }
+
     async fn authorize_and_build(
         &self,
         _request: tonic::Request<pb::AuthorizeAndBuildRequest>,
@@ -4,7 +4,9 @@ use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use penumbra_asset::{asset, asset::DenomMetadata, asset::Id, Value};
 use penumbra_chain::params::{ChainParameters, FmdParameters};
-use penumbra_crypto::{note, Address, FieldExt, Fq, FullViewingKey, Note, Nullifier, Rseed};
+use penumbra_crypto::{
+    keys::AddressIndex, note, Address, FieldExt, Fq, FullViewingKey, Note, Nullifier, Rseed,
+};
 use penumbra_dex::{
     lp::position::{self, Position, State},
     TradingPair,
@@ -12,13 +14,17 @@ use penumbra_dex::{
 use penumbra_num::Amount;
 use penumbra_proto::{
     client::v1alpha1::{
-        oblivious_query_service_client::ObliviousQueryServiceClient, ChainParametersRequest,
+        oblivious_query_service_client::ObliviousQueryServiceClient,
+        specific_query_service_client::SpecificQueryServiceClient,
+        tendermint_proxy_service_client::TendermintProxyServiceClient, ChainParametersRequest,
+        GetStatusRequest, KeyValueRequest, StateCommitmentTreeFrontierRequest,
     },
     DomainType,
 };
 use penumbra_stake::{DelegationToken, IdentityKey};
 use penumbra_tct as tct;
 use penumbra_transaction::Transaction;
+use prost::Message as _;
 use r2d2_sqlite::{
     rusqlite::{OpenFlags, OptionalExtension},
     SqliteConnectionManager,
@@ -32,8 +38,12 @@ use tokio::{
 };
 use url::Url;
 
-use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+use crate::{
+    sync::FilteredBlock, Ics20WithdrawalRecord, Ics20WithdrawalStatus, PaymentRequest,
+    PaymentRequestRecord, SpendableNoteRecord, SwapRecord,
+};
 
+mod migrate;
 mod sct;
 use sct::TreeStore;
 
@@ -57,6 +67,7 @@ pub struct Storage {
     scanned_notes_tx: tokio::sync::broadcast::Sender<SpendableNoteRecord>,
     scanned_nullifiers_tx: tokio::sync::broadcast::Sender<Nullifier>,
     scanned_swaps_tx: tokio::sync::broadcast::Sender<SwapRecord>,
+    recorded_payment_requests_tx: tokio::sync::broadcast::Sender<AddressIndex>,
 }
 
 impl Storage {
@@ -65,6 +76,20 @@ impl Storage {
         storage_path: Option<impl AsRef<Utf8Path>>,
         fvk: &FullViewingKey,
         node: Url,
+    ) -> anyhow::Result<Self> {
+        Self::load_or_initialize_with_birthday(storage_path, fvk, node, None).await
+    }
+
+    /// If the database at `storage_path` exists, [`Self::load`] it, otherwise, initialize it.
+    ///
+    /// If a `birthday` height is given, the node is asked for a snapshot of the state commitment
+    /// tree from before that height, and the new database starts syncing from there instead of
+    /// from genesis. If no snapshot is available, this falls back to syncing from genesis.
+    pub async fn load_or_initialize_with_birthday(
+        storage_path: Option<impl AsRef<Utf8Path>>,
+        fvk: &FullViewingKey,
+        node: Url,
+        birthday: Option<u64>,
     ) -> anyhow::Result<Self> {
         if let Some(path) = storage_path.as_ref() {
             if path.as_ref().exists() {
@@ -78,7 +103,7 @@ impl Storage {
         };
 
         let mut client = ObliviousQueryServiceClient::connect(node.to_string()).await?;
-        let params = client
+        let params: ChainParameters = client
             .chain_parameters(tonic::Request::new(ChainParametersRequest {
                 chain_id: String::new(),
             }))
@@ -86,7 +111,138 @@ impl Storage {
             .into_inner()
             .try_into()?;
 
-        Self::initialize(storage_path, fvk.clone(), params).await
+        let frontier = match birthday {
+            Some(birthday) => match Self::fetch_frontier(&node, &params.chain_id, birthday).await {
+                Ok(frontier) => Some(frontier),
+                Err(e) => {
+                    tracing::warn!(
+                        ?e,
+                        birthday,
+                        "could not fetch state commitment tree snapshot, syncing from genesis"
+                    );
+                    None
+                }
+            },
+            None => None,
+        };
+
+        Self::initialize_inner(storage_path, fvk.clone(), params, frontier).await
+    }
+
+    /// Fetch a snapshot of the state commitment tree from before the given `birthday` height,
+    /// checking that its root is the anchor the chain recorded at the snapshot's height.
+    async fn fetch_frontier(
+        node: &Url,
+        chain_id: &str,
+        birthday: u64,
+    ) -> anyhow::Result<(u64, tct::Tree, FmdParameters)> {
+        let mut client = SpecificQueryServiceClient::connect(node.to_string()).await?;
+        let response = client
+            .state_commitment_tree_frontier(tonic::Request::new(
+                StateCommitmentTreeFrontierRequest {
+                    chain_id: chain_id.to_string(),
+                    height: birthday,
+                },
+            ))
+            .await?
+            .into_inner();
+
+        let tree: tct::Tree = bincode::deserialize(&response.tree)?;
+        let anchor: tct::Root = response
+            .anchor
+            .ok_or_else(|| anyhow!("missing anchor in snapshot response"))?
+            .try_into()?;
+        if tree.root() != anchor {
+            anyhow::bail!(
+                "snapshot root {} does not match claimed anchor {}",
+                tree.root(),
+                anchor
+            );
+        }
+        Self::verify_anchor(node, chain_id, response.height, anchor).await?;
+        let fmd_parameters = response
+            .fmd_parameters
+            .ok_or_else(|| anyhow!("missing FMD parameters in snapshot response"))?
+            .try_into()?;
+
+        tracing::info!(height = response.height, "starting sync from snapshot");
+        Ok((response.height, tree, fmd_parameters))
+    }
+
+    /// Check that `anchor` is the anchor recorded in the chain state at `height`, using a proof of
+    /// the recorded anchor against the app hash of the node's latest block.
+    ///
+    /// The app hash is the one the node reports, rather than one taken from a header checked by a
+    /// light client, but this still ties the snapshot to the chain state the node has committed to.
+    async fn verify_anchor(
+        node: &Url,
+        chain_id: &str,
+        height: u64,
+        anchor: tct::Root,
+    ) -> anyhow::Result<()> {
+        let mut specific_client = SpecificQueryServiceClient::connect(node.to_string()).await?;
+        let mut tendermint_client = TendermintProxyServiceClient::connect(node.to_string()).await?;
+        let key = penumbra_sct::state_key::anchor_by_height(height);
+
+        // The proof is made against the node's latest state, so if a block is committed between
+        // the queries, the app hash won't match it; try again a few times before giving up.
+        for _ in 0..3 {
+            let status_before = tendermint_client
+                .get_status(GetStatusRequest {})
+                .await?
+                .into_inner()
+                .sync_info
+                .ok_or_else(|| anyhow!("missing sync info in status response"))?;
+            let response = specific_client
+                .key_value(KeyValueRequest {
+                    chain_id: chain_id.to_string(),
+                    key: key.clone(),
+                    proof: true,
+                })
+                .await?
+                .into_inner();
+            let status_after = tendermint_client
+                .get_status(GetStatusRequest {})
+                .await?
+                .into_inner()
+                .sync_info
+                .ok_or_else(|| anyhow!("missing sync info in status response"))?;
+            if status_before.latest_block_height != status_after.latest_block_height {
+                continue;
+            }
+
+            let value = response
+                .value
+                .ok_or_else(|| anyhow!("no anchor is recorded at height {height}"))?
+                .value;
+            let proofs = response
+                .proof
+                .ok_or_else(|| anyhow!("missing proof in key-value response"))?
+                .proofs
+                .into_iter()
+                .map(|proof| ics23::CommitmentProof::decode(proof.encode_to_vec().as_slice()))
+                .collect::<Result<Vec<_>, _>>()?;
+            penumbra_storage::proof::verify_membership(
+                &proofs,
+                &status_after.latest_app_hash,
+                key.as_bytes(),
+                &value,
+            )
+            .context("invalid proof of the anchor recorded for the snapshot")?;
+
+            let recorded = tct::Root::decode(value.as_slice())?;
+            if recorded != anchor {
+                anyhow::bail!(
+                    "snapshot root {} does not match the anchor {} recorded at height {}",
+                    anchor,
+                    recorded,
+                    height
+                );
+            }
+            return Ok(());
+        }
+
+        anyhow::bail!("the chain advanced while checking the snapshot's anchor")
     }
 
     fn connect(
@@ -129,34 +285,13 @@ impl Storage {
             scanned_notes_tx: broadcast::channel(128).0,
             scanned_nullifiers_tx: broadcast::channel(512).0,
             scanned_swaps_tx: broadcast::channel(128).0,
+            recorded_payment_requests_tx: broadcast::channel(16).0,
         };
 
         spawn_blocking(move || {
-            // Check the version of the software used when first initializing this database.
-            // If it doesn't match the current version, we should report the error to the user.
-            let actual_schema_hash: String = storage
-                .pool
-                .get()?
-                .query_row("SELECT schema_hash FROM schema_hash", (), |row| {
-                    row.get("schema_hash")
-                })
-                .context("failed to query database schema version: the database was probably created by an old client version, and needs to be reset and resynchronized")?;
-
-            if actual_schema_hash != *SCHEMA_HASH {
-                let database_client_version: String = storage
-                    .pool
-                    .get()?
-                    .query_row("SELECT client_version FROM client_version", (), |row| {
-                        row.get("client_version")
-                    })
-                    .context("failed to query client version: the database was probably created by an old client version, and needs to be reset and resynchronized")?;
-
-                return Err(anyhow!(
-                    "can't load view database created by client version {} using client version {}: they have different schemata, so you need to reset your view database and resynchronize",
-                    database_client_version,
-                    env!("VERGEN_GIT_SEMVER"),
-                ));
-            }
+            // Bring the database up to date with the current version of the schema, if it was
+            // created by an older version of the software.
+            migrate::migrate(&mut storage.pool.get()?)?;
 
             Ok(storage)
         })
@@ -167,6 +302,34 @@ impl Storage {
         storage_path: Option<impl AsRef<Utf8Path>>,
         fvk: FullViewingKey,
         params: ChainParameters,
+    ) -> anyhow::Result<Self> {
+        Self::initialize_inner(storage_path, fvk, params, None).await
+    }
+
+    /// Initialize the database starting from the given snapshot of the state commitment tree, as
+    /// of the end of the block at `height`, so that syncing resumes from the following block.
+    pub async fn initialize_from_frontier(
+        storage_path: Option<impl AsRef<Utf8Path>>,
+        fvk: FullViewingKey,
+        params: ChainParameters,
+        height: u64,
+        tree: tct::Tree,
+        fmd_parameters: FmdParameters,
+    ) -> anyhow::Result<Self> {
+        Self::initialize_inner(
+            storage_path,
+            fvk,
+            params,
+            Some((height, tree, fmd_parameters)),
+        )
+        .await
+    }
+
+    async fn initialize_inner(
+        storage_path: Option<impl AsRef<Utf8Path>>,
+        fvk: FullViewingKey,
+        params: ChainParameters,
+        frontier: Option<(u64, tct::Tree, FmdParameters)>,
     ) -> anyhow::Result<Self> {
         tracing::debug!(storage_path = ?storage_path.as_ref().map(AsRef::as_ref), ?fvk, ?params);
 
@@ -176,7 +339,7 @@ impl Storage {
         spawn_blocking(move || {
             // In one database transaction, populate everything
             let mut conn = pool.get()?;
-            let tx = conn.transaction()?;
+            let mut tx = conn.transaction()?;
 
             // Create the tables
             tx.execute_batch(include_str!("storage/schema.sql"))?;
@@ -193,12 +356,32 @@ impl Storage {
                 [fvk_bytes],
             )?;
 
-            // Insert -1 as a signaling value for pre-genesis.
-            // We just have to be careful to treat negative values as None
-            // in last_sync_height.
-            tx.execute("INSERT INTO sync_height (height) VALUES (-1)", ())?;
+            match frontier {
+                Some((height, tree, fmd_parameters)) => {
+                    // Start from the snapshot, as though we had already synced up to its height.
+                    tree.to_writer(&mut TreeStore(&mut tx))?;
 
-            // Insert the schema hash into the database
+                    let fmd_parameters_bytes = &FmdParameters::encode_to_vec(&fmd_parameters)[..];
+                    tx.execute(
+                        "INSERT INTO fmd_parameters (bytes) VALUES (?1)",
+                        [fmd_parameters_bytes],
+                    )?;
+
+                    tx.execute(
+                        "INSERT INTO sync_height (height) VALUES (?1)",
+                        [height as i64],
+                    )?;
+                }
+                None => {
+                    // Insert -1 as a signaling value for pre-genesis.
+                    // We just have to be careful to treat negative values as None
+                    // in last_sync_height.
+                    tx.execute("INSERT INTO sync_height (height) VALUES (-1)", ())?;
+                }
+            }
+
+            // Record the version of the schema, along with its hash for the sake of older clients
+            tx.pragma_update(None, "user_version", migrate::SCHEMA_VERSION)?;
             tx.execute(
                 "INSERT INTO schema_hash (schema_hash) VALUES (?1)",
                 [&*SCHEMA_HASH],
@@ -219,6 +402,7 @@ impl Storage {
                 scanned_notes_tx: broadcast::channel(128).0,
                 scanned_nullifiers_tx: broadcast::channel(512).0,
                 scanned_swaps_tx: broadcast::channel(128).0,
+                recorded_payment_requests_tx: broadcast::channel(16).0,
             })
         })
         .await?
@@ -609,6 +793,221 @@ impl Storage {
         .await?
     }
 
+    /// Returns the ICS-20 withdrawals made by the transaction with the given hash, in the order of
+    /// their actions.
+    pub async fn ics20_withdrawals_by_tx(
+        &self,
+        tx_hash: &[u8],
+    ) -> anyhow::Result<Vec<Ics20WithdrawalRecord>> {
+        let pool = self.pool.clone();
+        let tx_hash = tx_hash.to_vec();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT * FROM ics20_withdrawals WHERE tx_hash = ?1 ORDER BY action_index",
+                )?
+                .query_and_then([tx_hash], |row| row.try_into())?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Returns the ICS-20 withdrawals which have been neither acknowledged nor timed out yet.
+    pub async fn pending_ics20_withdrawals(&self) -> anyhow::Result<Vec<Ics20WithdrawalRecord>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT * FROM ics20_withdrawals WHERE status = 'pending'")?
+                .query_and_then((), |row| row.try_into())?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records the ICS-20 withdrawals made by the transaction with the given hash.
+    ///
+    /// Withdrawals which are already recorded are left as they are, so that rescanning a block
+    /// doesn't forget what became of them.
+    pub async fn record_ics20_withdrawals(
+        &self,
+        tx_hash: &[u8],
+        withdrawals: Vec<Ics20WithdrawalRecord>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let tx_hash = tx_hash.to_vec();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+
+            for withdrawal in withdrawals {
+                dbtx.execute(
+                    "INSERT OR IGNORE INTO ics20_withdrawals
+                    (tx_hash, action_index, source_channel, sequence, timeout_height, timeout_time, status, height_resolved)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    (
+                        &tx_hash,
+                        withdrawal.action_index as i64,
+                        &withdrawal.source_channel,
+                        withdrawal.sequence as i64,
+                        withdrawal.timeout_height as i64,
+                        withdrawal.timeout_time as i64,
+                        withdrawal.status.as_str(),
+                        withdrawal
+                            .status
+                            .height_resolved()
+                            .map(|height| height as i64),
+                    ),
+                )?;
+            }
+
+            dbtx.commit()?;
+            Ok::<_, anyhow::Error>(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    /// Records what became of the ICS-20 withdrawal sent over the given channel with the given
+    /// packet sequence number.
+    pub async fn resolve_ics20_withdrawal(
+        &self,
+        source_channel: String,
+        sequence: u64,
+        status: Ics20WithdrawalStatus,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "UPDATE ics20_withdrawals SET status = ?1, height_resolved = ?2
+                    WHERE source_channel = ?3 AND sequence = ?4",
+                    (
+                        status.as_str(),
+                        status.height_resolved().map(|height| height as i64),
+                        &source_channel,
+                        sequence as i64,
+                    ),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    /// Records a payment request, which asks to be paid at the address with the given index.
+    pub async fn record_payment_request(
+        &self,
+        address_index: AddressIndex,
+        request: &PaymentRequest,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let uri = request.to_string();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "INSERT INTO payment_requests (address_index, uri) VALUES (?1, ?2)",
+                    (address_index.to_bytes().to_vec(), uri),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        // Broadcast the request now that it's committed, so that a worker syncing from a detection
+        // server can start detecting payments to its address. This fails if there is no active
+        // receiver, such as when not syncing from a detection server, which is fine.
+        let _ = self.recorded_payment_requests_tx.send(address_index);
+
+        Ok(())
+    }
+
+    /// Subscribes to the payment requests recorded from now on, by the index of the address each
+    /// asks to be paid at.
+    pub fn subscribe_payment_requests(&self) -> broadcast::Receiver<AddressIndex> {
+        self.recorded_payment_requests_tx.subscribe()
+    }
+
+    /// Returns the payment requests made by this wallet, along with what has been received towards
+    /// each of them.
+    ///
+    /// Notes of the requested asset received at a request's address, up to its expiry height, count
+    /// towards it in the order they were received, and the request is paid at the height at which
+    /// they first add up to the amount requested.
+    pub async fn payment_requests(&self) -> anyhow::Result<Vec<PaymentRequestRecord>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get()?;
+
+            let requests = conn
+                .prepare_cached("SELECT address_index, uri FROM payment_requests")?
+                .query_and_then((), |row| {
+                    let address_index: Vec<u8> = row.get("address_index")?;
+                    let uri: String = row.get("uri")?;
+                    Ok::<_, anyhow::Error>((
+                        AddressIndex::try_from(address_index.as_slice())?,
+                        uri.parse::<PaymentRequest>()?,
+                    ))
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            let mut records = Vec::new();
+            for (address_index, request) in requests {
+                let mut amount_received = Amount::zero();
+                let mut height_paid = None;
+
+                for result in conn
+                    .prepare_cached(
+                        "SELECT notes.amount, spendable_notes.height_created
+                        FROM notes
+                        JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                        WHERE spendable_notes.address_index = ?1
+                        AND notes.asset_id = ?2
+                        AND (?3 IS NULL OR spendable_notes.height_created <= ?3)
+                        ORDER BY spendable_notes.height_created, spendable_notes.position",
+                    )?
+                    .query_and_then(
+                        (
+                            address_index.to_bytes().to_vec(),
+                            request.value.asset_id.to_bytes().to_vec(),
+                            request.expiry_height.map(|height| height as i64),
+                        ),
+                        |row| {
+                            let amount: [u8; 16] = row.get("amount")?;
+                            let height_created: u64 = row.get("height_created")?;
+                            Ok::<_, anyhow::Error>((
+                                Amount::from(u128::from_be_bytes(amount)),
+                                height_created,
+                            ))
+                        },
+                    )?
+                {
+                    let (amount, height_created) = result?;
+                    amount_received += amount;
+                    if height_paid.is_none() && amount_received >= request.value.amount {
+                        height_paid = Some(height_created);
+                    }
+                }
+
+                records.push(PaymentRequestRecord {
+                    request,
+                    address_index,
+                    amount_received,
+                    height_paid,
+                });
+            }
+
+            Ok(records)
+        })
+        .await?
+    }
+
     // Query for a note by its note commitment, optionally waiting until the note is detected.
     pub async fn note_by_nullifier(
         &self,
@@ -1414,3 +1813,299 @@ impl Storage {
         .await?
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_chain::{test_keys, NoteSource};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn note_record(
+        address: &Address,
+        value: Value,
+        height: u64,
+        position: tct::Position,
+    ) -> SpendableNoteRecord {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let note = Note::generate(&mut OsRng, address, value);
+        SpendableNoteRecord {
+            note_commitment: note.commit(),
+            address_index: fvk.address_index(&note.address()).unwrap(),
+            nullifier: fvk.derive_nullifier(position, &note.commit()),
+            note,
+            height_created: height,
+            height_spent: None,
+            position,
+            source: NoteSource::Transaction { id: [0; 32] },
+        }
+    }
+
+    fn staking(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    // records a block with notes of the given values at the given addresses, inserting them into
+    // the tree as the worker does.
+    async fn record_block(
+        storage: &Storage,
+        sct: &mut tct::Tree,
+        height: u64,
+        notes: &[(&Address, Value)],
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        let mut new_notes = Vec::new();
+        for (address, value) in notes {
+            let position = sct.position().unwrap();
+            let record = note_record(address, *value, height, position);
+            sct.insert(tct::Witness::Keep, record.note_commitment)?;
+            new_notes.push(record);
+        }
+        sct.end_block()?;
+
+        let filtered_block = FilteredBlock {
+            new_notes: new_notes.clone(),
+            new_swaps: Vec::new(),
+            spent_nullifiers: Vec::new(),
+            height,
+            fmd_parameters: None,
+            chain_parameters: None,
+        };
+        storage
+            .record_block(filtered_block, Vec::new(), sct)
+            .await?;
+        Ok(new_notes)
+    }
+
+    #[tokio::test]
+    async fn sync_resumes_after_the_frontier() -> anyhow::Result<()> {
+        // A snapshot of a tree that already holds other commitments, as of the end of block 10.
+        let mut tree = tct::Tree::new();
+        for i in 0..3u64 {
+            tree.insert(tct::Witness::Forget, tct::StateCommitment(Fq::from(i)))?;
+            tree.end_block()?;
+        }
+        let frontier_position = tree.position().unwrap();
+
+        let storage = Storage::initialize_from_frontier(
+            None::<&str>,
+            test_keys::FULL_VIEWING_KEY.clone(),
+            ChainParameters::default(),
+            10,
+            tree.clone(),
+            FmdParameters::default(),
+        )
+        .await?;
+        assert_eq!(storage.last_sync_height().await?, Some(10));
+        let mut sct = storage.state_commitment_tree().await?;
+        assert_eq!(sct.root(), tree.root());
+
+        // Blocks from before the snapshot can't be recorded...
+        assert!(record_block(&storage, &mut sct.clone(), 10, &[])
+            .await
+            .is_err());
+
+        // ...but the next block can, and its notes follow the snapshot in the tree.
+        let notes = record_block(
+            &storage,
+            &mut sct,
+            11,
+            &[(&test_keys::ADDRESS_0, staking(1000))],
+        )
+        .await?;
+        assert_eq!(storage.last_sync_height().await?, Some(11));
+        assert_eq!(notes[0].position, frontier_position);
+        assert_eq!(
+            storage
+                .note_by_commitment(notes[0].note_commitment, false)
+                .await?
+                .position,
+            frontier_position
+        );
+        assert!(sct.witness(notes[0].note_commitment).is_some());
+        assert_eq!(storage.state_commitment_tree().await?.root(), sct.root());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn payment_requests_count_notes_received_until_they_expire() -> anyhow::Result<()> {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let storage =
+            Storage::initialize(None::<&str>, fvk.clone(), ChainParameters::default()).await?;
+        let mut sct = tct::Tree::new();
+        record_block(&storage, &mut sct, 0, &[]).await?;
+
+        let address_index = AddressIndex::new_ephemeral(0, OsRng);
+        let address = fvk.payment_address(address_index).0;
+        let request = PaymentRequest {
+            address,
+            value: staking(100),
+            memo: None,
+            expiry_height: Some(3),
+        };
+        storage
+            .record_payment_request(address_index, &request)
+            .await?;
+
+        // Notes of other assets, or at other addresses, don't count towards the request.
+        let gm = asset::REGISTRY.parse_denom("ugm").unwrap().id();
+        record_block(
+            &storage,
+            &mut sct,
+            1,
+            &[
+                (&address, staking(40)),
+                (
+                    &address,
+                    Value {
+                        amount: 500u64.into(),
+                        asset_id: gm,
+                    },
+                ),
+                (&test_keys::ADDRESS_0, staking(500)),
+            ],
+        )
+        .await?;
+        let records = storage.payment_requests().await?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].address_index, address_index);
+        assert_eq!(records[0].amount_received, 40u64.into());
+        assert_eq!(records[0].height_paid, None);
+
+        record_block(&storage, &mut sct, 2, &[]).await?;
+        record_block(&storage, &mut sct, 3, &[(&address, staking(60))]).await?;
+        // Anything received after the request expired doesn't count.
+        record_block(&storage, &mut sct, 4, &[(&address, staking(70))]).await?;
+
+        let records = storage.payment_requests().await?;
+        assert_eq!(records[0].amount_received, 100u64.into());
+        assert_eq!(records[0].height_paid, Some(3));
+
+        Ok(())
+    }
+
+    fn withdrawal(channel: &str, sequence: u64, action_index: u64) -> Ics20WithdrawalRecord {
+        Ics20WithdrawalRecord {
+            action_index,
+            source_channel: channel.to_string(),
+            sequence,
+            timeout_height: 1_000,
+            timeout_time: 1_700_000_000_000_000_000,
+            status: Ics20WithdrawalStatus::Pending,
+        }
+    }
+
+    #[tokio::test]
+    async fn ics20_withdrawals_are_recorded_once() -> anyhow::Result<()> {
+        let storage = Storage::initialize(
+            None::<&str>,
+            test_keys::FULL_VIEWING_KEY.clone(),
+            ChainParameters::default(),
+        )
+        .await?;
+
+        let tx_hash = [1; 32];
+        let withdrawals = vec![withdrawal("channel-0", 1, 2), withdrawal("channel-1", 1, 0)];
+        storage
+            .record_ics20_withdrawals(&tx_hash, withdrawals.clone())
+            .await?;
+        storage
+            .resolve_ics20_withdrawal(
+                "channel-0".to_string(),
+                1,
+                Ics20WithdrawalStatus::Refunded { height: 5 },
+            )
+            .await?;
+
+        // Recording the withdrawals again, as rescanning their block would, doesn't forget what
+        // became of them.
+        storage
+            .record_ics20_withdrawals(&tx_hash, withdrawals.clone())
+            .await?;
+
+        // They're listed in the order of their actions.
+        assert_eq!(
+            storage.ics20_withdrawals_by_tx(&tx_hash).await?,
+            vec![
+                withdrawals[1].clone(),
+                Ics20WithdrawalRecord {
+                    status: Ics20WithdrawalStatus::Refunded { height: 5 },
+                    ..withdrawals[0].clone()
+                },
+            ]
+        );
+        assert!(storage.ics20_withdrawals_by_tx(&[2; 32]).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn only_pending_ics20_withdrawals_are_listed() -> anyhow::Result<()> {
+        let storage = Storage::initialize(
+            None::<&str>,
+            test_keys::FULL_VIEWING_KEY.clone(),
+            ChainParameters::default(),
+        )
+        .await?;
+
+        storage
+            .record_ics20_withdrawals(
+                &[1; 32],
+                vec![withdrawal("channel-0", 1, 0), withdrawal("channel-0", 2, 1)],
+            )
+            .await?;
+        storage
+            .record_ics20_withdrawals(&[2; 32], vec![withdrawal("channel-1", 1, 0)])
+            .await?;
+        assert_eq!(storage.pending_ics20_withdrawals().await?.len(), 3);
+
+        // Withdrawals are resolved by the channel and sequence number of their packets.
+        for (channel, sequence, status) in [
+            (
+                "channel-0",
+                1,
+                Ics20WithdrawalStatus::Acknowledged { height: 3 },
+            ),
+            ("channel-1", 1, Ics20WithdrawalStatus::Unknown { height: 4 }),
+        ] {
+            storage
+                .resolve_ics20_withdrawal(channel.to_string(), sequence, status)
+                .await?;
+        }
+        assert_eq!(
+            storage.pending_ics20_withdrawals().await?,
+            vec![withdrawal("channel-0", 2, 1)]
+        );
+
+        let statuses = |tx_hash: [u8; 32]| {
+            let storage = storage.clone();
+            async move {
+                anyhow::Ok(
+                    storage
+                        .ics20_withdrawals_by_tx(&tx_hash)
+                        .await?
+                        .into_iter()
+                        .map(|record| record.status)
+                        .collect::<Vec<_>>(),
+                )
+            }
+        };
+        assert_eq!(
+            statuses([1; 32]).await?,
+            vec![
+                Ics20WithdrawalStatus::Acknowledged { height: 3 },
+                Ics20WithdrawalStatus::Pending,
+            ]
+        );
+        assert_eq!(
+            statuses([2; 32]).await?,
+            vec![Ics20WithdrawalStatus::Unknown { height: 4 }]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+//! Forward-only migrations of the view database schema.
+//!
+//! The version of a database's schema is recorded in SQLite's `user_version`.  Databases created
+//! before versions were recorded are identified by the hash of the `schema.sql` that created them.
+//!
+//! Whenever `schema.sql` changes, a migration taking databases created by the previous version of
+//! it to the new one must be added to [`MIGRATIONS`], along with a fixture of the previous version
+//! for the tests in `tests/migrations.rs`.
+
+use anyhow::{anyhow, Context};
+use r2d2_sqlite::rusqlite::Connection;
+
+use super::SCHEMA_HASH;
+
+/// The migrations, in order: the migration at index `i` takes a database from version `i + 1` to
+/// version `i + 2`.
+const MIGRATIONS: &[&str] = &[
+    include_str!("migrations/0002_ics20_withdrawals.sql"),
+    include_str!("migrations/0003_payment_requests.sql"),
+    include_str!("migrations/0004_ics20_withdrawal_timeouts.sql"),
+];
+
+/// The version of the schema created by `schema.sql`.
+pub const SCHEMA_VERSION: u32 = MIGRATIONS.len() as u32 + 1;
+
+/// The hashes of the `schema.sql` files used by clients from before schema versions were recorded,
+/// along with the version of the schema they created.
+const LEGACY_SCHEMA_HASHES: &[(&str, u32)] = &[
+    (
+        "658b90d8fd068b2ea187207cbd15ab78fc569cda4372971de4316f0a56b31f27",
+        1,
+    ),
+    (
+        "15d44be78249b8da895e9706e38f69fdcb77e559eff754503588190a890bff15",
+        2,
+    ),
+];
+
+/// The version of the database's schema.
+pub fn version(conn: &Connection) -> anyhow::Result<u32> {
+    let version: u32 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    if version != 0 {
+        return Ok(version);
+    }
+
+    let schema_hash: String = conn
+        .query_row("SELECT schema_hash FROM schema_hash", (), |row| {
+            row.get("schema_hash")
+        })
+        .context("failed to query database schema version: the database was probably created by an old client version, and needs to be reset and resynchronized")?;
+
+    LEGACY_SCHEMA_HASHES
+        .iter()
+        .find(|(hash, _)| *hash == schema_hash)
+        .map(|(_, version)| *version)
+        .ok_or_else(|| {
+            anyhow!("can't load view database with unknown schema hash {schema_hash}: it was probably created by a development client version, so you need to reset your view database and resynchronize")
+        })
+}
+
+/// Migrate the database to the current version of the schema, in a single database transaction.
+pub fn migrate(conn: &mut Connection) -> anyhow::Result<()> {
+    let tx = conn.transaction()?;
+
+    let from = version(&tx)?;
+    if from > SCHEMA_VERSION {
+        let database_client_version: String = tx
+            .query_row("SELECT client_version FROM client_version", (), |row| {
+                row.get("client_version")
+            })
+            .context("failed to query client version")?;
+
+        return Err(anyhow!(
+            "can't load view database with schema version {} (created by client version {}) using client version {}, which only supports schema versions up to {}",
+            from,
+            database_client_version,
+            env!("VERGEN_GIT_SEMVER"),
+            SCHEMA_VERSION,
+        ));
+    }
+
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(from as usize - 1) {
+        let (from, to) = (index + 1, index + 2);
+        tracing::info!(from, to, "migrating view database schema");
+        tx.execute_batch(migration).with_context(|| {
+            format!("failed to migrate view database from version {from} to {to}")
+        })?;
+    }
+
+    tx.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+    // Older clients refuse to open databases with a schema hash they don't recognize, so keep it
+    // up to date as well.
+    tx.execute("UPDATE schema_hash SET schema_hash = ?1", [&*SCHEMA_HASH])?;
+
+    tx.commit()?;
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+-- outbound ICS-20 transfers made by this wallet, and what became of them
+CREATE TABLE ics20_withdrawals (
+    -- the transaction containing the withdrawal, and the index of its action
+    tx_hash                 BLOB NOT NULL,
+    action_index            BIGINT NOT NULL,
+    -- the channel and sequence number of the transfer's packet
+    source_channel          TEXT NOT NULL,
+    sequence                BIGINT NOT NULL,
+    -- one of 'pending', 'acknowledged', or 'refunded'
+    status                  TEXT NOT NULL,
+    -- null while pending, otherwise the height at which the transfer was resolved
+    height_resolved         BIGINT,
+    PRIMARY KEY (source_channel, sequence)
+);
+
+CREATE INDEX ics20_withdrawals_by_tx_idx ON ics20_withdrawals (tx_hash);
@@ -0,0 +1,7 @@
+-- payment requests made by this wallet, each asking to be paid at its own ephemeral address
+CREATE TABLE payment_requests (
+    -- the index of the address the request asks to be paid at
+    address_index           BLOB PRIMARY KEY NOT NULL,
+    -- the request itself, as a URI
+    uri                     TEXT NOT NULL
+);
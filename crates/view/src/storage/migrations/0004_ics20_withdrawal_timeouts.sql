@@ -0,0 +1,42 @@
+-- withdrawals now record when their packets time out, so that they stop being looked for once they
+-- have. the timeouts of existing withdrawals aren't known, so those still pending stop being
+-- looked for as of the current sync height.
+ALTER TABLE ics20_withdrawals RENAME TO ics20_withdrawals_v3;
+
+-- outbound ICS-20 transfers made by this wallet, and what became of them
+CREATE TABLE ics20_withdrawals (
+    -- the transaction containing the withdrawal, and the index of its action
+    tx_hash                 BLOB NOT NULL,
+    action_index            BIGINT NOT NULL,
+    -- the channel and sequence number of the transfer's packet
+    source_channel          TEXT NOT NULL,
+    sequence                BIGINT NOT NULL,
+    -- the height and time, in nanoseconds since the unix epoch, at which the packet times out
+    timeout_height          BIGINT NOT NULL,
+    timeout_time            BIGINT NOT NULL,
+    -- one of 'pending', 'acknowledged', 'refunded', or 'unknown'
+    status                  TEXT NOT NULL,
+    -- null while pending, otherwise the height at which the transfer was resolved
+    height_resolved         BIGINT,
+    PRIMARY KEY (source_channel, sequence)
+);
+
+INSERT INTO ics20_withdrawals
+    (tx_hash, action_index, source_channel, sequence, timeout_height, timeout_time, status, height_resolved)
+    SELECT
+        tx_hash,
+        action_index,
+        source_channel,
+        sequence,
+        0,
+        0,
+        CASE status WHEN 'pending' THEN 'unknown' ELSE status END,
+        CASE status
+            WHEN 'pending' THEN COALESCE((SELECT MAX(height) FROM sync_height), 0)
+            ELSE height_resolved
+        END
+    FROM ics20_withdrawals_v3;
+
+DROP TABLE ics20_withdrawals_v3;
+
+CREATE INDEX ics20_withdrawals_by_tx_idx ON ics20_withdrawals (tx_hash);
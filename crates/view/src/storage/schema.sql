@@ -122,4 +122,32 @@ CREATE TABLE positions (
      position_id            BLOB PRIMARY KEY NOT NULL,
      position_state         TEXT NOT NULL,
      trading_pair           TEXT NOT NULL
-);
\ No newline at end of file
+);
+
+-- outbound ICS-20 transfers made by this wallet, and what became of them
+CREATE TABLE ics20_withdrawals (
+    -- the transaction containing the withdrawal, and the index of its action
+    tx_hash                 BLOB NOT NULL,
+    action_index            BIGINT NOT NULL,
+    -- the channel and sequence number of the transfer's packet
+    source_channel          TEXT NOT NULL,
+    sequence                BIGINT NOT NULL,
+    -- the height and time, in nanoseconds since the unix epoch, at which the packet times out
+    timeout_height          BIGINT NOT NULL,
+    timeout_time            BIGINT NOT NULL,
+    -- one of 'pending', 'acknowledged', 'refunded', or 'unknown'
+    status                  TEXT NOT NULL,
+    -- null while pending, otherwise the height at which the transfer was resolved
+    height_resolved         BIGINT,
+    PRIMARY KEY (source_channel, sequence)
+);
+
+CREATE INDEX ics20_withdrawals_by_tx_idx ON ics20_withdrawals (tx_hash);
+
+-- payment requests made by this wallet, each asking to be paid at its own ephemeral address
+CREATE TABLE payment_requests (
+    -- the index of the address the request asks to be paid at
+    address_index           BLOB PRIMARY KEY NOT NULL,
+    -- the request itself, as a URI
+    uri                     TEXT NOT NULL
+);
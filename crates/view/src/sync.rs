@@ -5,10 +5,10 @@ use penumbra_chain::{
     NoteSource,
 };
 use penumbra_compact_block::{CompactBlock, StatePayload};
-use penumbra_crypto::{FullViewingKey, Note, NotePayload, Nullifier};
-use penumbra_dex::swap::{SwapPayload, SwapPlaintext};
+use penumbra_crypto::{note, FullViewingKey, Note, Nullifier};
+use penumbra_dex::swap::SwapPlaintext;
 use penumbra_tct as tct;
-use tracing::Instrument;
+use rayon::prelude::*;
 
 use crate::{SpendableNoteRecord, Storage, SwapRecord};
 
@@ -36,78 +36,98 @@ impl FilteredBlock {
     }
 }
 
+/// A compact block whose state payloads have been trial-decrypted, ready to be scanned.
+#[derive(Debug, Clone)]
+pub struct DecryptedBlock {
+    pub block: CompactBlock,
+    /// The notes in the block that were meant for us, by commitment.
+    pub notes: BTreeMap<note::StateCommitment, Note>,
+    /// The swaps in the block that were meant for us, by commitment.
+    pub swaps: BTreeMap<note::StateCommitment, SwapPlaintext>,
+}
+
+/// Trial-decrypt the state payloads of a block with our viewing key, in parallel.
+///
+/// Unlike inserting the block into the state commitment tree, this doesn't depend on having
+/// scanned the blocks before it, so it can be done well ahead of [`scan_block`].
+pub fn trial_decrypt_block(fvk: &FullViewingKey, block: CompactBlock) -> DecryptedBlock {
+    let notes = block
+        .state_payloads
+        .par_iter()
+        .filter_map(|payload| match payload {
+            StatePayload::Note { note, .. } => note.trial_decrypt(fvk),
+            _ => None,
+        })
+        .map(|note| (note.commit(), note))
+        .collect();
+    let swaps = block
+        .state_payloads
+        .par_iter()
+        .filter_map(|payload| match payload {
+            StatePayload::Swap { swap, .. } => swap.trial_decrypt(fvk),
+            _ => None,
+        })
+        .map(|swap| (swap.swap_commitment(), swap))
+        .collect();
+
+    DecryptedBlock {
+        block,
+        notes,
+        swaps,
+    }
+}
+
+/// Trial-decrypt a batch of blocks, in parallel both across and within blocks.
+///
+/// The decrypted blocks are returned in the same order as they were given.
+pub fn trial_decrypt_blocks(
+    fvk: &FullViewingKey,
+    blocks: Vec<CompactBlock>,
+) -> Vec<DecryptedBlock> {
+    blocks
+        .into_par_iter()
+        .map(|block| trial_decrypt_block(fvk, block))
+        .collect()
+}
+
 #[tracing::instrument(skip_all, fields(height = %height))]
 pub async fn scan_block(
     fvk: &FullViewingKey,
     state_commitment_tree: &mut tct::Tree,
-    CompactBlock {
-        height,
-        state_payloads,
-        nullifiers,
-        block_root,
-        epoch_root,
-        fmd_parameters,
-        swap_outputs,
-        chain_parameters,
-        // TODO: do we need this, or is there a bug in scan_block?
-        // proposal_started,
-        ..
-    }: CompactBlock,
+    DecryptedBlock {
+        block:
+            CompactBlock {
+                height,
+                state_payloads,
+                nullifiers,
+                block_root,
+                epoch_root,
+                fmd_parameters,
+                swap_outputs,
+                chain_parameters,
+                // TODO: do we need this, or is there a bug in scan_block?
+                // proposal_started,
+                ..
+            },
+        notes,
+        swaps: swap_advice,
+    }: DecryptedBlock,
     storage: &Storage,
 ) -> anyhow::Result<FilteredBlock> {
-    // Trial-decrypt a note with our own specific viewing key
-    let trial_decrypt_note = |note_payload: NotePayload| -> tokio::task::JoinHandle<Option<Note>> {
-        // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-        // need this so the task is 'static and not dependent on key lifetime
-        let fvk2 = fvk.clone();
-        tokio::spawn(
-            async move { note_payload.trial_decrypt(&fvk2) }.instrument(tracing::Span::current()),
-        )
-    };
-    // Trial-decrypt a swap with our own specific viewing key
-    let trial_decrypt_swap =
-        |swap_payload: SwapPayload| -> tokio::task::JoinHandle<Option<SwapPlaintext>> {
-            // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-            // need this so the task is 'static and not dependent on key lifetime
-            let fvk2 = fvk.clone();
-            tokio::spawn(
-                async move { swap_payload.trial_decrypt(&fvk2) }
-                    .instrument(tracing::Span::current()),
-            )
-        };
-
     // Nullifiers we've found in this block
     let spent_nullifiers: Vec<Nullifier> = nullifiers;
 
-    // Trial-decrypt the notes in this block, keeping track of the ones that were meant for us
-    let mut note_decryptions = Vec::new();
-    let mut swap_decryptions = Vec::new();
-    let mut unknown_commitments = Vec::new();
-
-    for payload in state_payloads.iter() {
-        match payload {
-            StatePayload::Note { note, .. } => {
-                note_decryptions.push(trial_decrypt_note((**note).clone()));
-            }
-            StatePayload::Swap { swap, .. } => {
-                swap_decryptions.push(trial_decrypt_swap((**swap).clone()));
-            }
-            StatePayload::RolledUp(commitment) => unknown_commitments.push(*commitment),
-        }
-    }
-    // Having started trial decryption in the background, ask the Storage for scanning advice:
+    // The notes meant for us were found by trial decryption; ask the Storage for scanning advice
+    // about the rest:
+    let unknown_commitments = state_payloads
+        .iter()
+        .filter_map(|payload| match payload {
+            StatePayload::RolledUp(commitment) => Some(*commitment),
+            _ => None,
+        })
+        .collect();
     let mut note_advice = storage.scan_advice(unknown_commitments).await?;
-    for decryption in note_decryptions {
-        if let Some(note) = decryption.await.unwrap() {
-            note_advice.insert(note.commit(), note);
-        }
-    }
-    let mut swap_advice = BTreeMap::new();
-    for decryption in swap_decryptions {
-        if let Some(swap) = decryption.await.unwrap() {
-            swap_advice.insert(swap.swap_commitment(), swap);
-        }
-    }
+    note_advice.extend(notes);
 
     // Newly detected spendable notes.
     let mut new_notes = Vec::new();
@@ -1,5 +1,7 @@
 use penumbra_transaction::{Id, Transaction, TransactionPerspective, TransactionView};
 
+use crate::Ics20WithdrawalRecord;
+
 #[derive(Debug, Clone)]
 pub struct TransactionInfo {
     // The height the transaction was included in a block, if known.
@@ -12,4 +14,6 @@ pub struct TransactionInfo {
     pub perspective: TransactionPerspective,
     // A precomputed transaction view of `transaction` from `perspective`, included for convenience of clients that don't have support for viewing transactions on their own.
     pub view: TransactionView,
+    // The status of each ICS-20 withdrawal in the transaction, in the order of their actions.
+    pub ics20_withdrawals: Vec<Ics20WithdrawalRecord>,
 }
@@ -3,40 +3,132 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use ibc_types2::core::channel::Packet;
 use penumbra_compact_block::CompactBlock;
-use penumbra_crypto::{FullViewingKey, Nullifier};
+use penumbra_crypto::{keys::AddressIndex, FullViewingKey, Nullifier};
 use penumbra_dex::lp::{position, LpNft};
+use penumbra_ibc::Ics20Withdrawal;
+use penumbra_ibc::{is_success_acknowledgement, IbcAction};
 use penumbra_proto::client::v1alpha1::specific_query_service_client::SpecificQueryServiceClient;
 use penumbra_proto::{
     self as proto,
     client::v1alpha1::{
+        detection_service_client::DetectionServiceClient,
         oblivious_query_service_client::ObliviousQueryServiceClient,
         tendermint_proxy_service_client::TendermintProxyServiceClient, CompactBlockRangeRequest,
-        GetBlockByHeightRequest,
+        CompactBlockRangeResponse, DetectedCompactBlockRangeRequest, GetBlockByHeightRequest,
+        GetBlockResultsRequest, TxResult,
     },
     DomainType,
 };
-use penumbra_transaction::Transaction;
+use penumbra_transaction::{Action, Transaction};
 use proto::client::v1alpha1::DenomMetadataByIdRequest;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use sha2::Digest;
-use tokio::sync::{watch, RwLock};
+use tokio::sync::{broadcast, watch, RwLock};
 use tonic::transport::Channel;
 use url::Url;
 
 use crate::{
-    sync::{scan_block, FilteredBlock},
-    Storage,
+    sync::{scan_block, trial_decrypt_block, DecryptedBlock, FilteredBlock},
+    Ics20WithdrawalRecord, Ics20WithdrawalStatus, PaymentRequestRecord, Storage,
 };
 
+/// Configuration for syncing from a detection server, which uses fuzzy message detection to
+/// withhold the notes of transactions that weren't meant for us, instead of scanning every note
+/// on the chain.
+///
+/// The server is given the detection keys of the first `address_count` addresses, along with those
+/// of the addresses of payment requests that could still be paid, so notes sent to other addresses,
+/// including other ephemeral ones, won't be found. Whenever a payment request is recorded, syncing
+/// starts over from the last synced height, so that the server is given the new request's key.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DetectionConfig {
+    /// The URL of the detection server.
+    pub server: Url,
+    /// The number of addresses to give the detection server the detection keys of.
+    #[serde(default = "default_address_count")]
+    pub address_count: u32,
+}
+
+fn default_address_count() -> u32 {
+    16
+}
+
+/// The most detection keys a detection server accepts in a single request.
+const MAX_DETECTION_KEYS: usize = 1024;
+
+impl DetectionConfig {
+    /// Returns the indices of the addresses to give the detection server the detection keys of, as
+    /// of the given height: the first `address_count` addresses, followed by those of the payment
+    /// requests which are neither paid nor expired.
+    ///
+    /// Fails if that's more keys than a detection server accepts.
+    pub(crate) fn address_indices(
+        &self,
+        payment_requests: impl IntoIterator<Item = PaymentRequestRecord>,
+        height: u64,
+    ) -> anyhow::Result<Vec<AddressIndex>> {
+        let payment_request_indices = payment_requests
+            .into_iter()
+            .filter(|record| record.height_paid.is_none() && !record.request.is_expired(height))
+            .map(|record| record.address_index)
+            .collect::<Vec<_>>();
+
+        let key_count = self.address_count as usize + payment_request_indices.len();
+        if key_count > MAX_DETECTION_KEYS {
+            anyhow::bail!(
+                "detecting payments to {} addresses and {} outstanding payment requests takes \
+                {key_count} detection keys, but detection servers accept at most \
+                {MAX_DETECTION_KEYS}",
+                self.address_count,
+                payment_request_indices.len(),
+            );
+        }
+
+        Ok((0..self.address_count)
+            .map(AddressIndex::from)
+            .chain(payment_request_indices)
+            .collect())
+    }
+}
+
+/// The most compact blocks to trial-decrypt at once, ahead of scanning them.
+const DECRYPTION_BATCH_SIZE: usize = 64;
+
+/// How many blocks after an ICS-20 withdrawal's packet times out to keep looking for its
+/// acknowledgement or timeout, giving relayers time to relay them.
+const ICS20_RESOLUTION_GRACE_BLOCKS: u64 = 720;
+
+/// How long after an ICS-20 withdrawal's packet times out to keep looking for its acknowledgement
+/// or timeout, in nanoseconds.
+const ICS20_RESOLUTION_GRACE_NANOS: u64 = 60 * 60 * 1_000_000_000;
+
+// decodes and trial-decrypts a batch of compact blocks in parallel, preserving their order.
+fn decrypt_batch(
+    fvk: &FullViewingKey,
+    batch: Vec<Result<CompactBlockRangeResponse, tonic::Status>>,
+) -> Vec<anyhow::Result<DecryptedBlock>> {
+    batch
+        .into_par_iter()
+        .map(|block| {
+            let block: CompactBlock = block?.try_into()?;
+            Ok(trial_decrypt_block(fvk, block))
+        })
+        .collect()
+}
+
 pub struct Worker {
     storage: Storage,
     client: ObliviousQueryServiceClient<Channel>,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
-    fvk: FullViewingKey, // TODO: notifications (see TODOs on ViewService)
+    fvk: Arc<FullViewingKey>, // TODO: notifications (see TODOs on ViewService)
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
     sync_height_tx: watch::Sender<u64>,
     tm_client: TendermintProxyServiceClient<Channel>,
     specific_client: SpecificQueryServiceClient<Channel>,
+    detection: Option<DetectionConfig>,
 }
 
 impl Worker {
@@ -49,6 +141,7 @@ impl Worker {
     pub async fn new(
         storage: Storage,
         node: Url,
+        detection: Option<DetectionConfig>,
     ) -> Result<
         (
             Self,
@@ -81,11 +174,12 @@ impl Worker {
                 storage,
                 client,
                 sct: sct.clone(),
-                fvk,
+                fvk: Arc::new(fvk),
                 error_slot: error_slot.clone(),
                 sync_height_tx,
                 tm_client,
                 specific_client,
+                detection,
             },
             sct,
             error_slot,
@@ -93,10 +187,12 @@ impl Worker {
         ))
     }
 
+    /// Downloads the transactions detected in the given block, along with their indices in the
+    /// block, which are also the indices of their results in the block's results.
     pub async fn fetch_transactions(
         &self,
         filtered_block: &FilteredBlock,
-    ) -> anyhow::Result<Vec<Transaction>> {
+    ) -> anyhow::Result<Vec<(usize, Transaction)>> {
         let inbound_transaction_ids = filtered_block.inbound_transaction_ids();
         let spent_nullifiers = filtered_block
             .spent_nullifiers
@@ -119,7 +215,14 @@ impl Worker {
 
         let mut transactions = Vec::new();
 
-        for tx_bytes in block.data.as_ref().expect("block data").txs.iter() {
+        for (index, tx_bytes) in block
+            .data
+            .as_ref()
+            .expect("block data")
+            .txs
+            .iter()
+            .enumerate()
+        {
             let tx_id: [u8; 32] = sha2::Sha256::digest(tx_bytes.as_slice())
                 .as_slice()
                 .try_into()
@@ -133,7 +236,7 @@ impl Worker {
                     .spent_nullifiers()
                     .any(|nf| spent_nullifiers.contains(&nf))
             {
-                transactions.push(transaction)
+                transactions.push((index, transaction))
             }
         }
         tracing::debug!(
@@ -145,7 +248,174 @@ impl Worker {
         Ok(transactions)
     }
 
+    /// Records this wallet's ICS-20 withdrawals in the given transactions, which were included in
+    /// the block at the given height, as pending, along with the packet sequence numbers they were
+    /// assigned, which are found in the `send_packet` events the transactions emitted.
+    ///
+    /// The events are read from the results of the whole block rather than looked up by
+    /// transaction, so that the node isn't told which transactions are ours.
+    async fn record_ics20_withdrawals(
+        &self,
+        height: u64,
+        transactions: &[(usize, Transaction)],
+    ) -> anyhow::Result<()> {
+        let mut block_results = None;
+
+        for (tx_index, transaction) in transactions {
+            let withdrawals = transaction
+                .actions()
+                .enumerate()
+                .filter_map(|(index, action)| match action {
+                    // Only track withdrawals whose refunds would come back to us.
+                    Action::Ics20Withdrawal(withdrawal)
+                        if self
+                            .fvk
+                            .incoming()
+                            .views_address(&withdrawal.return_address) =>
+                    {
+                        Some((index, withdrawal))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            if withdrawals.is_empty() {
+                continue;
+            }
+
+            if block_results.is_none() {
+                block_results =
+                    Some(fetch_block_results(&mut self.tm_client.clone(), height as i64).await?);
+            }
+            let tx_result = block_results
+                .as_ref()
+                .and_then(|results| results.get(*tx_index))
+                .ok_or_else(|| {
+                    anyhow::anyhow!("missing result for transaction {tx_index} in block {height}")
+                })?;
+
+            // A failed transaction sent no packets.
+            if tx_result.code != 0 {
+                continue;
+            }
+
+            let records = ics20_withdrawal_records(withdrawals, sent_packets(tx_result)?);
+            self.storage
+                .record_ics20_withdrawals(&transaction.id().0, records)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks the block at the given height for acknowledgements or timeouts of this wallet's
+    /// pending ICS-20 withdrawals, and records what became of them.
+    ///
+    /// Acknowledgements and timeouts are relayed in other users' transactions, which can't be
+    /// detected by scanning, so while any withdrawal is pending, every block is downloaded in full.
+    /// To bound this, a withdrawal stops being looked for a while after its packet times out, and
+    /// what became of it is recorded as unknown.
+    async fn resolve_ics20_withdrawals(&self, height: u64) -> anyhow::Result<()> {
+        let pending = self.storage.pending_ics20_withdrawals().await?;
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let block = fetch_block(&mut self.tm_client.clone(), height as i64).await?;
+        let txs = &block
+            .data
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("missing data for block {height}"))?
+            .txs;
+
+        let mut block_results = None;
+        let mut resolved_packets = BTreeSet::new();
+        for (tx_index, tx_bytes) in txs.iter().enumerate() {
+            let transaction = Transaction::decode(tx_bytes.as_slice())?;
+
+            let resolved = transaction
+                .actions()
+                .filter_map(|action| ics20_withdrawal_resolution(action, height))
+                .filter(|(packet, _)| is_pending(&pending, packet))
+                .collect::<Vec<_>>();
+            if resolved.is_empty() {
+                continue;
+            }
+
+            // A relayer's transaction can fail after it's included in a block, in which case none
+            // of its acknowledgements or timeouts were processed.
+            if block_results.is_none() {
+                block_results =
+                    Some(fetch_block_results(&mut self.tm_client.clone(), height as i64).await?);
+            }
+            let succeeded = block_results
+                .as_ref()
+                .and_then(|results| results.get(tx_index))
+                .map(|tx_result| tx_result.code == 0)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("missing result for transaction {tx_index} in block {height}")
+                })?;
+            if !succeeded {
+                tracing::debug!(
+                    tx_hash = ?hex::encode(transaction.id().0),
+                    "skipping failed transaction resolving ICS-20 withdrawals"
+                );
+                continue;
+            }
+
+            for (packet, status) in resolved {
+                let source_channel = packet.chan_on_a.to_string();
+                let sequence = u64::from(packet.sequence);
+
+                tracing::debug!(%source_channel, sequence, %status, "resolved ICS-20 withdrawal");
+                resolved_packets.insert((source_channel.clone(), sequence));
+                self.storage
+                    .resolve_ics20_withdrawal(source_channel, sequence, status)
+                    .await?;
+            }
+        }
+
+        let time = block
+            .header
+            .as_ref()
+            .and_then(|header| header.time.as_ref())
+            .ok_or_else(|| anyhow::anyhow!("missing time for block {height}"))?;
+        let time = (time.seconds as u64) * 1_000_000_000 + time.nanos as u64;
+        for withdrawal in pending {
+            let packet = (withdrawal.source_channel.clone(), withdrawal.sequence);
+            if resolved_packets.contains(&packet) || !is_timed_out(&withdrawal, height, time) {
+                continue;
+            }
+
+            tracing::debug!(
+                source_channel = %withdrawal.source_channel,
+                sequence = withdrawal.sequence,
+                "ICS-20 withdrawal timed out without being resolved"
+            );
+            self.storage
+                .resolve_ics20_withdrawal(
+                    withdrawal.source_channel,
+                    withdrawal.sequence,
+                    Ics20WithdrawalStatus::Unknown { height },
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn sync(&mut self) -> Result<(), anyhow::Error> {
+        // Syncing from a detection server starts over whenever a payment request is recorded, so
+        // that the server has the detection key of the request's address before anything can be
+        // paid to it.
+        while self.sync_until_payment_request().await? {}
+
+        Ok(())
+    }
+
+    /// Syncs until the stream of blocks ends, returning `true` if it stopped to start over because
+    /// a payment request was recorded while syncing from a detection server.
+    async fn sync_until_payment_request(&mut self) -> Result<bool, anyhow::Error> {
         // Do a single sync run, up to whatever the latest block height is
         tracing::info!("starting client sync");
 
@@ -158,17 +428,52 @@ impl Worker {
             .map(|h| h + 1)
             .unwrap_or(0);
 
-        let mut stream = self
-            .client
-            .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
-                chain_id: chain_id.clone(),
-                start_height,
-                end_height: 0,
-                // Instruct the server to keep feeding us blocks as they're created.
-                keep_alive: true,
-            }))
-            .await?
-            .into_inner();
+        // Subscribe before listing the payment requests, so that none are missed.
+        let mut recorded_payment_requests = self
+            .detection
+            .as_ref()
+            .map(|_| self.storage.subscribe_payment_requests());
+
+        let mut stream = if let Some(detection) = &self.detection {
+            tracing::info!(server = %detection.server, "syncing from detection server");
+            // Ephemeral addresses are derived from random indices, so their detection keys can't
+            // be known in advance: only the first `address_count` addresses are detected, along
+            // with the addresses of payment requests that could still be paid.
+            let detection_keys = detection
+                .address_indices(self.storage.payment_requests().await?, start_height)?
+                .into_iter()
+                .map(|index| {
+                    let (_, dtk) = self.fvk.payment_address(index);
+                    dtk.to_bytes().to_vec()
+                })
+                .collect();
+
+            DetectionServiceClient::connect(detection.server.to_string())
+                .await?
+                .detected_compact_block_range(tonic::Request::new(
+                    DetectedCompactBlockRangeRequest {
+                        chain_id: chain_id.clone(),
+                        start_height,
+                        end_height: 0,
+                        // Instruct the server to keep feeding us blocks as they're created.
+                        keep_alive: true,
+                        detection_keys,
+                    },
+                ))
+                .await?
+                .into_inner()
+        } else {
+            self.client
+                .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
+                    chain_id: chain_id.clone(),
+                    start_height,
+                    end_height: 0,
+                    // Instruct the server to keep feeding us blocks as they're created.
+                    keep_alive: true,
+                }))
+                .await?
+                .into_inner()
+        };
 
         // Spawn a task to consume items from the stream (somewhat)
         // independently of the execution of the block scanning.  This has two
@@ -184,21 +489,65 @@ impl Worker {
             }
         });
 
-        while let Some(block) = buffered_stream.recv().await {
-            let block: CompactBlock = block?.try_into()?;
+        // Spawn a second task to trial-decrypt the buffered blocks ahead of scanning them.  Trial
+        // decryption is most of the work of scanning a busy block, and unlike inserting into the
+        // SCT, it doesn't depend on having scanned the blocks before, so whatever blocks have
+        // been buffered are decrypted as a batch on the rayon thread pool, while the blocks are
+        // still scanned strictly in order below.
+        let fvk = self.fvk.clone();
+        let (decrypted_tx, mut decrypted_blocks) =
+            tokio::sync::mpsc::channel(DECRYPTION_BATCH_SIZE);
+        tokio::spawn(async move {
+            while let Some(block) = buffered_stream.recv().await {
+                let mut batch = vec![block];
+                while batch.len() < DECRYPTION_BATCH_SIZE {
+                    match buffered_stream.try_recv() {
+                        Ok(block) => batch.push(block),
+                        Err(_) => break,
+                    }
+                }
 
-            let height = block.height;
+                let fvk = fvk.clone();
+                let decrypted = tokio::task::spawn_blocking(move || decrypt_batch(&fvk, batch))
+                    .await
+                    .unwrap_or_else(|e| vec![Err(e.into())]);
+                for block in decrypted {
+                    if decrypted_tx.send(block).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        loop {
+            let block = tokio::select! {
+                // Check for new payment requests first, so that no block is scanned without the
+                // detection keys of every request recorded before it was.
+                biased;
+                _ = recorded_payment_request(&mut recorded_payment_requests) => {
+                    tracing::info!("payment request recorded, restarting sync");
+                    return Ok(true);
+                }
+                block = decrypted_blocks.recv() => match block {
+                    Some(block) => block?,
+                    None => break,
+                },
+            };
+
+            let height = block.block.height;
+
+            self.resolve_ics20_withdrawals(height).await?;
 
             // Lock the SCT only while processing this block.
             let mut sct_guard = self.sct.write().await;
 
-            if !block.requires_scanning() {
+            if !block.block.requires_scanning() {
                 // Optimization: if the block is empty, seal the in-memory SCT,
                 // and skip touching the database:
                 sct_guard.end_block().unwrap();
                 // We also need to end the epoch, since if there are no funding streams, then an
                 // epoch boundary won't necessarily require scanning:
-                if block.epoch_root.is_some() {
+                if block.block.epoch_root.is_some() {
                     sct_guard
                         .end_epoch()
                         .expect("ending the epoch must succeed");
@@ -216,7 +565,7 @@ impl Worker {
 
                 // LPNFT asset IDs won't be known to the chain, so we need to pre-populate them in the local
                 // registry based on transaction contents.
-                for transaction in &transactions {
+                for (_, transaction) in &transactions {
                     for action in transaction.actions() {
                         if let penumbra_transaction::Action::PositionOpen(position_open) = action {
                             let position_id = position_open.position.id();
@@ -252,6 +601,10 @@ impl Worker {
                     }
                 }
 
+                // Track any ICS-20 withdrawals we made, so we can tell what became of them.
+                self.record_ics20_withdrawals(filtered_block.height, &transactions)
+                    .await?;
+
                 // Record any new assets we detected.
                 for note_record in &filtered_block.new_notes {
                     // If the asset is already known, skip it.
@@ -293,6 +646,10 @@ impl Worker {
 
                 // Commit the block to the database.
 
+                let transactions = transactions
+                    .into_iter()
+                    .map(|(_, transaction)| transaction)
+                    .collect();
                 self.storage
                     .record_block(filtered_block.clone(), transactions, &mut sct_guard)
                     .await?;
@@ -308,11 +665,11 @@ impl Worker {
             // Check if we should stop waiting for blocks to arrive, because the view
             // services are dropped and we're supposed to shut down.
             if self.sync_height_tx.is_closed() {
-                return Ok(());
+                return Ok(false);
             }
         }
 
-        Ok(())
+        Ok(false)
     }
 
     pub async fn run(mut self) -> Result<(), anyhow::Error> {
@@ -332,6 +689,138 @@ impl Worker {
     }
 }
 
+// waits for a payment request to be recorded, or forever if payment requests aren't subscribed to.
+async fn recorded_payment_request(
+    recorded_payment_requests: &mut Option<broadcast::Receiver<AddressIndex>>,
+) {
+    match recorded_payment_requests {
+        // If the receiver lagged, it missed some requests, which were still recorded.
+        Some(recorded_payment_requests) => {
+            let _ = recorded_payment_requests.recv().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+// returns the packet of the ICS-20 withdrawal that the action acknowledges or times out, if any, and
+// what became of the withdrawal.
+fn ics20_withdrawal_resolution(
+    action: &Action,
+    height: u64,
+) -> Option<(&Packet, Ics20WithdrawalStatus)> {
+    match action {
+        Action::IbcAction(IbcAction::Acknowledgement(msg)) => {
+            let ack: Vec<u8> = msg.acknowledgement.clone().into();
+            Some((&msg.packet, acknowledgement_status(&ack, height)))
+        }
+        Action::IbcAction(IbcAction::Timeout(msg)) => {
+            Some((&msg.packet, Ics20WithdrawalStatus::Refunded { height }))
+        }
+        _ => None,
+    }
+}
+
+// a transfer which failed on the counterparty is refunded when its error acknowledgement is
+// processed.
+fn acknowledgement_status(ack: &[u8], height: u64) -> Ics20WithdrawalStatus {
+    if is_success_acknowledgement(ack) {
+        Ics20WithdrawalStatus::Acknowledged { height }
+    } else {
+        Ics20WithdrawalStatus::Refunded { height }
+    }
+}
+
+// returns whether the packet is the packet of one of the given pending withdrawals.
+fn is_pending(pending: &[Ics20WithdrawalRecord], packet: &Packet) -> bool {
+    let source_channel = packet.chan_on_a.to_string();
+    let sequence = u64::from(packet.sequence);
+
+    pending.iter().any(|withdrawal| {
+        withdrawal.source_channel == source_channel && withdrawal.sequence == sequence
+    })
+}
+
+// reads off the source channel and sequence number of each packet a transaction sent, in the order
+// the packets were sent, from its events.
+fn sent_packets(tx_result: &TxResult) -> anyhow::Result<Vec<(String, u64)>> {
+    // The events of the transaction are flattened into a list of tags.
+    let mut sent_packets = Vec::new();
+    let mut sequence = None;
+    for tag in &tx_result.tags {
+        match tag.key.as_slice() {
+            b"packet_sequence" => {
+                sequence = Some(std::str::from_utf8(&tag.value)?.parse::<u64>()?);
+            }
+            b"packet_src_channel" => {
+                if let Some(sequence) = sequence.take() {
+                    sent_packets.push((String::from_utf8(tag.value.clone())?, sequence));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(sent_packets)
+}
+
+// matches the ICS-20 withdrawals in a transaction, along with the indices of their actions, to the
+// packets the transaction sent, returning the records of the withdrawals whose packets were found.
+fn ics20_withdrawal_records(
+    withdrawals: Vec<(usize, &Ics20Withdrawal)>,
+    sent_packets: Vec<(String, u64)>,
+) -> Vec<Ics20WithdrawalRecord> {
+    let mut sent_packets = sent_packets.into_iter();
+    let mut records = Vec::new();
+    for (index, withdrawal) in withdrawals {
+        let source_channel = withdrawal.source_channel.to_string();
+        let Some((_, sequence)) = sent_packets.find(|(channel, _)| *channel == source_channel)
+        else {
+            tracing::warn!(index, "no packet found for ICS-20 withdrawal");
+            continue;
+        };
+
+        records.push(Ics20WithdrawalRecord {
+            action_index: index as u64,
+            source_channel,
+            sequence,
+            timeout_height: withdrawal.timeout_height,
+            timeout_time: withdrawal.timeout_time,
+            status: Ics20WithdrawalStatus::Pending,
+        });
+    }
+
+    records
+}
+
+// returns whether the withdrawal's packet timed out long enough before the block with the given
+// height and time, in nanoseconds since the unix epoch, that its acknowledgement or timeout would
+// have been relayed by now. Like a packet's, a withdrawal's timeouts are ignored when zero.
+fn is_timed_out(withdrawal: &Ics20WithdrawalRecord, height: u64, time: u64) -> bool {
+    let height_passed = withdrawal.timeout_height != 0
+        && height
+            >= withdrawal
+                .timeout_height
+                .saturating_add(ICS20_RESOLUTION_GRACE_BLOCKS);
+    let time_passed = withdrawal.timeout_time != 0
+        && time
+            >= withdrawal
+                .timeout_time
+                .saturating_add(ICS20_RESOLUTION_GRACE_NANOS);
+
+    height_passed || time_passed
+}
+
+async fn fetch_block_results(
+    client: &mut TendermintProxyServiceClient<Channel>,
+    height: i64,
+) -> anyhow::Result<Vec<TxResult>> {
+    Ok(client
+        .get_block_results(GetBlockResultsRequest { height })
+        .await?
+        .into_inner()
+        .txs_results)
+}
+
 async fn fetch_block(
     client: &mut TendermintProxyServiceClient<Channel>,
     height: i64,
@@ -380,3 +869,196 @@ async fn sct_divergence_check(
         Err(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_types2::{
+        core::{
+            channel::{ChannelId, PortId},
+            client::Height,
+        },
+        timestamp::Timestamp,
+        transfer::acknowledgement::TokenTransferAcknowledgement,
+    };
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_chain::test_keys;
+
+    use super::*;
+
+    fn packet(channel: u64, sequence: u64) -> Packet {
+        Packet {
+            sequence: sequence.into(),
+            port_on_a: PortId::transfer(),
+            chan_on_a: ChannelId::new(channel),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId::new(5),
+            data: vec![],
+            timeout_height_on_b: Height::new(0, 1_000).unwrap().into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(0).unwrap(),
+        }
+    }
+
+    fn pending(channel: u64, sequence: u64) -> Ics20WithdrawalRecord {
+        Ics20WithdrawalRecord {
+            action_index: 0,
+            source_channel: ChannelId::new(channel).to_string(),
+            sequence,
+            timeout_height: 1_000,
+            timeout_time: 0,
+            status: Ics20WithdrawalStatus::Pending,
+        }
+    }
+
+    fn tag(key: &str, value: &str) -> proto::client::v1alpha1::Tag {
+        proto::client::v1alpha1::Tag {
+            key: key.as_bytes().to_vec(),
+            value: value.as_bytes().to_vec(),
+            index: false,
+        }
+    }
+
+    #[test]
+    fn acknowledgements_resolve_withdrawals() {
+        let success: Vec<u8> = TokenTransferAcknowledgement::success().into();
+        assert_eq!(
+            acknowledgement_status(&success, 10),
+            Ics20WithdrawalStatus::Acknowledged { height: 10 }
+        );
+
+        let error: Vec<u8> = TokenTransferAcknowledgement::Error("failed".to_string()).into();
+        assert_eq!(
+            acknowledgement_status(&error, 10),
+            Ics20WithdrawalStatus::Refunded { height: 10 }
+        );
+    }
+
+    #[test]
+    fn only_pending_packets_are_resolved() {
+        let pending = vec![pending(0, 1), pending(1, 2)];
+
+        assert!(is_pending(&pending, &packet(0, 1)));
+        assert!(is_pending(&pending, &packet(1, 2)));
+        // the sequence numbers of different channels are unrelated.
+        assert!(!is_pending(&pending, &packet(0, 2)));
+        assert!(!is_pending(&pending, &packet(2, 1)));
+        assert!(!is_pending(&[], &packet(0, 1)));
+    }
+
+    #[test]
+    fn sent_packets_are_read_from_events() -> anyhow::Result<()> {
+        let tx_result = TxResult {
+            tags: vec![
+                tag("packet_sequence", "7"),
+                tag("packet_src_port", "transfer"),
+                tag("packet_src_channel", "channel-0"),
+                tag("amount", "100"),
+                tag("packet_sequence", "3"),
+                tag("packet_src_channel", "channel-1"),
+                // a channel without a sequence before it isn't a sent packet.
+                tag("packet_src_channel", "channel-2"),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sent_packets(&tx_result)?,
+            vec![("channel-0".to_string(), 7), ("channel-1".to_string(), 3)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn withdrawals_time_out_after_a_grace_period() {
+        let withdrawal = pending(0, 1);
+        let time = 1_700_000_000_000_000_000;
+
+        assert!(!is_timed_out(&withdrawal, 1_000, time));
+        assert!(!is_timed_out(
+            &withdrawal,
+            1_000 + ICS20_RESOLUTION_GRACE_BLOCKS - 1,
+            time
+        ));
+        assert!(is_timed_out(
+            &withdrawal,
+            1_000 + ICS20_RESOLUTION_GRACE_BLOCKS,
+            time
+        ));
+
+        // a withdrawal which times out by time rather than height.
+        let withdrawal = Ics20WithdrawalRecord {
+            timeout_height: 0,
+            timeout_time: time,
+            ..pending(0, 1)
+        };
+        assert!(!is_timed_out(&withdrawal, u64::MAX, time));
+        assert!(is_timed_out(
+            &withdrawal,
+            0,
+            time + ICS20_RESOLUTION_GRACE_NANOS
+        ));
+    }
+
+    fn payment_request(
+        index: u32,
+        expiry_height: Option<u64>,
+        height_paid: Option<u64>,
+    ) -> PaymentRequestRecord {
+        let address_index = AddressIndex::from(index);
+        PaymentRequestRecord {
+            request: crate::PaymentRequest {
+                address: test_keys::FULL_VIEWING_KEY.payment_address(address_index).0,
+                value: Value {
+                    amount: 1000u64.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+                memo: None,
+                expiry_height,
+            },
+            address_index,
+            amount_received: height_paid.map_or(0u64, |_| 1000).into(),
+            height_paid,
+        }
+    }
+
+    fn detection(address_count: u32) -> DetectionConfig {
+        DetectionConfig {
+            server: "http://127.0.0.1:8080".parse().unwrap(),
+            address_count,
+        }
+    }
+
+    #[test]
+    fn only_outstanding_payment_requests_are_detected() {
+        let payment_requests = vec![
+            payment_request(100, None, None),
+            payment_request(101, None, Some(8)),
+            payment_request(102, Some(5), None),
+            payment_request(103, Some(20), None),
+        ];
+
+        assert_eq!(
+            detection(2).address_indices(payment_requests, 10).unwrap(),
+            vec![0u32, 1, 100, 103]
+                .into_iter()
+                .map(AddressIndex::from)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn detection_keys_are_limited() {
+        let max = MAX_DETECTION_KEYS as u32;
+        assert_eq!(
+            detection(max).address_indices(vec![], 0).unwrap().len(),
+            MAX_DETECTION_KEYS
+        );
+        assert!(detection(max)
+            .address_indices(vec![payment_request(max, None, None)], 0)
+            .is_err());
+        // paid requests don't count towards the limit.
+        assert!(detection(max)
+            .address_indices(vec![payment_request(max, None, Some(0))], 0)
+            .is_ok());
+    }
+}
@@ -0,0 +1,301 @@
+//! Tests that view databases created by older versions of the schema are migrated to the current
+//! one when they're loaded.
+//!
+//! Each fixture in `tests/fixtures` is a `schema.sql` from an older version of the client, which
+//! is used to create a database the way that version would have, and fill it with scanned data,
+//! before loading it.
+
+use camino::Utf8PathBuf;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_chain::{params::ChainParameters, NoteSource};
+use penumbra_crypto::{
+    keys::{SeedPhrase, SpendKey},
+    Note,
+};
+use penumbra_proto::DomainType;
+use penumbra_tct as tct;
+use penumbra_transaction::Transaction;
+use penumbra_view::Storage;
+use r2d2_sqlite::rusqlite::{types::Value as SqlValue, Connection};
+use rand_core::OsRng;
+use sha2::{Digest, Sha256};
+use tempfile::TempDir;
+
+const FIXTURES: &[(u32, &str)] = &[
+    (1, include_str!("fixtures/schema_v1.sql")),
+    (2, include_str!("fixtures/schema_v2.sql")),
+    (3, include_str!("fixtures/schema_v3.sql")),
+];
+
+/// The last version of the schema from before versions were recorded in the database.
+const LAST_UNVERSIONED: u32 = 2;
+
+/// The tables holding what was scanned from the chain, which every version of the schema has, and
+/// whose rows should all survive migration unchanged.
+const SCANNED_TABLES: &[&str] = &[
+    "notes",
+    "spendable_notes",
+    "swaps",
+    "tx",
+    "tx_by_nullifier",
+    "sct_position",
+    "sct_forgotten",
+    "sct_hashes",
+    "sct_commitments",
+];
+
+// what was scanned into a fixture, to look up once it has been migrated.
+struct Scanned {
+    note: Note,
+    position: tct::Position,
+    tx_hash: Vec<u8>,
+}
+
+// creates a database with an old schema, populated as an old client would have left it.
+fn create_fixture(
+    path: &Utf8PathBuf,
+    version: u32,
+    schema: &str,
+    sync_height: i64,
+) -> anyhow::Result<Scanned> {
+    let fvk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0)
+        .full_viewing_key()
+        .clone();
+
+    let conn = Connection::open(path)?;
+    conn.execute_batch(schema)?;
+    conn.execute(
+        "INSERT INTO chain_params (bytes) VALUES (?1)",
+        [ChainParameters::default().encode_to_vec()],
+    )?;
+    conn.execute(
+        "INSERT INTO full_viewing_key (bytes) VALUES (?1)",
+        [fvk.encode_to_vec()],
+    )?;
+    conn.execute(
+        "INSERT INTO sync_height (height) VALUES (?1)",
+        [sync_height],
+    )?;
+    conn.execute(
+        "INSERT INTO schema_hash (schema_hash) VALUES (?1)",
+        [hex::encode(Sha256::digest(schema))],
+    )?;
+    conn.execute(
+        "INSERT INTO client_version (client_version) VALUES ('0.55.0')",
+        (),
+    )?;
+    if version > LAST_UNVERSIONED {
+        conn.pragma_update(None, "user_version", version)?;
+    }
+
+    // a note sent to the wallet by a transaction, which is the only commitment in the tree.
+    let (address, _) = fvk.payment_address(0.into());
+    let note = Note::generate(
+        &mut OsRng,
+        &address,
+        Value {
+            amount: 100u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        },
+    );
+    let commitment = note.commit().0.to_bytes().to_vec();
+    let position = tct::Position::from(0u64);
+    let nullifier = fvk
+        .derive_nullifier(position, &note.commit())
+        .to_bytes()
+        .to_vec();
+    let transaction = Transaction::default();
+    let tx_bytes = transaction.encode_to_vec();
+    let tx_hash = Sha256::digest(&tx_bytes).to_vec();
+    let source = NoteSource::Transaction {
+        id: tx_hash.as_slice().try_into()?,
+    }
+    .to_bytes()
+    .to_vec();
+
+    conn.execute(
+        "INSERT INTO notes (note_commitment, address, amount, asset_id, rseed)
+            VALUES (?1, ?2, ?3, ?4, ?5)",
+        (
+            &commitment,
+            note.address().to_vec(),
+            u128::from(note.amount()).to_be_bytes().to_vec(),
+            note.asset_id().to_bytes().to_vec(),
+            note.rseed().to_bytes().to_vec(),
+        ),
+    )?;
+    conn.execute(
+        "INSERT INTO spendable_notes
+            (note_commitment, nullifier, position, height_created, address_index, source, height_spent)
+            VALUES (?1, ?2, 0, ?3, ?4, ?5, NULL)",
+        (
+            &commitment,
+            &nullifier,
+            sync_height,
+            fvk.address_index(&address)
+                .expect("address is the wallet's")
+                .to_bytes()
+                .to_vec(),
+            &source,
+        ),
+    )?;
+    conn.execute(
+        "INSERT INTO tx (tx_hash, tx_bytes, block_height) VALUES (?1, ?2, ?3)",
+        (&tx_hash, &tx_bytes, sync_height),
+    )?;
+    conn.execute(
+        "INSERT INTO tx_by_nullifier (nullifier, tx_hash) VALUES (?1, ?2)",
+        (&nullifier, &tx_hash),
+    )?;
+    conn.execute("UPDATE sct_position SET position = 1", ())?;
+    conn.execute(
+        "INSERT INTO sct_commitments (position, commitment) VALUES (0, ?1)",
+        [&commitment],
+    )?;
+    conn.execute(
+        "INSERT INTO sct_hashes (position, height, hash) VALUES (0, 24, ?1)",
+        [vec![1u8; 32]],
+    )?;
+    // swaps are only compared row by row, so their contents needn't decode.
+    conn.execute(
+        "INSERT INTO swaps (swap_commitment, swap, position, nullifier, output_data, height_claimed, source)
+            VALUES (?1, ?2, 1, ?3, ?4, NULL, ?5)",
+        (
+            vec![2u8; 32],
+            vec![3u8; 16],
+            vec![4u8; 32],
+            vec![5u8; 16],
+            &source,
+        ),
+    )?;
+
+    Ok(Scanned {
+        note,
+        position,
+        tx_hash,
+    })
+}
+
+// the rows of the table, in a consistent order.
+fn rows(path: &Utf8PathBuf, table: &str) -> anyhow::Result<Vec<Vec<SqlValue>>> {
+    let conn = Connection::open(path)?;
+    let mut statement = conn.prepare(&format!("SELECT * FROM {table} ORDER BY 1"))?;
+    let columns = statement.column_count();
+    let rows = statement
+        .query_map((), |row| (0..columns).map(|i| row.get(i)).collect())?
+        .collect::<Result<_, _>>()?;
+
+    Ok(rows)
+}
+
+// the schema of the database, as the (whitespace-normalized) statements which created it.
+fn schema(path: &Utf8PathBuf) -> anyhow::Result<Vec<(String, String)>> {
+    let conn = Connection::open(path)?;
+    let mut statement =
+        conn.prepare("SELECT name, sql FROM sqlite_master WHERE sql IS NOT NULL ORDER BY name")?;
+    let schema = statement
+        .query_map((), |row| {
+            let name: String = row.get("name")?;
+            let sql: String = row.get("sql")?;
+            Ok((name, sql.split_whitespace().collect::<Vec<_>>().join(" ")))
+        })?
+        .collect::<Result<_, _>>()?;
+
+    Ok(schema)
+}
+
+fn user_version(path: &Utf8PathBuf) -> anyhow::Result<u32> {
+    Ok(Connection::open(path)?.pragma_query_value(None, "user_version", |row| row.get(0))?)
+}
+
+#[tokio::test]
+async fn fixtures_are_migrated_to_the_current_schema() -> anyhow::Result<()> {
+    let dir = TempDir::new()?;
+    let dir = Utf8PathBuf::from_path_buf(dir.path().to_owned()).expect("temp dir is utf-8");
+
+    // a freshly initialized database, to compare the migrated ones against.
+    let fresh = dir.join("fresh.sqlite");
+    let fvk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0)
+        .full_viewing_key()
+        .clone();
+    Storage::initialize(Some(&fresh), fvk, ChainParameters::default()).await?;
+    let current_schema = schema(&fresh)?;
+    let current_version = user_version(&fresh)?;
+
+    for (version, fixture) in FIXTURES {
+        let path = dir.join(format!("v{version}.sqlite"));
+        let scanned = create_fixture(&path, *version, fixture, 41)?;
+        let scanned_rows = SCANNED_TABLES
+            .iter()
+            .map(|table| rows(&path, table))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let storage = Storage::load(&path).await?;
+        assert_eq!(
+            storage.last_sync_height().await?,
+            Some(41),
+            "data is kept when migrating from version {version}"
+        );
+        storage.full_viewing_key().await?;
+        storage.chain_params().await?;
+
+        let record = storage
+            .note_by_commitment(scanned.note.commit(), false)
+            .await?;
+        assert_eq!(record.note, scanned.note);
+        assert_eq!(record.position, scanned.position);
+        assert_eq!(record.height_created, 41);
+        assert_eq!(
+            storage
+                .transaction_by_hash(&scanned.tx_hash)
+                .await?
+                .map(|(height, transaction)| (height, transaction.encode_to_vec())),
+            Some((41, Transaction::default().encode_to_vec())),
+            "transactions are kept when migrating from version {version}"
+        );
+        drop(storage);
+
+        for (table, scanned_rows) in SCANNED_TABLES.iter().zip(scanned_rows) {
+            assert_eq!(
+                rows(&path, table)?,
+                scanned_rows,
+                "{table} is kept when migrating from version {version}"
+            );
+        }
+
+        assert_eq!(
+            schema(&path)?,
+            current_schema,
+            "migrating from version {version} results in the current schema"
+        );
+        assert_eq!(user_version(&path)?, current_version);
+
+        // loading a database which is already up to date doesn't change it.
+        Storage::load(&path).await?;
+        assert_eq!(schema(&path)?, current_schema);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unknown_and_newer_schemas_are_refused() -> anyhow::Result<()> {
+    let dir = TempDir::new()?;
+    let dir = Utf8PathBuf::from_path_buf(dir.path().to_owned()).expect("temp dir is utf-8");
+
+    // a database created by an unreleased schema, which there's no migration from.
+    let unknown = dir.join("unknown.sqlite");
+    let (version, schema) = FIXTURES[0];
+    create_fixture(&unknown, version, schema, 41)?;
+    Connection::open(&unknown)?.execute("UPDATE schema_hash SET schema_hash = 'unknown'", ())?;
+    assert!(Storage::load(&unknown).await.is_err());
+
+    // a database created by a newer client.
+    let newer = dir.join("newer.sqlite");
+    let (version, current_schema) = FIXTURES.last().expect("there is a fixture");
+    create_fixture(&newer, *version, current_schema, 41)?;
+    Connection::open(&newer)?.pragma_update(None, "user_version", 1000)?;
+    assert!(Storage::load(&newer).await.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+//! Property tests for the planner's note selection strategies: whatever the strategy, the notes it
+//! selects must cover the amount required, and the plans built from them must balance.
+
+use once_cell::sync::Lazy;
+use penumbra_asset::{Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_chain::{
+    params::{ChainParameters, FmdParameters},
+    NoteSource,
+};
+use penumbra_crypto::{
+    keys::{AddressIndex, SeedPhrase, SpendKey},
+    FullViewingKey, Note,
+};
+use penumbra_num::Amount;
+use penumbra_view::{Planner, SelectionStrategy, SpendableNoteRecord};
+use proptest::prelude::*;
+use rand_core::OsRng;
+
+static FVK: Lazy<FullViewingKey> = Lazy::new(|| {
+    SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0)
+        .full_viewing_key()
+        .clone()
+});
+
+/// A spendable note of the staking token, received at the address with the given randomizer.
+fn record(amount: u64, address: u8, height: u64, position: u64) -> SpendableNoteRecord {
+    let address_index = AddressIndex {
+        account: 0,
+        randomizer: [address; 12],
+    };
+    let note = Note::generate(
+        &mut OsRng,
+        &FVK.payment_address(address_index).0,
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        },
+    );
+    let position = position.into();
+    SpendableNoteRecord {
+        note_commitment: note.commit(),
+        nullifier: FVK.derive_nullifier(position, &note.commit()),
+        note,
+        address_index,
+        height_created: height,
+        height_spent: None,
+        position,
+        source: NoteSource::default(),
+    }
+}
+
+fn records(notes: &[(u64, u8, u64)]) -> Vec<SpendableNoteRecord> {
+    notes
+        .iter()
+        .enumerate()
+        .map(|(i, &(amount, address, height))| record(amount, address, height, i as u64))
+        .collect()
+}
+
+fn total(records: &[SpendableNoteRecord]) -> Amount {
+    records
+        .iter()
+        .fold(Amount::zero(), |total, record| total + record.note.amount())
+}
+
+fn strategy() -> impl Strategy<Value = SelectionStrategy> {
+    prop_oneof![
+        Just(SelectionStrategy::OldestFirst),
+        Just(SelectionStrategy::MinimizeNotes),
+        Just(SelectionStrategy::AvoidMixingAddresses),
+    ]
+}
+
+fn notes() -> impl Strategy<Value = Vec<(u64, u8, u64)>> {
+    prop::collection::vec((1..1_000u64, 0..4u8, 0..100u64), 1..12)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn selection_covers_amount(
+        strategy in strategy(),
+        notes in notes(),
+        fraction in 0.0..=1.0f64,
+    ) {
+        let candidates = records(&notes);
+        let available = total(&candidates);
+        let amount = Amount::from(((u128::from(available) as f64 * fraction) as u64).max(1));
+
+        let selected = strategy.select(amount, candidates.clone()).unwrap();
+
+        // Every selected note is one of the candidates, and none is selected twice.
+        for record in &selected {
+            prop_assert!(candidates
+                .iter()
+                .any(|c| c.note_commitment == record.note_commitment));
+        }
+        let mut positions = selected.iter().map(|r| r.position).collect::<Vec<_>>();
+        positions.sort();
+        positions.dedup();
+        prop_assert_eq!(positions.len(), selected.len());
+
+        // The selected notes cover the amount, and the last one was needed to do so.
+        prop_assert!(total(&selected) >= amount);
+        prop_assert!(total(&selected[..selected.len() - 1]) < amount);
+    }
+
+    #[test]
+    fn selection_fails_without_enough_funds(strategy in strategy(), notes in notes()) {
+        let candidates = records(&notes);
+        let amount = total(&candidates) + Amount::from(1u64);
+
+        prop_assert!(strategy.select(amount, candidates).is_err());
+    }
+
+    #[test]
+    fn selection_avoids_mixing_addresses(notes in notes(), fraction in 0.0..=1.0f64) {
+        let candidates = records(&notes);
+        let largest_address_total = (0..4u8)
+            .map(|address| {
+                total(
+                    &candidates
+                        .iter()
+                        .filter(|r| r.address_index.randomizer[0] == address)
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .max()
+            .unwrap();
+        let amount =
+            Amount::from(((u128::from(largest_address_total) as f64 * fraction) as u64).max(1));
+
+        let selected = SelectionStrategy::AvoidMixingAddresses
+            .select(amount, candidates)
+            .unwrap();
+
+        // Since a single address can cover the amount, only one address should be spent from.
+        prop_assert!(selected
+            .iter()
+            .all(|r| r.address_index == selected[0].address_index));
+    }
+
+    #[test]
+    fn planned_transactions_balance(
+        strategy in strategy(),
+        uniform_arity in any::<bool>(),
+        notes in notes(),
+        outputs in prop::collection::vec(1..500u64, 1..4),
+    ) {
+        let candidates = records(&notes);
+        prop_assume!(u128::from(total(&candidates)) >= outputs.iter().map(|&o| o as u128).sum());
+
+        let self_address = FVK.payment_address(0u32.into()).0;
+        let mut planner = Planner::new(OsRng);
+        planner
+            .selection_strategy(strategy)
+            .uniform_arity(uniform_arity);
+        for &amount in &outputs {
+            planner.output(
+                Value {
+                    amount: amount.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+                self_address,
+            );
+        }
+        let plan = planner
+            .plan_with_spendable_and_votable_notes(
+                &ChainParameters::default(),
+                &FmdParameters::default(),
+                candidates,
+                Vec::new(),
+                self_address,
+            )
+            .unwrap();
+
+        let balance = plan
+            .actions
+            .iter()
+            .fold(Balance::zero(), |balance, action| balance + action.balance());
+        prop_assert!(balance.is_zero());
+
+        if uniform_arity {
+            let num_spends = plan.spend_plans().count();
+            prop_assert_eq!(num_spends, plan.num_outputs());
+            prop_assert!(num_spends.is_power_of_two());
+        }
+    }
+}
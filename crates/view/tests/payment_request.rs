@@ -0,0 +1,72 @@
+//! Tests that payment requests survive being encoded as URIs and parsed back.
+
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_crypto::keys::{AddressIndex, SeedPhrase, SpendKey};
+use penumbra_view::PaymentRequest;
+use rand_core::OsRng;
+
+fn request(memo: Option<&str>, expiry_height: Option<u64>) -> PaymentRequest {
+    let fvk = SpendKey::from_seed_phrase(SeedPhrase::generate(OsRng), 0)
+        .full_viewing_key()
+        .clone();
+
+    PaymentRequest {
+        address: fvk.payment_address(AddressIndex::new_ephemeral(0, OsRng)).0,
+        value: Value {
+            amount: 1_870_000u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        },
+        memo: memo.map(ToString::to_string),
+        expiry_height,
+    }
+}
+
+#[test]
+fn uri_roundtrip() -> anyhow::Result<()> {
+    for request in [
+        request(None, None),
+        request(Some("invoice 42"), None),
+        request(Some("a&b=c?d#e %20 ünïcode"), Some(1000)),
+    ] {
+        let uri = request.to_string();
+        assert!(uri.starts_with("penumbra:penumbrav2t"));
+        assert_eq!(uri.parse::<PaymentRequest>()?, request);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn unknown_parameters_are_ignored() -> anyhow::Result<()> {
+    let request = request(Some("invoice 42"), Some(1000));
+    let uri = format!("{request}&label=coffee");
+
+    assert_eq!(uri.parse::<PaymentRequest>()?, request);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_uris_are_rejected() {
+    let request = request(None, None);
+    let uri = request.to_string();
+
+    assert!(uri
+        .replacen("penumbra:", "bitcoin:", 1)
+        .parse::<PaymentRequest>()
+        .is_err());
+    assert!(format!("penumbra:{}", request.address)
+        .parse::<PaymentRequest>()
+        .is_err());
+    assert!(uri
+        .replacen("amount=", "amount=-", 1)
+        .parse::<PaymentRequest>()
+        .is_err());
+}
+
+#[test]
+fn expiry() {
+    assert!(!request(None, None).is_expired(u64::MAX));
+    assert!(!request(None, Some(1000)).is_expired(1000));
+    assert!(request(None, Some(1000)).is_expired(1001));
+}
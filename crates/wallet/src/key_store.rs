@@ -9,6 +9,12 @@ use serde_with::{serde_as, DisplayFromStr};
 pub struct KeyStore {
     #[serde_as(as = "DisplayFromStr")]
     pub spend_key: SpendKey,
+    /// The block height at which the wallet was created, if known.
+    ///
+    /// No notes can have been sent to the wallet before this height, so a new view database can
+    /// skip syncing the blocks before it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birthday: Option<u64>,
 }
 
 impl KeyStore {
@@ -48,6 +54,9 @@ impl KeyStore {
         // we can derive multiple spend seeds from a single seed phrase.
         let spend_key = SpendKey::from_seed_phrase(seed_phrase, 0);
 
-        Self { spend_key }
+        Self {
+            spend_key,
+            birthday: None,
+        }
     }
 }
@@ -17,7 +17,7 @@ use penumbra_shielded_pool::{OutputPlan, SpendPlan};
 use penumbra_stake::{IdentityKey, UndelegateClaimPlan};
 use penumbra_tct as tct;
 use penumbra_transaction::{
-    action::{Proposal, ProposalSubmit, ProposalWithdraw, ValidatorVote, Vote},
+    action::{Proposal, ProposalSubmit, ProposalWithdraw, SplitVote, ValidatorVote, Vote},
     memo::MemoPlaintext,
     plan::{ActionPlan, DelegatorVotePlan, MemoPlan, TransactionPlan},
 };
@@ -309,7 +309,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         &mut self,
         proposal: u64,
         start_position: tct::Position,
-        vote: Vote,
+        vote: impl Into<SplitVote>,
         note: Note,
         position: tct::Position,
         unbonded_amount: Amount,
@@ -318,7 +318,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             &mut self.rng,
             proposal,
             start_position,
-            vote,
+            vote.into(),
             note,
             position,
             unbonded_amount,